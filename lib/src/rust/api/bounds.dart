@@ -0,0 +1,132 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'gcode.dart';
+import 'machine_settings.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'toolpath.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `active_work_offset`, `check_job_lines`, `check_offset_changes`, `describe_violation`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            Future<ProgramExtents?>  programExtents({required List<ToolpathSegment> segments }) => RustLib.instance.api.crateApiBoundsProgramExtents(segments: segments);
+
+Future<BoundsReport>  checkSegments({required List<ToolpathSegment> segments , required TravelLimits limits , required Pos2D workOffset , required List<int> cutStartLines }) => RustLib.instance.api.crateApiBoundsCheckSegments(segments: segments, limits: limits, workOffset: workOffset, cutStartLines: cutStartLines);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BoundsViolation>>
+                abstract class BoundsViolation implements RustOpaqueInterface {
+                     int? get cut;
+
+
+ int get line;
+
+
+ String get message;
+
+
+ Pos2D get position;
+
+
+  set cut(int? cut);
+
+
+  set line(int line);
+
+
+  set message(String message);
+
+
+  set position(Pos2D position);
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ProgramExtents>>
+                abstract class ProgramExtents implements RustOpaqueInterface {
+                     Pos2D get max;
+
+
+ Pos2D get min;
+
+
+  set max(Pos2D max);
+
+
+  set min(Pos2D min);
+
+
+
+                    
+                }
+                
+
+class BoundsReport  {
+                final ProgramExtents? extents;
+final List<BoundsViolation> violations;
+
+                const BoundsReport({this.extents ,required this.violations ,});
+
+                static Future<BoundsReport>  default_()=>RustLib.instance.api.crateApiBoundsBoundsReportDefault();
+
+
+ Future<bool>  isWithinLimits()=>RustLib.instance.api.crateApiBoundsBoundsReportIsWithinLimits(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => extents.hashCode^violations.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BoundsReport &&
+                runtimeType == other.runtimeType
+                && extents == other.extents&& violations == other.violations;
+        
+            }
+
+class TravelLimits  {
+                final double xMin;
+final double xMax;
+final double yMin;
+final double yMax;
+
+                const TravelLimits({required this.xMin ,required this.xMax ,required this.yMin ,required this.yMax ,});
+
+                 Future<bool>  contains({required Pos2D position })=>RustLib.instance.api.crateApiBoundsTravelLimitsContains(that: this, position: position);
+
+
+static TravelLimits?  fromMachineSettings({required MachineSettings machineSettings })=>RustLib.instance.api.crateApiBoundsTravelLimitsFromMachineSettings(machineSettings: machineSettings);
+
+
+                
+
+                
+        @override
+        int get hashCode => xMin.hashCode^xMax.hashCode^yMin.hashCode^yMax.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TravelLimits &&
+                runtimeType == other.runtimeType
+                && xMin == other.xMin&& xMax == other.xMax&& yMin == other.yMin&& yMax == other.yMax;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `add_extended_option`, `apply_report_line`
+// These functions are ignored because they have generic arguments: `check_program`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            
+
+            class MachineCapabilities  {
+                final String? version;
+final String? firmware;
+final String options;
+final int? plannerBlocks;
+final int? rxBufferSize;
+final String axes;
+final List<String> extendedOptions;
+
+                const MachineCapabilities({this.version ,this.firmware ,required this.options ,this.plannerBlocks ,this.rxBufferSize ,required this.axes ,required this.extendedOptions ,});
+
+                static Future<MachineCapabilities>  default_()=>RustLib.instance.api.crateApiCapabilitiesMachineCapabilitiesDefault();
+
+
+ Future<bool>  hasAxis({required String axis })=>RustLib.instance.api.crateApiCapabilitiesMachineCapabilitiesHasAxis(that: this, axis: axis);
+
+
+ Future<bool>  hasMistCoolant()=>RustLib.instance.api.crateApiCapabilitiesMachineCapabilitiesHasMistCoolant(that: this, );
+
+
+ Future<bool>  hasProbe()=>RustLib.instance.api.crateApiCapabilitiesMachineCapabilitiesHasProbe(that: this, );
+
+
+ Future<bool>  hasRotaryAxis()=>RustLib.instance.api.crateApiCapabilitiesMachineCapabilitiesHasRotaryAxis(that: this, );
+
+
+ Future<bool>  hasThc()=>RustLib.instance.api.crateApiCapabilitiesMachineCapabilitiesHasThc(that: this, );
+
+
+ Future<bool>  hasVariableSpindle()=>RustLib.instance.api.crateApiCapabilitiesMachineCapabilitiesHasVariableSpindle(that: this, );
+
+
+ Future<bool>  isGrblhal()=>RustLib.instance.api.crateApiCapabilitiesMachineCapabilitiesIsGrblhal(that: this, );
+
+
+ Future<bool>  isKnown()=>RustLib.instance.api.crateApiCapabilitiesMachineCapabilitiesIsKnown(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => version.hashCode^firmware.hashCode^options.hashCode^plannerBlocks.hashCode^rxBufferSize.hashCode^axes.hashCode^extendedOptions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MachineCapabilities &&
+                runtimeType == other.runtimeType
+                && version == other.version&& firmware == other.firmware&& options == other.options&& plannerBlocks == other.plannerBlocks&& rxBufferSize == other.rxBufferSize&& axes == other.axes&& extendedOptions == other.extendedOptions;
+        
+            }
+            
\ No newline at end of file
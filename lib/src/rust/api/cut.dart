@@ -7,47 +7,80 @@ import '../frb_generated.dart';
 import 'gcode.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Cut>>
-abstract class Cut implements RustOpaqueInterface {
-  double get cutAngle;
 
-  double get cutFeedrate;
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
 
-  Pos2D get endPosition;
 
-  double get pierceDelay;
+            
 
-  double get pierceDelay2;
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Cut>>
+                abstract class Cut implements RustOpaqueInterface {
+                     double get cutAngle;
 
-  Pos2D get startPosition;
 
-  double get tubeWidth;
+ double get cutFeedrate;
+
+
+ Pos2D get endPosition;
+
+
+ double get pierceDelay;
+
+
+ double get pierceDelay2;
+
+
+ Pos2D get startPosition;
+
+
+ double get tubeWidth;
+
 
   set cutAngle(double cutAngle);
 
+
   set cutFeedrate(double cutFeedrate);
 
+
   set endPosition(Pos2D endPosition);
 
+
   set pierceDelay(double pierceDelay);
 
+
   set pierceDelay2(double pierceDelay2);
 
+
   set startPosition(Pos2D startPosition);
 
+
   set tubeWidth(double tubeWidth);
 
-  (double, double) getEndPos();
 
-  factory Cut() => RustLib.instance.api.crateApiCutCutNew();
+ (double,double)  getEndPos();
+
+
+factory Cut()=>RustLib.instance.api.crateApiCutCutNew();
+
+
+ void  setCutAngle({required double newAngle });
+
+
+ void  setCutFeedrate({required double newFeedrate });
+
+
+ void  setPierceDelay({required double newDelay });
+
+
+ void  setPierceDelay2({required double newDelay });
 
-  void setCutAngle({required double newAngle});
 
-  void setCutFeedrate({required double newFeedrate});
+ void  setTubeWidth({required double newWidth });
 
-  void setPierceDelay({required double newDelay});
 
-  void setPierceDelay2({required double newDelay});
 
-  void setTubeWidth({required double newWidth});
-}
+                    
+                }
+                
+            
\ No newline at end of file
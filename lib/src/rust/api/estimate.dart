@@ -0,0 +1,107 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'machine_settings.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'toolpath.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `along`, `move_time`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            Future<JobEstimate>  estimateSegments({required List<ToolpathSegment> segments , required MotionLimits limits , required ConsumableRatings ratings }) => RustLib.instance.api.crateApiEstimateEstimateSegments(segments: segments, limits: limits, ratings: ratings);
+
+            class ConsumableRatings  {
+                final int pierces;
+final double arcTime;
+
+                const ConsumableRatings({required this.pierces ,required this.arcTime ,});
+
+                static Future<ConsumableRatings>  default_()=>RustLib.instance.api.crateApiEstimateConsumableRatingsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => pierces.hashCode^arcTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConsumableRatings &&
+                runtimeType == other.runtimeType
+                && pierces == other.pierces&& arcTime == other.arcTime;
+        
+            }
+
+class JobEstimate  {
+                final double totalTime;
+final double cutTime;
+final double rapidTime;
+final double dwellTime;
+final double cutLength;
+final double rapidLength;
+final int pierceCount;
+final double arcTime;
+final double consumableWear;
+
+                const JobEstimate({required this.totalTime ,required this.cutTime ,required this.rapidTime ,required this.dwellTime ,required this.cutLength ,required this.rapidLength ,required this.pierceCount ,required this.arcTime ,required this.consumableWear ,});
+
+                static Future<JobEstimate>  default_()=>RustLib.instance.api.crateApiEstimateJobEstimateDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => totalTime.hashCode^cutTime.hashCode^rapidTime.hashCode^dwellTime.hashCode^cutLength.hashCode^rapidLength.hashCode^pierceCount.hashCode^arcTime.hashCode^consumableWear.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JobEstimate &&
+                runtimeType == other.runtimeType
+                && totalTime == other.totalTime&& cutTime == other.cutTime&& rapidTime == other.rapidTime&& dwellTime == other.dwellTime&& cutLength == other.cutLength&& rapidLength == other.rapidLength&& pierceCount == other.pierceCount&& arcTime == other.arcTime&& consumableWear == other.consumableWear;
+        
+            }
+
+class MotionLimits  {
+                final double xMaxRate;
+final double yMaxRate;
+final double xAcceleration;
+final double yAcceleration;
+
+                const MotionLimits({required this.xMaxRate ,required this.yMaxRate ,required this.xAcceleration ,required this.yAcceleration ,});
+
+                static Future<MotionLimits>  default_()=>RustLib.instance.api.crateApiEstimateMotionLimitsDefault();
+
+
+static MotionLimits  fromMachineSettings({required MachineSettings machineSettings })=>RustLib.instance.api.crateApiEstimateMotionLimitsFromMachineSettings(machineSettings: machineSettings);
+
+
+                
+
+                
+        @override
+        int get hashCode => xMaxRate.hashCode^yMaxRate.hashCode^xAcceleration.hashCode^yAcceleration.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MotionLimits &&
+                runtimeType == other.runtimeType
+                && xMaxRate == other.xMaxRate&& yMaxRate == other.yMaxRate&& xAcceleration == other.xAcceleration&& yAcceleration == other.yAcceleration;
+        
+            }
+            
\ No newline at end of file
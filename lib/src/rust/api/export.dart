@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'job_plan.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `comment`, `format_date`, `header_block`, `write_atomically`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            String  exportPath({required String path , required PostProcessor postProcessor }) => RustLib.instance.api.crateApiExportExportPath(path: path, postProcessor: postProcessor);
+
+            class ExportHeader  {
+                final String jobName;
+final Stock? stock;
+
+                const ExportHeader({required this.jobName ,this.stock ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => jobName.hashCode^stock.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExportHeader &&
+                runtimeType == other.runtimeType
+                && jobName == other.jobName&& stock == other.stock;
+        
+            }
+
+enum PostProcessor {
+                    grbl,
+mach3,
+linuxCnc,
+                    ;
+                     String  extension_()=>RustLib.instance.api.crateApiExportPostProcessorExtension(that: this, );
+
+
+                }
+            
\ No newline at end of file
@@ -4,96 +4,167 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'bounds.dart';
+import 'capabilities.dart';
 import 'cut.dart';
+import 'estimate.dart';
+import 'export.dart';
+import 'job_plan.dart';
+import 'lint.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'profile.dart';
+import 'render.dart';
 import 'settings.dart';
+import 'toolpath.dart';
+import 'wcs.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `begin_cut`, `cut_start_lines`, `set_units_to_mm`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            Future<Pos2D>  calculateEndPos({required Pos2D startPos , required double tubeWidth , required double cutAngle , required double overshootAmount , required bool cutRight }) => RustLib.instance.api.crateApiGcodeCalculateEndPos(startPos: startPos, tubeWidth: tubeWidth, cutAngle: cutAngle, overshootAmount: overshootAmount, cutRight: cutRight);
+
+Future<Pos2D>  getMidpoint({required Pos2D startPos , required Pos2D endPos }) => RustLib.instance.api.crateApiGcodeGetMidpoint(startPos: startPos, endPos: endPos);
+
+String  jog({required double xDist , required double yDist , required CutterSettings cutterSettings }) => RustLib.instance.api.crateApiGcodeJog(xDist: xDist, yDist: yDist, cutterSettings: cutterSettings);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Gcode>>
+                abstract class Gcode implements RustOpaqueInterface {
+                     Future<void>  addCommand({required String gCommand });
+
+
+ Future<void>  addCommandComment({required String gCommand , required String gComment });
+
+
+ void  addCut({required Cut tubeCut , required CutterSettings cutterSettings });
+
+
+ void  addProfile({required Profile profile , required CutParameters parameters });
+
+
+ Future<void>  arcXy({required Pos2D newPos , required Pos2D centerOffset , required bool clockwise , required double feedrate });
+
+
+ String get gcodeString;
 
-// These functions are ignored because they are not marked as `pub`: `set_units_to_mm`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DistUnit`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`
-
-Future<Pos2D> calculateEndPos({
-  required Pos2D startPos,
-  required double tubeWidth,
-  required double cutAngle,
-  required double overshootAmount,
-  required bool cutRight,
-}) => RustLib.instance.api.crateApiGcodeCalculateEndPos(
-  startPos: startPos,
-  tubeWidth: tubeWidth,
-  cutAngle: cutAngle,
-  overshootAmount: overshootAmount,
-  cutRight: cutRight,
-);
-
-Future<Pos2D> getMidpoint({required Pos2D startPos, required Pos2D endPos}) =>
-    RustLib.instance.api.crateApiGcodeGetMidpoint(
-      startPos: startPos,
-      endPos: endPos,
-    );
-
-String jog({
-  required double xDist,
-  required double yDist,
-  required CutterSettings cutterSettings,
-}) => RustLib.instance.api.crateApiGcodeJog(
-  xDist: xDist,
-  yDist: yDist,
-  cutterSettings: cutterSettings,
-);
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Gcode>>
-abstract class Gcode implements RustOpaqueInterface {
-  Future<void> addCommand({required String gCommand});
-
-  Future<void> addCommandComment({
-    required String gCommand,
-    required String gComment,
-  });
-
-  void addCut({required Cut tubeCut, required CutterSettings cutterSettings});
-
-  String get gcodeString;
 
   set gcodeString(String gcodeString);
 
-  Future<void> dwell({required double dwellTime});
 
-  String getGcodeString();
+ BoundsReport  checkBounds({required TravelLimits limits , required Pos2D workOffset });
+
+
+ Future<void>  checkCapabilities({required MachineCapabilities capabilities });
+
+
+ Future<void>  dwell({required double dwellTime });
+
+
+ JobEstimate  estimate({required MotionLimits limits , required ConsumableRatings ratings });
+
+
+ String  export_({required String path , required PostProcessor postProcessor , ExportHeader? header , required CutterSettings cutterSettings });
+
+
+ int  getCutCount();
+
+
+ int?  getCutStartLine({required int cutIndex });
+
+
+ ProgramExtents?  getExtents();
+
+
+ String  getGcodeString();
+
 
-  Future<void> home2D({required double feedrate});
+ List<ToolpathSegment>  getToolpath();
 
-  Future<void> moveXy({required Pos2D newPos, required double feedrate});
 
-  factory Gcode() => RustLib.instance.api.crateApiGcodeGcodeNew();
+ Future<void>  home2D({required double feedrate });
 
-  Future<void> setPlasmaEnabled({required bool enabled});
 
-  Future<void> setPositioningMode({required PositioningMode positioningMode});
+ Future<int>  lineCount();
 
-  Future<void> setZero();
 
-  Future<void> writeToFile({required String filename});
-}
+ List<LintDiagnostic>  lint({required LintOptions options });
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Pos2D>>
-abstract class Pos2D implements RustOpaqueInterface {
-  double get x;
 
-  double get y;
+ Future<void>  moveXy({required Pos2D newPos , required double feedrate });
+
+
+factory Gcode()=>RustLib.instance.api.crateApiGcodeGcodeNew();
+
+
+ Uint8List  renderPng({required RenderOptions options });
+
+
+ String  renderSvg({required RenderOptions options });
+
+
+ Future<void>  setPlasmaEnabled({required bool enabled });
+
+
+ Future<void>  setPositioningMode({required PositioningMode positioningMode });
+
+
+ Future<void>  setWorkCoordinateSystem({required WorkCoordinateSystem wcs });
+
+
+ Future<void>  setZero();
+
+
+ void  useCapabilities({required MachineCapabilities capabilities });
+
+
+ Future<void>  writeToFile({required String filename });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Pos2D>>
+                abstract class Pos2D implements RustOpaqueInterface {
+                     double get x;
+
+
+ double get y;
+
 
   set x(double x);
 
+
   set y(double y);
 
+
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<Pos2D> newInstance({required double x, required double y}) =>
-      RustLib.instance.api.crateApiGcodePos2DNew(x: x, y: y);
+static Future<Pos2D>  newInstance({required double x , required double y })=>RustLib.instance.api.crateApiGcodePos2DNew(x: x, y: y);
+
+
+ Future<Pos2D>  toScreenSpace({required double tubeWidth , required double scaleFactor });
+
+
+
+                    
+                }
+                
 
-  Future<Pos2D> toScreenSpace({
-    required double tubeWidth,
-    required double scaleFactor,
-  });
-}
+enum DistUnit {
+                    metric,
+imperial,
+                    ;
+                    
+                }
 
-enum PositioningMode { absolute, relative }
+enum PositioningMode {
+                    absolute,
+relative,
+                    ;
+                    
+                }
+            
\ No newline at end of file
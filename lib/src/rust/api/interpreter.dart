@@ -0,0 +1,123 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'gcode.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `machine_target_position`, `value_to_mm`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            Future<String>  stripComments({required String line }) => RustLib.instance.api.crateApiInterpreterStripComments(line: line);
+
+Future<List<Word>>  parseWords({required String line }) => RustLib.instance.api.crateApiInterpreterParseWords(line: line);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ModalState>>
+                abstract class ModalState implements RustOpaqueInterface {
+                     Future<void>  applyLine({required String line });
+
+
+ Future<void>  applyWords({required List<Word> words });
+
+
+ double? get feedrate;
+
+
+ MotionMode get motionMode;
+
+
+ double? get pierceDelay;
+
+
+ Pos2D get position;
+
+
+ PositioningMode get positioningMode;
+
+
+ bool get torchOn;
+
+
+ DistUnit get units;
+
+
+ Pos2D get workOffset;
+
+
+  set feedrate(double? feedrate);
+
+
+  set motionMode(MotionMode motionMode);
+
+
+  set pierceDelay(double? pierceDelay);
+
+
+  set position(Pos2D position);
+
+
+  set positioningMode(PositioningMode positioningMode);
+
+
+  set torchOn(bool torchOn);
+
+
+  set units(DistUnit units);
+
+
+  set workOffset(Pos2D workOffset);
+
+
+static Future<ModalState>  default_()=>RustLib.instance.api.crateApiInterpreterModalStateDefault();
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+static Future<ModalState>  newInstance()=>RustLib.instance.api.crateApiInterpreterModalStateNew();
+
+
+ Future<Pos2D>  targetPosition({required List<Word> words });
+
+
+
+                    
+                }
+                
+
+enum MotionMode {
+                    rapid,
+linear,
+arcClockwise,
+arcCounterClockwise,
+                    ;
+                    
+                }
+
+class Word  {
+                final String letter;
+final double value;
+
+                const Word({required this.letter ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => letter.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Word &&
+                runtimeType == other.runtimeType
+                && letter == other.letter&& value == other.value;
+        
+            }
+            
\ No newline at end of file
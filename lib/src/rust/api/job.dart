@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `acknowledge`, `begin_abort`, `fail`, `finish_abort`, `finish_if_done`, `in_flight_source_line`, `is_finished`, `job_lines`, `line_in_flight`, `lines`, `new`, `next_line`, `pause`, `prepare_for_transmission`, `progress`, `report`, `resume_cut_job_lines`, `resume_job_lines`, `resume`, `state`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `JobLine`, `Job`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            class JobProgress  {
+                final JobState state;
+final int currentLine;
+final int linesAcknowledged;
+final int totalLines;
+final double percent;
+final double elapsedSeconds;
+final double? remainingSeconds;
+final String? error;
+
+                const JobProgress({required this.state ,required this.currentLine ,required this.linesAcknowledged ,required this.totalLines ,required this.percent ,required this.elapsedSeconds ,this.remainingSeconds ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => state.hashCode^currentLine.hashCode^linesAcknowledged.hashCode^totalLines.hashCode^percent.hashCode^elapsedSeconds.hashCode^remainingSeconds.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JobProgress &&
+                runtimeType == other.runtimeType
+                && state == other.state&& currentLine == other.currentLine&& linesAcknowledged == other.linesAcknowledged&& totalLines == other.totalLines&& percent == other.percent&& elapsedSeconds == other.elapsedSeconds&& remainingSeconds == other.remainingSeconds&& error == other.error;
+        
+            }
+
+enum JobState {
+                    running,
+paused,
+aborting,
+completed,
+aborted,
+failed,
+                    ;
+                    
+                }
+            
\ No newline at end of file
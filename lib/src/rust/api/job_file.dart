@@ -0,0 +1,94 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'gcode.dart';
+import 'job_plan.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'settings.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cutter_settings`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JobFile>>
+                abstract class JobFile implements RustOpaqueInterface {
+                     MachineReference get machine;
+
+
+ JobPlan get plan;
+
+
+ int get version;
+
+
+  set machine(MachineReference machine);
+
+
+  set plan(JobPlan plan);
+
+
+  set version(int version);
+
+
+static JobFile  fromJson({required String contents })=>RustLib.instance.api.crateApiJobFileJobFileFromJson(contents: contents);
+
+
+static JobFile  load({required String path })=>RustLib.instance.api.crateApiJobFileJobFileLoad(path: path);
+
+
+factory JobFile({required JobPlan plan , required MachineReference machine })=>RustLib.instance.api.crateApiJobFileJobFileNew(plan: plan, machine: machine);
+
+
+ void  save({required String path });
+
+
+ Gcode  toGcode();
+
+
+ String  toJson();
+
+
+
+                    
+                }
+                
+
+class MachineReference  {
+                final String name;
+final bool useLaser;
+final double laserOffsetX;
+final double laserOffsetY;
+final bool homeAfterCut;
+
+                const MachineReference({required this.name ,required this.useLaser ,required this.laserOffsetX ,required this.laserOffsetY ,required this.homeAfterCut ,});
+
+                static MachineReference  fromSettings({required String name , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiJobFileMachineReferenceFromSettings(name: name, cutterSettings: cutterSettings);
+
+
+ bool  matches({required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiJobFileMachineReferenceMatches(that: this, cutterSettings: cutterSettings);
+
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^useLaser.hashCode^laserOffsetX.hashCode^laserOffsetY.hashCode^homeAfterCut.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MachineReference &&
+                runtimeType == other.runtimeType
+                && name == other.name&& useLaser == other.useLaser&& laserOffsetX == other.laserOffsetX&& laserOffsetY == other.laserOffsetY&& homeAfterCut == other.homeAfterCut;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,154 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cut.dart';
+import 'estimate.dart';
+import 'export.dart';
+import 'gcode.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'profile.dart';
+import 'report.dart';
+import 'settings.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Feature>>
+                abstract class Feature implements RustOpaqueInterface {
+                     CutParameters get parameters;
+
+
+ Profile get profile;
+
+
+  set parameters(CutParameters parameters);
+
+
+  set profile(Profile profile);
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JobPlan>>
+                abstract class JobPlan implements RustOpaqueInterface {
+                     void  addCut({required Cut cut });
+
+
+ void  addFeature({required Profile profile , required CutParameters parameters });
+
+
+ List<Cut> get cuts;
+
+
+ List<Feature> get features;
+
+
+ String get name;
+
+
+ Stock get stock;
+
+
+  set cuts(List<Cut> cuts);
+
+
+  set features(List<Feature> features);
+
+
+  set name(String name);
+
+
+  set stock(Stock stock);
+
+
+ String  export_({required String path , required PostProcessor postProcessor , required CutterSettings cutterSettings });
+
+
+factory JobPlan({required String name , required Stock stock })=>RustLib.instance.api.crateApiJobPlanJobPlanNew(name: name, stock: stock);
+
+
+ List<Part>  parts();
+
+
+ void  removeCut({required int cutIndex });
+
+
+ void  removeFeature({required int featureIndex });
+
+
+ JobReport  report({required CutterSettings cutterSettings });
+
+
+ String  saveReport({required String gcodePath , required CutterSettings cutterSettings });
+
+
+ Gcode  toGcode({required CutterSettings cutterSettings });
+
+
+
+                    
+                }
+                
+
+class Part  {
+                final double start;
+final double length;
+final double startAngle;
+final double endAngle;
+
+                const Part({required this.start ,required this.length ,required this.startAngle ,required this.endAngle ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^length.hashCode^startAngle.hashCode^endAngle.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Part &&
+                runtimeType == other.runtimeType
+                && start == other.start&& length == other.length&& startAngle == other.startAngle&& endAngle == other.endAngle;
+        
+            }
+
+class Stock  {
+                final String material;
+final double tubeWidth;
+final double length;
+
+                const Stock({required this.material ,required this.tubeWidth ,required this.length ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => material.hashCode^tubeWidth.hashCode^length.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Stock &&
+                runtimeType == other.runtimeType
+                && material == other.material&& tubeWidth == other.tubeWidth&& length == other.length;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'gcode.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'sender.dart';
+import 'settings.dart';
+part 'laser.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `command`, `finish_and_save_to`, `idle_position`, `measure_offset`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LaserCalibration>>
+                abstract class LaserCalibration implements RustOpaqueInterface {
+                    static Future<LaserCalibration>  default_()=>RustLib.instance.api.crateApiLaserLaserCalibrationDefault();
+
+
+ Pos2D  finish({required MachineConnection connection , required CutterSettings cutterSettings });
+
+
+ void  fireTestMark({required MachineConnection connection , required CutterSettings cutterSettings });
+
+
+ Pos2D?  markPosition();
+
+
+factory LaserCalibration()=>RustLib.instance.api.crateApiLaserLaserCalibrationNew();
+
+
+
+                    
+                }
+                
+
+@freezed
+                sealed class LaserOutput with _$LaserOutput  {
+                    const LaserOutput._();
+
+                     const factory LaserOutput.flood() = LaserOutput_Flood;
+ const factory LaserOutput.mist() = LaserOutput_Mist;
+ const factory LaserOutput.custom({   required String onCommand ,  required String offCommand , }) = LaserOutput_Custom;
+
+                    
+
+                    static Future<LaserOutput>  default_()=>RustLib.instance.api.crateApiLaserLaserOutputDefault();
+
+
+                }
+            
\ No newline at end of file
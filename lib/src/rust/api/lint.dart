@@ -0,0 +1,85 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `diagnostic`, `unknown_word`, `unreadable_text`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            Future<List<LintDiagnostic>>  lintProgram({required String program , required LintOptions options }) => RustLib.instance.api.crateApiLintLintProgram(program: program, options: options);
+
+            class LintDiagnostic  {
+                final Severity severity;
+final LintRule rule;
+final int line;
+final String message;
+
+                const LintDiagnostic({required this.severity ,required this.rule ,required this.line ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => severity.hashCode^rule.hashCode^line.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LintDiagnostic &&
+                runtimeType == other.runtimeType
+                && severity == other.severity&& rule == other.rule&& line == other.line&& message == other.message;
+        
+            }
+
+class LintOptions  {
+                final double maxTorchDwell;
+
+                const LintOptions({required this.maxTorchDwell ,});
+
+                static Future<LintOptions>  default_()=>RustLib.instance.api.crateApiLintLintOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxTorchDwell.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LintOptions &&
+                runtimeType == other.runtimeType
+                && maxTorchDwell == other.maxTorchDwell;
+        
+            }
+
+enum LintRule {
+                    torchLeftOn,
+rapidWithTorchOn,
+missingFeedrate,
+longTorchDwell,
+unitsNotSet,
+positioningModeNotSet,
+unknownWord,
+                    ;
+                    
+                }
+
+enum Severity {
+                    error,
+warning,
+                    ;
+                    
+                }
+            
\ No newline at end of file
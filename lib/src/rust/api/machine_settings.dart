@@ -0,0 +1,141 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_report_line`, `builtin_description`, `parse_setting_description`, `parse_setting_line`, `restore_commands`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MachineSettings>>
+                abstract class MachineSettings implements RustOpaqueInterface {
+                     List<SettingGroup> get groups;
+
+
+ List<MachineSetting> get settings;
+
+
+  set groups(List<SettingGroup> groups);
+
+
+  set settings(List<MachineSetting> settings);
+
+
+static Future<MachineSettings>  default_()=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsDefault();
+
+
+ Future<SettingDescription?>  description({required int id });
+
+
+ Future<MachineSetting?>  get_({required int id });
+
+
+ Future<void>  saveBackup({required String filename });
+
+
+ Future<String>  toBackup();
+
+
+ Future<String>  validate({required int id , required String value });
+
+
+
+                    
+                }
+                
+
+class MachineSetting  {
+                final int id;
+final String value;
+final SettingDescription? description;
+
+                const MachineSetting({required this.id ,required this.value ,this.description ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^value.hashCode^description.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MachineSetting &&
+                runtimeType == other.runtimeType
+                && id == other.id&& value == other.value&& description == other.description;
+        
+            }
+
+class SettingDescription  {
+                final String name;
+final String unit;
+final SettingType settingType;
+final int? group;
+final double? min;
+final double? max;
+
+                const SettingDescription({required this.name ,required this.unit ,required this.settingType ,this.group ,this.min ,this.max ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^unit.hashCode^settingType.hashCode^group.hashCode^min.hashCode^max.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SettingDescription &&
+                runtimeType == other.runtimeType
+                && name == other.name&& unit == other.unit&& settingType == other.settingType&& group == other.group&& min == other.min&& max == other.max;
+        
+            }
+
+class SettingGroup  {
+                final int id;
+final int parent;
+final String name;
+
+                const SettingGroup({required this.id ,required this.parent ,required this.name ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^parent.hashCode^name.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SettingGroup &&
+                runtimeType == other.runtimeType
+                && id == other.id&& parent == other.parent&& name == other.name;
+        
+            }
+
+enum SettingType {
+                    boolean,
+bitfield,
+integer,
+decimal,
+text,
+                    ;
+                    
+                }
+            
\ No newline at end of file
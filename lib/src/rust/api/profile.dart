@@ -0,0 +1,128 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'gcode.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `chain`, `contains`, `order_inside_first`, `points`, `reversed`, `transformed`, `transformed`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Profile>>
+                abstract class Profile implements RustOpaqueInterface {
+                     List<Contour> get contours;
+
+
+  set contours(List<Contour> contours);
+
+
+ (Pos2D,Pos2D)?  bounds();
+
+
+static Future<Profile>  default_()=>RustLib.instance.api.crateApiProfileProfileDefault();
+
+
+static Profile  fromDxf({required String contents })=>RustLib.instance.api.crateApiProfileProfileFromDxf(contents: contents);
+
+
+static Future<Profile>  fromSegments({required List<ProfileSegment> segments })=>RustLib.instance.api.crateApiProfileProfileFromSegments(segments: segments);
+
+
+static Profile  fromSvg({required String contents , required double tolerance })=>RustLib.instance.api.crateApiProfileProfileFromSvg(contents: contents, tolerance: tolerance);
+
+
+static Profile  importDxf({required String path })=>RustLib.instance.api.crateApiProfileProfileImportDxf(path: path);
+
+
+static Profile  importSvg({required String path , required double tolerance })=>RustLib.instance.api.crateApiProfileProfileImportSvg(path: path, tolerance: tolerance);
+
+
+ Profile  moved({required double dx , required double dy });
+
+
+ Profile  placeOnFlatStock({required double x , required double y });
+
+
+ Profile  placeOnTubeFace({required double tubeWidth , required double yPosition });
+
+
+ Profile  scaled({required double factor });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ProfileSegment>>
+                abstract class ProfileSegment implements RustOpaqueInterface {
+                     Future<void>  end();
+
+
+ Future<List<Pos2D>>  flatten({required double tolerance });
+
+
+ Future<void>  start();
+
+
+
+                    
+                }
+                
+
+class Contour  {
+                final List<ProfileSegment> segments;
+final bool closed;
+
+                const Contour({required this.segments ,required this.closed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => segments.hashCode^closed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Contour &&
+                runtimeType == other.runtimeType
+                && segments == other.segments&& closed == other.closed;
+        
+            }
+
+class CutParameters  {
+                final double feedrate;
+final double pierceDelay;
+
+                const CutParameters({required this.feedrate ,required this.pierceDelay ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => feedrate.hashCode^pierceDelay.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CutParameters &&
+                runtimeType == other.runtimeType
+                && feedrate == other.feedrate&& pierceDelay == other.pierceDelay;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,82 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'gcode.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'toolpath.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `crc32`, `dot`, `glyph`, `hex`, `layout`, `line`, `new`, `png_chunk`, `polyline`, `set`, `svg_points`, `text`, `to_png`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Canvas`, `Drawing`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            Future<String>  renderSvg({required List<ToolpathSegment> segments , required List<int> cutStartLines , required RenderOptions options }) => RustLib.instance.api.crateApiRenderRenderSvg(segments: segments, cutStartLines: cutStartLines, options: options);
+
+Future<Uint8List>  renderPng({required List<ToolpathSegment> segments , required List<int> cutStartLines , required RenderOptions options }) => RustLib.instance.api.crateApiRenderRenderPng(segments: segments, cutStartLines: cutStartLines, options: options);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Viewport>>
+                abstract class Viewport implements RustOpaqueInterface {
+                     Pos2D get offset;
+
+
+ double get scale;
+
+
+  set offset(Pos2D offset);
+
+
+  set scale(double scale);
+
+
+static Viewport  centeredOnTube({required double tubeWidth , required double scale })=>RustLib.instance.api.crateApiRenderViewportCenteredOnTube(tubeWidth: tubeWidth, scale: scale);
+
+
+static Viewport  fit({required Pos2D min , required Pos2D max , required double width , required double height , required double margin })=>RustLib.instance.api.crateApiRenderViewportFit(min: min, max: max, width: width, height: height, margin: margin);
+
+
+factory Viewport({required double scale , required Pos2D offset })=>RustLib.instance.api.crateApiRenderViewportNew(scale: scale, offset: offset);
+
+
+ Pos2D  toScreen({required Pos2D position });
+
+
+
+                    
+                }
+                
+
+class RenderOptions  {
+                final int width;
+final int height;
+final double margin;
+final double? tubeWidth;
+final bool showRapids;
+final bool showLabels;
+
+                const RenderOptions({required this.width ,required this.height ,required this.margin ,this.tubeWidth ,required this.showRapids ,required this.showLabels ,});
+
+                static Future<RenderOptions>  default_()=>RustLib.instance.api.crateApiRenderRenderOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => width.hashCode^height.hashCode^margin.hashCode^tubeWidth.hashCode^showRapids.hashCode^showLabels.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RenderOptions &&
+                runtimeType == other.runtimeType
+                && width == other.width&& height == other.height&& margin == other.margin&& tubeWidth == other.tubeWidth&& showRapids == other.showRapids&& showLabels == other.showLabels;
+        
+            }
+            
\ No newline at end of file
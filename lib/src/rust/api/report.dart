@@ -0,0 +1,106 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'estimate.dart';
+import 'job_plan.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `escape_html`, `format_duration`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            Future<String>  reportPath({required String gcodePath }) => RustLib.instance.api.crateApiReportReportPath(gcodePath: gcodePath);
+
+            class CutReport  {
+                final double position;
+final double angle;
+final double feedrate;
+final double pierceDelay;
+final double pierceDelay2;
+final double estimatedTime;
+
+                const CutReport({required this.position ,required this.angle ,required this.feedrate ,required this.pierceDelay ,required this.pierceDelay2 ,required this.estimatedTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => position.hashCode^angle.hashCode^feedrate.hashCode^pierceDelay.hashCode^pierceDelay2.hashCode^estimatedTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CutReport &&
+                runtimeType == other.runtimeType
+                && position == other.position&& angle == other.angle&& feedrate == other.feedrate&& pierceDelay == other.pierceDelay&& pierceDelay2 == other.pierceDelay2&& estimatedTime == other.estimatedTime;
+        
+            }
+
+class FeatureReport  {
+                final int contours;
+final double feedrate;
+final double pierceDelay;
+final double estimatedTime;
+
+                const FeatureReport({required this.contours ,required this.feedrate ,required this.pierceDelay ,required this.estimatedTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => contours.hashCode^feedrate.hashCode^pierceDelay.hashCode^estimatedTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FeatureReport &&
+                runtimeType == other.runtimeType
+                && contours == other.contours&& feedrate == other.feedrate&& pierceDelay == other.pierceDelay&& estimatedTime == other.estimatedTime;
+        
+            }
+
+class JobReport  {
+                final String name;
+final String material;
+final double tubeWidth;
+final double stockLength;
+final List<CutReport> cuts;
+final List<FeatureReport> features;
+final List<Part> parts;
+final double usedLength;
+final double wasteLength;
+final JobEstimate estimate;
+final String toolpathSvg;
+
+                const JobReport({required this.name ,required this.material ,required this.tubeWidth ,required this.stockLength ,required this.cuts ,required this.features ,required this.parts ,required this.usedLength ,required this.wasteLength ,required this.estimate ,required this.toolpathSvg ,});
+
+                 String  toHtml()=>RustLib.instance.api.crateApiReportJobReportToHtml(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^material.hashCode^tubeWidth.hashCode^stockLength.hashCode^cuts.hashCode^features.hashCode^parts.hashCode^usedLength.hashCode^wasteLength.hashCode^estimate.hashCode^toolpathSvg.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JobReport &&
+                runtimeType == other.runtimeType
+                && name == other.name&& material == other.material&& tubeWidth == other.tubeWidth&& stockLength == other.stockLength&& cuts == other.cuts&& features == other.features&& parts == other.parts&& usedLength == other.usedLength&& wasteLength == other.wasteLength&& estimate == other.estimate&& toolpathSvg == other.toolpathSvg;
+        
+            }
+            
\ No newline at end of file
@@ -4,46 +4,191 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'capabilities.dart';
+import 'gcode.dart';
+import 'homing.dart';
+import 'job.dart';
+import 'jog.dart';
+import 'machine_settings.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'settings.dart';
+import 'status.dart';
+import 'wcs.dart';
 
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MachineCommand`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MachineConnection>>
-abstract class MachineConnection implements RustOpaqueInterface {
-  void home({required CutterSettings cutterSettings});
+            // These functions are ignored because they are not marked as `pub`: `begin_stop`, `check_travel_limits`, `connect_with_transport`, `connect`, `finish_stop`, `handle_command`, `handle_line`, `handle_response`, `handle_status`, `job_active`, `new`, `next_job_line`, `poll`, `reject`, `run`, `send_event`, `send_manual_commands_when_idle`, `send_manual_commands`, `send_manual`, `set_state`, `start_job_with`, `stream`, `wait_for_reconnect`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MachineCommand`, `MachineEvent`, `MachineLink`, `SharedState`, `StreamExit`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `drop`, `eq`, `fmt`
 
-  Stream<MachinePosition> makeConnection();
 
-  factory MachineConnection() =>
-      RustLib.instance.api.crateApiSenderMachineConnectionNew();
+            
 
-  void sendGcodeCommand({required String command});
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MachineConnection>>
+                abstract class MachineConnection implements RustOpaqueInterface {
+                     void  abortJob();
 
-  void sendStringCommand({required String command});
 
-  void sendStringCommandLowPriority({required String command});
+ Fixture  captureFixture({required String name , WorkCoordinateSystem? wcs });
 
-  void setSerialPort({required String newPort});
-}
 
-class MachinePosition {
-  final double x;
-  final double y;
+ void  disconnect();
 
-  const MachinePosition.raw({required this.x, required this.y});
 
-  factory MachinePosition() =>
-      RustLib.instance.api.crateApiSenderMachinePositionNew();
+ MachineCapabilities  getCapabilities();
 
-  @override
-  int get hashCode => x.hashCode ^ y.hashCode;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is MachinePosition &&
-          runtimeType == other.runtimeType &&
-          x == other.x &&
-          y == other.y;
-}
+ ConnectionState  getConnectionState();
+
+
+ CoordinateOffsets  getCoordinateOffsets();
+
+
+ MachineStatus?  getLastStatus();
+
+
+ MachineSettings  getMachineSettings();
+
+
+ void  goToMachinePosition({required double x , required double y });
+
+
+ void  goToWorkZero();
+
+
+ void  home({required CutterSettings cutterSettings });
+
+
+ bool  isConnected();
+
+
+ bool  isHomingRequired();
+
+
+ void  jogStep({required double xDirection , required double yDirection , required JogStep step , required CutterSettings cutterSettings });
+
+
+ Stream<MachinePosition>  makeConnection();
+
+
+factory MachineConnection()=>RustLib.instance.api.crateApiSenderMachineConnectionNew();
+
+
+ void  pauseJob();
+
+
+ void  readCoordinateOffsets();
+
+
+ void  readMachineSettings();
+
+
+ void  recallFixture({required Fixture fixture });
+
+
+ int  restoreMachineSettings({required String filename });
+
+
+ void  resumeJob();
+
+
+ void  runHomingCycle({required HomingAxes axes });
+
+
+ void  selectWorkCoordinateSystem({required WorkCoordinateSystem wcs });
+
+
+ void  sendGcodeCommand({required String command });
+
+
+ void  sendStringCommand({required String command });
+
+
+ void  sendStringCommandLowPriority({required String command });
+
+
+ void  setBaudrate({required int newBaudrate });
+
+
+ void  setConnectionSettings({required CutterSettings cutterSettings });
+
+
+ void  setLaserPointer({required bool on_ , required CutterSettings cutterSettings });
+
+
+ void  setSerialPort({required String newPort });
+
+
+ void  setWorkOffset({required WorkCoordinateSystem wcs , required Axis axis , required double offset });
+
+
+ Stream<JobProgress>  startJob({required Gcode gcode });
+
+
+ Stream<JobProgress>  startJobFromCut({required Gcode gcode , required int cutIndex });
+
+
+ Stream<JobProgress>  startJobFromLine({required Gcode gcode , required int startLine });
+
+
+ void  startJog({required double xDirection , required double yDirection , required CutterSettings cutterSettings });
+
+
+ void  stopJog();
+
+
+ void  unlock();
+
+
+ void  writeMachineSetting({required int id , required String value });
+
+
+ void  zeroAllAxes({WorkCoordinateSystem? wcs });
+
+
+ void  zeroAtLaser({WorkCoordinateSystem? wcs , required CutterSettings cutterSettings });
+
+
+ void  zeroAxis({WorkCoordinateSystem? wcs , required Axis axis });
+
+
+
+                    
+                }
+                
+
+enum ConnectionState {
+                    disconnected,
+connecting,
+connected,
+reconnecting,
+                    ;
+                    
+                }
+
+class MachinePosition  {
+                final double x;
+final double y;
+
+                const MachinePosition.raw({required this.x ,required this.y ,});
+
+                factory MachinePosition()=>RustLib.instance.api.crateApiSenderMachinePositionNew();
+
+
+                
+
+                
+        @override
+        int get hashCode => x.hashCode^y.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MachinePosition &&
+                runtimeType == other.runtimeType
+                && x == other.x&& y == other.y;
+        
+            }
+            
\ No newline at end of file
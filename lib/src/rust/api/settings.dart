@@ -4,48 +4,235 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'estimate.dart';
+import 'laser.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'wcs.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CutMethod>>
-abstract class CutMethod implements RustOpaqueInterface {}
+            // These functions are ignored because they are not marked as `pub`: `load_from`, `save_to`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CutterSettings>>
-abstract class CutterSettings implements RustOpaqueInterface {
-  bool get clearExistingGcode;
 
-  CutMethod get cutMethod;
+            
 
-  bool get homeAfterCut;
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CutMethod>>
+                abstract class CutMethod implements RustOpaqueInterface {
+                    
 
-  double get jogSpeed;
+                    
+                }
+                
 
-  double get laserOffsetX;
 
-  double get laserOffsetY;
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CutterSettings>>
+                abstract class CutterSettings implements RustOpaqueInterface {
+                     bool get clearExistingGcode;
+
+
+ ConnectionSettings get connection;
+
+
+ ConsumableRatings get consumableRatings;
+
+
+ CutMethod get cutMethod;
+
+
+ List<Fixture> get fixtures;
+
+
+ bool get homeAfterCut;
+
+
+ double get jogSpeed;
+
+
+ double get laserOffsetX;
+
+
+ double get laserOffsetY;
+
+
+ LaserOutput get laserOutput;
+
+
+ MotionLimits get motionLimits;
+
+
+ bool get useLaser;
 
-  bool get useLaser;
 
   set clearExistingGcode(bool clearExistingGcode);
 
+
+  set connection(ConnectionSettings connection);
+
+
+  set consumableRatings(ConsumableRatings consumableRatings);
+
+
   set cutMethod(CutMethod cutMethod);
 
+
+  set fixtures(List<Fixture> fixtures);
+
+
   set homeAfterCut(bool homeAfterCut);
 
+
   set jogSpeed(double jogSpeed);
 
+
   set laserOffsetX(double laserOffsetX);
 
+
   set laserOffsetY(double laserOffsetY);
 
+
+  set laserOutput(LaserOutput laserOutput);
+
+
+  set motionLimits(MotionLimits motionLimits);
+
+
   set useLaser(bool useLaser);
 
-  static CutterSettings load() =>
-      RustLib.instance.api.crateApiSettingsCutterSettingsLoad();
 
-  factory CutterSettings() =>
-      RustLib.instance.api.crateApiSettingsCutterSettingsNew();
+static Future<CutterSettings>  default_()=>RustLib.instance.api.crateApiSettingsCutterSettingsDefault();
+
+
+ Fixture?  getFixture({required String name });
+
+
+static CutterSettings  load()=>RustLib.instance.api.crateApiSettingsCutterSettingsLoad();
+
+
+factory CutterSettings()=>RustLib.instance.api.crateApiSettingsCutterSettingsNew();
+
+
+ void  removeFixture({required String name });
+
+
+ void  save();
+
+
+ void  saveFixture({required Fixture fixture });
+
+
+ void  setBaudrate({required int newBaudrate });
+
+
+ void  setConsumableRatings({required ConsumableRatings ratings });
+
+
+ void  setDtrOnOpen({required ControlLineOnOpen behavior });
+
+
+ void  setFlowControl({required SerialFlowControl flowControl });
+
+
+ void  setLaserOutput({required LaserOutput output });
+
+
+ void  setLineEnding({required LineEnding lineEnding });
+
+
+ void  setLineNumbers({required bool enabled });
+
+
+ void  setMotionLimits({required MotionLimits limits });
+
+
+ void  setNetworkAddress({required String host , required int port });
+
+
+ void  setReadTimeoutMs({required int timeoutMs });
+
+
+ void  setRtsOnOpen({required ControlLineOnOpen behavior });
+
+
+ void  setSerialPort({required String newPort });
+
+
+ void  setTransport({required TransportKind transport });
+
+
+
+                    
+                }
+                
+
+class ConnectionSettings  {
+                final TransportKind transport;
+final String serialPort;
+final int baudrate;
+final SerialFlowControl flowControl;
+final ControlLineOnOpen dtrOnOpen;
+final ControlLineOnOpen rtsOnOpen;
+final int readTimeoutMs;
+final LineEnding lineEnding;
+final bool lineNumbers;
+final String host;
+final int networkPort;
+
+                const ConnectionSettings({required this.transport ,required this.serialPort ,required this.baudrate ,required this.flowControl ,required this.dtrOnOpen ,required this.rtsOnOpen ,required this.readTimeoutMs ,required this.lineEnding ,required this.lineNumbers ,required this.host ,required this.networkPort ,});
+
+                static Future<ConnectionSettings>  default_()=>RustLib.instance.api.crateApiSettingsConnectionSettingsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => transport.hashCode^serialPort.hashCode^baudrate.hashCode^flowControl.hashCode^dtrOnOpen.hashCode^rtsOnOpen.hashCode^readTimeoutMs.hashCode^lineEnding.hashCode^lineNumbers.hashCode^host.hashCode^networkPort.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConnectionSettings &&
+                runtimeType == other.runtimeType
+                && transport == other.transport&& serialPort == other.serialPort&& baudrate == other.baudrate&& flowControl == other.flowControl&& dtrOnOpen == other.dtrOnOpen&& rtsOnOpen == other.rtsOnOpen&& readTimeoutMs == other.readTimeoutMs&& lineEnding == other.lineEnding&& lineNumbers == other.lineNumbers&& host == other.host&& networkPort == other.networkPort;
+        
+            }
+
+enum ControlLineOnOpen {
+                    unchanged,
+high,
+low,
+pulse,
+                    ;
+                    
+                }
+
+enum LineEnding {
+                    lf,
+cr,
+crLf,
+                    ;
+                     Future<void>  asStr()=>RustLib.instance.api.crateApiSettingsLineEndingAsStr(that: this, );
+
+
+                }
+
+enum SerialFlowControl {
+                    none,
+software,
+hardware,
+                    ;
+                    
+                }
 
-  void save();
-}
+enum TransportKind {
+                    serial,
+tcp,
+webSocket,
+simulator,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -6,8 +6,12 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-String greet({required String name}) =>
-    RustLib.instance.api.crateApiSimpleGreet(name: name);
 
-List<String> getSerialPorts() =>
-    RustLib.instance.api.crateApiSimpleGetSerialPorts();
+            
+
+            String  greet({required String name }) => RustLib.instance.api.crateApiSimpleGreet(name: name);
+
+List<String>  getSerialPorts() => RustLib.instance.api.crateApiSimpleGetSerialPorts();
+
+            
+            
\ No newline at end of file
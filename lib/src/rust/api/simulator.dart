@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'gcode.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `active_offset`, `apply_coordinate_words`, `cancel_jog`, `enter_alarm`, `execute_line`, `execute_system_command`, `new`, `plan_words`, `planned_position`, `process_pending_lines`, `push_block`, `reset`, `to_work`, `unsupported_word`, `write_line`, `write_setting`, `write_status`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PlannedBlock`, `PtySimulator`, `SimulatedState`, `SimulatorTransport`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `drop`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `path`, `read`, `simulator`, `start`, `write_all`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GrblSimulator>>
+                abstract class GrblSimulator implements RustOpaqueInterface {
+                     Future<bool>  inAlarm();
+
+
+ Future<void>  injectAlarm({required int code });
+
+
+ Future<void>  injectError({required int code });
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+static Future<GrblSimulator>  newInstance({required SimulatorConfig config })=>RustLib.instance.api.crateApiSimulatorGrblSimulatorNew(config: config);
+
+
+ Future<Pos2D>  position();
+
+
+ Future<void>  receive({required List<int> bytes });
+
+
+ Future<void>  receivedLines();
+
+
+ Future<Uint8List>  takeOutput();
+
+
+ Future<bool>  torchOn();
+
+
+ Future<void>  update();
+
+
+
+                    
+                }
+                
+
+class SimulatorConfig  {
+                final BigInt plannerBlocks;
+final BigInt rxBufferSize;
+final double rapidRate;
+final double defaultFeedrate;
+final double timeScale;
+final bool homingRequired;
+
+                const SimulatorConfig({required this.plannerBlocks ,required this.rxBufferSize ,required this.rapidRate ,required this.defaultFeedrate ,required this.timeScale ,required this.homingRequired ,});
+
+                static Future<SimulatorConfig>  default_()=>RustLib.instance.api.crateApiSimulatorSimulatorConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => plannerBlocks.hashCode^rxBufferSize.hashCode^rapidRate.hashCode^defaultFeedrate.hashCode^timeScale.hashCode^homingRequired.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SimulatorConfig &&
+                runtimeType == other.runtimeType
+                && plannerBlocks == other.plannerBlocks&& rxBufferSize == other.rxBufferSize&& rapidRate == other.rapidRate&& defaultFeedrate == other.defaultFeedrate&& timeScale == other.timeScale&& homingRequired == other.homingRequired;
+        
+            }
+            
\ No newline at end of file
@@ -6,47 +6,241 @@
 import '../frb_generated.dart';
 import 'gcode.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'status.freezed.dart';
 
+            // These functions are ignored because they are not marked as `pub`: `apply_work_coordinate_offset`, `first_inner`, `parse_accessories`, `parse_number`, `parse_numbers`, `update_position`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `StatusParser`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `cmp`, `eq`, `fmt`, `hash`, `parse`, `partial_cmp`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `cmp`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `hash`, `parse`, `partial_cmp`
 
-Future<MachineStatus> parseStatus({required String statusString}) =>
-    RustLib.instance.api.crateApiStatusParseStatus(statusString: statusString);
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MachineStatus>>
-abstract class MachineStatus implements RustOpaqueInterface {
-  MachineState get machineState;
+            Future<MachineStatus>  parseStatus({required String statusString }) => RustLib.instance.api.crateApiStatusParseStatus(statusString: statusString);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MachineStatus>>
+                abstract class MachineStatus implements RustOpaqueInterface {
+                     Accessories? get accessories;
+
+
+ BufferState? get buffer;
+
+
+ double? get feedRate;
+
+
+ int? get lineNumber;
+
+
+ Float32List? get machinePosition;
+
+
+ MachineState get machineState;
+
+
+ Overrides? get overrides;
+
+
+ String? get pins;
+
+
+ Pos2D get position;
+
+
+ double? get spindleSpeed;
+
+
+ int? get subState;
+
+
+ Float32List? get workCoordinateOffset;
+
+
+ Float32List? get workPosition;
+
+
+  set accessories(Accessories? accessories);
+
+
+  set buffer(BufferState? buffer);
+
+
+  set feedRate(double? feedRate);
+
+
+  set lineNumber(int? lineNumber);
+
+
+  set machinePosition(Float32List? machinePosition);
 
-  Pos2D get position;
 
   set machineState(MachineState machineState);
 
+
+  set overrides(Overrides? overrides);
+
+
+  set pins(String? pins);
+
+
   set position(Pos2D position);
 
+
+  set spindleSpeed(double? spindleSpeed);
+
+
+  set subState(int? subState);
+
+
+  set workCoordinateOffset(Float32List? workCoordinateOffset);
+
+
+  set workPosition(Float32List? workPosition);
+
+
+static Future<MachineStatus>  default_()=>RustLib.instance.api.crateApiStatusMachineStatusDefault();
+
+
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<MachineStatus> newInstance() =>
-      RustLib.instance.api.crateApiStatusMachineStatusNew();
-}
+static Future<MachineStatus>  newInstance()=>RustLib.instance.api.crateApiStatusMachineStatusNew();
+
+
+ Future<bool>  pinTriggered({required String pin });
+
+
+
+                    
+                }
+                
+
+class Accessories  {
+                final bool spindleCw;
+final bool spindleCcw;
+final bool floodCoolant;
+final bool mistCoolant;
+final bool toolChangePending;
+
+                const Accessories({required this.spindleCw ,required this.spindleCcw ,required this.floodCoolant ,required this.mistCoolant ,required this.toolChangePending ,});
+
+                static Future<Accessories>  default_()=>RustLib.instance.api.crateApiStatusAccessoriesDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => spindleCw.hashCode^spindleCcw.hashCode^floodCoolant.hashCode^mistCoolant.hashCode^toolChangePending.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Accessories &&
+                runtimeType == other.runtimeType
+                && spindleCw == other.spindleCw&& spindleCcw == other.spindleCcw&& floodCoolant == other.floodCoolant&& mistCoolant == other.mistCoolant&& toolChangePending == other.toolChangePending;
+        
+            }
+
+class BufferState  {
+                final int plannerBlocks;
+final int rxBytes;
+
+                const BufferState({required this.plannerBlocks ,required this.rxBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => plannerBlocks.hashCode^rxBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BufferState &&
+                runtimeType == other.runtimeType
+                && plannerBlocks == other.plannerBlocks&& rxBytes == other.rxBytes;
+        
+            }
 
 enum MachineState {
-  idle,
-  run,
-  hold,
-  jog,
-  alarm,
-  door,
-  check,
-  home,
-  sleep,
-  tool,
-}
+                    idle,
+run,
+hold,
+jog,
+alarm,
+door,
+check,
+home,
+sleep,
+tool,
+                    ;
+                    
+                }
+
+class Overrides  {
+                final int feed;
+final int rapid;
+final int spindle;
+
+                const Overrides({required this.feed ,required this.rapid ,required this.spindle ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => feed.hashCode^rapid.hashCode^spindle.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Overrides &&
+                runtimeType == other.runtimeType
+                && feed == other.feed&& rapid == other.rapid&& spindle == other.spindle;
+        
+            }
 
 enum Rule {
-  number,
-  mpos,
-  state,
-  status;
-
-  static Future<void> allRules() =>
-      RustLib.instance.api.crateApiStatusRuleAllRules();
-}
+                    ///End-of-input
+eoi,
+number,
+axes,
+mpos,
+wpos,
+wco,
+buffer,
+lineNumber,
+feed,
+feedSpeed,
+overrides,
+letters,
+pins,
+accessories,
+unknownField,
+field,
+state,
+subState,
+status,
+                    ;
+                    static Future<void>  allRules()=>RustLib.instance.api.crateApiStatusRuleAllRules();
+
+
+                }
+
+@freezed
+                sealed class StatusParseError with _$StatusParseError implements FrbException {
+                    const StatusParseError._();
+
+                     const factory StatusParseError.invalidSyntax(  String field0,) = StatusParseError_InvalidSyntax;
+ const factory StatusParseError.invalidValue(  String field0,) = StatusParseError_InvalidValue;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
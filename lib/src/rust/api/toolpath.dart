@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'gcode.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `arc_center`, `arc_sweep`, `distance`, `flatten_arc`, `simulate_toolpath_at`, `word_value`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            Future<List<ToolpathSegment>>  simulateToolpath({required String program }) => RustLib.instance.api.crateApiToolpathSimulateToolpath(program: program);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SegmentKind>>
+                abstract class SegmentKind implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ToolpathSegment>>
+                abstract class ToolpathSegment implements RustOpaqueInterface {
+                     Pos2D get end;
+
+
+ double? get feedrate;
+
+
+ SegmentKind get kind;
+
+
+ int get line;
+
+
+ Pos2D get start;
+
+
+ bool get torchOn;
+
+
+  set end(Pos2D end);
+
+
+  set feedrate(double? feedrate);
+
+
+  set kind(SegmentKind kind);
+
+
+  set line(int line);
+
+
+  set start(Pos2D start);
+
+
+  set torchOn(bool torchOn);
+
+
+ Future<List<Pos2D>>  flatten({required double tolerance });
+
+
+ Future<double>  length();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `connect_tcp`, `open_transport`, `open`, `open`, `open`, `read_timeout`, `set_control_line`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SerialTransport`, `TcpTransport`, `WebSocketTransport`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `read`, `read`, `read`, `write_all`, `write_all`, `write_all`
+
+
+            
+
+            
+                abstract class Transport {
+                     Future<BigInt>  read({required List<int> buf });
+
+
+ Future<void>  writeAll({required List<int> data });
+
+
+                }
+                
+            
\ No newline at end of file
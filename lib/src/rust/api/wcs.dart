@@ -0,0 +1,127 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_report_line`, `letter`, `recall_commands`, `set_offset_command`, `set_position_command`, `wcs_p_word`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            enum Axis {
+                    x,
+y,
+z,
+                    ;
+                    
+                }
+
+class CoordinateOffsets  {
+                final List<WorkOffset> workOffsets;
+final Float32List? g92;
+final WorkCoordinateSystem? active;
+
+                const CoordinateOffsets({required this.workOffsets ,this.g92 ,this.active ,});
+
+                 Future<Fixture>  captureFixture({required String name , WorkCoordinateSystem? wcs })=>RustLib.instance.api.crateApiWcsCoordinateOffsetsCaptureFixture(that: this, name: name, wcs: wcs);
+
+
+static Future<CoordinateOffsets>  default_()=>RustLib.instance.api.crateApiWcsCoordinateOffsetsDefault();
+
+
+ Future<WorkOffset?>  offset({required WorkCoordinateSystem wcs })=>RustLib.instance.api.crateApiWcsCoordinateOffsetsOffset(that: this, wcs: wcs);
+
+
+                
+
+                
+        @override
+        int get hashCode => workOffsets.hashCode^g92.hashCode^active.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CoordinateOffsets &&
+                runtimeType == other.runtimeType
+                && workOffsets == other.workOffsets&& g92 == other.g92&& active == other.active;
+        
+            }
+
+class Fixture  {
+                final String name;
+final WorkCoordinateSystem wcs;
+final double x;
+final double y;
+
+                const Fixture({required this.name ,required this.wcs ,required this.x ,required this.y ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^wcs.hashCode^x.hashCode^y.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Fixture &&
+                runtimeType == other.runtimeType
+                && name == other.name&& wcs == other.wcs&& x == other.x&& y == other.y;
+        
+            }
+
+enum WorkCoordinateSystem {
+                    g54,
+g55,
+g56,
+g57,
+g58,
+g59,
+                    ;
+                    static Future<WorkCoordinateSystem?>  fromGcode({required String gcode })=>RustLib.instance.api.crateApiWcsWorkCoordinateSystemFromGcode(gcode: gcode);
+
+
+ Future<void>  gcode()=>RustLib.instance.api.crateApiWcsWorkCoordinateSystemGcode(that: this, );
+
+
+ Future<int>  index()=>RustLib.instance.api.crateApiWcsWorkCoordinateSystemIndex(that: this, );
+
+
+                }
+
+class WorkOffset  {
+                final WorkCoordinateSystem wcs;
+final double x;
+final double y;
+final double z;
+
+                const WorkOffset({required this.wcs ,required this.x ,required this.y ,required this.z ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => wcs.hashCode^x.hashCode^y.hashCode^z.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WorkOffset &&
+                runtimeType == other.runtimeType
+                && wcs == other.wcs&& x == other.x&& y == other.y&& z == other.z;
+        
+            }
+            
\ No newline at end of file
@@ -3,4729 +3,14183 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/bounds.dart';
+import 'api/capabilities.dart';
 import 'api/cut.dart';
+import 'api/estimate.dart';
+import 'api/export.dart';
 import 'api/gcode.dart';
+import 'api/homing.dart';
+import 'api/interpreter.dart';
+import 'api/job.dart';
+import 'api/job_file.dart';
+import 'api/job_plan.dart';
+import 'api/jog.dart';
+import 'api/laser.dart';
+import 'api/lint.dart';
+import 'api/machine_settings.dart';
+import 'api/profile.dart';
+import 'api/render.dart';
+import 'api/report.dart';
 import 'api/sender.dart';
 import 'api/settings.dart';
 import 'api/simple.dart';
+import 'api/simulator.dart';
 import 'api/status.dart';
+import 'api/toolpath.dart';
+import 'api/transport.dart';
+import 'api/wcs.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-    );
-  }
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.10.0';
-
-  @override
-  int get rustContentHash => -36696261;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_flutter_tube_cutter',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  double crateApiCutCutAutoAccessorGetCutAngle({required Cut that});
-
-  double crateApiCutCutAutoAccessorGetCutFeedrate({required Cut that});
-
-  Pos2D crateApiCutCutAutoAccessorGetEndPosition({required Cut that});
-
-  double crateApiCutCutAutoAccessorGetPierceDelay({required Cut that});
-
-  double crateApiCutCutAutoAccessorGetPierceDelay2({required Cut that});
-
-  Pos2D crateApiCutCutAutoAccessorGetStartPosition({required Cut that});
-
-  double crateApiCutCutAutoAccessorGetTubeWidth({required Cut that});
-
-  void crateApiCutCutAutoAccessorSetCutAngle({
-    required Cut that,
-    required double cutAngle,
-  });
-
-  void crateApiCutCutAutoAccessorSetCutFeedrate({
-    required Cut that,
-    required double cutFeedrate,
-  });
-
-  void crateApiCutCutAutoAccessorSetEndPosition({
-    required Cut that,
-    required Pos2D endPosition,
-  });
-
-  void crateApiCutCutAutoAccessorSetPierceDelay({
-    required Cut that,
-    required double pierceDelay,
-  });
-
-  void crateApiCutCutAutoAccessorSetPierceDelay2({
-    required Cut that,
-    required double pierceDelay2,
-  });
-
-  void crateApiCutCutAutoAccessorSetStartPosition({
-    required Cut that,
-    required Pos2D startPosition,
-  });
-
-  void crateApiCutCutAutoAccessorSetTubeWidth({
-    required Cut that,
-    required double tubeWidth,
-  });
-
-  (double, double) crateApiCutCutGetEndPos({required Cut that});
-
-  Cut crateApiCutCutNew();
-
-  void crateApiCutCutSetCutAngle({required Cut that, required double newAngle});
+                  RustLib._();
 
-  void crateApiCutCutSetCutFeedrate({
-    required Cut that,
-    required double newFeedrate,
-  });
-
-  void crateApiCutCutSetPierceDelay({
-    required Cut that,
-    required double newDelay,
-  });
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                    );
+                  }
 
-  void crateApiCutCutSetPierceDelay2({
-    required Cut that,
-    required double newDelay,
-  });
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  void crateApiCutCutSetTubeWidth({
-    required Cut that,
-    required double newWidth,
-  });
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  bool crateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcode({
-    required CutterSettings that,
-  });
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  CutMethod crateApiSettingsCutterSettingsAutoAccessorGetCutMethod({
-    required CutterSettings that,
-  });
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  bool crateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCut({
-    required CutterSettings that,
-  });
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
 
-  double crateApiSettingsCutterSettingsAutoAccessorGetJogSpeed({
-    required CutterSettings that,
-  });
+                  }
 
-  double crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetX({
-    required CutterSettings that,
-  });
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  double crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetY({
-    required CutterSettings that,
-  });
+                  @override
+                  String get codegenVersion => '2.10.0';
 
-  bool crateApiSettingsCutterSettingsAutoAccessorGetUseLaser({
-    required CutterSettings that,
-  });
+                  @override
+                  int get rustContentHash => -1746841638;
 
-  void crateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcode({
-    required CutterSettings that,
-    required bool clearExistingGcode,
-  });
-
-  void crateApiSettingsCutterSettingsAutoAccessorSetCutMethod({
-    required CutterSettings that,
-    required CutMethod cutMethod,
-  });
-
-  void crateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCut({
-    required CutterSettings that,
-    required bool homeAfterCut,
-  });
-
-  void crateApiSettingsCutterSettingsAutoAccessorSetJogSpeed({
-    required CutterSettings that,
-    required double jogSpeed,
-  });
-
-  void crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetX({
-    required CutterSettings that,
-    required double laserOffsetX,
-  });
-
-  void crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetY({
-    required CutterSettings that,
-    required double laserOffsetY,
-  });
-
-  void crateApiSettingsCutterSettingsAutoAccessorSetUseLaser({
-    required CutterSettings that,
-    required bool useLaser,
-  });
-
-  CutterSettings crateApiSettingsCutterSettingsLoad();
-
-  CutterSettings crateApiSettingsCutterSettingsNew();
-
-  void crateApiSettingsCutterSettingsSave({required CutterSettings that});
-
-  Future<void> crateApiGcodeGcodeAddCommand({
-    required Gcode that,
-    required String gCommand,
-  });
-
-  Future<void> crateApiGcodeGcodeAddCommandComment({
-    required Gcode that,
-    required String gCommand,
-    required String gComment,
-  });
-
-  void crateApiGcodeGcodeAddCut({
-    required Gcode that,
-    required Cut tubeCut,
-    required CutterSettings cutterSettings,
-  });
-
-  String crateApiGcodeGcodeAutoAccessorGetGcodeString({required Gcode that});
-
-  void crateApiGcodeGcodeAutoAccessorSetGcodeString({
-    required Gcode that,
-    required String gcodeString,
-  });
-
-  Future<void> crateApiGcodeGcodeDwell({
-    required Gcode that,
-    required double dwellTime,
-  });
-
-  String crateApiGcodeGcodeGetGcodeString({required Gcode that});
-
-  Future<void> crateApiGcodeGcodeHome2D({
-    required Gcode that,
-    required double feedrate,
-  });
-
-  Future<void> crateApiGcodeGcodeMoveXy({
-    required Gcode that,
-    required Pos2D newPos,
-    required double feedrate,
-  });
-
-  Gcode crateApiGcodeGcodeNew();
-
-  Future<void> crateApiGcodeGcodeSetPlasmaEnabled({
-    required Gcode that,
-    required bool enabled,
-  });
-
-  Future<void> crateApiGcodeGcodeSetPositioningMode({
-    required Gcode that,
-    required PositioningMode positioningMode,
-  });
-
-  Future<void> crateApiGcodeGcodeSetZero({required Gcode that});
-
-  Future<void> crateApiGcodeGcodeWriteToFile({
-    required Gcode that,
-    required String filename,
-  });
-
-  void crateApiSenderMachineConnectionHome({
-    required MachineConnection that,
-    required CutterSettings cutterSettings,
-  });
-
-  Stream<MachinePosition> crateApiSenderMachineConnectionMakeConnection({
-    required MachineConnection that,
-  });
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_flutter_tube_cutter',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  MachineConnection crateApiSenderMachineConnectionNew();
+                abstract class RustLibApi extends BaseApi {
+                  int? crateApiBoundsBoundsViolationAutoAccessorGetCut({required BoundsViolation that });
 
-  void crateApiSenderMachineConnectionSendGcodeCommand({
-    required MachineConnection that,
-    required String command,
-  });
+int crateApiBoundsBoundsViolationAutoAccessorGetLine({required BoundsViolation that });
 
-  void crateApiSenderMachineConnectionSendStringCommand({
-    required MachineConnection that,
-    required String command,
-  });
+String crateApiBoundsBoundsViolationAutoAccessorGetMessage({required BoundsViolation that });
 
-  void crateApiSenderMachineConnectionSendStringCommandLowPriority({
-    required MachineConnection that,
-    required String command,
-  });
+Pos2D crateApiBoundsBoundsViolationAutoAccessorGetPosition({required BoundsViolation that });
 
-  void crateApiSenderMachineConnectionSetSerialPort({
-    required MachineConnection that,
-    required String newPort,
-  });
+void crateApiBoundsBoundsViolationAutoAccessorSetCut({required BoundsViolation that , int? cut });
 
-  MachineState crateApiStatusMachineStatusAutoAccessorGetMachineState({
-    required MachineStatus that,
-  });
+void crateApiBoundsBoundsViolationAutoAccessorSetLine({required BoundsViolation that , required int line });
 
-  Pos2D crateApiStatusMachineStatusAutoAccessorGetPosition({
-    required MachineStatus that,
-  });
+void crateApiBoundsBoundsViolationAutoAccessorSetMessage({required BoundsViolation that , required String message });
 
-  void crateApiStatusMachineStatusAutoAccessorSetMachineState({
-    required MachineStatus that,
-    required MachineState machineState,
-  });
+void crateApiBoundsBoundsViolationAutoAccessorSetPosition({required BoundsViolation that , required Pos2D position });
 
-  void crateApiStatusMachineStatusAutoAccessorSetPosition({
-    required MachineStatus that,
-    required Pos2D position,
-  });
+double crateApiCutCutAutoAccessorGetCutAngle({required Cut that });
 
-  Future<MachineStatus> crateApiStatusMachineStatusNew();
+double crateApiCutCutAutoAccessorGetCutFeedrate({required Cut that });
 
-  double crateApiGcodePos2DAutoAccessorGetX({required Pos2D that});
+Pos2D crateApiCutCutAutoAccessorGetEndPosition({required Cut that });
 
-  double crateApiGcodePos2DAutoAccessorGetY({required Pos2D that});
+double crateApiCutCutAutoAccessorGetPierceDelay({required Cut that });
 
-  void crateApiGcodePos2DAutoAccessorSetX({
-    required Pos2D that,
-    required double x,
-  });
+double crateApiCutCutAutoAccessorGetPierceDelay2({required Cut that });
 
-  void crateApiGcodePos2DAutoAccessorSetY({
-    required Pos2D that,
-    required double y,
-  });
+Pos2D crateApiCutCutAutoAccessorGetStartPosition({required Cut that });
 
-  Future<Pos2D> crateApiGcodePos2DNew({required double x, required double y});
+double crateApiCutCutAutoAccessorGetTubeWidth({required Cut that });
 
-  Future<Pos2D> crateApiGcodePos2DToScreenSpace({
-    required Pos2D that,
-    required double tubeWidth,
-    required double scaleFactor,
-  });
+void crateApiCutCutAutoAccessorSetCutAngle({required Cut that , required double cutAngle });
 
-  Future<Pos2D> crateApiGcodeCalculateEndPos({
-    required Pos2D startPos,
-    required double tubeWidth,
-    required double cutAngle,
-    required double overshootAmount,
-    required bool cutRight,
-  });
+void crateApiCutCutAutoAccessorSetCutFeedrate({required Cut that , required double cutFeedrate });
 
-  Future<Pos2D> crateApiGcodeGetMidpoint({
-    required Pos2D startPos,
-    required Pos2D endPos,
-  });
+void crateApiCutCutAutoAccessorSetEndPosition({required Cut that , required Pos2D endPosition });
 
-  List<String> crateApiSimpleGetSerialPorts();
+void crateApiCutCutAutoAccessorSetPierceDelay({required Cut that , required double pierceDelay });
 
-  String crateApiSimpleGreet({required String name});
+void crateApiCutCutAutoAccessorSetPierceDelay2({required Cut that , required double pierceDelay2 });
 
-  Future<void> crateApiSimpleInitApp();
+void crateApiCutCutAutoAccessorSetStartPosition({required Cut that , required Pos2D startPosition });
 
-  String crateApiGcodeJog({
-    required double xDist,
-    required double yDist,
-    required CutterSettings cutterSettings,
-  });
+void crateApiCutCutAutoAccessorSetTubeWidth({required Cut that , required double tubeWidth });
 
-  MachinePosition crateApiSenderMachinePositionNew();
+(double,double) crateApiCutCutGetEndPos({required Cut that });
 
-  Future<MachineStatus> crateApiStatusParseStatus({
-    required String statusString,
-  });
+Cut crateApiCutCutNew();
 
-  Future<void> crateApiStatusRuleAllRules();
+void crateApiCutCutSetCutAngle({required Cut that , required double newAngle });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Cut;
+void crateApiCutCutSetCutFeedrate({required Cut that , required double newFeedrate });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Cut;
+void crateApiCutCutSetPierceDelay({required Cut that , required double newDelay });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CutPtr;
+void crateApiCutCutSetPierceDelay2({required Cut that , required double newDelay });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CutMethod;
+void crateApiCutCutSetTubeWidth({required Cut that , required double newWidth });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CutMethod;
+bool crateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcode({required CutterSettings that });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CutMethodPtr;
+ConnectionSettings crateApiSettingsCutterSettingsAutoAccessorGetConnection({required CutterSettings that });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CutterSettings;
+ConsumableRatings crateApiSettingsCutterSettingsAutoAccessorGetConsumableRatings({required CutterSettings that });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CutterSettings;
+CutMethod crateApiSettingsCutterSettingsAutoAccessorGetCutMethod({required CutterSettings that });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_CutterSettingsPtr;
+List<Fixture> crateApiSettingsCutterSettingsAutoAccessorGetFixtures({required CutterSettings that });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Gcode;
+bool crateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCut({required CutterSettings that });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Gcode;
+double crateApiSettingsCutterSettingsAutoAccessorGetJogSpeed({required CutterSettings that });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GcodePtr;
+double crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetX({required CutterSettings that });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_MachineConnection;
+double crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetY({required CutterSettings that });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_MachineConnection;
+LaserOutput crateApiSettingsCutterSettingsAutoAccessorGetLaserOutput({required CutterSettings that });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_MachineConnectionPtr;
+MotionLimits crateApiSettingsCutterSettingsAutoAccessorGetMotionLimits({required CutterSettings that });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_MachineStatus;
+bool crateApiSettingsCutterSettingsAutoAccessorGetUseLaser({required CutterSettings that });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_MachineStatus;
+void crateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcode({required CutterSettings that , required bool clearExistingGcode });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_MachineStatusPtr;
+void crateApiSettingsCutterSettingsAutoAccessorSetConnection({required CutterSettings that , required ConnectionSettings connection });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Pos2D;
+void crateApiSettingsCutterSettingsAutoAccessorSetConsumableRatings({required CutterSettings that , required ConsumableRatings consumableRatings });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Pos2D;
+void crateApiSettingsCutterSettingsAutoAccessorSetCutMethod({required CutterSettings that , required CutMethod cutMethod });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Pos2DPtr;
-}
+void crateApiSettingsCutterSettingsAutoAccessorSetFixtures({required CutterSettings that , required List<Fixture> fixtures });
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+void crateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCut({required CutterSettings that , required bool homeAfterCut });
 
-  @override
-  double crateApiCutCutAutoAccessorGetCutAngle({required Cut that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
+void crateApiSettingsCutterSettingsAutoAccessorSetJogSpeed({required CutterSettings that , required double jogSpeed });
+
+void crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetX({required CutterSettings that , required double laserOffsetX });
+
+void crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetY({required CutterSettings that , required double laserOffsetY });
+
+void crateApiSettingsCutterSettingsAutoAccessorSetLaserOutput({required CutterSettings that , required LaserOutput laserOutput });
+
+void crateApiSettingsCutterSettingsAutoAccessorSetMotionLimits({required CutterSettings that , required MotionLimits motionLimits });
+
+void crateApiSettingsCutterSettingsAutoAccessorSetUseLaser({required CutterSettings that , required bool useLaser });
+
+Future<CutterSettings> crateApiSettingsCutterSettingsDefault();
+
+Fixture? crateApiSettingsCutterSettingsGetFixture({required CutterSettings that , required String name });
+
+CutterSettings crateApiSettingsCutterSettingsLoad();
+
+CutterSettings crateApiSettingsCutterSettingsNew();
+
+void crateApiSettingsCutterSettingsRemoveFixture({required CutterSettings that , required String name });
+
+void crateApiSettingsCutterSettingsSave({required CutterSettings that });
+
+void crateApiSettingsCutterSettingsSaveFixture({required CutterSettings that , required Fixture fixture });
+
+void crateApiSettingsCutterSettingsSetBaudrate({required CutterSettings that , required int newBaudrate });
+
+void crateApiSettingsCutterSettingsSetConsumableRatings({required CutterSettings that , required ConsumableRatings ratings });
+
+void crateApiSettingsCutterSettingsSetDtrOnOpen({required CutterSettings that , required ControlLineOnOpen behavior });
+
+void crateApiSettingsCutterSettingsSetFlowControl({required CutterSettings that , required SerialFlowControl flowControl });
+
+void crateApiSettingsCutterSettingsSetLaserOutput({required CutterSettings that , required LaserOutput output });
+
+void crateApiSettingsCutterSettingsSetLineEnding({required CutterSettings that , required LineEnding lineEnding });
+
+void crateApiSettingsCutterSettingsSetLineNumbers({required CutterSettings that , required bool enabled });
+
+void crateApiSettingsCutterSettingsSetMotionLimits({required CutterSettings that , required MotionLimits limits });
+
+void crateApiSettingsCutterSettingsSetNetworkAddress({required CutterSettings that , required String host , required int port });
+
+void crateApiSettingsCutterSettingsSetReadTimeoutMs({required CutterSettings that , required int timeoutMs });
+
+void crateApiSettingsCutterSettingsSetRtsOnOpen({required CutterSettings that , required ControlLineOnOpen behavior });
+
+void crateApiSettingsCutterSettingsSetSerialPort({required CutterSettings that , required String newPort });
+
+void crateApiSettingsCutterSettingsSetTransport({required CutterSettings that , required TransportKind transport });
+
+CutParameters crateApiJobPlanFeatureAutoAccessorGetParameters({required Feature that });
+
+Profile crateApiJobPlanFeatureAutoAccessorGetProfile({required Feature that });
+
+void crateApiJobPlanFeatureAutoAccessorSetParameters({required Feature that , required CutParameters parameters });
+
+void crateApiJobPlanFeatureAutoAccessorSetProfile({required Feature that , required Profile profile });
+
+Future<void> crateApiGcodeGcodeAddCommand({required Gcode that , required String gCommand });
+
+Future<void> crateApiGcodeGcodeAddCommandComment({required Gcode that , required String gCommand , required String gComment });
+
+void crateApiGcodeGcodeAddCut({required Gcode that , required Cut tubeCut , required CutterSettings cutterSettings });
+
+void crateApiGcodeGcodeAddProfile({required Gcode that , required Profile profile , required CutParameters parameters });
+
+Future<void> crateApiGcodeGcodeArcXy({required Gcode that , required Pos2D newPos , required Pos2D centerOffset , required bool clockwise , required double feedrate });
+
+String crateApiGcodeGcodeAutoAccessorGetGcodeString({required Gcode that });
+
+void crateApiGcodeGcodeAutoAccessorSetGcodeString({required Gcode that , required String gcodeString });
+
+BoundsReport crateApiGcodeGcodeCheckBounds({required Gcode that , required TravelLimits limits , required Pos2D workOffset });
+
+Future<void> crateApiGcodeGcodeCheckCapabilities({required Gcode that , required MachineCapabilities capabilities });
+
+Future<void> crateApiGcodeGcodeDwell({required Gcode that , required double dwellTime });
+
+JobEstimate crateApiGcodeGcodeEstimate({required Gcode that , required MotionLimits limits , required ConsumableRatings ratings });
+
+String crateApiGcodeGcodeExport({required Gcode that , required String path , required PostProcessor postProcessor , ExportHeader? header , required CutterSettings cutterSettings });
+
+int crateApiGcodeGcodeGetCutCount({required Gcode that });
+
+int? crateApiGcodeGcodeGetCutStartLine({required Gcode that , required int cutIndex });
+
+ProgramExtents? crateApiGcodeGcodeGetExtents({required Gcode that });
+
+String crateApiGcodeGcodeGetGcodeString({required Gcode that });
+
+List<ToolpathSegment> crateApiGcodeGcodeGetToolpath({required Gcode that });
+
+Future<void> crateApiGcodeGcodeHome2D({required Gcode that , required double feedrate });
+
+Future<int> crateApiGcodeGcodeLineCount({required Gcode that });
+
+List<LintDiagnostic> crateApiGcodeGcodeLint({required Gcode that , required LintOptions options });
+
+Future<void> crateApiGcodeGcodeMoveXy({required Gcode that , required Pos2D newPos , required double feedrate });
+
+Gcode crateApiGcodeGcodeNew();
+
+Uint8List crateApiGcodeGcodeRenderPng({required Gcode that , required RenderOptions options });
+
+String crateApiGcodeGcodeRenderSvg({required Gcode that , required RenderOptions options });
+
+Future<void> crateApiGcodeGcodeSetPlasmaEnabled({required Gcode that , required bool enabled });
+
+Future<void> crateApiGcodeGcodeSetPositioningMode({required Gcode that , required PositioningMode positioningMode });
+
+Future<void> crateApiGcodeGcodeSetWorkCoordinateSystem({required Gcode that , required WorkCoordinateSystem wcs });
+
+Future<void> crateApiGcodeGcodeSetZero({required Gcode that });
+
+void crateApiGcodeGcodeUseCapabilities({required Gcode that , required MachineCapabilities capabilities });
+
+Future<void> crateApiGcodeGcodeWriteToFile({required Gcode that , required String filename });
+
+Future<bool> crateApiSimulatorGrblSimulatorInAlarm({required GrblSimulator that });
+
+Future<void> crateApiSimulatorGrblSimulatorInjectAlarm({required GrblSimulator that , required int code });
+
+Future<void> crateApiSimulatorGrblSimulatorInjectError({required GrblSimulator that , required int code });
+
+Future<GrblSimulator> crateApiSimulatorGrblSimulatorNew({required SimulatorConfig config });
+
+Future<Pos2D> crateApiSimulatorGrblSimulatorPosition({required GrblSimulator that });
+
+Future<void> crateApiSimulatorGrblSimulatorReceive({required GrblSimulator that , required List<int> bytes });
+
+Future<void> crateApiSimulatorGrblSimulatorReceivedLines({required GrblSimulator that });
+
+Future<Uint8List> crateApiSimulatorGrblSimulatorTakeOutput({required GrblSimulator that });
+
+Future<bool> crateApiSimulatorGrblSimulatorTorchOn({required GrblSimulator that });
+
+Future<void> crateApiSimulatorGrblSimulatorUpdate({required GrblSimulator that });
+
+MachineReference crateApiJobFileJobFileAutoAccessorGetMachine({required JobFile that });
+
+JobPlan crateApiJobFileJobFileAutoAccessorGetPlan({required JobFile that });
+
+int crateApiJobFileJobFileAutoAccessorGetVersion({required JobFile that });
+
+void crateApiJobFileJobFileAutoAccessorSetMachine({required JobFile that , required MachineReference machine });
+
+void crateApiJobFileJobFileAutoAccessorSetPlan({required JobFile that , required JobPlan plan });
+
+void crateApiJobFileJobFileAutoAccessorSetVersion({required JobFile that , required int version });
+
+JobFile crateApiJobFileJobFileFromJson({required String contents });
+
+JobFile crateApiJobFileJobFileLoad({required String path });
+
+JobFile crateApiJobFileJobFileNew({required JobPlan plan , required MachineReference machine });
+
+void crateApiJobFileJobFileSave({required JobFile that , required String path });
+
+Gcode crateApiJobFileJobFileToGcode({required JobFile that });
+
+String crateApiJobFileJobFileToJson({required JobFile that });
+
+void crateApiJobPlanJobPlanAddCut({required JobPlan that , required Cut cut });
+
+void crateApiJobPlanJobPlanAddFeature({required JobPlan that , required Profile profile , required CutParameters parameters });
+
+List<Cut> crateApiJobPlanJobPlanAutoAccessorGetCuts({required JobPlan that });
+
+List<Feature> crateApiJobPlanJobPlanAutoAccessorGetFeatures({required JobPlan that });
+
+String crateApiJobPlanJobPlanAutoAccessorGetName({required JobPlan that });
+
+Stock crateApiJobPlanJobPlanAutoAccessorGetStock({required JobPlan that });
+
+void crateApiJobPlanJobPlanAutoAccessorSetCuts({required JobPlan that , required List<Cut> cuts });
+
+void crateApiJobPlanJobPlanAutoAccessorSetFeatures({required JobPlan that , required List<Feature> features });
+
+void crateApiJobPlanJobPlanAutoAccessorSetName({required JobPlan that , required String name });
+
+void crateApiJobPlanJobPlanAutoAccessorSetStock({required JobPlan that , required Stock stock });
+
+String crateApiJobPlanJobPlanExport({required JobPlan that , required String path , required PostProcessor postProcessor , required CutterSettings cutterSettings });
+
+JobPlan crateApiJobPlanJobPlanNew({required String name , required Stock stock });
+
+List<Part> crateApiJobPlanJobPlanParts({required JobPlan that });
+
+void crateApiJobPlanJobPlanRemoveCut({required JobPlan that , required int cutIndex });
+
+void crateApiJobPlanJobPlanRemoveFeature({required JobPlan that , required int featureIndex });
+
+JobReport crateApiJobPlanJobPlanReport({required JobPlan that , required CutterSettings cutterSettings });
+
+String crateApiJobPlanJobPlanSaveReport({required JobPlan that , required String gcodePath , required CutterSettings cutterSettings });
+
+Gcode crateApiJobPlanJobPlanToGcode({required JobPlan that , required CutterSettings cutterSettings });
+
+Future<LaserCalibration> crateApiLaserLaserCalibrationDefault();
+
+Pos2D crateApiLaserLaserCalibrationFinish({required LaserCalibration that , required MachineConnection connection , required CutterSettings cutterSettings });
+
+void crateApiLaserLaserCalibrationFireTestMark({required LaserCalibration that , required MachineConnection connection , required CutterSettings cutterSettings });
+
+Pos2D? crateApiLaserLaserCalibrationMarkPosition({required LaserCalibration that });
+
+LaserCalibration crateApiLaserLaserCalibrationNew();
+
+void crateApiSenderMachineConnectionAbortJob({required MachineConnection that });
+
+Fixture crateApiSenderMachineConnectionCaptureFixture({required MachineConnection that , required String name , WorkCoordinateSystem? wcs });
+
+void crateApiSenderMachineConnectionDisconnect({required MachineConnection that });
+
+MachineCapabilities crateApiSenderMachineConnectionGetCapabilities({required MachineConnection that });
+
+ConnectionState crateApiSenderMachineConnectionGetConnectionState({required MachineConnection that });
+
+CoordinateOffsets crateApiSenderMachineConnectionGetCoordinateOffsets({required MachineConnection that });
+
+MachineStatus? crateApiSenderMachineConnectionGetLastStatus({required MachineConnection that });
+
+MachineSettings crateApiSenderMachineConnectionGetMachineSettings({required MachineConnection that });
+
+void crateApiSenderMachineConnectionGoToMachinePosition({required MachineConnection that , required double x , required double y });
+
+void crateApiSenderMachineConnectionGoToWorkZero({required MachineConnection that });
+
+void crateApiSenderMachineConnectionHome({required MachineConnection that , required CutterSettings cutterSettings });
+
+bool crateApiSenderMachineConnectionIsConnected({required MachineConnection that });
+
+bool crateApiSenderMachineConnectionIsHomingRequired({required MachineConnection that });
+
+void crateApiSenderMachineConnectionJogStep({required MachineConnection that , required double xDirection , required double yDirection , required JogStep step , required CutterSettings cutterSettings });
+
+Stream<MachinePosition> crateApiSenderMachineConnectionMakeConnection({required MachineConnection that });
+
+MachineConnection crateApiSenderMachineConnectionNew();
+
+void crateApiSenderMachineConnectionPauseJob({required MachineConnection that });
+
+void crateApiSenderMachineConnectionReadCoordinateOffsets({required MachineConnection that });
+
+void crateApiSenderMachineConnectionReadMachineSettings({required MachineConnection that });
+
+void crateApiSenderMachineConnectionRecallFixture({required MachineConnection that , required Fixture fixture });
+
+int crateApiSenderMachineConnectionRestoreMachineSettings({required MachineConnection that , required String filename });
+
+void crateApiSenderMachineConnectionResumeJob({required MachineConnection that });
+
+void crateApiSenderMachineConnectionRunHomingCycle({required MachineConnection that , required HomingAxes axes });
+
+void crateApiSenderMachineConnectionSelectWorkCoordinateSystem({required MachineConnection that , required WorkCoordinateSystem wcs });
+
+void crateApiSenderMachineConnectionSendGcodeCommand({required MachineConnection that , required String command });
+
+void crateApiSenderMachineConnectionSendStringCommand({required MachineConnection that , required String command });
+
+void crateApiSenderMachineConnectionSendStringCommandLowPriority({required MachineConnection that , required String command });
+
+void crateApiSenderMachineConnectionSetBaudrate({required MachineConnection that , required int newBaudrate });
+
+void crateApiSenderMachineConnectionSetConnectionSettings({required MachineConnection that , required CutterSettings cutterSettings });
+
+void crateApiSenderMachineConnectionSetLaserPointer({required MachineConnection that , required bool on_ , required CutterSettings cutterSettings });
+
+void crateApiSenderMachineConnectionSetSerialPort({required MachineConnection that , required String newPort });
+
+void crateApiSenderMachineConnectionSetWorkOffset({required MachineConnection that , required WorkCoordinateSystem wcs , required Axis axis , required double offset });
+
+Stream<JobProgress> crateApiSenderMachineConnectionStartJob({required MachineConnection that , required Gcode gcode });
+
+Stream<JobProgress> crateApiSenderMachineConnectionStartJobFromCut({required MachineConnection that , required Gcode gcode , required int cutIndex });
+
+Stream<JobProgress> crateApiSenderMachineConnectionStartJobFromLine({required MachineConnection that , required Gcode gcode , required int startLine });
+
+void crateApiSenderMachineConnectionStartJog({required MachineConnection that , required double xDirection , required double yDirection , required CutterSettings cutterSettings });
+
+void crateApiSenderMachineConnectionStopJog({required MachineConnection that });
+
+void crateApiSenderMachineConnectionUnlock({required MachineConnection that });
+
+void crateApiSenderMachineConnectionWriteMachineSetting({required MachineConnection that , required int id , required String value });
+
+void crateApiSenderMachineConnectionZeroAllAxes({required MachineConnection that , WorkCoordinateSystem? wcs });
+
+void crateApiSenderMachineConnectionZeroAtLaser({required MachineConnection that , WorkCoordinateSystem? wcs , required CutterSettings cutterSettings });
+
+void crateApiSenderMachineConnectionZeroAxis({required MachineConnection that , WorkCoordinateSystem? wcs , required Axis axis });
+
+List<SettingGroup> crateApiMachineSettingsMachineSettingsAutoAccessorGetGroups({required MachineSettings that });
+
+List<MachineSetting> crateApiMachineSettingsMachineSettingsAutoAccessorGetSettings({required MachineSettings that });
+
+void crateApiMachineSettingsMachineSettingsAutoAccessorSetGroups({required MachineSettings that , required List<SettingGroup> groups });
+
+void crateApiMachineSettingsMachineSettingsAutoAccessorSetSettings({required MachineSettings that , required List<MachineSetting> settings });
+
+Future<MachineSettings> crateApiMachineSettingsMachineSettingsDefault();
+
+Future<SettingDescription?> crateApiMachineSettingsMachineSettingsDescription({required MachineSettings that , required int id });
+
+Future<MachineSetting?> crateApiMachineSettingsMachineSettingsGet({required MachineSettings that , required int id });
+
+Future<void> crateApiMachineSettingsMachineSettingsSaveBackup({required MachineSettings that , required String filename });
+
+Future<String> crateApiMachineSettingsMachineSettingsToBackup({required MachineSettings that });
+
+Future<String> crateApiMachineSettingsMachineSettingsValidate({required MachineSettings that , required int id , required String value });
+
+Accessories? crateApiStatusMachineStatusAutoAccessorGetAccessories({required MachineStatus that });
+
+BufferState? crateApiStatusMachineStatusAutoAccessorGetBuffer({required MachineStatus that });
+
+double? crateApiStatusMachineStatusAutoAccessorGetFeedRate({required MachineStatus that });
+
+int? crateApiStatusMachineStatusAutoAccessorGetLineNumber({required MachineStatus that });
+
+Float32List? crateApiStatusMachineStatusAutoAccessorGetMachinePosition({required MachineStatus that });
+
+MachineState crateApiStatusMachineStatusAutoAccessorGetMachineState({required MachineStatus that });
+
+Overrides? crateApiStatusMachineStatusAutoAccessorGetOverrides({required MachineStatus that });
+
+String? crateApiStatusMachineStatusAutoAccessorGetPins({required MachineStatus that });
+
+Pos2D crateApiStatusMachineStatusAutoAccessorGetPosition({required MachineStatus that });
+
+double? crateApiStatusMachineStatusAutoAccessorGetSpindleSpeed({required MachineStatus that });
+
+int? crateApiStatusMachineStatusAutoAccessorGetSubState({required MachineStatus that });
+
+Float32List? crateApiStatusMachineStatusAutoAccessorGetWorkCoordinateOffset({required MachineStatus that });
+
+Float32List? crateApiStatusMachineStatusAutoAccessorGetWorkPosition({required MachineStatus that });
+
+void crateApiStatusMachineStatusAutoAccessorSetAccessories({required MachineStatus that , Accessories? accessories });
+
+void crateApiStatusMachineStatusAutoAccessorSetBuffer({required MachineStatus that , BufferState? buffer });
+
+void crateApiStatusMachineStatusAutoAccessorSetFeedRate({required MachineStatus that , double? feedRate });
+
+void crateApiStatusMachineStatusAutoAccessorSetLineNumber({required MachineStatus that , int? lineNumber });
+
+void crateApiStatusMachineStatusAutoAccessorSetMachinePosition({required MachineStatus that , Float32List? machinePosition });
+
+void crateApiStatusMachineStatusAutoAccessorSetMachineState({required MachineStatus that , required MachineState machineState });
+
+void crateApiStatusMachineStatusAutoAccessorSetOverrides({required MachineStatus that , Overrides? overrides });
+
+void crateApiStatusMachineStatusAutoAccessorSetPins({required MachineStatus that , String? pins });
+
+void crateApiStatusMachineStatusAutoAccessorSetPosition({required MachineStatus that , required Pos2D position });
+
+void crateApiStatusMachineStatusAutoAccessorSetSpindleSpeed({required MachineStatus that , double? spindleSpeed });
+
+void crateApiStatusMachineStatusAutoAccessorSetSubState({required MachineStatus that , int? subState });
+
+void crateApiStatusMachineStatusAutoAccessorSetWorkCoordinateOffset({required MachineStatus that , Float32List? workCoordinateOffset });
+
+void crateApiStatusMachineStatusAutoAccessorSetWorkPosition({required MachineStatus that , Float32List? workPosition });
+
+Future<MachineStatus> crateApiStatusMachineStatusDefault();
+
+Future<MachineStatus> crateApiStatusMachineStatusNew();
+
+Future<bool> crateApiStatusMachineStatusPinTriggered({required MachineStatus that , required String pin });
+
+Future<void> crateApiInterpreterModalStateApplyLine({required ModalState that , required String line });
+
+Future<void> crateApiInterpreterModalStateApplyWords({required ModalState that , required List<Word> words });
+
+double? crateApiInterpreterModalStateAutoAccessorGetFeedrate({required ModalState that });
+
+MotionMode crateApiInterpreterModalStateAutoAccessorGetMotionMode({required ModalState that });
+
+double? crateApiInterpreterModalStateAutoAccessorGetPierceDelay({required ModalState that });
+
+Pos2D crateApiInterpreterModalStateAutoAccessorGetPosition({required ModalState that });
+
+PositioningMode crateApiInterpreterModalStateAutoAccessorGetPositioningMode({required ModalState that });
+
+bool crateApiInterpreterModalStateAutoAccessorGetTorchOn({required ModalState that });
+
+DistUnit crateApiInterpreterModalStateAutoAccessorGetUnits({required ModalState that });
+
+Pos2D crateApiInterpreterModalStateAutoAccessorGetWorkOffset({required ModalState that });
+
+void crateApiInterpreterModalStateAutoAccessorSetFeedrate({required ModalState that , double? feedrate });
+
+void crateApiInterpreterModalStateAutoAccessorSetMotionMode({required ModalState that , required MotionMode motionMode });
+
+void crateApiInterpreterModalStateAutoAccessorSetPierceDelay({required ModalState that , double? pierceDelay });
+
+void crateApiInterpreterModalStateAutoAccessorSetPosition({required ModalState that , required Pos2D position });
+
+void crateApiInterpreterModalStateAutoAccessorSetPositioningMode({required ModalState that , required PositioningMode positioningMode });
+
+void crateApiInterpreterModalStateAutoAccessorSetTorchOn({required ModalState that , required bool torchOn });
+
+void crateApiInterpreterModalStateAutoAccessorSetUnits({required ModalState that , required DistUnit units });
+
+void crateApiInterpreterModalStateAutoAccessorSetWorkOffset({required ModalState that , required Pos2D workOffset });
+
+Future<ModalState> crateApiInterpreterModalStateDefault();
+
+Future<ModalState> crateApiInterpreterModalStateNew();
+
+Future<Pos2D> crateApiInterpreterModalStateTargetPosition({required ModalState that , required List<Word> words });
+
+double crateApiGcodePos2DAutoAccessorGetX({required Pos2D that });
+
+double crateApiGcodePos2DAutoAccessorGetY({required Pos2D that });
+
+void crateApiGcodePos2DAutoAccessorSetX({required Pos2D that , required double x });
+
+void crateApiGcodePos2DAutoAccessorSetY({required Pos2D that , required double y });
+
+Future<Pos2D> crateApiGcodePos2DNew({required double x , required double y });
+
+Future<Pos2D> crateApiGcodePos2DToScreenSpace({required Pos2D that , required double tubeWidth , required double scaleFactor });
+
+Future<void> crateApiProfileProfileSegmentEnd({required ProfileSegment that });
+
+Future<List<Pos2D>> crateApiProfileProfileSegmentFlatten({required ProfileSegment that , required double tolerance });
+
+Future<void> crateApiProfileProfileSegmentStart({required ProfileSegment that });
+
+List<Contour> crateApiProfileProfileAutoAccessorGetContours({required Profile that });
+
+void crateApiProfileProfileAutoAccessorSetContours({required Profile that , required List<Contour> contours });
+
+(Pos2D,Pos2D)? crateApiProfileProfileBounds({required Profile that });
+
+Future<Profile> crateApiProfileProfileDefault();
+
+Profile crateApiProfileProfileFromDxf({required String contents });
+
+Future<Profile> crateApiProfileProfileFromSegments({required List<ProfileSegment> segments });
+
+Profile crateApiProfileProfileFromSvg({required String contents , required double tolerance });
+
+Profile crateApiProfileProfileImportDxf({required String path });
+
+Profile crateApiProfileProfileImportSvg({required String path , required double tolerance });
+
+Profile crateApiProfileProfileMoved({required Profile that , required double dx , required double dy });
+
+Profile crateApiProfileProfilePlaceOnFlatStock({required Profile that , required double x , required double y });
+
+Profile crateApiProfileProfilePlaceOnTubeFace({required Profile that , required double tubeWidth , required double yPosition });
+
+Profile crateApiProfileProfileScaled({required Profile that , required double factor });
+
+Pos2D crateApiBoundsProgramExtentsAutoAccessorGetMax({required ProgramExtents that });
+
+Pos2D crateApiBoundsProgramExtentsAutoAccessorGetMin({required ProgramExtents that });
+
+void crateApiBoundsProgramExtentsAutoAccessorSetMax({required ProgramExtents that , required Pos2D max });
+
+void crateApiBoundsProgramExtentsAutoAccessorSetMin({required ProgramExtents that , required Pos2D min });
+
+Pos2D crateApiToolpathToolpathSegmentAutoAccessorGetEnd({required ToolpathSegment that });
+
+double? crateApiToolpathToolpathSegmentAutoAccessorGetFeedrate({required ToolpathSegment that });
+
+SegmentKind crateApiToolpathToolpathSegmentAutoAccessorGetKind({required ToolpathSegment that });
+
+int crateApiToolpathToolpathSegmentAutoAccessorGetLine({required ToolpathSegment that });
+
+Pos2D crateApiToolpathToolpathSegmentAutoAccessorGetStart({required ToolpathSegment that });
+
+bool crateApiToolpathToolpathSegmentAutoAccessorGetTorchOn({required ToolpathSegment that });
+
+void crateApiToolpathToolpathSegmentAutoAccessorSetEnd({required ToolpathSegment that , required Pos2D end });
+
+void crateApiToolpathToolpathSegmentAutoAccessorSetFeedrate({required ToolpathSegment that , double? feedrate });
+
+void crateApiToolpathToolpathSegmentAutoAccessorSetKind({required ToolpathSegment that , required SegmentKind kind });
+
+void crateApiToolpathToolpathSegmentAutoAccessorSetLine({required ToolpathSegment that , required int line });
+
+void crateApiToolpathToolpathSegmentAutoAccessorSetStart({required ToolpathSegment that , required Pos2D start });
+
+void crateApiToolpathToolpathSegmentAutoAccessorSetTorchOn({required ToolpathSegment that , required bool torchOn });
+
+Future<List<Pos2D>> crateApiToolpathToolpathSegmentFlatten({required ToolpathSegment that , required double tolerance });
+
+Future<double> crateApiToolpathToolpathSegmentLength({required ToolpathSegment that });
+
+Pos2D crateApiRenderViewportAutoAccessorGetOffset({required Viewport that });
+
+double crateApiRenderViewportAutoAccessorGetScale({required Viewport that });
+
+void crateApiRenderViewportAutoAccessorSetOffset({required Viewport that , required Pos2D offset });
+
+void crateApiRenderViewportAutoAccessorSetScale({required Viewport that , required double scale });
+
+Viewport crateApiRenderViewportCenteredOnTube({required double tubeWidth , required double scale });
+
+Viewport crateApiRenderViewportFit({required Pos2D min , required Pos2D max , required double width , required double height , required double margin });
+
+Viewport crateApiRenderViewportNew({required double scale , required Pos2D offset });
+
+Pos2D crateApiRenderViewportToScreen({required Viewport that , required Pos2D position });
+
+Future<Accessories> crateApiStatusAccessoriesDefault();
+
+Future<BoundsReport> crateApiBoundsBoundsReportDefault();
+
+Future<bool> crateApiBoundsBoundsReportIsWithinLimits({required BoundsReport that });
+
+Future<Pos2D> crateApiGcodeCalculateEndPos({required Pos2D startPos , required double tubeWidth , required double cutAngle , required double overshootAmount , required bool cutRight });
+
+Future<BoundsReport> crateApiBoundsCheckSegments({required List<ToolpathSegment> segments , required TravelLimits limits , required Pos2D workOffset , required List<int> cutStartLines });
+
+Future<ConnectionSettings> crateApiSettingsConnectionSettingsDefault();
+
+Future<ConsumableRatings> crateApiEstimateConsumableRatingsDefault();
+
+Future<Fixture> crateApiWcsCoordinateOffsetsCaptureFixture({required CoordinateOffsets that , required String name , WorkCoordinateSystem? wcs });
+
+Future<CoordinateOffsets> crateApiWcsCoordinateOffsetsDefault();
+
+Future<WorkOffset?> crateApiWcsCoordinateOffsetsOffset({required CoordinateOffsets that , required WorkCoordinateSystem wcs });
+
+Future<JobEstimate> crateApiEstimateEstimateSegments({required List<ToolpathSegment> segments , required MotionLimits limits , required ConsumableRatings ratings });
+
+String crateApiExportExportPath({required String path , required PostProcessor postProcessor });
+
+Future<Pos2D> crateApiGcodeGetMidpoint({required Pos2D startPos , required Pos2D endPos });
+
+List<String> crateApiSimpleGetSerialPorts();
+
+String crateApiSimpleGreet({required String name });
+
+Future<void> crateApiSimpleInitApp();
+
+Future<JobEstimate> crateApiEstimateJobEstimateDefault();
+
+String crateApiReportJobReportToHtml({required JobReport that });
+
+String crateApiGcodeJog({required double xDist , required double yDist , required CutterSettings cutterSettings });
+
+Future<double> crateApiJogJogStepDistance({required JogStep that });
+
+Future<LaserOutput> crateApiLaserLaserOutputDefault();
+
+Future<void> crateApiSettingsLineEndingAsStr({required LineEnding that });
+
+Future<LintOptions> crateApiLintLintOptionsDefault();
+
+Future<List<LintDiagnostic>> crateApiLintLintProgram({required String program , required LintOptions options });
+
+Future<MachineCapabilities> crateApiCapabilitiesMachineCapabilitiesDefault();
+
+Future<bool> crateApiCapabilitiesMachineCapabilitiesHasAxis({required MachineCapabilities that , required String axis });
+
+Future<bool> crateApiCapabilitiesMachineCapabilitiesHasMistCoolant({required MachineCapabilities that });
+
+Future<bool> crateApiCapabilitiesMachineCapabilitiesHasProbe({required MachineCapabilities that });
+
+Future<bool> crateApiCapabilitiesMachineCapabilitiesHasRotaryAxis({required MachineCapabilities that });
+
+Future<bool> crateApiCapabilitiesMachineCapabilitiesHasThc({required MachineCapabilities that });
+
+Future<bool> crateApiCapabilitiesMachineCapabilitiesHasVariableSpindle({required MachineCapabilities that });
+
+Future<bool> crateApiCapabilitiesMachineCapabilitiesIsGrblhal({required MachineCapabilities that });
+
+Future<bool> crateApiCapabilitiesMachineCapabilitiesIsKnown({required MachineCapabilities that });
+
+MachinePosition crateApiSenderMachinePositionNew();
+
+MachineReference crateApiJobFileMachineReferenceFromSettings({required String name , required CutterSettings cutterSettings });
+
+bool crateApiJobFileMachineReferenceMatches({required MachineReference that , required CutterSettings cutterSettings });
+
+Future<MotionLimits> crateApiEstimateMotionLimitsDefault();
+
+MotionLimits crateApiEstimateMotionLimitsFromMachineSettings({required MachineSettings machineSettings });
+
+Future<MachineStatus> crateApiStatusParseStatus({required String statusString });
+
+Future<List<Word>> crateApiInterpreterParseWords({required String line });
+
+String crateApiExportPostProcessorExtension({required PostProcessor that });
+
+Future<ProgramExtents?> crateApiBoundsProgramExtents({required List<ToolpathSegment> segments });
+
+Future<RenderOptions> crateApiRenderRenderOptionsDefault();
+
+Future<Uint8List> crateApiRenderRenderPng({required List<ToolpathSegment> segments , required List<int> cutStartLines , required RenderOptions options });
+
+Future<String> crateApiRenderRenderSvg({required List<ToolpathSegment> segments , required List<int> cutStartLines , required RenderOptions options });
+
+Future<String> crateApiReportReportPath({required String gcodePath });
+
+Future<void> crateApiStatusRuleAllRules();
+
+Future<List<ToolpathSegment>> crateApiToolpathSimulateToolpath({required String program });
+
+Future<SimulatorConfig> crateApiSimulatorSimulatorConfigDefault();
+
+Future<String> crateApiInterpreterStripComments({required String line });
+
+Future<bool> crateApiBoundsTravelLimitsContains({required TravelLimits that , required Pos2D position });
+
+TravelLimits? crateApiBoundsTravelLimitsFromMachineSettings({required MachineSettings machineSettings });
+
+Future<WorkCoordinateSystem?> crateApiWcsWorkCoordinateSystemFromGcode({required String gcode });
+
+Future<void> crateApiWcsWorkCoordinateSystemGcode({required WorkCoordinateSystem that });
+
+Future<int> crateApiWcsWorkCoordinateSystemIndex({required WorkCoordinateSystem that });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BoundsViolation;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BoundsViolation;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BoundsViolationPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Cut;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Cut;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CutPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CutMethod;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CutMethod;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CutMethodPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CutterSettings;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CutterSettings;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CutterSettingsPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Feature;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Feature;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_FeaturePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Gcode;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Gcode;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GcodePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_GrblSimulator;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_GrblSimulator;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GrblSimulatorPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_JobFile;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_JobFile;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_JobFilePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_JobPlan;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_JobPlan;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_JobPlanPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LaserCalibration;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LaserCalibration;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LaserCalibrationPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MachineConnection;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MachineConnection;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MachineConnectionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MachineSettings;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MachineSettings;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MachineSettingsPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MachineStatus;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MachineStatus;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MachineStatusPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ModalState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ModalState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ModalStatePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Pos2D;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Pos2D;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Pos2DPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Profile;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Profile;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ProfilePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ProfileSegment;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ProfileSegment;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ProfileSegmentPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ProgramExtents;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ProgramExtents;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ProgramExtentsPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SegmentKind;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SegmentKind;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SegmentKindPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ToolpathSegment;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ToolpathSegment;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ToolpathSegmentPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Viewport;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Viewport;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ViewportPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override int? crateApiBoundsBoundsViolationAutoAccessorGetCut({required BoundsViolation that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsViolationAutoAccessorGetCutConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsViolationAutoAccessorGetCutConstMeta => const TaskConstMeta(
+            debugName: "BoundsViolation_auto_accessor_get_cut",
+            argNames: ["that"],
+        );
+        
+
+@override int crateApiBoundsBoundsViolationAutoAccessorGetLine({required BoundsViolation that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsViolationAutoAccessorGetLineConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsViolationAutoAccessorGetLineConstMeta => const TaskConstMeta(
+            debugName: "BoundsViolation_auto_accessor_get_line",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiBoundsBoundsViolationAutoAccessorGetMessage({required BoundsViolation that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsViolationAutoAccessorGetMessageConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsViolationAutoAccessorGetMessageConstMeta => const TaskConstMeta(
+            debugName: "BoundsViolation_auto_accessor_get_message",
+            argNames: ["that"],
+        );
+        
+
+@override Pos2D crateApiBoundsBoundsViolationAutoAccessorGetPosition({required BoundsViolation that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsViolationAutoAccessorGetPositionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsViolationAutoAccessorGetPositionConstMeta => const TaskConstMeta(
+            debugName: "BoundsViolation_auto_accessor_get_position",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiBoundsBoundsViolationAutoAccessorSetCut({required BoundsViolation that , int? cut })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(that, serializer);
+sse_encode_opt_box_autoadd_u_32(cut, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsViolationAutoAccessorSetCutConstMeta,
+            argValues: [that, cut],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsViolationAutoAccessorSetCutConstMeta => const TaskConstMeta(
+            debugName: "BoundsViolation_auto_accessor_set_cut",
+            argNames: ["that", "cut"],
+        );
+        
+
+@override void crateApiBoundsBoundsViolationAutoAccessorSetLine({required BoundsViolation that , required int line })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(that, serializer);
+sse_encode_u_32(line, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsViolationAutoAccessorSetLineConstMeta,
+            argValues: [that, line],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsViolationAutoAccessorSetLineConstMeta => const TaskConstMeta(
+            debugName: "BoundsViolation_auto_accessor_set_line",
+            argNames: ["that", "line"],
+        );
+        
+
+@override void crateApiBoundsBoundsViolationAutoAccessorSetMessage({required BoundsViolation that , required String message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(that, serializer);
+sse_encode_String(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsViolationAutoAccessorSetMessageConstMeta,
+            argValues: [that, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsViolationAutoAccessorSetMessageConstMeta => const TaskConstMeta(
+            debugName: "BoundsViolation_auto_accessor_set_message",
+            argNames: ["that", "message"],
+        );
+        
+
+@override void crateApiBoundsBoundsViolationAutoAccessorSetPosition({required BoundsViolation that , required Pos2D position })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(position, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsViolationAutoAccessorSetPositionConstMeta,
+            argValues: [that, position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsViolationAutoAccessorSetPositionConstMeta => const TaskConstMeta(
+            debugName: "BoundsViolation_auto_accessor_set_position",
+            argNames: ["that", "position"],
+        );
+        
+
+@override double crateApiCutCutAutoAccessorGetCutAngle({required Cut that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorGetCutAngleConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorGetCutAngleConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_get_cut_angle",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiCutCutAutoAccessorGetCutFeedrate({required Cut that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorGetCutFeedrateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorGetCutFeedrateConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_get_cut_feedrate",
+            argNames: ["that"],
+        );
+        
+
+@override Pos2D crateApiCutCutAutoAccessorGetEndPosition({required Cut that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorGetEndPositionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorGetEndPositionConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_get_end_position",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiCutCutAutoAccessorGetPierceDelay({required Cut that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorGetPierceDelayConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorGetPierceDelayConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_get_pierce_delay",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiCutCutAutoAccessorGetPierceDelay2({required Cut that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorGetPierceDelay2ConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorGetPierceDelay2ConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_get_pierce_delay_2",
+            argNames: ["that"],
+        );
+        
+
+@override Pos2D crateApiCutCutAutoAccessorGetStartPosition({required Cut that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorGetStartPositionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorGetStartPositionConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_get_start_position",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiCutCutAutoAccessorGetTubeWidth({required Cut that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorGetTubeWidthConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorGetTubeWidthConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_get_tube_width",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiCutCutAutoAccessorSetCutAngle({required Cut that , required double cutAngle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(cutAngle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorSetCutAngleConstMeta,
+            argValues: [that, cutAngle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorSetCutAngleConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_set_cut_angle",
+            argNames: ["that", "cutAngle"],
+        );
+        
+
+@override void crateApiCutCutAutoAccessorSetCutFeedrate({required Cut that , required double cutFeedrate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(cutFeedrate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorSetCutFeedrateConstMeta,
+            argValues: [that, cutFeedrate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorSetCutFeedrateConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_set_cut_feedrate",
+            argNames: ["that", "cutFeedrate"],
+        );
+        
+
+@override void crateApiCutCutAutoAccessorSetEndPosition({required Cut that , required Pos2D endPosition })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(endPosition, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorSetEndPositionConstMeta,
+            argValues: [that, endPosition],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorSetEndPositionConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_set_end_position",
+            argNames: ["that", "endPosition"],
+        );
+        
+
+@override void crateApiCutCutAutoAccessorSetPierceDelay({required Cut that , required double pierceDelay })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(pierceDelay, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorSetPierceDelayConstMeta,
+            argValues: [that, pierceDelay],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorSetPierceDelayConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_set_pierce_delay",
+            argNames: ["that", "pierceDelay"],
+        );
+        
+
+@override void crateApiCutCutAutoAccessorSetPierceDelay2({required Cut that , required double pierceDelay2 })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(pierceDelay2, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorSetPierceDelay2ConstMeta,
+            argValues: [that, pierceDelay2],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorSetPierceDelay2ConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_set_pierce_delay_2",
+            argNames: ["that", "pierceDelay2"],
+        );
+        
+
+@override void crateApiCutCutAutoAccessorSetStartPosition({required Cut that , required Pos2D startPosition })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(startPosition, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorSetStartPositionConstMeta,
+            argValues: [that, startPosition],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorSetStartPositionConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_set_start_position",
+            argNames: ["that", "startPosition"],
+        );
+        
+
+@override void crateApiCutCutAutoAccessorSetTubeWidth({required Cut that , required double tubeWidth })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(tubeWidth, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutAutoAccessorSetTubeWidthConstMeta,
+            argValues: [that, tubeWidth],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutAutoAccessorSetTubeWidthConstMeta => const TaskConstMeta(
+            debugName: "Cut_auto_accessor_set_tube_width",
+            argNames: ["that", "tubeWidth"],
+        );
+        
+
+@override (double,double) crateApiCutCutGetEndPos({required Cut that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_f_32_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutGetEndPosConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutGetEndPosConstMeta => const TaskConstMeta(
+            debugName: "Cut_get_end_pos",
+            argNames: ["that"],
+        );
+        
+
+@override Cut crateApiCutCutNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutNewConstMeta => const TaskConstMeta(
+            debugName: "Cut_new",
+            argNames: [],
+        );
+        
+
+@override void crateApiCutCutSetCutAngle({required Cut that , required double newAngle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(newAngle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutSetCutAngleConstMeta,
+            argValues: [that, newAngle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutSetCutAngleConstMeta => const TaskConstMeta(
+            debugName: "Cut_set_cut_angle",
+            argNames: ["that", "newAngle"],
+        );
+        
+
+@override void crateApiCutCutSetCutFeedrate({required Cut that , required double newFeedrate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(newFeedrate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutSetCutFeedrateConstMeta,
+            argValues: [that, newFeedrate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutSetCutFeedrateConstMeta => const TaskConstMeta(
+            debugName: "Cut_set_cut_feedrate",
+            argNames: ["that", "newFeedrate"],
+        );
+        
+
+@override void crateApiCutCutSetPierceDelay({required Cut that , required double newDelay })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(newDelay, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutSetPierceDelayConstMeta,
+            argValues: [that, newDelay],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutSetPierceDelayConstMeta => const TaskConstMeta(
+            debugName: "Cut_set_pierce_delay",
+            argNames: ["that", "newDelay"],
+        );
+        
+
+@override void crateApiCutCutSetPierceDelay2({required Cut that , required double newDelay })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(newDelay, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutSetPierceDelay2ConstMeta,
+            argValues: [that, newDelay],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutSetPierceDelay2ConstMeta => const TaskConstMeta(
+            debugName: "Cut_set_pierce_delay_2",
+            argNames: ["that", "newDelay"],
+        );
+        
+
+@override void crateApiCutCutSetTubeWidth({required Cut that , required double newWidth })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(that, serializer);
+sse_encode_f_32(newWidth, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCutCutSetTubeWidthConstMeta,
+            argValues: [that, newWidth],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCutCutSetTubeWidthConstMeta => const TaskConstMeta(
+            debugName: "Cut_set_tube_width",
+            argNames: ["that", "newWidth"],
+        );
+        
+
+@override bool crateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcode({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcodeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcodeConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_clear_existing_gcode",
+            argNames: ["that"],
+        );
+        
+
+@override ConnectionSettings crateApiSettingsCutterSettingsAutoAccessorGetConnection({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_settings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetConnectionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetConnectionConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_connection",
+            argNames: ["that"],
+        );
+        
+
+@override ConsumableRatings crateApiSettingsCutterSettingsAutoAccessorGetConsumableRatings({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_consumable_ratings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetConsumableRatingsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetConsumableRatingsConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_consumable_ratings",
+            argNames: ["that"],
+        );
+        
+
+@override CutMethod crateApiSettingsCutterSettingsAutoAccessorGetCutMethod({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetCutMethodConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetCutMethodConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_cut_method",
+            argNames: ["that"],
+        );
+        
+
+@override List<Fixture> crateApiSettingsCutterSettingsAutoAccessorGetFixtures({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_fixture,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetFixturesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetFixturesConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_fixtures",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCut({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCutConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCutConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_home_after_cut",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiSettingsCutterSettingsAutoAccessorGetJogSpeed({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetJogSpeedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetJogSpeedConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_jog_speed",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetX({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_f_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorGetCutAngleConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorGetCutAngleConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_get_cut_angle",
-        argNames: ["that"],
-      );
-
-  @override
-  double crateApiCutCutAutoAccessorGetCutFeedrate({required Cut that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_f_32,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetXConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetXConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_laser_offset_x",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetY({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetYConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetYConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_laser_offset_y",
+            argNames: ["that"],
+        );
+        
+
+@override LaserOutput crateApiSettingsCutterSettingsAutoAccessorGetLaserOutput({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_laser_output,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOutputConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOutputConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_laser_output",
+            argNames: ["that"],
+        );
+        
+
+@override MotionLimits crateApiSettingsCutterSettingsAutoAccessorGetMotionLimits({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_motion_limits,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetMotionLimitsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetMotionLimitsConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_motion_limits",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiSettingsCutterSettingsAutoAccessorGetUseLaser({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorGetUseLaserConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorGetUseLaserConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_get_use_laser",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcode({required CutterSettings that , required bool clearExistingGcode })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_bool(clearExistingGcode, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcodeConstMeta,
+            argValues: [that, clearExistingGcode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcodeConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_clear_existing_gcode",
+            argNames: ["that", "clearExistingGcode"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetConnection({required CutterSettings that , required ConnectionSettings connection })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_connection_settings(connection, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetConnectionConstMeta,
+            argValues: [that, connection],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetConnectionConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_connection",
+            argNames: ["that", "connection"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetConsumableRatings({required CutterSettings that , required ConsumableRatings consumableRatings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_consumable_ratings(consumableRatings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetConsumableRatingsConstMeta,
+            argValues: [that, consumableRatings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetConsumableRatingsConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_consumable_ratings",
+            argNames: ["that", "consumableRatings"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetCutMethod({required CutterSettings that , required CutMethod cutMethod })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(cutMethod, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetCutMethodConstMeta,
+            argValues: [that, cutMethod],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetCutMethodConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_cut_method",
+            argNames: ["that", "cutMethod"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetFixtures({required CutterSettings that , required List<Fixture> fixtures })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_list_fixture(fixtures, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetFixturesConstMeta,
+            argValues: [that, fixtures],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetFixturesConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_fixtures",
+            argNames: ["that", "fixtures"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCut({required CutterSettings that , required bool homeAfterCut })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_bool(homeAfterCut, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCutConstMeta,
+            argValues: [that, homeAfterCut],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCutConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_home_after_cut",
+            argNames: ["that", "homeAfterCut"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetJogSpeed({required CutterSettings that , required double jogSpeed })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_f_32(jogSpeed, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetJogSpeedConstMeta,
+            argValues: [that, jogSpeed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetJogSpeedConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_jog_speed",
+            argNames: ["that", "jogSpeed"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetX({required CutterSettings that , required double laserOffsetX })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_f_32(laserOffsetX, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetXConstMeta,
+            argValues: [that, laserOffsetX],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetXConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_laser_offset_x",
+            argNames: ["that", "laserOffsetX"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetY({required CutterSettings that , required double laserOffsetY })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_f_32(laserOffsetY, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetYConstMeta,
+            argValues: [that, laserOffsetY],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetYConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_laser_offset_y",
+            argNames: ["that", "laserOffsetY"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetLaserOutput({required CutterSettings that , required LaserOutput laserOutput })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_laser_output(laserOutput, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOutputConstMeta,
+            argValues: [that, laserOutput],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOutputConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_laser_output",
+            argNames: ["that", "laserOutput"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetMotionLimits({required CutterSettings that , required MotionLimits motionLimits })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_motion_limits(motionLimits, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetMotionLimitsConstMeta,
+            argValues: [that, motionLimits],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetMotionLimitsConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_motion_limits",
+            argNames: ["that", "motionLimits"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsAutoAccessorSetUseLaser({required CutterSettings that , required bool useLaser })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_bool(useLaser, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsAutoAccessorSetUseLaserConstMeta,
+            argValues: [that, useLaser],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsAutoAccessorSetUseLaserConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_auto_accessor_set_use_laser",
+            argNames: ["that", "useLaser"],
+        );
+        
+
+@override Future<CutterSettings> crateApiSettingsCutterSettingsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsDefaultConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_default",
+            argNames: [],
+        );
+        
+
+@override Fixture? crateApiSettingsCutterSettingsGetFixture({required CutterSettings that , required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_fixture,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsGetFixtureConstMeta,
+            argValues: [that, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsGetFixtureConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_get_fixture",
+            argNames: ["that", "name"],
+        );
+        
+
+@override CutterSettings crateApiSettingsCutterSettingsLoad()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsLoadConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsLoadConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_load",
+            argNames: [],
+        );
+        
+
+@override CutterSettings crateApiSettingsCutterSettingsNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsNewConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_new",
+            argNames: [],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsRemoveFixture({required CutterSettings that , required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsRemoveFixtureConstMeta,
+            argValues: [that, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsRemoveFixtureConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_remove_fixture",
+            argNames: ["that", "name"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSave({required CutterSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSaveConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSaveConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_save",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSaveFixture({required CutterSettings that , required Fixture fixture })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_box_autoadd_fixture(fixture, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSaveFixtureConstMeta,
+            argValues: [that, fixture],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSaveFixtureConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_save_fixture",
+            argNames: ["that", "fixture"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetBaudrate({required CutterSettings that , required int newBaudrate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_u_32(newBaudrate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetBaudrateConstMeta,
+            argValues: [that, newBaudrate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetBaudrateConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_baudrate",
+            argNames: ["that", "newBaudrate"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetConsumableRatings({required CutterSettings that , required ConsumableRatings ratings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_box_autoadd_consumable_ratings(ratings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetConsumableRatingsConstMeta,
+            argValues: [that, ratings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetConsumableRatingsConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_consumable_ratings",
+            argNames: ["that", "ratings"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetDtrOnOpen({required CutterSettings that , required ControlLineOnOpen behavior })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_control_line_on_open(behavior, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetDtrOnOpenConstMeta,
+            argValues: [that, behavior],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetDtrOnOpenConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_dtr_on_open",
+            argNames: ["that", "behavior"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetFlowControl({required CutterSettings that , required SerialFlowControl flowControl })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_serial_flow_control(flowControl, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetFlowControlConstMeta,
+            argValues: [that, flowControl],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetFlowControlConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_flow_control",
+            argNames: ["that", "flowControl"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetLaserOutput({required CutterSettings that , required LaserOutput output })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_box_autoadd_laser_output(output, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetLaserOutputConstMeta,
+            argValues: [that, output],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetLaserOutputConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_laser_output",
+            argNames: ["that", "output"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetLineEnding({required CutterSettings that , required LineEnding lineEnding })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_line_ending(lineEnding, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetLineEndingConstMeta,
+            argValues: [that, lineEnding],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetLineEndingConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_line_ending",
+            argNames: ["that", "lineEnding"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetLineNumbers({required CutterSettings that , required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetLineNumbersConstMeta,
+            argValues: [that, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetLineNumbersConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_line_numbers",
+            argNames: ["that", "enabled"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetMotionLimits({required CutterSettings that , required MotionLimits limits })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_box_autoadd_motion_limits(limits, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetMotionLimitsConstMeta,
+            argValues: [that, limits],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetMotionLimitsConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_motion_limits",
+            argNames: ["that", "limits"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetNetworkAddress({required CutterSettings that , required String host , required int port })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_String(host, serializer);
+sse_encode_u_16(port, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetNetworkAddressConstMeta,
+            argValues: [that, host, port],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetNetworkAddressConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_network_address",
+            argNames: ["that", "host", "port"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetReadTimeoutMs({required CutterSettings that , required int timeoutMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_u_32(timeoutMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetReadTimeoutMsConstMeta,
+            argValues: [that, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetReadTimeoutMsConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_read_timeout_ms",
+            argNames: ["that", "timeoutMs"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetRtsOnOpen({required CutterSettings that , required ControlLineOnOpen behavior })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_control_line_on_open(behavior, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetRtsOnOpenConstMeta,
+            argValues: [that, behavior],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetRtsOnOpenConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_rts_on_open",
+            argNames: ["that", "behavior"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetSerialPort({required CutterSettings that , required String newPort })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_String(newPort, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetSerialPortConstMeta,
+            argValues: [that, newPort],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetSerialPortConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_serial_port",
+            argNames: ["that", "newPort"],
+        );
+        
+
+@override void crateApiSettingsCutterSettingsSetTransport({required CutterSettings that , required TransportKind transport })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(that, serializer);
+sse_encode_transport_kind(transport, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsCutterSettingsSetTransportConstMeta,
+            argValues: [that, transport],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsCutterSettingsSetTransportConstMeta => const TaskConstMeta(
+            debugName: "CutterSettings_set_transport",
+            argNames: ["that", "transport"],
+        );
+        
+
+@override CutParameters crateApiJobPlanFeatureAutoAccessorGetParameters({required Feature that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cut_parameters,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanFeatureAutoAccessorGetParametersConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanFeatureAutoAccessorGetParametersConstMeta => const TaskConstMeta(
+            debugName: "Feature_auto_accessor_get_parameters",
+            argNames: ["that"],
+        );
+        
+
+@override Profile crateApiJobPlanFeatureAutoAccessorGetProfile({required Feature that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanFeatureAutoAccessorGetProfileConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanFeatureAutoAccessorGetProfileConstMeta => const TaskConstMeta(
+            debugName: "Feature_auto_accessor_get_profile",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiJobPlanFeatureAutoAccessorSetParameters({required Feature that , required CutParameters parameters })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(that, serializer);
+sse_encode_cut_parameters(parameters, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanFeatureAutoAccessorSetParametersConstMeta,
+            argValues: [that, parameters],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanFeatureAutoAccessorSetParametersConstMeta => const TaskConstMeta(
+            debugName: "Feature_auto_accessor_set_parameters",
+            argNames: ["that", "parameters"],
+        );
+        
+
+@override void crateApiJobPlanFeatureAutoAccessorSetProfile({required Feature that , required Profile profile })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(profile, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanFeatureAutoAccessorSetProfileConstMeta,
+            argValues: [that, profile],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanFeatureAutoAccessorSetProfileConstMeta => const TaskConstMeta(
+            debugName: "Feature_auto_accessor_set_profile",
+            argNames: ["that", "profile"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeAddCommand({required Gcode that , required String gCommand })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_String(gCommand, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeAddCommandConstMeta,
+            argValues: [that, gCommand],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeAddCommandConstMeta => const TaskConstMeta(
+            debugName: "Gcode_add_command",
+            argNames: ["that", "gCommand"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeAddCommandComment({required Gcode that , required String gCommand , required String gComment })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_String(gCommand, serializer);
+sse_encode_String(gComment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeAddCommandCommentConstMeta,
+            argValues: [that, gCommand, gComment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeAddCommandCommentConstMeta => const TaskConstMeta(
+            debugName: "Gcode_add_command_comment",
+            argNames: ["that", "gCommand", "gComment"],
+        );
+        
+
+@override void crateApiGcodeGcodeAddCut({required Gcode that , required Cut tubeCut , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(tubeCut, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeAddCutConstMeta,
+            argValues: [that, tubeCut, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeAddCutConstMeta => const TaskConstMeta(
+            debugName: "Gcode_add_cut",
+            argNames: ["that", "tubeCut", "cutterSettings"],
+        );
+        
+
+@override void crateApiGcodeGcodeAddProfile({required Gcode that , required Profile profile , required CutParameters parameters })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(profile, serializer);
+sse_encode_box_autoadd_cut_parameters(parameters, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeAddProfileConstMeta,
+            argValues: [that, profile, parameters],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeAddProfileConstMeta => const TaskConstMeta(
+            debugName: "Gcode_add_profile",
+            argNames: ["that", "profile", "parameters"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeArcXy({required Gcode that , required Pos2D newPos , required Pos2D centerOffset , required bool clockwise , required double feedrate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(newPos, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(centerOffset, serializer);
+sse_encode_bool(clockwise, serializer);
+sse_encode_f_32(feedrate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeArcXyConstMeta,
+            argValues: [that, newPos, centerOffset, clockwise, feedrate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeArcXyConstMeta => const TaskConstMeta(
+            debugName: "Gcode_arc_xy",
+            argNames: ["that", "newPos", "centerOffset", "clockwise", "feedrate"],
+        );
+        
+
+@override String crateApiGcodeGcodeAutoAccessorGetGcodeString({required Gcode that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeAutoAccessorGetGcodeStringConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeAutoAccessorGetGcodeStringConstMeta => const TaskConstMeta(
+            debugName: "Gcode_auto_accessor_get_gcode_string",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiGcodeGcodeAutoAccessorSetGcodeString({required Gcode that , required String gcodeString })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_String(gcodeString, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeAutoAccessorSetGcodeStringConstMeta,
+            argValues: [that, gcodeString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeAutoAccessorSetGcodeStringConstMeta => const TaskConstMeta(
+            debugName: "Gcode_auto_accessor_set_gcode_string",
+            argNames: ["that", "gcodeString"],
+        );
+        
+
+@override BoundsReport crateApiGcodeGcodeCheckBounds({required Gcode that , required TravelLimits limits , required Pos2D workOffset })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_box_autoadd_travel_limits(limits, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(workOffset, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bounds_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeCheckBoundsConstMeta,
+            argValues: [that, limits, workOffset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeCheckBoundsConstMeta => const TaskConstMeta(
+            debugName: "Gcode_check_bounds",
+            argNames: ["that", "limits", "workOffset"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeCheckCapabilities({required Gcode that , required MachineCapabilities capabilities })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_box_autoadd_machine_capabilities(capabilities, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeCheckCapabilitiesConstMeta,
+            argValues: [that, capabilities],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeCheckCapabilitiesConstMeta => const TaskConstMeta(
+            debugName: "Gcode_check_capabilities",
+            argNames: ["that", "capabilities"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeDwell({required Gcode that , required double dwellTime })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_f_32(dwellTime, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeDwellConstMeta,
+            argValues: [that, dwellTime],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeDwellConstMeta => const TaskConstMeta(
+            debugName: "Gcode_dwell",
+            argNames: ["that", "dwellTime"],
+        );
+        
+
+@override JobEstimate crateApiGcodeGcodeEstimate({required Gcode that , required MotionLimits limits , required ConsumableRatings ratings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_box_autoadd_motion_limits(limits, serializer);
+sse_encode_box_autoadd_consumable_ratings(ratings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_job_estimate,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeEstimateConstMeta,
+            argValues: [that, limits, ratings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeEstimateConstMeta => const TaskConstMeta(
+            debugName: "Gcode_estimate",
+            argNames: ["that", "limits", "ratings"],
+        );
+        
+
+@override String crateApiGcodeGcodeExport({required Gcode that , required String path , required PostProcessor postProcessor , ExportHeader? header , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_String(path, serializer);
+sse_encode_post_processor(postProcessor, serializer);
+sse_encode_opt_box_autoadd_export_header(header, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeExportConstMeta,
+            argValues: [that, path, postProcessor, header, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeExportConstMeta => const TaskConstMeta(
+            debugName: "Gcode_export",
+            argNames: ["that", "path", "postProcessor", "header", "cutterSettings"],
+        );
+        
+
+@override int crateApiGcodeGcodeGetCutCount({required Gcode that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeGetCutCountConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeGetCutCountConstMeta => const TaskConstMeta(
+            debugName: "Gcode_get_cut_count",
+            argNames: ["that"],
+        );
+        
+
+@override int? crateApiGcodeGcodeGetCutStartLine({required Gcode that , required int cutIndex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_u_32(cutIndex, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeGetCutStartLineConstMeta,
+            argValues: [that, cutIndex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeGetCutStartLineConstMeta => const TaskConstMeta(
+            debugName: "Gcode_get_cut_start_line",
+            argNames: ["that", "cutIndex"],
+        );
+        
+
+@override ProgramExtents? crateApiGcodeGcodeGetExtents({required Gcode that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeGetExtentsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeGetExtentsConstMeta => const TaskConstMeta(
+            debugName: "Gcode_get_extents",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiGcodeGcodeGetGcodeString({required Gcode that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeGetGcodeStringConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeGetGcodeStringConstMeta => const TaskConstMeta(
+            debugName: "Gcode_get_gcode_string",
+            argNames: ["that"],
+        );
+        
+
+@override List<ToolpathSegment> crateApiGcodeGcodeGetToolpath({required Gcode that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeGetToolpathConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeGetToolpathConstMeta => const TaskConstMeta(
+            debugName: "Gcode_get_toolpath",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeHome2D({required Gcode that , required double feedrate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_f_32(feedrate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeHome2DConstMeta,
+            argValues: [that, feedrate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeHome2DConstMeta => const TaskConstMeta(
+            debugName: "Gcode_home2D",
+            argNames: ["that", "feedrate"],
+        );
+        
+
+@override Future<int> crateApiGcodeGcodeLineCount({required Gcode that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeLineCountConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeLineCountConstMeta => const TaskConstMeta(
+            debugName: "Gcode_line_count",
+            argNames: ["that"],
+        );
+        
+
+@override List<LintDiagnostic> crateApiGcodeGcodeLint({required Gcode that , required LintOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_box_autoadd_lint_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_lint_diagnostic,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeLintConstMeta,
+            argValues: [that, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeLintConstMeta => const TaskConstMeta(
+            debugName: "Gcode_lint",
+            argNames: ["that", "options"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeMoveXy({required Gcode that , required Pos2D newPos , required double feedrate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(newPos, serializer);
+sse_encode_f_32(feedrate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeMoveXyConstMeta,
+            argValues: [that, newPos, feedrate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeMoveXyConstMeta => const TaskConstMeta(
+            debugName: "Gcode_move_xy",
+            argNames: ["that", "newPos", "feedrate"],
+        );
+        
+
+@override Gcode crateApiGcodeGcodeNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeNewConstMeta => const TaskConstMeta(
+            debugName: "Gcode_new",
+            argNames: [],
+        );
+        
+
+@override Uint8List crateApiGcodeGcodeRenderPng({required Gcode that , required RenderOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeRenderPngConstMeta,
+            argValues: [that, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeRenderPngConstMeta => const TaskConstMeta(
+            debugName: "Gcode_render_png",
+            argNames: ["that", "options"],
+        );
+        
+
+@override String crateApiGcodeGcodeRenderSvg({required Gcode that , required RenderOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeRenderSvgConstMeta,
+            argValues: [that, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeRenderSvgConstMeta => const TaskConstMeta(
+            debugName: "Gcode_render_svg",
+            argNames: ["that", "options"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeSetPlasmaEnabled({required Gcode that , required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeSetPlasmaEnabledConstMeta,
+            argValues: [that, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeSetPlasmaEnabledConstMeta => const TaskConstMeta(
+            debugName: "Gcode_set_plasma_enabled",
+            argNames: ["that", "enabled"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeSetPositioningMode({required Gcode that , required PositioningMode positioningMode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_positioning_mode(positioningMode, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeSetPositioningModeConstMeta,
+            argValues: [that, positioningMode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeSetPositioningModeConstMeta => const TaskConstMeta(
+            debugName: "Gcode_set_positioning_mode",
+            argNames: ["that", "positioningMode"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeSetWorkCoordinateSystem({required Gcode that , required WorkCoordinateSystem wcs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_work_coordinate_system(wcs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeSetWorkCoordinateSystemConstMeta,
+            argValues: [that, wcs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeSetWorkCoordinateSystemConstMeta => const TaskConstMeta(
+            debugName: "Gcode_set_work_coordinate_system",
+            argNames: ["that", "wcs"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeSetZero({required Gcode that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeSetZeroConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeSetZeroConstMeta => const TaskConstMeta(
+            debugName: "Gcode_set_zero",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiGcodeGcodeUseCapabilities({required Gcode that , required MachineCapabilities capabilities })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_box_autoadd_machine_capabilities(capabilities, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeUseCapabilitiesConstMeta,
+            argValues: [that, capabilities],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeUseCapabilitiesConstMeta => const TaskConstMeta(
+            debugName: "Gcode_use_capabilities",
+            argNames: ["that", "capabilities"],
+        );
+        
+
+@override Future<void> crateApiGcodeGcodeWriteToFile({required Gcode that , required String filename })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(that, serializer);
+sse_encode_String(filename, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGcodeGcodeWriteToFileConstMeta,
+            argValues: [that, filename],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGcodeWriteToFileConstMeta => const TaskConstMeta(
+            debugName: "Gcode_write_to_file",
+            argNames: ["that", "filename"],
+        );
+        
+
+@override Future<bool> crateApiSimulatorGrblSimulatorInAlarm({required GrblSimulator that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorInAlarmConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorInAlarmConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_in_alarm",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiSimulatorGrblSimulatorInjectAlarm({required GrblSimulator that , required int code })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(that, serializer);
+sse_encode_u_32(code, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorInjectAlarmConstMeta,
+            argValues: [that, code],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorInjectAlarmConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_inject_alarm",
+            argNames: ["that", "code"],
+        );
+        
+
+@override Future<void> crateApiSimulatorGrblSimulatorInjectError({required GrblSimulator that , required int code })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(that, serializer);
+sse_encode_u_32(code, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorInjectErrorConstMeta,
+            argValues: [that, code],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorInjectErrorConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_inject_error",
+            argNames: ["that", "code"],
+        );
+        
+
+@override Future<GrblSimulator> crateApiSimulatorGrblSimulatorNew({required SimulatorConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_simulator_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorNewConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorNewConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_new",
+            argNames: ["config"],
+        );
+        
+
+@override Future<Pos2D> crateApiSimulatorGrblSimulatorPosition({required GrblSimulator that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorPositionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorPositionConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_position",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiSimulatorGrblSimulatorReceive({required GrblSimulator that , required List<int> bytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(that, serializer);
+sse_encode_list_prim_u_8_loose(bytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorReceiveConstMeta,
+            argValues: [that, bytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorReceiveConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_receive",
+            argNames: ["that", "bytes"],
+        );
+        
+
+@override Future<void> crateApiSimulatorGrblSimulatorReceivedLines({required GrblSimulator that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorReceivedLinesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorReceivedLinesConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_received_lines",
+            argNames: ["that"],
+        );
+        
+
+@override Future<Uint8List> crateApiSimulatorGrblSimulatorTakeOutput({required GrblSimulator that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorTakeOutputConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorTakeOutputConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_take_output",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiSimulatorGrblSimulatorTorchOn({required GrblSimulator that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorTorchOnConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorTorchOnConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_torch_on",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiSimulatorGrblSimulatorUpdate({required GrblSimulator that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorGrblSimulatorUpdateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorGrblSimulatorUpdateConstMeta => const TaskConstMeta(
+            debugName: "GrblSimulator_update",
+            argNames: ["that"],
+        );
+        
+
+@override MachineReference crateApiJobFileJobFileAutoAccessorGetMachine({required JobFile that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_machine_reference,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileAutoAccessorGetMachineConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileAutoAccessorGetMachineConstMeta => const TaskConstMeta(
+            debugName: "JobFile_auto_accessor_get_machine",
+            argNames: ["that"],
+        );
+        
+
+@override JobPlan crateApiJobFileJobFileAutoAccessorGetPlan({required JobFile that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileAutoAccessorGetPlanConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileAutoAccessorGetPlanConstMeta => const TaskConstMeta(
+            debugName: "JobFile_auto_accessor_get_plan",
+            argNames: ["that"],
+        );
+        
+
+@override int crateApiJobFileJobFileAutoAccessorGetVersion({required JobFile that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileAutoAccessorGetVersionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileAutoAccessorGetVersionConstMeta => const TaskConstMeta(
+            debugName: "JobFile_auto_accessor_get_version",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiJobFileJobFileAutoAccessorSetMachine({required JobFile that , required MachineReference machine })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(that, serializer);
+sse_encode_machine_reference(machine, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileAutoAccessorSetMachineConstMeta,
+            argValues: [that, machine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileAutoAccessorSetMachineConstMeta => const TaskConstMeta(
+            debugName: "JobFile_auto_accessor_set_machine",
+            argNames: ["that", "machine"],
+        );
+        
+
+@override void crateApiJobFileJobFileAutoAccessorSetPlan({required JobFile that , required JobPlan plan })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(plan, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileAutoAccessorSetPlanConstMeta,
+            argValues: [that, plan],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileAutoAccessorSetPlanConstMeta => const TaskConstMeta(
+            debugName: "JobFile_auto_accessor_set_plan",
+            argNames: ["that", "plan"],
+        );
+        
+
+@override void crateApiJobFileJobFileAutoAccessorSetVersion({required JobFile that , required int version })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(that, serializer);
+sse_encode_u_32(version, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileAutoAccessorSetVersionConstMeta,
+            argValues: [that, version],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileAutoAccessorSetVersionConstMeta => const TaskConstMeta(
+            debugName: "JobFile_auto_accessor_set_version",
+            argNames: ["that", "version"],
+        );
+        
+
+@override JobFile crateApiJobFileJobFileFromJson({required String contents })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(contents, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileFromJsonConstMeta,
+            argValues: [contents],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileFromJsonConstMeta => const TaskConstMeta(
+            debugName: "JobFile_from_json",
+            argNames: ["contents"],
+        );
+        
+
+@override JobFile crateApiJobFileJobFileLoad({required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileLoadConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileLoadConstMeta => const TaskConstMeta(
+            debugName: "JobFile_load",
+            argNames: ["path"],
+        );
+        
+
+@override JobFile crateApiJobFileJobFileNew({required JobPlan plan , required MachineReference machine })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(plan, serializer);
+sse_encode_box_autoadd_machine_reference(machine, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileNewConstMeta,
+            argValues: [plan, machine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileNewConstMeta => const TaskConstMeta(
+            debugName: "JobFile_new",
+            argNames: ["plan", "machine"],
+        );
+        
+
+@override void crateApiJobFileJobFileSave({required JobFile that , required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(that, serializer);
+sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileSaveConstMeta,
+            argValues: [that, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileSaveConstMeta => const TaskConstMeta(
+            debugName: "JobFile_save",
+            argNames: ["that", "path"],
+        );
+        
+
+@override Gcode crateApiJobFileJobFileToGcode({required JobFile that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileToGcodeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileToGcodeConstMeta => const TaskConstMeta(
+            debugName: "JobFile_to_gcode",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiJobFileJobFileToJson({required JobFile that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileJobFileToJsonConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileJobFileToJsonConstMeta => const TaskConstMeta(
+            debugName: "JobFile_to_json",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiJobPlanJobPlanAddCut({required JobPlan that , required Cut cut })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(cut, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAddCutConstMeta,
+            argValues: [that, cut],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAddCutConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_add_cut",
+            argNames: ["that", "cut"],
+        );
+        
+
+@override void crateApiJobPlanJobPlanAddFeature({required JobPlan that , required Profile profile , required CutParameters parameters })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(profile, serializer);
+sse_encode_box_autoadd_cut_parameters(parameters, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAddFeatureConstMeta,
+            argValues: [that, profile, parameters],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAddFeatureConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_add_feature",
+            argNames: ["that", "profile", "parameters"],
+        );
+        
+
+@override List<Cut> crateApiJobPlanJobPlanAutoAccessorGetCuts({required JobPlan that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAutoAccessorGetCutsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAutoAccessorGetCutsConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_auto_accessor_get_cuts",
+            argNames: ["that"],
+        );
+        
+
+@override List<Feature> crateApiJobPlanJobPlanAutoAccessorGetFeatures({required JobPlan that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAutoAccessorGetFeaturesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAutoAccessorGetFeaturesConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_auto_accessor_get_features",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiJobPlanJobPlanAutoAccessorGetName({required JobPlan that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAutoAccessorGetNameConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAutoAccessorGetNameConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_auto_accessor_get_name",
+            argNames: ["that"],
+        );
+        
+
+@override Stock crateApiJobPlanJobPlanAutoAccessorGetStock({required JobPlan that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stock,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAutoAccessorGetStockConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAutoAccessorGetStockConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_auto_accessor_get_stock",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiJobPlanJobPlanAutoAccessorSetCuts({required JobPlan that , required List<Cut> cuts })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(cuts, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAutoAccessorSetCutsConstMeta,
+            argValues: [that, cuts],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAutoAccessorSetCutsConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_auto_accessor_set_cuts",
+            argNames: ["that", "cuts"],
+        );
+        
+
+@override void crateApiJobPlanJobPlanAutoAccessorSetFeatures({required JobPlan that , required List<Feature> features })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(features, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAutoAccessorSetFeaturesConstMeta,
+            argValues: [that, features],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAutoAccessorSetFeaturesConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_auto_accessor_set_features",
+            argNames: ["that", "features"],
+        );
+        
+
+@override void crateApiJobPlanJobPlanAutoAccessorSetName({required JobPlan that , required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAutoAccessorSetNameConstMeta,
+            argValues: [that, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAutoAccessorSetNameConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_auto_accessor_set_name",
+            argNames: ["that", "name"],
+        );
+        
+
+@override void crateApiJobPlanJobPlanAutoAccessorSetStock({required JobPlan that , required Stock stock })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_stock(stock, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanAutoAccessorSetStockConstMeta,
+            argValues: [that, stock],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanAutoAccessorSetStockConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_auto_accessor_set_stock",
+            argNames: ["that", "stock"],
+        );
+        
+
+@override String crateApiJobPlanJobPlanExport({required JobPlan that , required String path , required PostProcessor postProcessor , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_String(path, serializer);
+sse_encode_post_processor(postProcessor, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanExportConstMeta,
+            argValues: [that, path, postProcessor, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanExportConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_export",
+            argNames: ["that", "path", "postProcessor", "cutterSettings"],
+        );
+        
+
+@override JobPlan crateApiJobPlanJobPlanNew({required String name , required Stock stock })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_box_autoadd_stock(stock, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanNewConstMeta,
+            argValues: [name, stock],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanNewConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_new",
+            argNames: ["name", "stock"],
+        );
+        
+
+@override List<Part> crateApiJobPlanJobPlanParts({required JobPlan that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_part,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanPartsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanPartsConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_parts",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiJobPlanJobPlanRemoveCut({required JobPlan that , required int cutIndex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_u_32(cutIndex, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanRemoveCutConstMeta,
+            argValues: [that, cutIndex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanRemoveCutConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_remove_cut",
+            argNames: ["that", "cutIndex"],
+        );
+        
+
+@override void crateApiJobPlanJobPlanRemoveFeature({required JobPlan that , required int featureIndex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_u_32(featureIndex, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanRemoveFeatureConstMeta,
+            argValues: [that, featureIndex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanRemoveFeatureConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_remove_feature",
+            argNames: ["that", "featureIndex"],
+        );
+        
+
+@override JobReport crateApiJobPlanJobPlanReport({required JobPlan that , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_job_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanReportConstMeta,
+            argValues: [that, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanReportConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_report",
+            argNames: ["that", "cutterSettings"],
+        );
+        
+
+@override String crateApiJobPlanJobPlanSaveReport({required JobPlan that , required String gcodePath , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_String(gcodePath, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanSaveReportConstMeta,
+            argValues: [that, gcodePath, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanSaveReportConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_save_report",
+            argNames: ["that", "gcodePath", "cutterSettings"],
+        );
+        
+
+@override Gcode crateApiJobPlanJobPlanToGcode({required JobPlan that , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobPlanJobPlanToGcodeConstMeta,
+            argValues: [that, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobPlanJobPlanToGcodeConstMeta => const TaskConstMeta(
+            debugName: "JobPlan_to_gcode",
+            argNames: ["that", "cutterSettings"],
+        );
+        
+
+@override Future<LaserCalibration> crateApiLaserLaserCalibrationDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLaserLaserCalibrationDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLaserLaserCalibrationDefaultConstMeta => const TaskConstMeta(
+            debugName: "LaserCalibration_default",
+            argNames: [],
+        );
+        
+
+@override Pos2D crateApiLaserLaserCalibrationFinish({required LaserCalibration that , required MachineConnection connection , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(connection, serializer);
+sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLaserLaserCalibrationFinishConstMeta,
+            argValues: [that, connection, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLaserLaserCalibrationFinishConstMeta => const TaskConstMeta(
+            debugName: "LaserCalibration_finish",
+            argNames: ["that", "connection", "cutterSettings"],
+        );
+        
+
+@override void crateApiLaserLaserCalibrationFireTestMark({required LaserCalibration that , required MachineConnection connection , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(connection, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLaserLaserCalibrationFireTestMarkConstMeta,
+            argValues: [that, connection, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLaserLaserCalibrationFireTestMarkConstMeta => const TaskConstMeta(
+            debugName: "LaserCalibration_fire_test_mark",
+            argNames: ["that", "connection", "cutterSettings"],
+        );
+        
+
+@override Pos2D? crateApiLaserLaserCalibrationMarkPosition({required LaserCalibration that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLaserLaserCalibrationMarkPositionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLaserLaserCalibrationMarkPositionConstMeta => const TaskConstMeta(
+            debugName: "LaserCalibration_mark_position",
+            argNames: ["that"],
+        );
+        
+
+@override LaserCalibration crateApiLaserLaserCalibrationNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLaserLaserCalibrationNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLaserLaserCalibrationNewConstMeta => const TaskConstMeta(
+            debugName: "LaserCalibration_new",
+            argNames: [],
+        );
+        
+
+@override void crateApiSenderMachineConnectionAbortJob({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionAbortJobConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionAbortJobConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_abort_job",
+            argNames: ["that"],
+        );
+        
+
+@override Fixture crateApiSenderMachineConnectionCaptureFixture({required MachineConnection that , required String name , WorkCoordinateSystem? wcs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_opt_box_autoadd_work_coordinate_system(wcs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fixture,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionCaptureFixtureConstMeta,
+            argValues: [that, name, wcs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionCaptureFixtureConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_capture_fixture",
+            argNames: ["that", "name", "wcs"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionDisconnect({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionDisconnectConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionDisconnectConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_disconnect",
+            argNames: ["that"],
+        );
+        
+
+@override MachineCapabilities crateApiSenderMachineConnectionGetCapabilities({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_machine_capabilities,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionGetCapabilitiesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionGetCapabilitiesConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_get_capabilities",
+            argNames: ["that"],
+        );
+        
+
+@override ConnectionState crateApiSenderMachineConnectionGetConnectionState({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionGetConnectionStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionGetConnectionStateConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_get_connection_state",
+            argNames: ["that"],
+        );
+        
+
+@override CoordinateOffsets crateApiSenderMachineConnectionGetCoordinateOffsets({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_coordinate_offsets,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionGetCoordinateOffsetsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionGetCoordinateOffsetsConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_get_coordinate_offsets",
+            argNames: ["that"],
+        );
+        
+
+@override MachineStatus? crateApiSenderMachineConnectionGetLastStatus({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionGetLastStatusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionGetLastStatusConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_get_last_status",
+            argNames: ["that"],
+        );
+        
+
+@override MachineSettings crateApiSenderMachineConnectionGetMachineSettings({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionGetMachineSettingsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionGetMachineSettingsConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_get_machine_settings",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionGoToMachinePosition({required MachineConnection that , required double x , required double y })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_f_32(x, serializer);
+sse_encode_f_32(y, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionGoToMachinePositionConstMeta,
+            argValues: [that, x, y],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionGoToMachinePositionConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_go_to_machine_position",
+            argNames: ["that", "x", "y"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionGoToWorkZero({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionGoToWorkZeroConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionGoToWorkZeroConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_go_to_work_zero",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionHome({required MachineConnection that , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionHomeConstMeta,
+            argValues: [that, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionHomeConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_home",
+            argNames: ["that", "cutterSettings"],
+        );
+        
+
+@override bool crateApiSenderMachineConnectionIsConnected({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionIsConnectedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionIsConnectedConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_is_connected",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiSenderMachineConnectionIsHomingRequired({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionIsHomingRequiredConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionIsHomingRequiredConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_is_homing_required",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionJogStep({required MachineConnection that , required double xDirection , required double yDirection , required JogStep step , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_f_32(xDirection, serializer);
+sse_encode_f_32(yDirection, serializer);
+sse_encode_jog_step(step, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionJogStepConstMeta,
+            argValues: [that, xDirection, yDirection, step, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionJogStepConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_jog_step",
+            argNames: ["that", "xDirection", "yDirection", "step", "cutterSettings"],
+        );
+        
+
+@override Stream<MachinePosition> crateApiSenderMachineConnectionMakeConnection({required MachineConnection that })  { 
+            final sink = RustStreamSink<MachinePosition>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_StreamSink_machine_position_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionMakeConnectionConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionMakeConnectionConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_make_connection",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override MachineConnection crateApiSenderMachineConnectionNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionNewConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_new",
+            argNames: [],
+        );
+        
+
+@override void crateApiSenderMachineConnectionPauseJob({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionPauseJobConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionPauseJobConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_pause_job",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionReadCoordinateOffsets({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionReadCoordinateOffsetsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionReadCoordinateOffsetsConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_read_coordinate_offsets",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionReadMachineSettings({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionReadMachineSettingsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionReadMachineSettingsConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_read_machine_settings",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionRecallFixture({required MachineConnection that , required Fixture fixture })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_box_autoadd_fixture(fixture, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionRecallFixtureConstMeta,
+            argValues: [that, fixture],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionRecallFixtureConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_recall_fixture",
+            argNames: ["that", "fixture"],
+        );
+        
+
+@override int crateApiSenderMachineConnectionRestoreMachineSettings({required MachineConnection that , required String filename })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_String(filename, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionRestoreMachineSettingsConstMeta,
+            argValues: [that, filename],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionRestoreMachineSettingsConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_restore_machine_settings",
+            argNames: ["that", "filename"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionResumeJob({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionResumeJobConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionResumeJobConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_resume_job",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionRunHomingCycle({required MachineConnection that , required HomingAxes axes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_homing_axes(axes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionRunHomingCycleConstMeta,
+            argValues: [that, axes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionRunHomingCycleConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_run_homing_cycle",
+            argNames: ["that", "axes"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionSelectWorkCoordinateSystem({required MachineConnection that , required WorkCoordinateSystem wcs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_work_coordinate_system(wcs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionSelectWorkCoordinateSystemConstMeta,
+            argValues: [that, wcs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionSelectWorkCoordinateSystemConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_select_work_coordinate_system",
+            argNames: ["that", "wcs"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionSendGcodeCommand({required MachineConnection that , required String command })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_String(command, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionSendGcodeCommandConstMeta,
+            argValues: [that, command],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionSendGcodeCommandConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_send_gcode_command",
+            argNames: ["that", "command"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionSendStringCommand({required MachineConnection that , required String command })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_String(command, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionSendStringCommandConstMeta,
+            argValues: [that, command],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionSendStringCommandConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_send_string_command",
+            argNames: ["that", "command"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionSendStringCommandLowPriority({required MachineConnection that , required String command })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_String(command, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorGetCutFeedrateConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorGetCutFeedrateConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_get_cut_feedrate",
-        argNames: ["that"],
-      );
-
-  @override
-  Pos2D crateApiCutCutAutoAccessorGetEndPosition({required Cut that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorGetEndPositionConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorGetEndPositionConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_get_end_position",
-        argNames: ["that"],
-      );
-
-  @override
-  double crateApiCutCutAutoAccessorGetPierceDelay({required Cut that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_f_32,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionSendStringCommandLowPriorityConstMeta,
+            argValues: [that, command],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionSendStringCommandLowPriorityConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_send_string_command_low_priority",
+            argNames: ["that", "command"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionSetBaudrate({required MachineConnection that , required int newBaudrate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_u_32(newBaudrate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorGetPierceDelayConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorGetPierceDelayConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_get_pierce_delay",
-        argNames: ["that"],
-      );
-
-  @override
-  double crateApiCutCutAutoAccessorGetPierceDelay2({required Cut that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_f_32,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionSetBaudrateConstMeta,
+            argValues: [that, newBaudrate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionSetBaudrateConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_set_baudrate",
+            argNames: ["that", "newBaudrate"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionSetConnectionSettings({required MachineConnection that , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorGetPierceDelay2ConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorGetPierceDelay2ConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_get_pierce_delay_2",
-        argNames: ["that"],
-      );
-
-  @override
-  Pos2D crateApiCutCutAutoAccessorGetStartPosition({required Cut that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorGetStartPositionConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorGetStartPositionConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_get_start_position",
-        argNames: ["that"],
-      );
-
-  @override
-  double crateApiCutCutAutoAccessorGetTubeWidth({required Cut that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_f_32,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionSetConnectionSettingsConstMeta,
+            argValues: [that, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionSetConnectionSettingsConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_set_connection_settings",
+            argNames: ["that", "cutterSettings"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionSetLaserPointer({required MachineConnection that , required bool on_ , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_bool(on_, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionSetLaserPointerConstMeta,
+            argValues: [that, on_, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionSetLaserPointerConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_set_laser_pointer",
+            argNames: ["that", "on_", "cutterSettings"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionSetSerialPort({required MachineConnection that , required String newPort })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_String(newPort, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorGetTubeWidthConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorGetTubeWidthConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_get_tube_width",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiCutCutAutoAccessorSetCutAngle({
-    required Cut that,
-    required double cutAngle,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(cutAngle, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorSetCutAngleConstMeta,
-        argValues: [that, cutAngle],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorSetCutAngleConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_set_cut_angle",
-        argNames: ["that", "cutAngle"],
-      );
-
-  @override
-  void crateApiCutCutAutoAccessorSetCutFeedrate({
-    required Cut that,
-    required double cutFeedrate,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(cutFeedrate, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorSetCutFeedrateConstMeta,
-        argValues: [that, cutFeedrate],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorSetCutFeedrateConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_set_cut_feedrate",
-        argNames: ["that", "cutFeedrate"],
-      );
-
-  @override
-  void crateApiCutCutAutoAccessorSetEndPosition({
-    required Cut that,
-    required Pos2D endPosition,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            endPosition,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorSetEndPositionConstMeta,
-        argValues: [that, endPosition],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorSetEndPositionConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_set_end_position",
-        argNames: ["that", "endPosition"],
-      );
-
-  @override
-  void crateApiCutCutAutoAccessorSetPierceDelay({
-    required Cut that,
-    required double pierceDelay,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(pierceDelay, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorSetPierceDelayConstMeta,
-        argValues: [that, pierceDelay],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorSetPierceDelayConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_set_pierce_delay",
-        argNames: ["that", "pierceDelay"],
-      );
-
-  @override
-  void crateApiCutCutAutoAccessorSetPierceDelay2({
-    required Cut that,
-    required double pierceDelay2,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(pierceDelay2, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorSetPierceDelay2ConstMeta,
-        argValues: [that, pierceDelay2],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorSetPierceDelay2ConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_set_pierce_delay_2",
-        argNames: ["that", "pierceDelay2"],
-      );
-
-  @override
-  void crateApiCutCutAutoAccessorSetStartPosition({
-    required Cut that,
-    required Pos2D startPosition,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            startPosition,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorSetStartPositionConstMeta,
-        argValues: [that, startPosition],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorSetStartPositionConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_set_start_position",
-        argNames: ["that", "startPosition"],
-      );
-
-  @override
-  void crateApiCutCutAutoAccessorSetTubeWidth({
-    required Cut that,
-    required double tubeWidth,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(tubeWidth, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutAutoAccessorSetTubeWidthConstMeta,
-        argValues: [that, tubeWidth],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutAutoAccessorSetTubeWidthConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_auto_accessor_set_tube_width",
-        argNames: ["that", "tubeWidth"],
-      );
-
-  @override
-  (double, double) crateApiCutCutGetEndPos({required Cut that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_record_f_32_f_32,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionSetSerialPortConstMeta,
+            argValues: [that, newPort],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionSetSerialPortConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_set_serial_port",
+            argNames: ["that", "newPort"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionSetWorkOffset({required MachineConnection that , required WorkCoordinateSystem wcs , required Axis axis , required double offset })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_work_coordinate_system(wcs, serializer);
+sse_encode_axis(axis, serializer);
+sse_encode_f_32(offset, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionSetWorkOffsetConstMeta,
+            argValues: [that, wcs, axis, offset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionSetWorkOffsetConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_set_work_offset",
+            argNames: ["that", "wcs", "axis", "offset"],
+        );
+        
+
+@override Stream<JobProgress> crateApiSenderMachineConnectionStartJob({required MachineConnection that , required Gcode gcode })  { 
+            final sink = RustStreamSink<JobProgress>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(gcode, serializer);
+sse_encode_StreamSink_job_progress_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionStartJobConstMeta,
+            argValues: [that, gcode, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionStartJobConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_start_job",
+            argNames: ["that", "gcode", "sink"],
+        );
+        
+
+@override Stream<JobProgress> crateApiSenderMachineConnectionStartJobFromCut({required MachineConnection that , required Gcode gcode , required int cutIndex })  { 
+            final sink = RustStreamSink<JobProgress>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(gcode, serializer);
+sse_encode_u_32(cutIndex, serializer);
+sse_encode_StreamSink_job_progress_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionStartJobFromCutConstMeta,
+            argValues: [that, gcode, cutIndex, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionStartJobFromCutConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_start_job_from_cut",
+            argNames: ["that", "gcode", "cutIndex", "sink"],
+        );
+        
+
+@override Stream<JobProgress> crateApiSenderMachineConnectionStartJobFromLine({required MachineConnection that , required Gcode gcode , required int startLine })  { 
+            final sink = RustStreamSink<JobProgress>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(gcode, serializer);
+sse_encode_u_32(startLine, serializer);
+sse_encode_StreamSink_job_progress_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionStartJobFromLineConstMeta,
+            argValues: [that, gcode, startLine, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionStartJobFromLineConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_start_job_from_line",
+            argNames: ["that", "gcode", "startLine", "sink"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionStartJog({required MachineConnection that , required double xDirection , required double yDirection , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_f_32(xDirection, serializer);
+sse_encode_f_32(yDirection, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionStartJogConstMeta,
+            argValues: [that, xDirection, yDirection, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionStartJogConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_start_jog",
+            argNames: ["that", "xDirection", "yDirection", "cutterSettings"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionStopJog({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionStopJogConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionStopJogConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_stop_jog",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionUnlock({required MachineConnection that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionUnlockConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionUnlockConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_unlock",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionWriteMachineSetting({required MachineConnection that , required int id , required String value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_u_32(id, serializer);
+sse_encode_String(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionWriteMachineSettingConstMeta,
+            argValues: [that, id, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionWriteMachineSettingConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_write_machine_setting",
+            argNames: ["that", "id", "value"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionZeroAllAxes({required MachineConnection that , WorkCoordinateSystem? wcs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_opt_box_autoadd_work_coordinate_system(wcs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionZeroAllAxesConstMeta,
+            argValues: [that, wcs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionZeroAllAxesConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_zero_all_axes",
+            argNames: ["that", "wcs"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionZeroAtLaser({required MachineConnection that , WorkCoordinateSystem? wcs , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_opt_box_autoadd_work_coordinate_system(wcs, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionZeroAtLaserConstMeta,
+            argValues: [that, wcs, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionZeroAtLaserConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_zero_at_laser",
+            argNames: ["that", "wcs", "cutterSettings"],
+        );
+        
+
+@override void crateApiSenderMachineConnectionZeroAxis({required MachineConnection that , WorkCoordinateSystem? wcs , required Axis axis })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(that, serializer);
+sse_encode_opt_box_autoadd_work_coordinate_system(wcs, serializer);
+sse_encode_axis(axis, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSenderMachineConnectionZeroAxisConstMeta,
+            argValues: [that, wcs, axis],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachineConnectionZeroAxisConstMeta => const TaskConstMeta(
+            debugName: "MachineConnection_zero_axis",
+            argNames: ["that", "wcs", "axis"],
+        );
+        
+
+@override List<SettingGroup> crateApiMachineSettingsMachineSettingsAutoAccessorGetGroups({required MachineSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_setting_group,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsAutoAccessorGetGroupsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsAutoAccessorGetGroupsConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_auto_accessor_get_groups",
+            argNames: ["that"],
+        );
+        
+
+@override List<MachineSetting> crateApiMachineSettingsMachineSettingsAutoAccessorGetSettings({required MachineSettings that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_machine_setting,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsAutoAccessorGetSettingsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsAutoAccessorGetSettingsConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_auto_accessor_get_settings",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiMachineSettingsMachineSettingsAutoAccessorSetGroups({required MachineSettings that , required List<SettingGroup> groups })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(that, serializer);
+sse_encode_list_setting_group(groups, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsAutoAccessorSetGroupsConstMeta,
+            argValues: [that, groups],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsAutoAccessorSetGroupsConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_auto_accessor_set_groups",
+            argNames: ["that", "groups"],
+        );
+        
+
+@override void crateApiMachineSettingsMachineSettingsAutoAccessorSetSettings({required MachineSettings that , required List<MachineSetting> settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(that, serializer);
+sse_encode_list_machine_setting(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsAutoAccessorSetSettingsConstMeta,
+            argValues: [that, settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsAutoAccessorSetSettingsConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_auto_accessor_set_settings",
+            argNames: ["that", "settings"],
+        );
+        
+
+@override Future<MachineSettings> crateApiMachineSettingsMachineSettingsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsDefaultConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_default",
+            argNames: [],
+        );
+        
+
+@override Future<SettingDescription?> crateApiMachineSettingsMachineSettingsDescription({required MachineSettings that , required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(that, serializer);
+sse_encode_u_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 200, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_setting_description,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsDescriptionConstMeta,
+            argValues: [that, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsDescriptionConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_description",
+            argNames: ["that", "id"],
+        );
+        
+
+@override Future<MachineSetting?> crateApiMachineSettingsMachineSettingsGet({required MachineSettings that , required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(that, serializer);
+sse_encode_u_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 201, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_machine_setting,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsGetConstMeta,
+            argValues: [that, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsGetConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_get",
+            argNames: ["that", "id"],
+        );
+        
+
+@override Future<void> crateApiMachineSettingsMachineSettingsSaveBackup({required MachineSettings that , required String filename })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(that, serializer);
+sse_encode_String(filename, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 202, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsSaveBackupConstMeta,
+            argValues: [that, filename],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsSaveBackupConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_save_backup",
+            argNames: ["that", "filename"],
+        );
+        
+
+@override Future<String> crateApiMachineSettingsMachineSettingsToBackup({required MachineSettings that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 203, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsToBackupConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsToBackupConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_to_backup",
+            argNames: ["that"],
+        );
+        
+
+@override Future<String> crateApiMachineSettingsMachineSettingsValidate({required MachineSettings that , required int id , required String value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(that, serializer);
+sse_encode_u_32(id, serializer);
+sse_encode_String(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 204, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMachineSettingsMachineSettingsValidateConstMeta,
+            argValues: [that, id, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMachineSettingsMachineSettingsValidateConstMeta => const TaskConstMeta(
+            debugName: "MachineSettings_validate",
+            argNames: ["that", "id", "value"],
+        );
+        
+
+@override Accessories? crateApiStatusMachineStatusAutoAccessorGetAccessories({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 205)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_accessories,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetAccessoriesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetAccessoriesConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_accessories",
+            argNames: ["that"],
+        );
+        
+
+@override BufferState? crateApiStatusMachineStatusAutoAccessorGetBuffer({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 206)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_buffer_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetBufferConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetBufferConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_buffer",
+            argNames: ["that"],
+        );
+        
+
+@override double? crateApiStatusMachineStatusAutoAccessorGetFeedRate({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 207)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetFeedRateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetFeedRateConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_feed_rate",
+            argNames: ["that"],
+        );
+        
+
+@override int? crateApiStatusMachineStatusAutoAccessorGetLineNumber({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 208)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetLineNumberConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetLineNumberConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_line_number",
+            argNames: ["that"],
+        );
+        
+
+@override Float32List? crateApiStatusMachineStatusAutoAccessorGetMachinePosition({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 209)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_f_32_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetMachinePositionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetMachinePositionConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_machine_position",
+            argNames: ["that"],
+        );
+        
+
+@override MachineState crateApiStatusMachineStatusAutoAccessorGetMachineState({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 210)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_machine_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetMachineStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetMachineStateConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_machine_state",
+            argNames: ["that"],
+        );
+        
+
+@override Overrides? crateApiStatusMachineStatusAutoAccessorGetOverrides({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 211)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_overrides,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetOverridesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetOverridesConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_overrides",
+            argNames: ["that"],
+        );
+        
+
+@override String? crateApiStatusMachineStatusAutoAccessorGetPins({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 212)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetPinsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetPinsConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_pins",
+            argNames: ["that"],
+        );
+        
+
+@override Pos2D crateApiStatusMachineStatusAutoAccessorGetPosition({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 213)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetPositionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetPositionConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_position",
+            argNames: ["that"],
+        );
+        
+
+@override double? crateApiStatusMachineStatusAutoAccessorGetSpindleSpeed({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 214)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetSpindleSpeedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetSpindleSpeedConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_spindle_speed",
+            argNames: ["that"],
+        );
+        
+
+@override int? crateApiStatusMachineStatusAutoAccessorGetSubState({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 215)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetSubStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetSubStateConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_sub_state",
+            argNames: ["that"],
+        );
+        
+
+@override Float32List? crateApiStatusMachineStatusAutoAccessorGetWorkCoordinateOffset({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 216)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_f_32_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetWorkCoordinateOffsetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetWorkCoordinateOffsetConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_work_coordinate_offset",
+            argNames: ["that"],
+        );
+        
+
+@override Float32List? crateApiStatusMachineStatusAutoAccessorGetWorkPosition({required MachineStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 217)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_f_32_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorGetWorkPositionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorGetWorkPositionConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_get_work_position",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetAccessories({required MachineStatus that , Accessories? accessories })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_box_autoadd_accessories(accessories, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 218)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetAccessoriesConstMeta,
+            argValues: [that, accessories],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetAccessoriesConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_accessories",
+            argNames: ["that", "accessories"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetBuffer({required MachineStatus that , BufferState? buffer })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_box_autoadd_buffer_state(buffer, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 219)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetBufferConstMeta,
+            argValues: [that, buffer],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetBufferConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_buffer",
+            argNames: ["that", "buffer"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetFeedRate({required MachineStatus that , double? feedRate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_box_autoadd_f_32(feedRate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 220)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetFeedRateConstMeta,
+            argValues: [that, feedRate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetFeedRateConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_feed_rate",
+            argNames: ["that", "feedRate"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetLineNumber({required MachineStatus that , int? lineNumber })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_box_autoadd_u_32(lineNumber, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 221)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetLineNumberConstMeta,
+            argValues: [that, lineNumber],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetLineNumberConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_line_number",
+            argNames: ["that", "lineNumber"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetMachinePosition({required MachineStatus that , Float32List? machinePosition })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_list_prim_f_32_strict(machinePosition, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 222)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutGetEndPosConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutGetEndPosConstMeta =>
-      const TaskConstMeta(debugName: "Cut_get_end_pos", argNames: ["that"]);
-
-  @override
-  Cut crateApiCutCutNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutNewConstMeta =>
-      const TaskConstMeta(debugName: "Cut_new", argNames: []);
-
-  @override
-  void crateApiCutCutSetCutAngle({
-    required Cut that,
-    required double newAngle,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(newAngle, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutSetCutAngleConstMeta,
-        argValues: [that, newAngle],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutSetCutAngleConstMeta => const TaskConstMeta(
-    debugName: "Cut_set_cut_angle",
-    argNames: ["that", "newAngle"],
-  );
-
-  @override
-  void crateApiCutCutSetCutFeedrate({
-    required Cut that,
-    required double newFeedrate,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(newFeedrate, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutSetCutFeedrateConstMeta,
-        argValues: [that, newFeedrate],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutSetCutFeedrateConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_set_cut_feedrate",
-        argNames: ["that", "newFeedrate"],
-      );
-
-  @override
-  void crateApiCutCutSetPierceDelay({
-    required Cut that,
-    required double newDelay,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(newDelay, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutSetPierceDelayConstMeta,
-        argValues: [that, newDelay],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutSetPierceDelayConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_set_pierce_delay",
-        argNames: ["that", "newDelay"],
-      );
-
-  @override
-  void crateApiCutCutSetPierceDelay2({
-    required Cut that,
-    required double newDelay,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(newDelay, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutSetPierceDelay2ConstMeta,
-        argValues: [that, newDelay],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutSetPierceDelay2ConstMeta =>
-      const TaskConstMeta(
-        debugName: "Cut_set_pierce_delay_2",
-        argNames: ["that", "newDelay"],
-      );
-
-  @override
-  void crateApiCutCutSetTubeWidth({
-    required Cut that,
-    required double newWidth,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(newWidth, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCutCutSetTubeWidthConstMeta,
-        argValues: [that, newWidth],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCutCutSetTubeWidthConstMeta => const TaskConstMeta(
-    debugName: "Cut_set_tube_width",
-    argNames: ["that", "newWidth"],
-  );
-
-  @override
-  bool crateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcode({
-    required CutterSettings that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetMachinePositionConstMeta,
+            argValues: [that, machinePosition],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetMachinePositionConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_machine_position",
+            argNames: ["that", "machinePosition"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetMachineState({required MachineStatus that , required MachineState machineState })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_machine_state(machineState, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 223)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetMachineStateConstMeta,
+            argValues: [that, machineState],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetMachineStateConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_machine_state",
+            argNames: ["that", "machineState"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetOverrides({required MachineStatus that , Overrides? overrides })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_box_autoadd_overrides(overrides, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 224)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetOverridesConstMeta,
+            argValues: [that, overrides],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetOverridesConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_overrides",
+            argNames: ["that", "overrides"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetPins({required MachineStatus that , String? pins })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_String(pins, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 225)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetPinsConstMeta,
+            argValues: [that, pins],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetPinsConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_pins",
+            argNames: ["that", "pins"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetPosition({required MachineStatus that , required Pos2D position })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(position, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 226)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetPositionConstMeta,
+            argValues: [that, position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetPositionConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_position",
+            argNames: ["that", "position"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetSpindleSpeed({required MachineStatus that , double? spindleSpeed })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_box_autoadd_f_32(spindleSpeed, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 227)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetSpindleSpeedConstMeta,
+            argValues: [that, spindleSpeed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetSpindleSpeedConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_spindle_speed",
+            argNames: ["that", "spindleSpeed"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetSubState({required MachineStatus that , int? subState })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_box_autoadd_u_32(subState, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 228)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetSubStateConstMeta,
+            argValues: [that, subState],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetSubStateConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_sub_state",
+            argNames: ["that", "subState"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetWorkCoordinateOffset({required MachineStatus that , Float32List? workCoordinateOffset })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_list_prim_f_32_strict(workCoordinateOffset, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 229)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetWorkCoordinateOffsetConstMeta,
+            argValues: [that, workCoordinateOffset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetWorkCoordinateOffsetConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_work_coordinate_offset",
+            argNames: ["that", "workCoordinateOffset"],
+        );
+        
+
+@override void crateApiStatusMachineStatusAutoAccessorSetWorkPosition({required MachineStatus that , Float32List? workPosition })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_opt_list_prim_f_32_strict(workPosition, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 230)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusAutoAccessorSetWorkPositionConstMeta,
+            argValues: [that, workPosition],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusAutoAccessorSetWorkPositionConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_auto_accessor_set_work_position",
+            argNames: ["that", "workPosition"],
+        );
+        
+
+@override Future<MachineStatus> crateApiStatusMachineStatusDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 231, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusDefaultConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_default",
+            argNames: [],
+        );
+        
+
+@override Future<MachineStatus> crateApiStatusMachineStatusNew()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 232, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusNewConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_new",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiStatusMachineStatusPinTriggered({required MachineStatus that , required String pin })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(that, serializer);
+sse_encode_Char(pin, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 233, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcodeConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcodeConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_get_clear_existing_gcode",
-        argNames: ["that"],
-      );
-
-  @override
-  CutMethod crateApiSettingsCutterSettingsAutoAccessorGetCutMethod({
-    required CutterSettings that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorGetCutMethodConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorGetCutMethodConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_get_cut_method",
-        argNames: ["that"],
-      );
-
-  @override
-  bool crateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCut({
-    required CutterSettings that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiStatusMachineStatusPinTriggeredConstMeta,
+            argValues: [that, pin],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusMachineStatusPinTriggeredConstMeta => const TaskConstMeta(
+            debugName: "MachineStatus_pin_triggered",
+            argNames: ["that", "pin"],
+        );
+        
+
+@override Future<void> crateApiInterpreterModalStateApplyLine({required ModalState that , required String line })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_String(line, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 234, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateApplyLineConstMeta,
+            argValues: [that, line],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateApplyLineConstMeta => const TaskConstMeta(
+            debugName: "ModalState_apply_line",
+            argNames: ["that", "line"],
+        );
+        
+
+@override Future<void> crateApiInterpreterModalStateApplyWords({required ModalState that , required List<Word> words })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_list_word(words, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 235, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateApplyWordsConstMeta,
+            argValues: [that, words],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateApplyWordsConstMeta => const TaskConstMeta(
+            debugName: "ModalState_apply_words",
+            argNames: ["that", "words"],
+        );
+        
+
+@override double? crateApiInterpreterModalStateAutoAccessorGetFeedrate({required ModalState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 236)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorGetFeedrateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorGetFeedrateConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_get_feedrate",
+            argNames: ["that"],
+        );
+        
+
+@override MotionMode crateApiInterpreterModalStateAutoAccessorGetMotionMode({required ModalState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 237)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_motion_mode,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorGetMotionModeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorGetMotionModeConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_get_motion_mode",
+            argNames: ["that"],
+        );
+        
+
+@override double? crateApiInterpreterModalStateAutoAccessorGetPierceDelay({required ModalState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 238)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorGetPierceDelayConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorGetPierceDelayConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_get_pierce_delay",
+            argNames: ["that"],
+        );
+        
+
+@override Pos2D crateApiInterpreterModalStateAutoAccessorGetPosition({required ModalState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 239)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorGetPositionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorGetPositionConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_get_position",
+            argNames: ["that"],
+        );
+        
+
+@override PositioningMode crateApiInterpreterModalStateAutoAccessorGetPositioningMode({required ModalState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 240)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_positioning_mode,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorGetPositioningModeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorGetPositioningModeConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_get_positioning_mode",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiInterpreterModalStateAutoAccessorGetTorchOn({required ModalState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 241)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCutConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCutConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_get_home_after_cut",
-        argNames: ["that"],
-      );
-
-  @override
-  double crateApiSettingsCutterSettingsAutoAccessorGetJogSpeed({
-    required CutterSettings that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_f_32,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorGetTorchOnConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorGetTorchOnConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_get_torch_on",
+            argNames: ["that"],
+        );
+        
+
+@override DistUnit crateApiInterpreterModalStateAutoAccessorGetUnits({required ModalState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 242)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_dist_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorGetUnitsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorGetUnitsConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_get_units",
+            argNames: ["that"],
+        );
+        
+
+@override Pos2D crateApiInterpreterModalStateAutoAccessorGetWorkOffset({required ModalState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 243)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorGetWorkOffsetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorGetWorkOffsetConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_get_work_offset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiInterpreterModalStateAutoAccessorSetFeedrate({required ModalState that , double? feedrate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_opt_box_autoadd_f_32(feedrate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 244)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorSetFeedrateConstMeta,
+            argValues: [that, feedrate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorSetFeedrateConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_set_feedrate",
+            argNames: ["that", "feedrate"],
+        );
+        
+
+@override void crateApiInterpreterModalStateAutoAccessorSetMotionMode({required ModalState that , required MotionMode motionMode })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_motion_mode(motionMode, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 245)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorSetMotionModeConstMeta,
+            argValues: [that, motionMode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorSetMotionModeConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_set_motion_mode",
+            argNames: ["that", "motionMode"],
+        );
+        
+
+@override void crateApiInterpreterModalStateAutoAccessorSetPierceDelay({required ModalState that , double? pierceDelay })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_opt_box_autoadd_f_32(pierceDelay, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 246)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorSetPierceDelayConstMeta,
+            argValues: [that, pierceDelay],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorSetPierceDelayConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_set_pierce_delay",
+            argNames: ["that", "pierceDelay"],
+        );
+        
+
+@override void crateApiInterpreterModalStateAutoAccessorSetPosition({required ModalState that , required Pos2D position })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(position, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 247)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorSetPositionConstMeta,
+            argValues: [that, position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorSetPositionConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_set_position",
+            argNames: ["that", "position"],
+        );
+        
+
+@override void crateApiInterpreterModalStateAutoAccessorSetPositioningMode({required ModalState that , required PositioningMode positioningMode })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_positioning_mode(positioningMode, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 248)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorSetPositioningModeConstMeta,
+            argValues: [that, positioningMode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorSetPositioningModeConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_set_positioning_mode",
+            argNames: ["that", "positioningMode"],
+        );
+        
+
+@override void crateApiInterpreterModalStateAutoAccessorSetTorchOn({required ModalState that , required bool torchOn })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_bool(torchOn, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 249)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorSetTorchOnConstMeta,
+            argValues: [that, torchOn],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorSetTorchOnConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_set_torch_on",
+            argNames: ["that", "torchOn"],
+        );
+        
+
+@override void crateApiInterpreterModalStateAutoAccessorSetUnits({required ModalState that , required DistUnit units })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_dist_unit(units, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 250)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorSetUnitsConstMeta,
+            argValues: [that, units],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorSetUnitsConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_set_units",
+            argNames: ["that", "units"],
+        );
+        
+
+@override void crateApiInterpreterModalStateAutoAccessorSetWorkOffset({required ModalState that , required Pos2D workOffset })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(workOffset, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 251)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateAutoAccessorSetWorkOffsetConstMeta,
+            argValues: [that, workOffset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateAutoAccessorSetWorkOffsetConstMeta => const TaskConstMeta(
+            debugName: "ModalState_auto_accessor_set_work_offset",
+            argNames: ["that", "workOffset"],
+        );
+        
+
+@override Future<ModalState> crateApiInterpreterModalStateDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 252, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateDefaultConstMeta => const TaskConstMeta(
+            debugName: "ModalState_default",
+            argNames: [],
+        );
+        
+
+@override Future<ModalState> crateApiInterpreterModalStateNew()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 253, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateNewConstMeta => const TaskConstMeta(
+            debugName: "ModalState_new",
+            argNames: [],
+        );
+        
+
+@override Future<Pos2D> crateApiInterpreterModalStateTargetPosition({required ModalState that , required List<Word> words })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(that, serializer);
+sse_encode_list_word(words, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 254, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorGetJogSpeedConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorGetJogSpeedConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_get_jog_speed",
-        argNames: ["that"],
-      );
-
-  @override
-  double crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetX({
-    required CutterSettings that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiInterpreterModalStateTargetPositionConstMeta,
+            argValues: [that, words],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterModalStateTargetPositionConstMeta => const TaskConstMeta(
+            debugName: "ModalState_target_position",
+            argNames: ["that", "words"],
+        );
+        
+
+@override double crateApiGcodePos2DAutoAccessorGetX({required Pos2D that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 255)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_f_32,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetXConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetXConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_get_laser_offset_x",
-        argNames: ["that"],
-      );
-
-  @override
-  double crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetY({
-    required CutterSettings that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGcodePos2DAutoAccessorGetXConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodePos2DAutoAccessorGetXConstMeta => const TaskConstMeta(
+            debugName: "Pos2D_auto_accessor_get_x",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiGcodePos2DAutoAccessorGetY({required Pos2D that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 256)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_f_32,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetYConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetYConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_get_laser_offset_y",
-        argNames: ["that"],
-      );
-
-  @override
-  bool crateApiSettingsCutterSettingsAutoAccessorGetUseLaser({
-    required CutterSettings that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGcodePos2DAutoAccessorGetYConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodePos2DAutoAccessorGetYConstMeta => const TaskConstMeta(
+            debugName: "Pos2D_auto_accessor_get_y",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiGcodePos2DAutoAccessorSetX({required Pos2D that , required double x })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(that, serializer);
+sse_encode_f_32(x, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 257)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodePos2DAutoAccessorSetXConstMeta,
+            argValues: [that, x],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodePos2DAutoAccessorSetXConstMeta => const TaskConstMeta(
+            debugName: "Pos2D_auto_accessor_set_x",
+            argNames: ["that", "x"],
+        );
+        
+
+@override void crateApiGcodePos2DAutoAccessorSetY({required Pos2D that , required double y })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(that, serializer);
+sse_encode_f_32(y, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 258)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodePos2DAutoAccessorSetYConstMeta,
+            argValues: [that, y],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodePos2DAutoAccessorSetYConstMeta => const TaskConstMeta(
+            debugName: "Pos2D_auto_accessor_set_y",
+            argNames: ["that", "y"],
+        );
+        
+
+@override Future<Pos2D> crateApiGcodePos2DNew({required double x , required double y })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_32(x, serializer);
+sse_encode_f_32(y, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 259, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodePos2DNewConstMeta,
+            argValues: [x, y],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodePos2DNewConstMeta => const TaskConstMeta(
+            debugName: "Pos2D_new",
+            argNames: ["x", "y"],
+        );
+        
+
+@override Future<Pos2D> crateApiGcodePos2DToScreenSpace({required Pos2D that , required double tubeWidth , required double scaleFactor })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(that, serializer);
+sse_encode_f_32(tubeWidth, serializer);
+sse_encode_f_32(scaleFactor, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 260, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodePos2DToScreenSpaceConstMeta,
+            argValues: [that, tubeWidth, scaleFactor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodePos2DToScreenSpaceConstMeta => const TaskConstMeta(
+            debugName: "Pos2D_to_screen_space",
+            argNames: ["that", "tubeWidth", "scaleFactor"],
+        );
+        
+
+@override Future<void> crateApiProfileProfileSegmentEnd({required ProfileSegment that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 261, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileSegmentEndConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileSegmentEndConstMeta => const TaskConstMeta(
+            debugName: "ProfileSegment_end",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<Pos2D>> crateApiProfileProfileSegmentFlatten({required ProfileSegment that , required double tolerance })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(that, serializer);
+sse_encode_f_32(tolerance, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 262, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileSegmentFlattenConstMeta,
+            argValues: [that, tolerance],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileSegmentFlattenConstMeta => const TaskConstMeta(
+            debugName: "ProfileSegment_flatten",
+            argNames: ["that", "tolerance"],
+        );
+        
+
+@override Future<void> crateApiProfileProfileSegmentStart({required ProfileSegment that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 263, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileSegmentStartConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileSegmentStartConstMeta => const TaskConstMeta(
+            debugName: "ProfileSegment_start",
+            argNames: ["that"],
+        );
+        
+
+@override List<Contour> crateApiProfileProfileAutoAccessorGetContours({required Profile that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 264)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_contour,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileAutoAccessorGetContoursConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileAutoAccessorGetContoursConstMeta => const TaskConstMeta(
+            debugName: "Profile_auto_accessor_get_contours",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiProfileProfileAutoAccessorSetContours({required Profile that , required List<Contour> contours })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(that, serializer);
+sse_encode_list_contour(contours, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 265)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileAutoAccessorSetContoursConstMeta,
+            argValues: [that, contours],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileAutoAccessorSetContoursConstMeta => const TaskConstMeta(
+            debugName: "Profile_auto_accessor_set_contours",
+            argNames: ["that", "contours"],
+        );
+        
+
+@override (Pos2D,Pos2D)? crateApiProfileProfileBounds({required Profile that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 266)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileBoundsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileBoundsConstMeta => const TaskConstMeta(
+            debugName: "Profile_bounds",
+            argNames: ["that"],
+        );
+        
+
+@override Future<Profile> crateApiProfileProfileDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 267, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileDefaultConstMeta => const TaskConstMeta(
+            debugName: "Profile_default",
+            argNames: [],
+        );
+        
+
+@override Profile crateApiProfileProfileFromDxf({required String contents })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(contents, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 268)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileFromDxfConstMeta,
+            argValues: [contents],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileFromDxfConstMeta => const TaskConstMeta(
+            debugName: "Profile_from_dxf",
+            argNames: ["contents"],
+        );
+        
+
+@override Future<Profile> crateApiProfileProfileFromSegments({required List<ProfileSegment> segments })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(segments, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 269, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileFromSegmentsConstMeta,
+            argValues: [segments],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileFromSegmentsConstMeta => const TaskConstMeta(
+            debugName: "Profile_from_segments",
+            argNames: ["segments"],
+        );
+        
+
+@override Profile crateApiProfileProfileFromSvg({required String contents , required double tolerance })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(contents, serializer);
+sse_encode_f_32(tolerance, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 270)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileFromSvgConstMeta,
+            argValues: [contents, tolerance],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileFromSvgConstMeta => const TaskConstMeta(
+            debugName: "Profile_from_svg",
+            argNames: ["contents", "tolerance"],
+        );
+        
+
+@override Profile crateApiProfileProfileImportDxf({required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 271)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileImportDxfConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileImportDxfConstMeta => const TaskConstMeta(
+            debugName: "Profile_import_dxf",
+            argNames: ["path"],
+        );
+        
+
+@override Profile crateApiProfileProfileImportSvg({required String path , required double tolerance })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_f_32(tolerance, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 272)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileImportSvgConstMeta,
+            argValues: [path, tolerance],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileImportSvgConstMeta => const TaskConstMeta(
+            debugName: "Profile_import_svg",
+            argNames: ["path", "tolerance"],
+        );
+        
+
+@override Profile crateApiProfileProfileMoved({required Profile that , required double dx , required double dy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(that, serializer);
+sse_encode_f_32(dx, serializer);
+sse_encode_f_32(dy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 273)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileMovedConstMeta,
+            argValues: [that, dx, dy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileMovedConstMeta => const TaskConstMeta(
+            debugName: "Profile_moved",
+            argNames: ["that", "dx", "dy"],
+        );
+        
+
+@override Profile crateApiProfileProfilePlaceOnFlatStock({required Profile that , required double x , required double y })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(that, serializer);
+sse_encode_f_32(x, serializer);
+sse_encode_f_32(y, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 274)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfilePlaceOnFlatStockConstMeta,
+            argValues: [that, x, y],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfilePlaceOnFlatStockConstMeta => const TaskConstMeta(
+            debugName: "Profile_place_on_flat_stock",
+            argNames: ["that", "x", "y"],
+        );
+        
+
+@override Profile crateApiProfileProfilePlaceOnTubeFace({required Profile that , required double tubeWidth , required double yPosition })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(that, serializer);
+sse_encode_f_32(tubeWidth, serializer);
+sse_encode_f_32(yPosition, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 275)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProfileProfilePlaceOnTubeFaceConstMeta,
+            argValues: [that, tubeWidth, yPosition],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfilePlaceOnTubeFaceConstMeta => const TaskConstMeta(
+            debugName: "Profile_place_on_tube_face",
+            argNames: ["that", "tubeWidth", "yPosition"],
+        );
+        
+
+@override Profile crateApiProfileProfileScaled({required Profile that , required double factor })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(that, serializer);
+sse_encode_f_32(factor, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 276)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProfileProfileScaledConstMeta,
+            argValues: [that, factor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProfileProfileScaledConstMeta => const TaskConstMeta(
+            debugName: "Profile_scaled",
+            argNames: ["that", "factor"],
+        );
+        
+
+@override Pos2D crateApiBoundsProgramExtentsAutoAccessorGetMax({required ProgramExtents that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 277)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsProgramExtentsAutoAccessorGetMaxConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsProgramExtentsAutoAccessorGetMaxConstMeta => const TaskConstMeta(
+            debugName: "ProgramExtents_auto_accessor_get_max",
+            argNames: ["that"],
+        );
+        
+
+@override Pos2D crateApiBoundsProgramExtentsAutoAccessorGetMin({required ProgramExtents that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 278)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsProgramExtentsAutoAccessorGetMinConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsProgramExtentsAutoAccessorGetMinConstMeta => const TaskConstMeta(
+            debugName: "ProgramExtents_auto_accessor_get_min",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiBoundsProgramExtentsAutoAccessorSetMax({required ProgramExtents that , required Pos2D max })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(max, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 279)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsProgramExtentsAutoAccessorSetMaxConstMeta,
+            argValues: [that, max],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsProgramExtentsAutoAccessorSetMaxConstMeta => const TaskConstMeta(
+            debugName: "ProgramExtents_auto_accessor_set_max",
+            argNames: ["that", "max"],
+        );
+        
+
+@override void crateApiBoundsProgramExtentsAutoAccessorSetMin({required ProgramExtents that , required Pos2D min })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(min, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 280)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsProgramExtentsAutoAccessorSetMinConstMeta,
+            argValues: [that, min],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsProgramExtentsAutoAccessorSetMinConstMeta => const TaskConstMeta(
+            debugName: "ProgramExtents_auto_accessor_set_min",
+            argNames: ["that", "min"],
+        );
+        
+
+@override Pos2D crateApiToolpathToolpathSegmentAutoAccessorGetEnd({required ToolpathSegment that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 281)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorGetEndConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorGetEndConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_get_end",
+            argNames: ["that"],
+        );
+        
+
+@override double? crateApiToolpathToolpathSegmentAutoAccessorGetFeedrate({required ToolpathSegment that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 282)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorGetFeedrateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorGetFeedrateConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_get_feedrate",
+            argNames: ["that"],
+        );
+        
+
+@override SegmentKind crateApiToolpathToolpathSegmentAutoAccessorGetKind({required ToolpathSegment that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 283)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorGetKindConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorGetKindConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_get_kind",
+            argNames: ["that"],
+        );
+        
+
+@override int crateApiToolpathToolpathSegmentAutoAccessorGetLine({required ToolpathSegment that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 284)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorGetLineConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorGetLineConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_get_line",
+            argNames: ["that"],
+        );
+        
+
+@override Pos2D crateApiToolpathToolpathSegmentAutoAccessorGetStart({required ToolpathSegment that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 285)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorGetStartConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorGetStartConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_get_start",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiToolpathToolpathSegmentAutoAccessorGetTorchOn({required ToolpathSegment that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 286)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorGetUseLaserConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorGetUseLaserConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_get_use_laser",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcode({
-    required CutterSettings that,
-    required bool clearExistingGcode,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          sse_encode_bool(clearExistingGcode, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcodeConstMeta,
-        argValues: [that, clearExistingGcode],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcodeConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_set_clear_existing_gcode",
-        argNames: ["that", "clearExistingGcode"],
-      );
-
-  @override
-  void crateApiSettingsCutterSettingsAutoAccessorSetCutMethod({
-    required CutterSettings that,
-    required CutMethod cutMethod,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-            cutMethod,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorSetCutMethodConstMeta,
-        argValues: [that, cutMethod],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorSetCutMethodConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_set_cut_method",
-        argNames: ["that", "cutMethod"],
-      );
-
-  @override
-  void crateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCut({
-    required CutterSettings that,
-    required bool homeAfterCut,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          sse_encode_bool(homeAfterCut, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCutConstMeta,
-        argValues: [that, homeAfterCut],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCutConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_set_home_after_cut",
-        argNames: ["that", "homeAfterCut"],
-      );
-
-  @override
-  void crateApiSettingsCutterSettingsAutoAccessorSetJogSpeed({
-    required CutterSettings that,
-    required double jogSpeed,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(jogSpeed, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorSetJogSpeedConstMeta,
-        argValues: [that, jogSpeed],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorSetJogSpeedConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_set_jog_speed",
-        argNames: ["that", "jogSpeed"],
-      );
-
-  @override
-  void crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetX({
-    required CutterSettings that,
-    required double laserOffsetX,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(laserOffsetX, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetXConstMeta,
-        argValues: [that, laserOffsetX],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetXConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_set_laser_offset_x",
-        argNames: ["that", "laserOffsetX"],
-      );
-
-  @override
-  void crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetY({
-    required CutterSettings that,
-    required double laserOffsetY,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(laserOffsetY, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetYConstMeta,
-        argValues: [that, laserOffsetY],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetYConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_set_laser_offset_y",
-        argNames: ["that", "laserOffsetY"],
-      );
-
-  @override
-  void crateApiSettingsCutterSettingsAutoAccessorSetUseLaser({
-    required CutterSettings that,
-    required bool useLaser,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          sse_encode_bool(useLaser, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSettingsCutterSettingsAutoAccessorSetUseLaserConstMeta,
-        argValues: [that, useLaser],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSettingsCutterSettingsAutoAccessorSetUseLaserConstMeta =>
-      const TaskConstMeta(
-        debugName: "CutterSettings_auto_accessor_set_use_laser",
-        argNames: ["that", "useLaser"],
-      );
-
-  @override
-  CutterSettings crateApiSettingsCutterSettingsLoad() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSettingsCutterSettingsLoadConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSettingsCutterSettingsLoadConstMeta =>
-      const TaskConstMeta(debugName: "CutterSettings_load", argNames: []);
-
-  @override
-  CutterSettings crateApiSettingsCutterSettingsNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSettingsCutterSettingsNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSettingsCutterSettingsNewConstMeta =>
-      const TaskConstMeta(debugName: "CutterSettings_new", argNames: []);
-
-  @override
-  void crateApiSettingsCutterSettingsSave({required CutterSettings that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSettingsCutterSettingsSaveConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSettingsCutterSettingsSaveConstMeta =>
-      const TaskConstMeta(debugName: "CutterSettings_save", argNames: ["that"]);
-
-  @override
-  Future<void> crateApiGcodeGcodeAddCommand({
-    required Gcode that,
-    required String gCommand,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_String(gCommand, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 39,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeAddCommandConstMeta,
-        argValues: [that, gCommand],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeAddCommandConstMeta =>
-      const TaskConstMeta(
-        debugName: "Gcode_add_command",
-        argNames: ["that", "gCommand"],
-      );
-
-  @override
-  Future<void> crateApiGcodeGcodeAddCommandComment({
-    required Gcode that,
-    required String gCommand,
-    required String gComment,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_String(gCommand, serializer);
-          sse_encode_String(gComment, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 40,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeAddCommandCommentConstMeta,
-        argValues: [that, gCommand, gComment],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeAddCommandCommentConstMeta =>
-      const TaskConstMeta(
-        debugName: "Gcode_add_command_comment",
-        argNames: ["that", "gCommand", "gComment"],
-      );
-
-  @override
-  void crateApiGcodeGcodeAddCut({
-    required Gcode that,
-    required Cut tubeCut,
-    required CutterSettings cutterSettings,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-            tubeCut,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            cutterSettings,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeAddCutConstMeta,
-        argValues: [that, tubeCut, cutterSettings],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeAddCutConstMeta => const TaskConstMeta(
-    debugName: "Gcode_add_cut",
-    argNames: ["that", "tubeCut", "cutterSettings"],
-  );
-
-  @override
-  String crateApiGcodeGcodeAutoAccessorGetGcodeString({required Gcode that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorGetTorchOnConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorGetTorchOnConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_get_torch_on",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiToolpathToolpathSegmentAutoAccessorSetEnd({required ToolpathSegment that , required Pos2D end })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(end, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 287)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeAutoAccessorGetGcodeStringConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeAutoAccessorGetGcodeStringConstMeta =>
-      const TaskConstMeta(
-        debugName: "Gcode_auto_accessor_get_gcode_string",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiGcodeGcodeAutoAccessorSetGcodeString({
-    required Gcode that,
-    required String gcodeString,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_String(gcodeString, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeAutoAccessorSetGcodeStringConstMeta,
-        argValues: [that, gcodeString],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeAutoAccessorSetGcodeStringConstMeta =>
-      const TaskConstMeta(
-        debugName: "Gcode_auto_accessor_set_gcode_string",
-        argNames: ["that", "gcodeString"],
-      );
-
-  @override
-  Future<void> crateApiGcodeGcodeDwell({
-    required Gcode that,
-    required double dwellTime,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(dwellTime, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 44,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeDwellConstMeta,
-        argValues: [that, dwellTime],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeDwellConstMeta => const TaskConstMeta(
-    debugName: "Gcode_dwell",
-    argNames: ["that", "dwellTime"],
-  );
-
-  @override
-  String crateApiGcodeGcodeGetGcodeString({required Gcode that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorSetEndConstMeta,
+            argValues: [that, end],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorSetEndConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_set_end",
+            argNames: ["that", "end"],
+        );
+        
+
+@override void crateApiToolpathToolpathSegmentAutoAccessorSetFeedrate({required ToolpathSegment that , double? feedrate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+sse_encode_opt_box_autoadd_f_32(feedrate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 288)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeGetGcodeStringConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeGetGcodeStringConstMeta =>
-      const TaskConstMeta(
-        debugName: "Gcode_get_gcode_string",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<void> crateApiGcodeGcodeHome2D({
-    required Gcode that,
-    required double feedrate,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(feedrate, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 46,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeHome2DConstMeta,
-        argValues: [that, feedrate],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeHome2DConstMeta => const TaskConstMeta(
-    debugName: "Gcode_home2D",
-    argNames: ["that", "feedrate"],
-  );
-
-  @override
-  Future<void> crateApiGcodeGcodeMoveXy({
-    required Gcode that,
-    required Pos2D newPos,
-    required double feedrate,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            newPos,
-            serializer,
-          );
-          sse_encode_f_32(feedrate, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 47,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeMoveXyConstMeta,
-        argValues: [that, newPos, feedrate],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeMoveXyConstMeta => const TaskConstMeta(
-    debugName: "Gcode_move_xy",
-    argNames: ["that", "newPos", "feedrate"],
-  );
-
-  @override
-  Gcode crateApiGcodeGcodeNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeNewConstMeta =>
-      const TaskConstMeta(debugName: "Gcode_new", argNames: []);
-
-  @override
-  Future<void> crateApiGcodeGcodeSetPlasmaEnabled({
-    required Gcode that,
-    required bool enabled,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_bool(enabled, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 49,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeSetPlasmaEnabledConstMeta,
-        argValues: [that, enabled],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeSetPlasmaEnabledConstMeta =>
-      const TaskConstMeta(
-        debugName: "Gcode_set_plasma_enabled",
-        argNames: ["that", "enabled"],
-      );
-
-  @override
-  Future<void> crateApiGcodeGcodeSetPositioningMode({
-    required Gcode that,
-    required PositioningMode positioningMode,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_positioning_mode(positioningMode, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 50,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeSetPositioningModeConstMeta,
-        argValues: [that, positioningMode],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeSetPositioningModeConstMeta =>
-      const TaskConstMeta(
-        debugName: "Gcode_set_positioning_mode",
-        argNames: ["that", "positioningMode"],
-      );
-
-  @override
-  Future<void> crateApiGcodeGcodeSetZero({required Gcode that}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 51,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeSetZeroConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeSetZeroConstMeta =>
-      const TaskConstMeta(debugName: "Gcode_set_zero", argNames: ["that"]);
-
-  @override
-  Future<void> crateApiGcodeGcodeWriteToFile({
-    required Gcode that,
-    required String filename,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-            that,
-            serializer,
-          );
-          sse_encode_String(filename, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 52,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGcodeWriteToFileConstMeta,
-        argValues: [that, filename],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGcodeWriteToFileConstMeta =>
-      const TaskConstMeta(
-        debugName: "Gcode_write_to_file",
-        argNames: ["that", "filename"],
-      );
-
-  @override
-  void crateApiSenderMachineConnectionHome({
-    required MachineConnection that,
-    required CutterSettings cutterSettings,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            cutterSettings,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSenderMachineConnectionHomeConstMeta,
-        argValues: [that, cutterSettings],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSenderMachineConnectionHomeConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineConnection_home",
-        argNames: ["that", "cutterSettings"],
-      );
-
-  @override
-  Stream<MachinePosition> crateApiSenderMachineConnectionMakeConnection({
-    required MachineConnection that,
-  }) {
-    final sink = RustStreamSink<MachinePosition>();
-    handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-            that,
-            serializer,
-          );
-          sse_encode_StreamSink_machine_position_Sse(sink, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSenderMachineConnectionMakeConnectionConstMeta,
-        argValues: [that, sink],
-        apiImpl: this,
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiSenderMachineConnectionMakeConnectionConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineConnection_make_connection",
-        argNames: ["that", "sink"],
-      );
-
-  @override
-  MachineConnection crateApiSenderMachineConnectionNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSenderMachineConnectionNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSenderMachineConnectionNewConstMeta =>
-      const TaskConstMeta(debugName: "MachineConnection_new", argNames: []);
-
-  @override
-  void crateApiSenderMachineConnectionSendGcodeCommand({
-    required MachineConnection that,
-    required String command,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-            that,
-            serializer,
-          );
-          sse_encode_String(command, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSenderMachineConnectionSendGcodeCommandConstMeta,
-        argValues: [that, command],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSenderMachineConnectionSendGcodeCommandConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineConnection_send_gcode_command",
-        argNames: ["that", "command"],
-      );
-
-  @override
-  void crateApiSenderMachineConnectionSendStringCommand({
-    required MachineConnection that,
-    required String command,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-            that,
-            serializer,
-          );
-          sse_encode_String(command, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSenderMachineConnectionSendStringCommandConstMeta,
-        argValues: [that, command],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSenderMachineConnectionSendStringCommandConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineConnection_send_string_command",
-        argNames: ["that", "command"],
-      );
-
-  @override
-  void crateApiSenderMachineConnectionSendStringCommandLowPriority({
-    required MachineConnection that,
-    required String command,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-            that,
-            serializer,
-          );
-          sse_encode_String(command, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiSenderMachineConnectionSendStringCommandLowPriorityConstMeta,
-        argValues: [that, command],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiSenderMachineConnectionSendStringCommandLowPriorityConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineConnection_send_string_command_low_priority",
-        argNames: ["that", "command"],
-      );
-
-  @override
-  void crateApiSenderMachineConnectionSetSerialPort({
-    required MachineConnection that,
-    required String newPort,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-            that,
-            serializer,
-          );
-          sse_encode_String(newPort, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSenderMachineConnectionSetSerialPortConstMeta,
-        argValues: [that, newPort],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSenderMachineConnectionSetSerialPortConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineConnection_set_serial_port",
-        argNames: ["that", "newPort"],
-      );
-
-  @override
-  MachineState crateApiStatusMachineStatusAutoAccessorGetMachineState({
-    required MachineStatus that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_machine_state,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorSetFeedrateConstMeta,
+            argValues: [that, feedrate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorSetFeedrateConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_set_feedrate",
+            argNames: ["that", "feedrate"],
+        );
+        
+
+@override void crateApiToolpathToolpathSegmentAutoAccessorSetKind({required ToolpathSegment that , required SegmentKind kind })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(kind, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 289)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorSetKindConstMeta,
+            argValues: [that, kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorSetKindConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_set_kind",
+            argNames: ["that", "kind"],
+        );
+        
+
+@override void crateApiToolpathToolpathSegmentAutoAccessorSetLine({required ToolpathSegment that , required int line })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+sse_encode_u_32(line, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 290)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorSetLineConstMeta,
+            argValues: [that, line],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorSetLineConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_set_line",
+            argNames: ["that", "line"],
+        );
+        
+
+@override void crateApiToolpathToolpathSegmentAutoAccessorSetStart({required ToolpathSegment that , required Pos2D start })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(start, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 291)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorSetStartConstMeta,
+            argValues: [that, start],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorSetStartConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_set_start",
+            argNames: ["that", "start"],
+        );
+        
+
+@override void crateApiToolpathToolpathSegmentAutoAccessorSetTorchOn({required ToolpathSegment that , required bool torchOn })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+sse_encode_bool(torchOn, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 292)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentAutoAccessorSetTorchOnConstMeta,
+            argValues: [that, torchOn],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentAutoAccessorSetTorchOnConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_auto_accessor_set_torch_on",
+            argNames: ["that", "torchOn"],
+        );
+        
+
+@override Future<List<Pos2D>> crateApiToolpathToolpathSegmentFlatten({required ToolpathSegment that , required double tolerance })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+sse_encode_f_32(tolerance, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 293, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiStatusMachineStatusAutoAccessorGetMachineStateConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiStatusMachineStatusAutoAccessorGetMachineStateConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineStatus_auto_accessor_get_machine_state",
-        argNames: ["that"],
-      );
-
-  @override
-  Pos2D crateApiStatusMachineStatusAutoAccessorGetPosition({
-    required MachineStatus that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiStatusMachineStatusAutoAccessorGetPositionConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiStatusMachineStatusAutoAccessorGetPositionConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineStatus_auto_accessor_get_position",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiStatusMachineStatusAutoAccessorSetMachineState({
-    required MachineStatus that,
-    required MachineState machineState,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-            that,
-            serializer,
-          );
-          sse_encode_machine_state(machineState, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiStatusMachineStatusAutoAccessorSetMachineStateConstMeta,
-        argValues: [that, machineState],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiStatusMachineStatusAutoAccessorSetMachineStateConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineStatus_auto_accessor_set_machine_state",
-        argNames: ["that", "machineState"],
-      );
-
-  @override
-  void crateApiStatusMachineStatusAutoAccessorSetPosition({
-    required MachineStatus that,
-    required Pos2D position,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            position,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiStatusMachineStatusAutoAccessorSetPositionConstMeta,
-        argValues: [that, position],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiStatusMachineStatusAutoAccessorSetPositionConstMeta =>
-      const TaskConstMeta(
-        debugName: "MachineStatus_auto_accessor_set_position",
-        argNames: ["that", "position"],
-      );
-
-  @override
-  Future<MachineStatus> crateApiStatusMachineStatusNew() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 64,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiStatusMachineStatusNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStatusMachineStatusNewConstMeta =>
-      const TaskConstMeta(debugName: "MachineStatus_new", argNames: []);
-
-  @override
-  double crateApiGcodePos2DAutoAccessorGetX({required Pos2D that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentFlattenConstMeta,
+            argValues: [that, tolerance],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentFlattenConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_flatten",
+            argNames: ["that", "tolerance"],
+        );
+        
+
+@override Future<double> crateApiToolpathToolpathSegmentLength({required ToolpathSegment that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 294, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_f_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodePos2DAutoAccessorGetXConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodePos2DAutoAccessorGetXConstMeta =>
-      const TaskConstMeta(
-        debugName: "Pos2D_auto_accessor_get_x",
-        argNames: ["that"],
-      );
-
-  @override
-  double crateApiGcodePos2DAutoAccessorGetY({required Pos2D that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiToolpathToolpathSegmentLengthConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathToolpathSegmentLengthConstMeta => const TaskConstMeta(
+            debugName: "ToolpathSegment_length",
+            argNames: ["that"],
+        );
+        
+
+@override Pos2D crateApiRenderViewportAutoAccessorGetOffset({required Viewport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 297)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRenderViewportAutoAccessorGetOffsetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderViewportAutoAccessorGetOffsetConstMeta => const TaskConstMeta(
+            debugName: "Viewport_auto_accessor_get_offset",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiRenderViewportAutoAccessorGetScale({required Viewport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 298)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_f_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodePos2DAutoAccessorGetYConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodePos2DAutoAccessorGetYConstMeta =>
-      const TaskConstMeta(
-        debugName: "Pos2D_auto_accessor_get_y",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiGcodePos2DAutoAccessorSetX({
-    required Pos2D that,
-    required double x,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(x, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodePos2DAutoAccessorSetXConstMeta,
-        argValues: [that, x],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodePos2DAutoAccessorSetXConstMeta =>
-      const TaskConstMeta(
-        debugName: "Pos2D_auto_accessor_set_x",
-        argNames: ["that", "x"],
-      );
-
-  @override
-  void crateApiGcodePos2DAutoAccessorSetY({
-    required Pos2D that,
-    required double y,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(y, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodePos2DAutoAccessorSetYConstMeta,
-        argValues: [that, y],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodePos2DAutoAccessorSetYConstMeta =>
-      const TaskConstMeta(
-        debugName: "Pos2D_auto_accessor_set_y",
-        argNames: ["that", "y"],
-      );
-
-  @override
-  Future<Pos2D> crateApiGcodePos2DNew({required double x, required double y}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_f_32(x, serializer);
-          sse_encode_f_32(y, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 69,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodePos2DNewConstMeta,
-        argValues: [x, y],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodePos2DNewConstMeta =>
-      const TaskConstMeta(debugName: "Pos2D_new", argNames: ["x", "y"]);
-
-  @override
-  Future<Pos2D> crateApiGcodePos2DToScreenSpace({
-    required Pos2D that,
-    required double tubeWidth,
-    required double scaleFactor,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            that,
-            serializer,
-          );
-          sse_encode_f_32(tubeWidth, serializer);
-          sse_encode_f_32(scaleFactor, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 70,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodePos2DToScreenSpaceConstMeta,
-        argValues: [that, tubeWidth, scaleFactor],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodePos2DToScreenSpaceConstMeta =>
-      const TaskConstMeta(
-        debugName: "Pos2D_to_screen_space",
-        argNames: ["that", "tubeWidth", "scaleFactor"],
-      );
-
-  @override
-  Future<Pos2D> crateApiGcodeCalculateEndPos({
-    required Pos2D startPos,
-    required double tubeWidth,
-    required double cutAngle,
-    required double overshootAmount,
-    required bool cutRight,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            startPos,
-            serializer,
-          );
-          sse_encode_f_32(tubeWidth, serializer);
-          sse_encode_f_32(cutAngle, serializer);
-          sse_encode_f_32(overshootAmount, serializer);
-          sse_encode_bool(cutRight, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 71,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeCalculateEndPosConstMeta,
-        argValues: [startPos, tubeWidth, cutAngle, overshootAmount, cutRight],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeCalculateEndPosConstMeta =>
-      const TaskConstMeta(
-        debugName: "calculate_end_pos",
-        argNames: [
-          "startPos",
-          "tubeWidth",
-          "cutAngle",
-          "overshootAmount",
-          "cutRight",
-        ],
-      );
-
-  @override
-  Future<Pos2D> crateApiGcodeGetMidpoint({
-    required Pos2D startPos,
-    required Pos2D endPos,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            startPos,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-            endPos,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 72,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeGetMidpointConstMeta,
-        argValues: [startPos, endPos],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeGetMidpointConstMeta => const TaskConstMeta(
-    debugName: "get_midpoint",
-    argNames: ["startPos", "endPos"],
-  );
-
-  @override
-  List<String> crateApiSimpleGetSerialPorts() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiRenderViewportAutoAccessorGetScaleConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderViewportAutoAccessorGetScaleConstMeta => const TaskConstMeta(
+            debugName: "Viewport_auto_accessor_get_scale",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiRenderViewportAutoAccessorSetOffset({required Viewport that , required Pos2D offset })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(offset, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 299)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRenderViewportAutoAccessorSetOffsetConstMeta,
+            argValues: [that, offset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderViewportAutoAccessorSetOffsetConstMeta => const TaskConstMeta(
+            debugName: "Viewport_auto_accessor_set_offset",
+            argNames: ["that", "offset"],
+        );
+        
+
+@override void crateApiRenderViewportAutoAccessorSetScale({required Viewport that , required double scale })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(that, serializer);
+sse_encode_f_32(scale, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 300)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRenderViewportAutoAccessorSetScaleConstMeta,
+            argValues: [that, scale],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderViewportAutoAccessorSetScaleConstMeta => const TaskConstMeta(
+            debugName: "Viewport_auto_accessor_set_scale",
+            argNames: ["that", "scale"],
+        );
+        
+
+@override Viewport crateApiRenderViewportCenteredOnTube({required double tubeWidth , required double scale })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_32(tubeWidth, serializer);
+sse_encode_f_32(scale, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 301)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRenderViewportCenteredOnTubeConstMeta,
+            argValues: [tubeWidth, scale],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderViewportCenteredOnTubeConstMeta => const TaskConstMeta(
+            debugName: "Viewport_centered_on_tube",
+            argNames: ["tubeWidth", "scale"],
+        );
+        
+
+@override Viewport crateApiRenderViewportFit({required Pos2D min , required Pos2D max , required double width , required double height , required double margin })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(min, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(max, serializer);
+sse_encode_f_32(width, serializer);
+sse_encode_f_32(height, serializer);
+sse_encode_f_32(margin, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 302)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRenderViewportFitConstMeta,
+            argValues: [min, max, width, height, margin],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderViewportFitConstMeta => const TaskConstMeta(
+            debugName: "Viewport_fit",
+            argNames: ["min", "max", "width", "height", "margin"],
+        );
+        
+
+@override Viewport crateApiRenderViewportNew({required double scale , required Pos2D offset })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_32(scale, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(offset, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 303)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRenderViewportNewConstMeta,
+            argValues: [scale, offset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderViewportNewConstMeta => const TaskConstMeta(
+            debugName: "Viewport_new",
+            argNames: ["scale", "offset"],
+        );
+        
+
+@override Pos2D crateApiRenderViewportToScreen({required Viewport that , required Pos2D position })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(position, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 304)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRenderViewportToScreenConstMeta,
+            argValues: [that, position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderViewportToScreenConstMeta => const TaskConstMeta(
+            debugName: "Viewport_to_screen",
+            argNames: ["that", "position"],
+        );
+        
+
+@override Future<Accessories> crateApiStatusAccessoriesDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 305, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_accessories,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusAccessoriesDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusAccessoriesDefaultConstMeta => const TaskConstMeta(
+            debugName: "accessories_default",
+            argNames: [],
+        );
+        
+
+@override Future<BoundsReport> crateApiBoundsBoundsReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 306, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bounds_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "bounds_report_default",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiBoundsBoundsReportIsWithinLimits({required BoundsReport that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_bounds_report(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 307, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsBoundsReportIsWithinLimitsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsBoundsReportIsWithinLimitsConstMeta => const TaskConstMeta(
+            debugName: "bounds_report_is_within_limits",
+            argNames: ["that"],
+        );
+        
+
+@override Future<Pos2D> crateApiGcodeCalculateEndPos({required Pos2D startPos , required double tubeWidth , required double cutAngle , required double overshootAmount , required bool cutRight })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(startPos, serializer);
+sse_encode_f_32(tubeWidth, serializer);
+sse_encode_f_32(cutAngle, serializer);
+sse_encode_f_32(overshootAmount, serializer);
+sse_encode_bool(cutRight, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 308, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeCalculateEndPosConstMeta,
+            argValues: [startPos, tubeWidth, cutAngle, overshootAmount, cutRight],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeCalculateEndPosConstMeta => const TaskConstMeta(
+            debugName: "calculate_end_pos",
+            argNames: ["startPos", "tubeWidth", "cutAngle", "overshootAmount", "cutRight"],
+        );
+        
+
+@override Future<BoundsReport> crateApiBoundsCheckSegments({required List<ToolpathSegment> segments , required TravelLimits limits , required Pos2D workOffset , required List<int> cutStartLines })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(segments, serializer);
+sse_encode_box_autoadd_travel_limits(limits, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(workOffset, serializer);
+sse_encode_list_prim_u_32_loose(cutStartLines, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 309, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bounds_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsCheckSegmentsConstMeta,
+            argValues: [segments, limits, workOffset, cutStartLines],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsCheckSegmentsConstMeta => const TaskConstMeta(
+            debugName: "check_segments",
+            argNames: ["segments", "limits", "workOffset", "cutStartLines"],
+        );
+        
+
+@override Future<ConnectionSettings> crateApiSettingsConnectionSettingsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 310, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_settings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsConnectionSettingsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsConnectionSettingsDefaultConstMeta => const TaskConstMeta(
+            debugName: "connection_settings_default",
+            argNames: [],
+        );
+        
+
+@override Future<ConsumableRatings> crateApiEstimateConsumableRatingsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 311, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_consumable_ratings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEstimateConsumableRatingsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEstimateConsumableRatingsDefaultConstMeta => const TaskConstMeta(
+            debugName: "consumable_ratings_default",
+            argNames: [],
+        );
+        
+
+@override Future<Fixture> crateApiWcsCoordinateOffsetsCaptureFixture({required CoordinateOffsets that , required String name , WorkCoordinateSystem? wcs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_coordinate_offsets(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_opt_box_autoadd_work_coordinate_system(wcs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 312, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fixture,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWcsCoordinateOffsetsCaptureFixtureConstMeta,
+            argValues: [that, name, wcs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWcsCoordinateOffsetsCaptureFixtureConstMeta => const TaskConstMeta(
+            debugName: "coordinate_offsets_capture_fixture",
+            argNames: ["that", "name", "wcs"],
+        );
+        
+
+@override Future<CoordinateOffsets> crateApiWcsCoordinateOffsetsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 313, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_coordinate_offsets,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiWcsCoordinateOffsetsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWcsCoordinateOffsetsDefaultConstMeta => const TaskConstMeta(
+            debugName: "coordinate_offsets_default",
+            argNames: [],
+        );
+        
+
+@override Future<WorkOffset?> crateApiWcsCoordinateOffsetsOffset({required CoordinateOffsets that , required WorkCoordinateSystem wcs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_coordinate_offsets(that, serializer);
+sse_encode_work_coordinate_system(wcs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 314, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_work_offset,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiWcsCoordinateOffsetsOffsetConstMeta,
+            argValues: [that, wcs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWcsCoordinateOffsetsOffsetConstMeta => const TaskConstMeta(
+            debugName: "coordinate_offsets_offset",
+            argNames: ["that", "wcs"],
+        );
+        
+
+@override Future<JobEstimate> crateApiEstimateEstimateSegments({required List<ToolpathSegment> segments , required MotionLimits limits , required ConsumableRatings ratings })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(segments, serializer);
+sse_encode_box_autoadd_motion_limits(limits, serializer);
+sse_encode_box_autoadd_consumable_ratings(ratings, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 315, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_job_estimate,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEstimateEstimateSegmentsConstMeta,
+            argValues: [segments, limits, ratings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEstimateEstimateSegmentsConstMeta => const TaskConstMeta(
+            debugName: "estimate_segments",
+            argNames: ["segments", "limits", "ratings"],
+        );
+        
+
+@override String crateApiExportExportPath({required String path , required PostProcessor postProcessor })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_post_processor(postProcessor, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 316)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExportExportPathConstMeta,
+            argValues: [path, postProcessor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportExportPathConstMeta => const TaskConstMeta(
+            debugName: "export_path",
+            argNames: ["path", "postProcessor"],
+        );
+        
+
+@override Future<Pos2D> crateApiGcodeGetMidpoint({required Pos2D startPos , required Pos2D endPos })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(startPos, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(endPos, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 317, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeGetMidpointConstMeta,
+            argValues: [startPos, endPos],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeGetMidpointConstMeta => const TaskConstMeta(
+            debugName: "get_midpoint",
+            argNames: ["startPos", "endPos"],
+        );
+        
+
+@override List<String> crateApiSimpleGetSerialPorts()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 318)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGetSerialPortsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGetSerialPortsConstMeta =>
-      const TaskConstMeta(debugName: "get_serial_ports", argNames: []);
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleGetSerialPortsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGetSerialPortsConstMeta => const TaskConstMeta(
+            debugName: "get_serial_ports",
+            argNames: [],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 319)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 75,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  String crateApiGcodeJog({
-    required double xDist,
-    required double yDist,
-    required CutterSettings cutterSettings,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_f_32(xDist, serializer);
-          sse_encode_f_32(yDist, serializer);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-            cutterSettings,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 320, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<JobEstimate> crateApiEstimateJobEstimateDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 321, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_job_estimate,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEstimateJobEstimateDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEstimateJobEstimateDefaultConstMeta => const TaskConstMeta(
+            debugName: "job_estimate_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiReportJobReportToHtml({required JobReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_job_report(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 322)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGcodeJogConstMeta,
-        argValues: [xDist, yDist, cutterSettings],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGcodeJogConstMeta => const TaskConstMeta(
-    debugName: "jog",
-    argNames: ["xDist", "yDist", "cutterSettings"],
-  );
-
-  @override
-  MachinePosition crateApiSenderMachinePositionNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_machine_position,
+        )
+        ,
+            constMeta: kCrateApiReportJobReportToHtmlConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReportJobReportToHtmlConstMeta => const TaskConstMeta(
+            debugName: "job_report_to_html",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiGcodeJog({required double xDist , required double yDist , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_32(xDist, serializer);
+sse_encode_f_32(yDist, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 323)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcodeJogConstMeta,
+            argValues: [xDist, yDist, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcodeJogConstMeta => const TaskConstMeta(
+            debugName: "jog",
+            argNames: ["xDist", "yDist", "cutterSettings"],
+        );
+        
+
+@override Future<double> crateApiJogJogStepDistance({required JogStep that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_jog_step(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 324, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJogJogStepDistanceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJogJogStepDistanceConstMeta => const TaskConstMeta(
+            debugName: "jog_step_distance",
+            argNames: ["that"],
+        );
+        
+
+@override Future<LaserOutput> crateApiLaserLaserOutputDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 325, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_laser_output,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLaserLaserOutputDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLaserLaserOutputDefaultConstMeta => const TaskConstMeta(
+            debugName: "laser_output_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSettingsLineEndingAsStr({required LineEnding that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_line_ending(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 326, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSettingsLineEndingAsStrConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsLineEndingAsStrConstMeta => const TaskConstMeta(
+            debugName: "line_ending_as_str",
+            argNames: ["that"],
+        );
+        
+
+@override Future<LintOptions> crateApiLintLintOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 327, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lint_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLintLintOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLintLintOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "lint_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<LintDiagnostic>> crateApiLintLintProgram({required String program , required LintOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(program, serializer);
+sse_encode_box_autoadd_lint_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 328, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_lint_diagnostic,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLintLintProgramConstMeta,
+            argValues: [program, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLintLintProgramConstMeta => const TaskConstMeta(
+            debugName: "lint_program",
+            argNames: ["program", "options"],
+        );
+        
+
+@override Future<MachineCapabilities> crateApiCapabilitiesMachineCapabilitiesDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 329, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_machine_capabilities,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSenderMachinePositionNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSenderMachinePositionNewConstMeta =>
-      const TaskConstMeta(debugName: "machine_position_new", argNames: []);
-
-  @override
-  Future<MachineStatus> crateApiStatusParseStatus({
-    required String statusString,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(statusString, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 78,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiStatusParseStatusConstMeta,
-        argValues: [statusString],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStatusParseStatusConstMeta => const TaskConstMeta(
-    debugName: "parse_status",
-    argNames: ["statusString"],
-  );
-
-  @override
-  Future<void> crateApiStatusRuleAllRules() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 79,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiStatusRuleAllRulesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStatusRuleAllRulesConstMeta =>
-      const TaskConstMeta(debugName: "rule_all_rules", argNames: []);
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Cut => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Cut => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CutMethod => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CutMethod => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CutterSettings => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CutterSettings => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Gcode => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Gcode => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_MachineConnection => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_MachineConnection => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_MachineStatus => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_MachineStatus => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Pos2D => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Pos2D => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  Cut
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CutMethod
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutMethodImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CutterSettings
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutterSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Gcode
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return GcodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  MachineConnection
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MachineConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  MachineStatus
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MachineStatusImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Pos2D
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Pos2DImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Cut
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CutterSettings
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutterSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Gcode
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return GcodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  MachineConnection
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MachineConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  MachineStatus
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MachineStatusImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Pos2D
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Pos2DImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Cut
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CutterSettings
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutterSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Gcode
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return GcodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  MachineConnection
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MachineConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  MachineStatus
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MachineStatusImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Pos2D
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Pos2DImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Cut
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CutMethod
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutMethodImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CutterSettings
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CutterSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Gcode
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return GcodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  MachineConnection
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MachineConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  MachineStatus
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MachineStatusImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Pos2D
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Pos2DImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  RustStreamSink<MachinePosition> dco_decode_StreamSink_machine_position_Sse(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  MachinePosition dco_decode_machine_position(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return MachinePosition.raw(
-      x: dco_decode_f_32(arr[0]),
-      y: dco_decode_f_32(arr[1]),
-    );
-  }
-
-  @protected
-  MachineState dco_decode_machine_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MachineState.values[raw as int];
-  }
-
-  @protected
-  PositioningMode dco_decode_positioning_mode(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PositioningMode.values[raw as int];
-  }
-
-  @protected
-  (double, double) dco_decode_record_f_32_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_f_32(arr[0]), dco_decode_f_32(arr[1]));
-  }
-
-  @protected
-  Rule dco_decode_rule(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Rule.values[raw as int];
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  Cut
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CutMethod
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutMethodImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CutterSettings
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutterSettingsImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Gcode
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return GcodeImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MachineConnection
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MachineConnectionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MachineStatus
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MachineStatusImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Pos2D
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Pos2DImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Cut
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CutterSettings
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutterSettingsImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Gcode
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return GcodeImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MachineConnection
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MachineConnectionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MachineStatus
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MachineStatusImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Pos2D
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Pos2DImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Cut
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CutterSettings
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutterSettingsImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Gcode
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return GcodeImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MachineConnection
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MachineConnectionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MachineStatus
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MachineStatusImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Pos2D
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Pos2DImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Cut
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CutMethod
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutMethodImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CutterSettings
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CutterSettingsImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Gcode
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return GcodeImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MachineConnection
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MachineConnectionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MachineStatus
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MachineStatusImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Pos2D
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Pos2DImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  RustStreamSink<MachinePosition> sse_decode_StreamSink_machine_position_Sse(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  MachinePosition sse_decode_machine_position(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_x = sse_decode_f_32(deserializer);
-    var var_y = sse_decode_f_32(deserializer);
-    return MachinePosition.raw(x: var_x, y: var_y);
-  }
-
-  @protected
-  MachineState sse_decode_machine_state(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return MachineState.values[inner];
-  }
-
-  @protected
-  PositioningMode sse_decode_positioning_mode(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return PositioningMode.values[inner];
-  }
-
-  @protected
-  (double, double) sse_decode_record_f_32_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_f_32(deserializer);
-    var var_field1 = sse_decode_f_32(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  Rule sse_decode_rule(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return Rule.values[inner];
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    Cut self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    CutMethod self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutMethodImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    CutterSettings self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutterSettingsImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    Gcode self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as GcodeImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    MachineConnection self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MachineConnectionImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    MachineStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MachineStatusImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    Pos2D self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Pos2DImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    Cut self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    CutterSettings self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutterSettingsImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    Gcode self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as GcodeImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    MachineConnection self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MachineConnectionImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    MachineStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MachineStatusImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    Pos2D self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Pos2DImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    Cut self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    CutterSettings self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutterSettingsImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    Gcode self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as GcodeImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    MachineConnection self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MachineConnectionImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    MachineStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MachineStatusImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    Pos2D self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Pos2DImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    Cut self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    CutMethod self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutMethodImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    CutterSettings self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CutterSettingsImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    Gcode self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as GcodeImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    MachineConnection self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MachineConnectionImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    MachineStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MachineStatusImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    Pos2D self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Pos2DImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_StreamSink_machine_position_Sse(
-    RustStreamSink<MachinePosition> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiCapabilitiesMachineCapabilitiesDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapabilitiesMachineCapabilitiesDefaultConstMeta => const TaskConstMeta(
+            debugName: "machine_capabilities_default",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiCapabilitiesMachineCapabilitiesHasAxis({required MachineCapabilities that , required String axis })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_machine_capabilities(that, serializer);
+sse_encode_Char(axis, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 330, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCapabilitiesMachineCapabilitiesHasAxisConstMeta,
+            argValues: [that, axis],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapabilitiesMachineCapabilitiesHasAxisConstMeta => const TaskConstMeta(
+            debugName: "machine_capabilities_has_axis",
+            argNames: ["that", "axis"],
+        );
+        
+
+@override Future<bool> crateApiCapabilitiesMachineCapabilitiesHasMistCoolant({required MachineCapabilities that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_machine_capabilities(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 331, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCapabilitiesMachineCapabilitiesHasMistCoolantConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapabilitiesMachineCapabilitiesHasMistCoolantConstMeta => const TaskConstMeta(
+            debugName: "machine_capabilities_has_mist_coolant",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiCapabilitiesMachineCapabilitiesHasProbe({required MachineCapabilities that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_machine_capabilities(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 332, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCapabilitiesMachineCapabilitiesHasProbeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapabilitiesMachineCapabilitiesHasProbeConstMeta => const TaskConstMeta(
+            debugName: "machine_capabilities_has_probe",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiCapabilitiesMachineCapabilitiesHasRotaryAxis({required MachineCapabilities that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_machine_capabilities(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 333, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCapabilitiesMachineCapabilitiesHasRotaryAxisConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapabilitiesMachineCapabilitiesHasRotaryAxisConstMeta => const TaskConstMeta(
+            debugName: "machine_capabilities_has_rotary_axis",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiCapabilitiesMachineCapabilitiesHasThc({required MachineCapabilities that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_machine_capabilities(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 334, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCapabilitiesMachineCapabilitiesHasThcConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapabilitiesMachineCapabilitiesHasThcConstMeta => const TaskConstMeta(
+            debugName: "machine_capabilities_has_thc",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiCapabilitiesMachineCapabilitiesHasVariableSpindle({required MachineCapabilities that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_machine_capabilities(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 335, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCapabilitiesMachineCapabilitiesHasVariableSpindleConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapabilitiesMachineCapabilitiesHasVariableSpindleConstMeta => const TaskConstMeta(
+            debugName: "machine_capabilities_has_variable_spindle",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiCapabilitiesMachineCapabilitiesIsGrblhal({required MachineCapabilities that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_machine_capabilities(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 336, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCapabilitiesMachineCapabilitiesIsGrblhalConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapabilitiesMachineCapabilitiesIsGrblhalConstMeta => const TaskConstMeta(
+            debugName: "machine_capabilities_is_grblhal",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiCapabilitiesMachineCapabilitiesIsKnown({required MachineCapabilities that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_machine_capabilities(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 337, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCapabilitiesMachineCapabilitiesIsKnownConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapabilitiesMachineCapabilitiesIsKnownConstMeta => const TaskConstMeta(
+            debugName: "machine_capabilities_is_known",
+            argNames: ["that"],
+        );
+        
+
+@override MachinePosition crateApiSenderMachinePositionNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 338)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_machine_position,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_machine_position(
-    MachinePosition self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.x, serializer);
-    sse_encode_f_32(self.y, serializer);
-  }
-
-  @protected
-  void sse_encode_machine_state(MachineState self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_positioning_mode(
-    PositioningMode self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_record_f_32_f_32(
-    (double, double) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.$1, serializer);
-    sse_encode_f_32(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_rule(Rule self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
-
-@sealed
-class CutImpl extends RustOpaque implements Cut {
-  // Not to be used by end users
-  CutImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  CutImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Cut,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Cut,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_CutPtr,
-  );
-
-  double get cutAngle =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorGetCutAngle(that: this);
-
-  double get cutFeedrate =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorGetCutFeedrate(that: this);
-
-  Pos2D get endPosition =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorGetEndPosition(that: this);
-
-  double get pierceDelay =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorGetPierceDelay(that: this);
-
-  double get pierceDelay2 => RustLib.instance.api
-      .crateApiCutCutAutoAccessorGetPierceDelay2(that: this);
-
-  Pos2D get startPosition => RustLib.instance.api
-      .crateApiCutCutAutoAccessorGetStartPosition(that: this);
-
-  double get tubeWidth =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorGetTubeWidth(that: this);
-
-  set cutAngle(double cutAngle) => RustLib.instance.api
-      .crateApiCutCutAutoAccessorSetCutAngle(that: this, cutAngle: cutAngle);
-
-  set cutFeedrate(double cutFeedrate) =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorSetCutFeedrate(
-        that: this,
-        cutFeedrate: cutFeedrate,
-      );
-
-  set endPosition(Pos2D endPosition) =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorSetEndPosition(
-        that: this,
-        endPosition: endPosition,
-      );
-
-  set pierceDelay(double pierceDelay) =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorSetPierceDelay(
-        that: this,
-        pierceDelay: pierceDelay,
-      );
-
-  set pierceDelay2(double pierceDelay2) =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorSetPierceDelay2(
-        that: this,
-        pierceDelay2: pierceDelay2,
-      );
-
-  set startPosition(Pos2D startPosition) =>
-      RustLib.instance.api.crateApiCutCutAutoAccessorSetStartPosition(
-        that: this,
-        startPosition: startPosition,
-      );
-
-  set tubeWidth(double tubeWidth) => RustLib.instance.api
-      .crateApiCutCutAutoAccessorSetTubeWidth(that: this, tubeWidth: tubeWidth);
-
-  (double, double) getEndPos() =>
-      RustLib.instance.api.crateApiCutCutGetEndPos(that: this);
-
-  void setCutAngle({required double newAngle}) => RustLib.instance.api
-      .crateApiCutCutSetCutAngle(that: this, newAngle: newAngle);
-
-  void setCutFeedrate({required double newFeedrate}) => RustLib.instance.api
-      .crateApiCutCutSetCutFeedrate(that: this, newFeedrate: newFeedrate);
-
-  void setPierceDelay({required double newDelay}) => RustLib.instance.api
-      .crateApiCutCutSetPierceDelay(that: this, newDelay: newDelay);
-
-  void setPierceDelay2({required double newDelay}) => RustLib.instance.api
-      .crateApiCutCutSetPierceDelay2(that: this, newDelay: newDelay);
-
-  void setTubeWidth({required double newWidth}) => RustLib.instance.api
-      .crateApiCutCutSetTubeWidth(that: this, newWidth: newWidth);
-}
-
-@sealed
-class CutMethodImpl extends RustOpaque implements CutMethod {
-  // Not to be used by end users
-  CutMethodImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  CutMethodImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_CutMethod,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_CutMethod,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_CutMethodPtr,
-  );
-}
-
-@sealed
-class CutterSettingsImpl extends RustOpaque implements CutterSettings {
-  // Not to be used by end users
-  CutterSettingsImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  CutterSettingsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_CutterSettings,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_CutterSettings,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_CutterSettingsPtr,
-  );
-
-  bool get clearExistingGcode => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcode(
-        that: this,
-      );
-
-  CutMethod get cutMethod => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorGetCutMethod(that: this);
-
-  bool get homeAfterCut => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCut(that: this);
-
-  double get jogSpeed => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorGetJogSpeed(that: this);
-
-  double get laserOffsetX => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetX(that: this);
-
-  double get laserOffsetY => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetY(that: this);
-
-  bool get useLaser => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorGetUseLaser(that: this);
-
-  set clearExistingGcode(bool clearExistingGcode) => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcode(
-        that: this,
-        clearExistingGcode: clearExistingGcode,
-      );
-
-  set cutMethod(CutMethod cutMethod) => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorSetCutMethod(
-        that: this,
-        cutMethod: cutMethod,
-      );
-
-  set homeAfterCut(bool homeAfterCut) => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCut(
-        that: this,
-        homeAfterCut: homeAfterCut,
-      );
-
-  set jogSpeed(double jogSpeed) => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorSetJogSpeed(
-        that: this,
-        jogSpeed: jogSpeed,
-      );
-
-  set laserOffsetX(double laserOffsetX) => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetX(
-        that: this,
-        laserOffsetX: laserOffsetX,
-      );
-
-  set laserOffsetY(double laserOffsetY) => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetY(
-        that: this,
-        laserOffsetY: laserOffsetY,
-      );
-
-  set useLaser(bool useLaser) => RustLib.instance.api
-      .crateApiSettingsCutterSettingsAutoAccessorSetUseLaser(
-        that: this,
-        useLaser: useLaser,
-      );
-
-  void save() =>
-      RustLib.instance.api.crateApiSettingsCutterSettingsSave(that: this);
-}
-
-@sealed
-class GcodeImpl extends RustOpaque implements Gcode {
-  // Not to be used by end users
-  GcodeImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  GcodeImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Gcode,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Gcode,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_GcodePtr,
-  );
-
-  Future<void> addCommand({required String gCommand}) => RustLib.instance.api
-      .crateApiGcodeGcodeAddCommand(that: this, gCommand: gCommand);
-
-  Future<void> addCommandComment({
-    required String gCommand,
-    required String gComment,
-  }) => RustLib.instance.api.crateApiGcodeGcodeAddCommandComment(
-    that: this,
-    gCommand: gCommand,
-    gComment: gComment,
-  );
-
-  void addCut({required Cut tubeCut, required CutterSettings cutterSettings}) =>
-      RustLib.instance.api.crateApiGcodeGcodeAddCut(
-        that: this,
-        tubeCut: tubeCut,
-        cutterSettings: cutterSettings,
-      );
-
-  String get gcodeString => RustLib.instance.api
-      .crateApiGcodeGcodeAutoAccessorGetGcodeString(that: this);
-
-  set gcodeString(String gcodeString) =>
-      RustLib.instance.api.crateApiGcodeGcodeAutoAccessorSetGcodeString(
-        that: this,
-        gcodeString: gcodeString,
-      );
-
-  Future<void> dwell({required double dwellTime}) => RustLib.instance.api
-      .crateApiGcodeGcodeDwell(that: this, dwellTime: dwellTime);
-
-  String getGcodeString() =>
-      RustLib.instance.api.crateApiGcodeGcodeGetGcodeString(that: this);
-
-  Future<void> home2D({required double feedrate}) => RustLib.instance.api
-      .crateApiGcodeGcodeHome2D(that: this, feedrate: feedrate);
-
-  Future<void> moveXy({required Pos2D newPos, required double feedrate}) =>
-      RustLib.instance.api.crateApiGcodeGcodeMoveXy(
-        that: this,
-        newPos: newPos,
-        feedrate: feedrate,
-      );
-
-  Future<void> setPlasmaEnabled({required bool enabled}) => RustLib.instance.api
-      .crateApiGcodeGcodeSetPlasmaEnabled(that: this, enabled: enabled);
-
-  Future<void> setPositioningMode({required PositioningMode positioningMode}) =>
-      RustLib.instance.api.crateApiGcodeGcodeSetPositioningMode(
-        that: this,
-        positioningMode: positioningMode,
-      );
-
-  Future<void> setZero() =>
-      RustLib.instance.api.crateApiGcodeGcodeSetZero(that: this);
-
-  Future<void> writeToFile({required String filename}) => RustLib.instance.api
-      .crateApiGcodeGcodeWriteToFile(that: this, filename: filename);
-}
-
-@sealed
-class MachineConnectionImpl extends RustOpaque implements MachineConnection {
-  // Not to be used by end users
-  MachineConnectionImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  MachineConnectionImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_MachineConnection,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_MachineConnection,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_MachineConnectionPtr,
-  );
-
-  void home({required CutterSettings cutterSettings}) =>
-      RustLib.instance.api.crateApiSenderMachineConnectionHome(
-        that: this,
-        cutterSettings: cutterSettings,
-      );
-
-  Stream<MachinePosition> makeConnection() => RustLib.instance.api
-      .crateApiSenderMachineConnectionMakeConnection(that: this);
-
-  void sendGcodeCommand({required String command}) =>
-      RustLib.instance.api.crateApiSenderMachineConnectionSendGcodeCommand(
-        that: this,
-        command: command,
-      );
-
-  void sendStringCommand({required String command}) =>
-      RustLib.instance.api.crateApiSenderMachineConnectionSendStringCommand(
-        that: this,
-        command: command,
-      );
-
-  void sendStringCommandLowPriority({required String command}) => RustLib
-      .instance
-      .api
-      .crateApiSenderMachineConnectionSendStringCommandLowPriority(
-        that: this,
-        command: command,
-      );
-
-  void setSerialPort({required String newPort}) =>
-      RustLib.instance.api.crateApiSenderMachineConnectionSetSerialPort(
-        that: this,
-        newPort: newPort,
-      );
-}
-
-@sealed
-class MachineStatusImpl extends RustOpaque implements MachineStatus {
-  // Not to be used by end users
-  MachineStatusImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  MachineStatusImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_MachineStatus,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_MachineStatus,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_MachineStatusPtr,
-  );
-
-  MachineState get machineState => RustLib.instance.api
-      .crateApiStatusMachineStatusAutoAccessorGetMachineState(that: this);
-
-  Pos2D get position => RustLib.instance.api
-      .crateApiStatusMachineStatusAutoAccessorGetPosition(that: this);
-
-  set machineState(MachineState machineState) => RustLib.instance.api
-      .crateApiStatusMachineStatusAutoAccessorSetMachineState(
-        that: this,
-        machineState: machineState,
-      );
-
-  set position(Pos2D position) =>
-      RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetPosition(
-        that: this,
-        position: position,
-      );
-}
-
-@sealed
-class Pos2DImpl extends RustOpaque implements Pos2D {
-  // Not to be used by end users
-  Pos2DImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Pos2DImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Pos2D,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Pos2D,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Pos2DPtr,
-  );
-
-  double get x =>
-      RustLib.instance.api.crateApiGcodePos2DAutoAccessorGetX(that: this);
-
-  double get y =>
-      RustLib.instance.api.crateApiGcodePos2DAutoAccessorGetY(that: this);
-
-  set x(double x) =>
-      RustLib.instance.api.crateApiGcodePos2DAutoAccessorSetX(that: this, x: x);
-
-  set y(double y) =>
-      RustLib.instance.api.crateApiGcodePos2DAutoAccessorSetY(that: this, y: y);
-
-  Future<Pos2D> toScreenSpace({
-    required double tubeWidth,
-    required double scaleFactor,
-  }) => RustLib.instance.api.crateApiGcodePos2DToScreenSpace(
-    that: this,
-    tubeWidth: tubeWidth,
-    scaleFactor: scaleFactor,
-  );
-}
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSenderMachinePositionNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSenderMachinePositionNewConstMeta => const TaskConstMeta(
+            debugName: "machine_position_new",
+            argNames: [],
+        );
+        
+
+@override MachineReference crateApiJobFileMachineReferenceFromSettings({required String name , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 339)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_machine_reference,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileMachineReferenceFromSettingsConstMeta,
+            argValues: [name, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileMachineReferenceFromSettingsConstMeta => const TaskConstMeta(
+            debugName: "machine_reference_from_settings",
+            argNames: ["name", "cutterSettings"],
+        );
+        
+
+@override bool crateApiJobFileMachineReferenceMatches({required MachineReference that , required CutterSettings cutterSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_machine_reference(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(cutterSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 340)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJobFileMachineReferenceMatchesConstMeta,
+            argValues: [that, cutterSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJobFileMachineReferenceMatchesConstMeta => const TaskConstMeta(
+            debugName: "machine_reference_matches",
+            argNames: ["that", "cutterSettings"],
+        );
+        
+
+@override Future<MotionLimits> crateApiEstimateMotionLimitsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 341, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_motion_limits,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEstimateMotionLimitsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEstimateMotionLimitsDefaultConstMeta => const TaskConstMeta(
+            debugName: "motion_limits_default",
+            argNames: [],
+        );
+        
+
+@override MotionLimits crateApiEstimateMotionLimitsFromMachineSettings({required MachineSettings machineSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(machineSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 342)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_motion_limits,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEstimateMotionLimitsFromMachineSettingsConstMeta,
+            argValues: [machineSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEstimateMotionLimitsFromMachineSettingsConstMeta => const TaskConstMeta(
+            debugName: "motion_limits_from_machine_settings",
+            argNames: ["machineSettings"],
+        );
+        
+
+@override Future<MachineStatus> crateApiStatusParseStatus({required String statusString })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(statusString, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 343, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus,
+          decodeErrorData: sse_decode_status_parse_error,
+        )
+        ,
+            constMeta: kCrateApiStatusParseStatusConstMeta,
+            argValues: [statusString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusParseStatusConstMeta => const TaskConstMeta(
+            debugName: "parse_status",
+            argNames: ["statusString"],
+        );
+        
+
+@override Future<List<Word>> crateApiInterpreterParseWords({required String line })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(line, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 344, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_word,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterParseWordsConstMeta,
+            argValues: [line],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterParseWordsConstMeta => const TaskConstMeta(
+            debugName: "parse_words",
+            argNames: ["line"],
+        );
+        
+
+@override String crateApiExportPostProcessorExtension({required PostProcessor that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_post_processor(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 345)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExportPostProcessorExtensionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportPostProcessorExtensionConstMeta => const TaskConstMeta(
+            debugName: "post_processor_extension",
+            argNames: ["that"],
+        );
+        
+
+@override Future<ProgramExtents?> crateApiBoundsProgramExtents({required List<ToolpathSegment> segments })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(segments, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 346, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsProgramExtentsConstMeta,
+            argValues: [segments],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsProgramExtentsConstMeta => const TaskConstMeta(
+            debugName: "program_extents",
+            argNames: ["segments"],
+        );
+        
+
+@override Future<RenderOptions> crateApiRenderRenderOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 347, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_render_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRenderRenderOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderRenderOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "render_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<Uint8List> crateApiRenderRenderPng({required List<ToolpathSegment> segments , required List<int> cutStartLines , required RenderOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(segments, serializer);
+sse_encode_list_prim_u_32_loose(cutStartLines, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 348, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRenderRenderPngConstMeta,
+            argValues: [segments, cutStartLines, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderRenderPngConstMeta => const TaskConstMeta(
+            debugName: "render_png",
+            argNames: ["segments", "cutStartLines", "options"],
+        );
+        
+
+@override Future<String> crateApiRenderRenderSvg({required List<ToolpathSegment> segments , required List<int> cutStartLines , required RenderOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(segments, serializer);
+sse_encode_list_prim_u_32_loose(cutStartLines, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 349, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRenderRenderSvgConstMeta,
+            argValues: [segments, cutStartLines, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderRenderSvgConstMeta => const TaskConstMeta(
+            debugName: "render_svg",
+            argNames: ["segments", "cutStartLines", "options"],
+        );
+        
+
+@override Future<String> crateApiReportReportPath({required String gcodePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(gcodePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 350, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiReportReportPathConstMeta,
+            argValues: [gcodePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReportReportPathConstMeta => const TaskConstMeta(
+            debugName: "report_path",
+            argNames: ["gcodePath"],
+        );
+        
+
+@override Future<void> crateApiStatusRuleAllRules()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 351, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStatusRuleAllRulesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatusRuleAllRulesConstMeta => const TaskConstMeta(
+            debugName: "rule_all_rules",
+            argNames: [],
+        );
+        
+
+@override Future<List<ToolpathSegment>> crateApiToolpathSimulateToolpath({required String program })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(program, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 352, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiToolpathSimulateToolpathConstMeta,
+            argValues: [program],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiToolpathSimulateToolpathConstMeta => const TaskConstMeta(
+            debugName: "simulate_toolpath",
+            argNames: ["program"],
+        );
+        
+
+@override Future<SimulatorConfig> crateApiSimulatorSimulatorConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 353, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_simulator_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimulatorSimulatorConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatorSimulatorConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "simulator_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiInterpreterStripComments({required String line })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(line, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 354, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInterpreterStripCommentsConstMeta,
+            argValues: [line],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInterpreterStripCommentsConstMeta => const TaskConstMeta(
+            debugName: "strip_comments",
+            argNames: ["line"],
+        );
+        
+
+@override Future<bool> crateApiBoundsTravelLimitsContains({required TravelLimits that , required Pos2D position })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_travel_limits(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(position, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 355, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsTravelLimitsContainsConstMeta,
+            argValues: [that, position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsTravelLimitsContainsConstMeta => const TaskConstMeta(
+            debugName: "travel_limits_contains",
+            argNames: ["that", "position"],
+        );
+        
+
+@override TravelLimits? crateApiBoundsTravelLimitsFromMachineSettings({required MachineSettings machineSettings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(machineSettings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 356)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_travel_limits,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBoundsTravelLimitsFromMachineSettingsConstMeta,
+            argValues: [machineSettings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBoundsTravelLimitsFromMachineSettingsConstMeta => const TaskConstMeta(
+            debugName: "travel_limits_from_machine_settings",
+            argNames: ["machineSettings"],
+        );
+        
+
+@override Future<WorkCoordinateSystem?> crateApiWcsWorkCoordinateSystemFromGcode({required String gcode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(gcode, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 357, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_work_coordinate_system,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiWcsWorkCoordinateSystemFromGcodeConstMeta,
+            argValues: [gcode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWcsWorkCoordinateSystemFromGcodeConstMeta => const TaskConstMeta(
+            debugName: "work_coordinate_system_from_gcode",
+            argNames: ["gcode"],
+        );
+        
+
+@override Future<void> crateApiWcsWorkCoordinateSystemGcode({required WorkCoordinateSystem that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_work_coordinate_system(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 358, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiWcsWorkCoordinateSystemGcodeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWcsWorkCoordinateSystemGcodeConstMeta => const TaskConstMeta(
+            debugName: "work_coordinate_system_gcode",
+            argNames: ["that"],
+        );
+        
+
+@override Future<int> crateApiWcsWorkCoordinateSystemIndex({required WorkCoordinateSystem that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_work_coordinate_system(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 359, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiWcsWorkCoordinateSystemIndexConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWcsWorkCoordinateSystemIndexConstMeta => const TaskConstMeta(
+            debugName: "work_coordinate_system_index",
+            argNames: ["that"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BoundsViolation => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BoundsViolation => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Cut => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Cut => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CutMethod => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CutMethod => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CutterSettings => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CutterSettings => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Feature => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Feature => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Gcode => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Gcode => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_GrblSimulator => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_GrblSimulator => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_JobFile => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_JobFile => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_JobPlan => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_JobPlan => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LaserCalibration => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LaserCalibration => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MachineConnection => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MachineConnection => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MachineSettings => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MachineSettings => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MachineStatus => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MachineStatus => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ModalState => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ModalState => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Pos2D => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Pos2D => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Profile => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Profile => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ProfileSegment => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ProfileSegment => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ProgramExtents => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ProgramExtents => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SegmentKind => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SegmentKind => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ToolpathSegment => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ToolpathSegment => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Viewport => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Viewport => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected BoundsViolation dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BoundsViolationImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Cut dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CutMethod dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutMethodImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CutterSettings dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutterSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Feature dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FeatureImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Gcode dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GcodeImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected GrblSimulator dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GrblSimulatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected JobFile dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JobFileImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected JobPlan dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JobPlanImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LaserCalibration dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LaserCalibrationImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineConnection dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineSettings dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineStatus dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineStatusImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ModalState dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ModalStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Pos2D dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Pos2DImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Profile dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProfileImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProfileSegment dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProfileSegmentImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProgramExtents dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProgramExtentsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SegmentKind dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SegmentKindImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ToolpathSegment dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ToolpathSegmentImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Viewport dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ViewportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected BoundsViolation dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BoundsViolationImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Cut dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CutterSettings dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutterSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Feature dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FeatureImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Gcode dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GcodeImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected GrblSimulator dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GrblSimulatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected JobFile dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JobFileImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected JobPlan dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JobPlanImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LaserCalibration dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LaserCalibrationImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineConnection dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineSettings dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineStatus dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineStatusImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ModalState dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ModalStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Pos2D dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Pos2DImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Profile dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProfileImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProgramExtents dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProgramExtentsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ToolpathSegment dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ToolpathSegmentImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Viewport dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ViewportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected BoundsViolation dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BoundsViolationImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Cut dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CutterSettings dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutterSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Feature dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FeatureImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Gcode dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GcodeImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected GrblSimulator dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GrblSimulatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected JobFile dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JobFileImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected JobPlan dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JobPlanImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LaserCalibration dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LaserCalibrationImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineConnection dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineSettings dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineStatus dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineStatusImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ModalState dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ModalStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Pos2D dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Pos2DImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Profile dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProfileImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProfileSegment dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProfileSegmentImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProgramExtents dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProgramExtentsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ToolpathSegment dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ToolpathSegmentImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Viewport dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ViewportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected String dco_decode_Char(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return String.fromCharCode(raw); }
+
+@protected BoundsViolation dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BoundsViolationImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Cut dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CutMethod dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutMethodImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CutterSettings dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CutterSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Feature dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FeatureImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Gcode dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GcodeImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected GrblSimulator dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GrblSimulatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected JobFile dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JobFileImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected JobPlan dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JobPlanImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LaserCalibration dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LaserCalibrationImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineConnection dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineSettings dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineSettingsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MachineStatus dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineStatusImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ModalState dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ModalStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Pos2D dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Pos2DImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Profile dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProfileImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProfileSegment dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProfileSegmentImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProgramExtents dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProgramExtentsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SegmentKind dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SegmentKindImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ToolpathSegment dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ToolpathSegmentImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Viewport dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ViewportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<JobProgress> dco_decode_StreamSink_job_progress_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<MachinePosition> dco_decode_StreamSink_machine_position_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected Transport dco_decode_TraitDef_Transport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected Accessories dco_decode_accessories(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return Accessories(spindleCw: dco_decode_bool(arr[0]),
+spindleCcw: dco_decode_bool(arr[1]),
+floodCoolant: dco_decode_bool(arr[2]),
+mistCoolant: dco_decode_bool(arr[3]),
+toolChangePending: dco_decode_bool(arr[4]),); }
+
+@protected Axis dco_decode_axis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Axis.values[raw as int]; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected BoundsReport dco_decode_bounds_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BoundsReport(extents: dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(arr[0]),
+violations: dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(arr[1]),); }
+
+@protected MachineStatus dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(raw); }
+
+@protected Pos2D dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(raw); }
+
+@protected ProgramExtents dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(raw); }
+
+@protected Accessories dco_decode_box_autoadd_accessories(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_accessories(raw); }
+
+@protected BoundsReport dco_decode_box_autoadd_bounds_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_bounds_report(raw); }
+
+@protected BufferState dco_decode_box_autoadd_buffer_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_buffer_state(raw); }
+
+@protected ConsumableRatings dco_decode_box_autoadd_consumable_ratings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_consumable_ratings(raw); }
+
+@protected CoordinateOffsets dco_decode_box_autoadd_coordinate_offsets(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_coordinate_offsets(raw); }
+
+@protected CutParameters dco_decode_box_autoadd_cut_parameters(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cut_parameters(raw); }
+
+@protected ExportHeader dco_decode_box_autoadd_export_header(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_export_header(raw); }
+
+@protected double dco_decode_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected Fixture dco_decode_box_autoadd_fixture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_fixture(raw); }
+
+@protected JobReport dco_decode_box_autoadd_job_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_job_report(raw); }
+
+@protected LaserOutput dco_decode_box_autoadd_laser_output(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_laser_output(raw); }
+
+@protected LintOptions dco_decode_box_autoadd_lint_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_lint_options(raw); }
+
+@protected MachineCapabilities dco_decode_box_autoadd_machine_capabilities(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_machine_capabilities(raw); }
+
+@protected MachineReference dco_decode_box_autoadd_machine_reference(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_machine_reference(raw); }
+
+@protected MachineSetting dco_decode_box_autoadd_machine_setting(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_machine_setting(raw); }
+
+@protected MotionLimits dco_decode_box_autoadd_motion_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_motion_limits(raw); }
+
+@protected Overrides dco_decode_box_autoadd_overrides(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_overrides(raw); }
+
+@protected (Pos2D,Pos2D) dco_decode_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as (Pos2D,Pos2D); }
+
+@protected RenderOptions dco_decode_box_autoadd_render_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_render_options(raw); }
+
+@protected SettingDescription dco_decode_box_autoadd_setting_description(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_setting_description(raw); }
+
+@protected SimulatorConfig dco_decode_box_autoadd_simulator_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_simulator_config(raw); }
+
+@protected Stock dco_decode_box_autoadd_stock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stock(raw); }
+
+@protected TravelLimits dco_decode_box_autoadd_travel_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_travel_limits(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected WorkCoordinateSystem dco_decode_box_autoadd_work_coordinate_system(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_work_coordinate_system(raw); }
+
+@protected WorkOffset dco_decode_box_autoadd_work_offset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_work_offset(raw); }
+
+@protected BufferState dco_decode_buffer_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BufferState(plannerBlocks: dco_decode_u_32(arr[0]),
+rxBytes: dco_decode_u_32(arr[1]),); }
+
+@protected ConnectionSettings dco_decode_connection_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return ConnectionSettings(transport: dco_decode_transport_kind(arr[0]),
+serialPort: dco_decode_String(arr[1]),
+baudrate: dco_decode_u_32(arr[2]),
+flowControl: dco_decode_serial_flow_control(arr[3]),
+dtrOnOpen: dco_decode_control_line_on_open(arr[4]),
+rtsOnOpen: dco_decode_control_line_on_open(arr[5]),
+readTimeoutMs: dco_decode_u_32(arr[6]),
+lineEnding: dco_decode_line_ending(arr[7]),
+lineNumbers: dco_decode_bool(arr[8]),
+host: dco_decode_String(arr[9]),
+networkPort: dco_decode_u_16(arr[10]),); }
+
+@protected ConnectionState dco_decode_connection_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConnectionState.values[raw as int]; }
+
+@protected ConsumableRatings dco_decode_consumable_ratings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ConsumableRatings(pierces: dco_decode_u_32(arr[0]),
+arcTime: dco_decode_f_32(arr[1]),); }
+
+@protected Contour dco_decode_contour(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Contour(segments: dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(arr[0]),
+closed: dco_decode_bool(arr[1]),); }
+
+@protected ControlLineOnOpen dco_decode_control_line_on_open(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ControlLineOnOpen.values[raw as int]; }
+
+@protected CoordinateOffsets dco_decode_coordinate_offsets(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CoordinateOffsets(workOffsets: dco_decode_list_work_offset(arr[0]),
+g92: dco_decode_opt_list_prim_f_32_strict(arr[1]),
+active: dco_decode_opt_box_autoadd_work_coordinate_system(arr[2]),); }
+
+@protected CutParameters dco_decode_cut_parameters(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CutParameters(feedrate: dco_decode_f_32(arr[0]),
+pierceDelay: dco_decode_f_32(arr[1]),); }
+
+@protected CutReport dco_decode_cut_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CutReport(position: dco_decode_f_32(arr[0]),
+angle: dco_decode_f_32(arr[1]),
+feedrate: dco_decode_f_32(arr[2]),
+pierceDelay: dco_decode_f_32(arr[3]),
+pierceDelay2: dco_decode_f_32(arr[4]),
+estimatedTime: dco_decode_f_32(arr[5]),); }
+
+@protected DistUnit dco_decode_dist_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DistUnit.values[raw as int]; }
+
+@protected ExportHeader dco_decode_export_header(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ExportHeader(jobName: dco_decode_String(arr[0]),
+stock: dco_decode_opt_box_autoadd_stock(arr[1]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FeatureReport dco_decode_feature_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FeatureReport(contours: dco_decode_u_32(arr[0]),
+feedrate: dco_decode_f_32(arr[1]),
+pierceDelay: dco_decode_f_32(arr[2]),
+estimatedTime: dco_decode_f_32(arr[3]),); }
+
+@protected Fixture dco_decode_fixture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Fixture(name: dco_decode_String(arr[0]),
+wcs: dco_decode_work_coordinate_system(arr[1]),
+x: dco_decode_f_32(arr[2]),
+y: dco_decode_f_32(arr[3]),); }
+
+@protected HomingAxes dco_decode_homing_axes(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HomingAxes.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected JobEstimate dco_decode_job_estimate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return JobEstimate(totalTime: dco_decode_f_32(arr[0]),
+cutTime: dco_decode_f_32(arr[1]),
+rapidTime: dco_decode_f_32(arr[2]),
+dwellTime: dco_decode_f_32(arr[3]),
+cutLength: dco_decode_f_32(arr[4]),
+rapidLength: dco_decode_f_32(arr[5]),
+pierceCount: dco_decode_u_32(arr[6]),
+arcTime: dco_decode_f_32(arr[7]),
+consumableWear: dco_decode_f_32(arr[8]),); }
+
+@protected JobProgress dco_decode_job_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return JobProgress(state: dco_decode_job_state(arr[0]),
+currentLine: dco_decode_u_32(arr[1]),
+linesAcknowledged: dco_decode_u_32(arr[2]),
+totalLines: dco_decode_u_32(arr[3]),
+percent: dco_decode_f_32(arr[4]),
+elapsedSeconds: dco_decode_f_32(arr[5]),
+remainingSeconds: dco_decode_opt_box_autoadd_f_32(arr[6]),
+error: dco_decode_opt_String(arr[7]),); }
+
+@protected JobReport dco_decode_job_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return JobReport(name: dco_decode_String(arr[0]),
+material: dco_decode_String(arr[1]),
+tubeWidth: dco_decode_f_32(arr[2]),
+stockLength: dco_decode_f_32(arr[3]),
+cuts: dco_decode_list_cut_report(arr[4]),
+features: dco_decode_list_feature_report(arr[5]),
+parts: dco_decode_list_part(arr[6]),
+usedLength: dco_decode_f_32(arr[7]),
+wasteLength: dco_decode_f_32(arr[8]),
+estimate: dco_decode_job_estimate(arr[9]),
+toolpathSvg: dco_decode_String(arr[10]),); }
+
+@protected JobState dco_decode_job_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JobState.values[raw as int]; }
+
+@protected JogStep dco_decode_jog_step(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JogStep.values[raw as int]; }
+
+@protected LaserOutput dco_decode_laser_output(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return LaserOutput_Flood();
+case 1: return LaserOutput_Mist();
+case 2: return LaserOutput_Custom(onCommand: dco_decode_String(raw[1]),offCommand: dco_decode_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected LineEnding dco_decode_line_ending(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LineEnding.values[raw as int]; }
+
+@protected LintDiagnostic dco_decode_lint_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LintDiagnostic(severity: dco_decode_severity(arr[0]),
+rule: dco_decode_lint_rule(arr[1]),
+line: dco_decode_u_32(arr[2]),
+message: dco_decode_String(arr[3]),); }
+
+@protected LintOptions dco_decode_lint_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return LintOptions(maxTorchDwell: dco_decode_f_32(arr[0]),); }
+
+@protected LintRule dco_decode_lint_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LintRule.values[raw as int]; }
+
+@protected List<BoundsViolation> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation).toList(); }
+
+@protected List<Cut> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut).toList(); }
+
+@protected List<Feature> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature).toList(); }
+
+@protected List<Pos2D> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D).toList(); }
+
+@protected List<ProfileSegment> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment).toList(); }
+
+@protected List<ToolpathSegment> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment).toList(); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<Contour> dco_decode_list_contour(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_contour).toList(); }
+
+@protected List<CutReport> dco_decode_list_cut_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cut_report).toList(); }
+
+@protected List<FeatureReport> dco_decode_list_feature_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_feature_report).toList(); }
+
+@protected List<Fixture> dco_decode_list_fixture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_fixture).toList(); }
+
+@protected List<LintDiagnostic> dco_decode_list_lint_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lint_diagnostic).toList(); }
+
+@protected List<MachineSetting> dco_decode_list_machine_setting(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_machine_setting).toList(); }
+
+@protected List<Part> dco_decode_list_part(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_part).toList(); }
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float32List; }
+
+@protected List<int> dco_decode_list_prim_u_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<SettingGroup> dco_decode_list_setting_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_setting_group).toList(); }
+
+@protected List<Word> dco_decode_list_word(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_word).toList(); }
+
+@protected List<WorkOffset> dco_decode_list_work_offset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_work_offset).toList(); }
+
+@protected MachineCapabilities dco_decode_machine_capabilities(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return MachineCapabilities(version: dco_decode_opt_String(arr[0]),
+firmware: dco_decode_opt_String(arr[1]),
+options: dco_decode_String(arr[2]),
+plannerBlocks: dco_decode_opt_box_autoadd_u_32(arr[3]),
+rxBufferSize: dco_decode_opt_box_autoadd_u_32(arr[4]),
+axes: dco_decode_String(arr[5]),
+extendedOptions: dco_decode_list_String(arr[6]),); }
+
+@protected MachinePosition dco_decode_machine_position(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MachinePosition.raw(x: dco_decode_f_32(arr[0]),
+y: dco_decode_f_32(arr[1]),); }
+
+@protected MachineReference dco_decode_machine_reference(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MachineReference(name: dco_decode_String(arr[0]),
+useLaser: dco_decode_bool(arr[1]),
+laserOffsetX: dco_decode_f_32(arr[2]),
+laserOffsetY: dco_decode_f_32(arr[3]),
+homeAfterCut: dco_decode_bool(arr[4]),); }
+
+@protected MachineSetting dco_decode_machine_setting(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MachineSetting(id: dco_decode_u_32(arr[0]),
+value: dco_decode_String(arr[1]),
+description: dco_decode_opt_box_autoadd_setting_description(arr[2]),); }
+
+@protected MachineState dco_decode_machine_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MachineState.values[raw as int]; }
+
+@protected MotionLimits dco_decode_motion_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MotionLimits(xMaxRate: dco_decode_f_32(arr[0]),
+yMaxRate: dco_decode_f_32(arr[1]),
+xAcceleration: dco_decode_f_32(arr[2]),
+yAcceleration: dco_decode_f_32(arr[3]),); }
+
+@protected MotionMode dco_decode_motion_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MotionMode.values[raw as int]; }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected MachineStatus? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(raw); }
+
+@protected Pos2D? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(raw); }
+
+@protected ProgramExtents? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(raw); }
+
+@protected Accessories? dco_decode_opt_box_autoadd_accessories(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_accessories(raw); }
+
+@protected BufferState? dco_decode_opt_box_autoadd_buffer_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_buffer_state(raw); }
+
+@protected ExportHeader? dco_decode_opt_box_autoadd_export_header(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_export_header(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_32(raw); }
+
+@protected Fixture? dco_decode_opt_box_autoadd_fixture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_fixture(raw); }
+
+@protected MachineSetting? dco_decode_opt_box_autoadd_machine_setting(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_machine_setting(raw); }
+
+@protected Overrides? dco_decode_opt_box_autoadd_overrides(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_overrides(raw); }
+
+@protected (Pos2D,Pos2D)? dco_decode_opt_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(raw); }
+
+@protected SettingDescription? dco_decode_opt_box_autoadd_setting_description(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_setting_description(raw); }
+
+@protected Stock? dco_decode_opt_box_autoadd_stock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_stock(raw); }
+
+@protected TravelLimits? dco_decode_opt_box_autoadd_travel_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_travel_limits(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected WorkCoordinateSystem? dco_decode_opt_box_autoadd_work_coordinate_system(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_work_coordinate_system(raw); }
+
+@protected WorkOffset? dco_decode_opt_box_autoadd_work_offset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_work_offset(raw); }
+
+@protected Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_f_32_strict(raw); }
+
+@protected Overrides dco_decode_overrides(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Overrides(feed: dco_decode_u_32(arr[0]),
+rapid: dco_decode_u_32(arr[1]),
+spindle: dco_decode_u_32(arr[2]),); }
+
+@protected Part dco_decode_part(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Part(start: dco_decode_f_32(arr[0]),
+length: dco_decode_f_32(arr[1]),
+startAngle: dco_decode_f_32(arr[2]),
+endAngle: dco_decode_f_32(arr[3]),); }
+
+@protected PositioningMode dco_decode_positioning_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PositioningMode.values[raw as int]; }
+
+@protected PostProcessor dco_decode_post_processor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PostProcessor.values[raw as int]; }
+
+@protected (Pos2D,Pos2D) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(arr[0]),dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(arr[1]),); }
+
+@protected (double,double) dco_decode_record_f_32_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_f_32(arr[0]),dco_decode_f_32(arr[1]),); }
+
+@protected RenderOptions dco_decode_render_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return RenderOptions(width: dco_decode_u_32(arr[0]),
+height: dco_decode_u_32(arr[1]),
+margin: dco_decode_f_32(arr[2]),
+tubeWidth: dco_decode_opt_box_autoadd_f_32(arr[3]),
+showRapids: dco_decode_bool(arr[4]),
+showLabels: dco_decode_bool(arr[5]),); }
+
+@protected Rule dco_decode_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Rule.values[raw as int]; }
+
+@protected SerialFlowControl dco_decode_serial_flow_control(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SerialFlowControl.values[raw as int]; }
+
+@protected SettingDescription dco_decode_setting_description(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SettingDescription(name: dco_decode_String(arr[0]),
+unit: dco_decode_String(arr[1]),
+settingType: dco_decode_setting_type(arr[2]),
+group: dco_decode_opt_box_autoadd_u_32(arr[3]),
+min: dco_decode_opt_box_autoadd_f_32(arr[4]),
+max: dco_decode_opt_box_autoadd_f_32(arr[5]),); }
+
+@protected SettingGroup dco_decode_setting_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SettingGroup(id: dco_decode_u_32(arr[0]),
+parent: dco_decode_u_32(arr[1]),
+name: dco_decode_String(arr[2]),); }
+
+@protected SettingType dco_decode_setting_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SettingType.values[raw as int]; }
+
+@protected Severity dco_decode_severity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Severity.values[raw as int]; }
+
+@protected SimulatorConfig dco_decode_simulator_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SimulatorConfig(plannerBlocks: dco_decode_usize(arr[0]),
+rxBufferSize: dco_decode_usize(arr[1]),
+rapidRate: dco_decode_f_32(arr[2]),
+defaultFeedrate: dco_decode_f_32(arr[3]),
+timeScale: dco_decode_f_32(arr[4]),
+homingRequired: dco_decode_bool(arr[5]),); }
+
+@protected StatusParseError dco_decode_status_parse_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return StatusParseError_InvalidSyntax(dco_decode_String(raw[1]),);
+case 1: return StatusParseError_InvalidValue(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected Stock dco_decode_stock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Stock(material: dco_decode_String(arr[0]),
+tubeWidth: dco_decode_f_32(arr[1]),
+length: dco_decode_f_32(arr[2]),); }
+
+@protected TransportKind dco_decode_transport_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TransportKind.values[raw as int]; }
+
+@protected TravelLimits dco_decode_travel_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return TravelLimits(xMin: dco_decode_f_32(arr[0]),
+xMax: dco_decode_f_32(arr[1]),
+yMin: dco_decode_f_32(arr[2]),
+yMax: dco_decode_f_32(arr[3]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected Word dco_decode_word(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Word(letter: dco_decode_Char(arr[0]),
+value: dco_decode_f_32(arr[1]),); }
+
+@protected WorkCoordinateSystem dco_decode_work_coordinate_system(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return WorkCoordinateSystem.values[raw as int]; }
+
+@protected WorkOffset dco_decode_work_offset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return WorkOffset(wcs: dco_decode_work_coordinate_system(arr[0]),
+x: dco_decode_f_32(arr[1]),
+y: dco_decode_f_32(arr[2]),
+z: dco_decode_f_32(arr[3]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected BoundsViolation sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BoundsViolationImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Cut sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CutMethod sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutMethodImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CutterSettings sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutterSettingsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Feature sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return FeatureImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Gcode sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GcodeImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected GrblSimulator sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GrblSimulatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected JobFile sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return JobFileImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected JobPlan sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return JobPlanImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LaserCalibration sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LaserCalibrationImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineConnection sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineConnectionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineSettings sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineSettingsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineStatus sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineStatusImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ModalState sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ModalStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Pos2D sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Pos2DImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Profile sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProfileImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProfileSegment sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProfileSegmentImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProgramExtents sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProgramExtentsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SegmentKind sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SegmentKindImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ToolpathSegment sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ToolpathSegmentImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Viewport sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ViewportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected BoundsViolation sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BoundsViolationImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Cut sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CutterSettings sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutterSettingsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Feature sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return FeatureImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Gcode sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GcodeImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected GrblSimulator sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GrblSimulatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected JobFile sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return JobFileImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected JobPlan sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return JobPlanImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LaserCalibration sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LaserCalibrationImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineConnection sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineConnectionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineSettings sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineSettingsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineStatus sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineStatusImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ModalState sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ModalStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Pos2D sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Pos2DImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Profile sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProfileImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProgramExtents sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProgramExtentsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ToolpathSegment sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ToolpathSegmentImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Viewport sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ViewportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected BoundsViolation sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BoundsViolationImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Cut sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CutterSettings sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutterSettingsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Feature sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return FeatureImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Gcode sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GcodeImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected GrblSimulator sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GrblSimulatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected JobFile sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return JobFileImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected JobPlan sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return JobPlanImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LaserCalibration sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LaserCalibrationImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineConnection sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineConnectionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineSettings sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineSettingsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineStatus sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineStatusImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ModalState sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ModalStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Pos2D sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Pos2DImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Profile sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProfileImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProfileSegment sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProfileSegmentImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProgramExtents sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProgramExtentsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ToolpathSegment sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ToolpathSegmentImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Viewport sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ViewportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected String sse_decode_Char(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return inner; }
+
+@protected BoundsViolation sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BoundsViolationImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Cut sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CutMethod sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutMethodImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CutterSettings sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CutterSettingsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Feature sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return FeatureImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Gcode sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GcodeImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected GrblSimulator sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GrblSimulatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected JobFile sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return JobFileImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected JobPlan sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return JobPlanImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LaserCalibration sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LaserCalibrationImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineConnection sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineConnectionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineSettings sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineSettingsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MachineStatus sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MachineStatusImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ModalState sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ModalStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Pos2D sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Pos2DImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Profile sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProfileImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProfileSegment sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProfileSegmentImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProgramExtents sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProgramExtentsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SegmentKind sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SegmentKindImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ToolpathSegment sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ToolpathSegmentImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Viewport sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ViewportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<JobProgress> sse_decode_StreamSink_job_progress_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<MachinePosition> sse_decode_StreamSink_machine_position_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected Accessories sse_decode_accessories(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_spindleCw = sse_decode_bool(deserializer);
+var var_spindleCcw = sse_decode_bool(deserializer);
+var var_floodCoolant = sse_decode_bool(deserializer);
+var var_mistCoolant = sse_decode_bool(deserializer);
+var var_toolChangePending = sse_decode_bool(deserializer);
+return Accessories(spindleCw: var_spindleCw, spindleCcw: var_spindleCcw, floodCoolant: var_floodCoolant, mistCoolant: var_mistCoolant, toolChangePending: var_toolChangePending); }
+
+@protected Axis sse_decode_axis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Axis.values[inner]; }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected BoundsReport sse_decode_bounds_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_extents = sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(deserializer);
+var var_violations = sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(deserializer);
+return BoundsReport(extents: var_extents, violations: var_violations); }
+
+@protected MachineStatus sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(deserializer)); }
+
+@protected Pos2D sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(deserializer)); }
+
+@protected ProgramExtents sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(deserializer)); }
+
+@protected Accessories sse_decode_box_autoadd_accessories(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_accessories(deserializer)); }
+
+@protected BoundsReport sse_decode_box_autoadd_bounds_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bounds_report(deserializer)); }
+
+@protected BufferState sse_decode_box_autoadd_buffer_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_buffer_state(deserializer)); }
+
+@protected ConsumableRatings sse_decode_box_autoadd_consumable_ratings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_consumable_ratings(deserializer)); }
+
+@protected CoordinateOffsets sse_decode_box_autoadd_coordinate_offsets(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_coordinate_offsets(deserializer)); }
+
+@protected CutParameters sse_decode_box_autoadd_cut_parameters(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cut_parameters(deserializer)); }
+
+@protected ExportHeader sse_decode_box_autoadd_export_header(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_export_header(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_32(deserializer)); }
+
+@protected Fixture sse_decode_box_autoadd_fixture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_fixture(deserializer)); }
+
+@protected JobReport sse_decode_box_autoadd_job_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_job_report(deserializer)); }
+
+@protected LaserOutput sse_decode_box_autoadd_laser_output(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_laser_output(deserializer)); }
+
+@protected LintOptions sse_decode_box_autoadd_lint_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_lint_options(deserializer)); }
+
+@protected MachineCapabilities sse_decode_box_autoadd_machine_capabilities(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_machine_capabilities(deserializer)); }
+
+@protected MachineReference sse_decode_box_autoadd_machine_reference(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_machine_reference(deserializer)); }
+
+@protected MachineSetting sse_decode_box_autoadd_machine_setting(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_machine_setting(deserializer)); }
+
+@protected MotionLimits sse_decode_box_autoadd_motion_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_motion_limits(deserializer)); }
+
+@protected Overrides sse_decode_box_autoadd_overrides(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_overrides(deserializer)); }
+
+@protected (Pos2D,Pos2D) sse_decode_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(deserializer)); }
+
+@protected RenderOptions sse_decode_box_autoadd_render_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_render_options(deserializer)); }
+
+@protected SettingDescription sse_decode_box_autoadd_setting_description(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_setting_description(deserializer)); }
+
+@protected SimulatorConfig sse_decode_box_autoadd_simulator_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_simulator_config(deserializer)); }
+
+@protected Stock sse_decode_box_autoadd_stock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stock(deserializer)); }
+
+@protected TravelLimits sse_decode_box_autoadd_travel_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_travel_limits(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected WorkCoordinateSystem sse_decode_box_autoadd_work_coordinate_system(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_work_coordinate_system(deserializer)); }
+
+@protected WorkOffset sse_decode_box_autoadd_work_offset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_work_offset(deserializer)); }
+
+@protected BufferState sse_decode_buffer_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_plannerBlocks = sse_decode_u_32(deserializer);
+var var_rxBytes = sse_decode_u_32(deserializer);
+return BufferState(plannerBlocks: var_plannerBlocks, rxBytes: var_rxBytes); }
+
+@protected ConnectionSettings sse_decode_connection_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_transport = sse_decode_transport_kind(deserializer);
+var var_serialPort = sse_decode_String(deserializer);
+var var_baudrate = sse_decode_u_32(deserializer);
+var var_flowControl = sse_decode_serial_flow_control(deserializer);
+var var_dtrOnOpen = sse_decode_control_line_on_open(deserializer);
+var var_rtsOnOpen = sse_decode_control_line_on_open(deserializer);
+var var_readTimeoutMs = sse_decode_u_32(deserializer);
+var var_lineEnding = sse_decode_line_ending(deserializer);
+var var_lineNumbers = sse_decode_bool(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_networkPort = sse_decode_u_16(deserializer);
+return ConnectionSettings(transport: var_transport, serialPort: var_serialPort, baudrate: var_baudrate, flowControl: var_flowControl, dtrOnOpen: var_dtrOnOpen, rtsOnOpen: var_rtsOnOpen, readTimeoutMs: var_readTimeoutMs, lineEnding: var_lineEnding, lineNumbers: var_lineNumbers, host: var_host, networkPort: var_networkPort); }
+
+@protected ConnectionState sse_decode_connection_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConnectionState.values[inner]; }
+
+@protected ConsumableRatings sse_decode_consumable_ratings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pierces = sse_decode_u_32(deserializer);
+var var_arcTime = sse_decode_f_32(deserializer);
+return ConsumableRatings(pierces: var_pierces, arcTime: var_arcTime); }
+
+@protected Contour sse_decode_contour(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_segments = sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(deserializer);
+var var_closed = sse_decode_bool(deserializer);
+return Contour(segments: var_segments, closed: var_closed); }
+
+@protected ControlLineOnOpen sse_decode_control_line_on_open(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ControlLineOnOpen.values[inner]; }
+
+@protected CoordinateOffsets sse_decode_coordinate_offsets(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_workOffsets = sse_decode_list_work_offset(deserializer);
+var var_g92 = sse_decode_opt_list_prim_f_32_strict(deserializer);
+var var_active = sse_decode_opt_box_autoadd_work_coordinate_system(deserializer);
+return CoordinateOffsets(workOffsets: var_workOffsets, g92: var_g92, active: var_active); }
+
+@protected CutParameters sse_decode_cut_parameters(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_feedrate = sse_decode_f_32(deserializer);
+var var_pierceDelay = sse_decode_f_32(deserializer);
+return CutParameters(feedrate: var_feedrate, pierceDelay: var_pierceDelay); }
+
+@protected CutReport sse_decode_cut_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_position = sse_decode_f_32(deserializer);
+var var_angle = sse_decode_f_32(deserializer);
+var var_feedrate = sse_decode_f_32(deserializer);
+var var_pierceDelay = sse_decode_f_32(deserializer);
+var var_pierceDelay2 = sse_decode_f_32(deserializer);
+var var_estimatedTime = sse_decode_f_32(deserializer);
+return CutReport(position: var_position, angle: var_angle, feedrate: var_feedrate, pierceDelay: var_pierceDelay, pierceDelay2: var_pierceDelay2, estimatedTime: var_estimatedTime); }
+
+@protected DistUnit sse_decode_dist_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DistUnit.values[inner]; }
+
+@protected ExportHeader sse_decode_export_header(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_jobName = sse_decode_String(deserializer);
+var var_stock = sse_decode_opt_box_autoadd_stock(deserializer);
+return ExportHeader(jobName: var_jobName, stock: var_stock); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected FeatureReport sse_decode_feature_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_contours = sse_decode_u_32(deserializer);
+var var_feedrate = sse_decode_f_32(deserializer);
+var var_pierceDelay = sse_decode_f_32(deserializer);
+var var_estimatedTime = sse_decode_f_32(deserializer);
+return FeatureReport(contours: var_contours, feedrate: var_feedrate, pierceDelay: var_pierceDelay, estimatedTime: var_estimatedTime); }
+
+@protected Fixture sse_decode_fixture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_wcs = sse_decode_work_coordinate_system(deserializer);
+var var_x = sse_decode_f_32(deserializer);
+var var_y = sse_decode_f_32(deserializer);
+return Fixture(name: var_name, wcs: var_wcs, x: var_x, y: var_y); }
+
+@protected HomingAxes sse_decode_homing_axes(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return HomingAxes.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected JobEstimate sse_decode_job_estimate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalTime = sse_decode_f_32(deserializer);
+var var_cutTime = sse_decode_f_32(deserializer);
+var var_rapidTime = sse_decode_f_32(deserializer);
+var var_dwellTime = sse_decode_f_32(deserializer);
+var var_cutLength = sse_decode_f_32(deserializer);
+var var_rapidLength = sse_decode_f_32(deserializer);
+var var_pierceCount = sse_decode_u_32(deserializer);
+var var_arcTime = sse_decode_f_32(deserializer);
+var var_consumableWear = sse_decode_f_32(deserializer);
+return JobEstimate(totalTime: var_totalTime, cutTime: var_cutTime, rapidTime: var_rapidTime, dwellTime: var_dwellTime, cutLength: var_cutLength, rapidLength: var_rapidLength, pierceCount: var_pierceCount, arcTime: var_arcTime, consumableWear: var_consumableWear); }
+
+@protected JobProgress sse_decode_job_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_job_state(deserializer);
+var var_currentLine = sse_decode_u_32(deserializer);
+var var_linesAcknowledged = sse_decode_u_32(deserializer);
+var var_totalLines = sse_decode_u_32(deserializer);
+var var_percent = sse_decode_f_32(deserializer);
+var var_elapsedSeconds = sse_decode_f_32(deserializer);
+var var_remainingSeconds = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return JobProgress(state: var_state, currentLine: var_currentLine, linesAcknowledged: var_linesAcknowledged, totalLines: var_totalLines, percent: var_percent, elapsedSeconds: var_elapsedSeconds, remainingSeconds: var_remainingSeconds, error: var_error); }
+
+@protected JobReport sse_decode_job_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_material = sse_decode_String(deserializer);
+var var_tubeWidth = sse_decode_f_32(deserializer);
+var var_stockLength = sse_decode_f_32(deserializer);
+var var_cuts = sse_decode_list_cut_report(deserializer);
+var var_features = sse_decode_list_feature_report(deserializer);
+var var_parts = sse_decode_list_part(deserializer);
+var var_usedLength = sse_decode_f_32(deserializer);
+var var_wasteLength = sse_decode_f_32(deserializer);
+var var_estimate = sse_decode_job_estimate(deserializer);
+var var_toolpathSvg = sse_decode_String(deserializer);
+return JobReport(name: var_name, material: var_material, tubeWidth: var_tubeWidth, stockLength: var_stockLength, cuts: var_cuts, features: var_features, parts: var_parts, usedLength: var_usedLength, wasteLength: var_wasteLength, estimate: var_estimate, toolpathSvg: var_toolpathSvg); }
+
+@protected JobState sse_decode_job_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return JobState.values[inner]; }
+
+@protected JogStep sse_decode_jog_step(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return JogStep.values[inner]; }
+
+@protected LaserOutput sse_decode_laser_output(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return LaserOutput_Flood();case 1: return LaserOutput_Mist();case 2: var var_onCommand = sse_decode_String(deserializer);
+var var_offCommand = sse_decode_String(deserializer);
+return LaserOutput_Custom(onCommand: var_onCommand, offCommand: var_offCommand); default: throw UnimplementedError(''); }
+             }
+
+@protected LineEnding sse_decode_line_ending(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LineEnding.values[inner]; }
+
+@protected LintDiagnostic sse_decode_lint_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_severity = sse_decode_severity(deserializer);
+var var_rule = sse_decode_lint_rule(deserializer);
+var var_line = sse_decode_u_32(deserializer);
+var var_message = sse_decode_String(deserializer);
+return LintDiagnostic(severity: var_severity, rule: var_rule, line: var_line, message: var_message); }
+
+@protected LintOptions sse_decode_lint_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxTorchDwell = sse_decode_f_32(deserializer);
+return LintOptions(maxTorchDwell: var_maxTorchDwell); }
+
+@protected LintRule sse_decode_lint_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LintRule.values[inner]; }
+
+@protected List<BoundsViolation> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BoundsViolation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Cut> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Cut>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Feature> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Feature>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Pos2D> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Pos2D>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ProfileSegment> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProfileSegment>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ToolpathSegment> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ToolpathSegment>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(deserializer)); }
+        return ans_;
+         }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Contour> sse_decode_list_contour(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Contour>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_contour(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CutReport> sse_decode_list_cut_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CutReport>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cut_report(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FeatureReport> sse_decode_list_feature_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FeatureReport>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_feature_report(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Fixture> sse_decode_list_fixture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Fixture>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_fixture(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LintDiagnostic> sse_decode_list_lint_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LintDiagnostic>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lint_diagnostic(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MachineSetting> sse_decode_list_machine_setting(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MachineSetting>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_machine_setting(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Part> sse_decode_list_part(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Part>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_part(deserializer)); }
+        return ans_;
+         }
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<SettingGroup> sse_decode_list_setting_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SettingGroup>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_setting_group(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Word> sse_decode_list_word(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Word>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_word(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WorkOffset> sse_decode_list_work_offset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WorkOffset>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_work_offset(deserializer)); }
+        return ans_;
+         }
+
+@protected MachineCapabilities sse_decode_machine_capabilities(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_version = sse_decode_opt_String(deserializer);
+var var_firmware = sse_decode_opt_String(deserializer);
+var var_options = sse_decode_String(deserializer);
+var var_plannerBlocks = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_rxBufferSize = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_axes = sse_decode_String(deserializer);
+var var_extendedOptions = sse_decode_list_String(deserializer);
+return MachineCapabilities(version: var_version, firmware: var_firmware, options: var_options, plannerBlocks: var_plannerBlocks, rxBufferSize: var_rxBufferSize, axes: var_axes, extendedOptions: var_extendedOptions); }
+
+@protected MachinePosition sse_decode_machine_position(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_x = sse_decode_f_32(deserializer);
+var var_y = sse_decode_f_32(deserializer);
+return MachinePosition.raw(x: var_x, y: var_y); }
+
+@protected MachineReference sse_decode_machine_reference(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_useLaser = sse_decode_bool(deserializer);
+var var_laserOffsetX = sse_decode_f_32(deserializer);
+var var_laserOffsetY = sse_decode_f_32(deserializer);
+var var_homeAfterCut = sse_decode_bool(deserializer);
+return MachineReference(name: var_name, useLaser: var_useLaser, laserOffsetX: var_laserOffsetX, laserOffsetY: var_laserOffsetY, homeAfterCut: var_homeAfterCut); }
+
+@protected MachineSetting sse_decode_machine_setting(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_value = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_box_autoadd_setting_description(deserializer);
+return MachineSetting(id: var_id, value: var_value, description: var_description); }
+
+@protected MachineState sse_decode_machine_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MachineState.values[inner]; }
+
+@protected MotionLimits sse_decode_motion_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_xMaxRate = sse_decode_f_32(deserializer);
+var var_yMaxRate = sse_decode_f_32(deserializer);
+var var_xAcceleration = sse_decode_f_32(deserializer);
+var var_yAcceleration = sse_decode_f_32(deserializer);
+return MotionLimits(xMaxRate: var_xMaxRate, yMaxRate: var_yMaxRate, xAcceleration: var_xAcceleration, yAcceleration: var_yAcceleration); }
+
+@protected MotionMode sse_decode_motion_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MotionMode.values[inner]; }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MachineStatus? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Pos2D? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProgramExtents? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Accessories? sse_decode_opt_box_autoadd_accessories(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_accessories(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BufferState? sse_decode_opt_box_autoadd_buffer_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_buffer_state(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ExportHeader? sse_decode_opt_box_autoadd_export_header(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_export_header(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Fixture? sse_decode_opt_box_autoadd_fixture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_fixture(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MachineSetting? sse_decode_opt_box_autoadd_machine_setting(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_machine_setting(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Overrides? sse_decode_opt_box_autoadd_overrides(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_overrides(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected (Pos2D,Pos2D)? sse_decode_opt_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SettingDescription? sse_decode_opt_box_autoadd_setting_description(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_setting_description(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Stock? sse_decode_opt_box_autoadd_stock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_stock(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TravelLimits? sse_decode_opt_box_autoadd_travel_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_travel_limits(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected WorkCoordinateSystem? sse_decode_opt_box_autoadd_work_coordinate_system(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_work_coordinate_system(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected WorkOffset? sse_decode_opt_box_autoadd_work_offset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_work_offset(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Float32List? sse_decode_opt_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_f_32_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Overrides sse_decode_overrides(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_feed = sse_decode_u_32(deserializer);
+var var_rapid = sse_decode_u_32(deserializer);
+var var_spindle = sse_decode_u_32(deserializer);
+return Overrides(feed: var_feed, rapid: var_rapid, spindle: var_spindle); }
+
+@protected Part sse_decode_part(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_f_32(deserializer);
+var var_length = sse_decode_f_32(deserializer);
+var var_startAngle = sse_decode_f_32(deserializer);
+var var_endAngle = sse_decode_f_32(deserializer);
+return Part(start: var_start, length: var_length, startAngle: var_startAngle, endAngle: var_endAngle); }
+
+@protected PositioningMode sse_decode_positioning_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PositioningMode.values[inner]; }
+
+@protected PostProcessor sse_decode_post_processor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PostProcessor.values[inner]; }
+
+@protected (Pos2D,Pos2D) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(deserializer);
+var var_field1 = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(deserializer);
+return (var_field0, var_field1); }
+
+@protected (double,double) sse_decode_record_f_32_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_f_32(deserializer);
+var var_field1 = sse_decode_f_32(deserializer);
+return (var_field0, var_field1); }
+
+@protected RenderOptions sse_decode_render_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_margin = sse_decode_f_32(deserializer);
+var var_tubeWidth = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_showRapids = sse_decode_bool(deserializer);
+var var_showLabels = sse_decode_bool(deserializer);
+return RenderOptions(width: var_width, height: var_height, margin: var_margin, tubeWidth: var_tubeWidth, showRapids: var_showRapids, showLabels: var_showLabels); }
+
+@protected Rule sse_decode_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Rule.values[inner]; }
+
+@protected SerialFlowControl sse_decode_serial_flow_control(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SerialFlowControl.values[inner]; }
+
+@protected SettingDescription sse_decode_setting_description(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_settingType = sse_decode_setting_type(deserializer);
+var var_group = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_min = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_max = sse_decode_opt_box_autoadd_f_32(deserializer);
+return SettingDescription(name: var_name, unit: var_unit, settingType: var_settingType, group: var_group, min: var_min, max: var_max); }
+
+@protected SettingGroup sse_decode_setting_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_parent = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+return SettingGroup(id: var_id, parent: var_parent, name: var_name); }
+
+@protected SettingType sse_decode_setting_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SettingType.values[inner]; }
+
+@protected Severity sse_decode_severity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Severity.values[inner]; }
+
+@protected SimulatorConfig sse_decode_simulator_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_plannerBlocks = sse_decode_usize(deserializer);
+var var_rxBufferSize = sse_decode_usize(deserializer);
+var var_rapidRate = sse_decode_f_32(deserializer);
+var var_defaultFeedrate = sse_decode_f_32(deserializer);
+var var_timeScale = sse_decode_f_32(deserializer);
+var var_homingRequired = sse_decode_bool(deserializer);
+return SimulatorConfig(plannerBlocks: var_plannerBlocks, rxBufferSize: var_rxBufferSize, rapidRate: var_rapidRate, defaultFeedrate: var_defaultFeedrate, timeScale: var_timeScale, homingRequired: var_homingRequired); }
+
+@protected StatusParseError sse_decode_status_parse_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return StatusParseError_InvalidSyntax(var_field0);case 1: var var_field0 = sse_decode_String(deserializer);
+return StatusParseError_InvalidValue(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected Stock sse_decode_stock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_material = sse_decode_String(deserializer);
+var var_tubeWidth = sse_decode_f_32(deserializer);
+var var_length = sse_decode_f_32(deserializer);
+return Stock(material: var_material, tubeWidth: var_tubeWidth, length: var_length); }
+
+@protected TransportKind sse_decode_transport_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TransportKind.values[inner]; }
+
+@protected TravelLimits sse_decode_travel_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_xMin = sse_decode_f_32(deserializer);
+var var_xMax = sse_decode_f_32(deserializer);
+var var_yMin = sse_decode_f_32(deserializer);
+var var_yMax = sse_decode_f_32(deserializer);
+return TravelLimits(xMin: var_xMin, xMax: var_xMax, yMin: var_yMin, yMax: var_yMax); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected Word sse_decode_word(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_letter = sse_decode_Char(deserializer);
+var var_value = sse_decode_f_32(deserializer);
+return Word(letter: var_letter, value: var_value); }
+
+@protected WorkCoordinateSystem sse_decode_work_coordinate_system(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return WorkCoordinateSystem.values[inner]; }
+
+@protected WorkOffset sse_decode_work_offset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_wcs = sse_decode_work_coordinate_system(deserializer);
+var var_x = sse_decode_f_32(deserializer);
+var var_y = sse_decode_f_32(deserializer);
+var var_z = sse_decode_f_32(deserializer);
+return WorkOffset(wcs: var_wcs, x: var_x, y: var_y, z: var_z); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(BoundsViolation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BoundsViolationImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(Cut self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(CutMethod self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutMethodImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(CutterSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutterSettingsImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(Feature self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as FeatureImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(Gcode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GcodeImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(GrblSimulator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GrblSimulatorImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(JobFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as JobFileImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(JobPlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as JobPlanImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(LaserCalibration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LaserCalibrationImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(MachineConnection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineConnectionImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(MachineSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineSettingsImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineStatusImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ModalState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ModalStateImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Pos2DImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(Profile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProfileImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(ProfileSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProfileSegmentImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProgramExtentsImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(SegmentKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SegmentKindImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ToolpathSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ToolpathSegmentImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(Viewport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ViewportImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(BoundsViolation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BoundsViolationImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(Cut self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(CutterSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutterSettingsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(Feature self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as FeatureImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(Gcode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GcodeImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(GrblSimulator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GrblSimulatorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(JobFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as JobFileImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(JobPlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as JobPlanImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(LaserCalibration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LaserCalibrationImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(MachineConnection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineConnectionImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(MachineSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineSettingsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineStatusImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ModalState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ModalStateImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Pos2DImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(Profile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProfileImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProgramExtentsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ToolpathSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ToolpathSegmentImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(Viewport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ViewportImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(BoundsViolation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BoundsViolationImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(Cut self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(CutterSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutterSettingsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(Feature self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as FeatureImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(Gcode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GcodeImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(GrblSimulator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GrblSimulatorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(JobFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as JobFileImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(JobPlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as JobPlanImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(LaserCalibration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LaserCalibrationImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(MachineConnection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineConnectionImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(MachineSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineSettingsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineStatusImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ModalState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ModalStateImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Pos2DImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(Profile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProfileImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(ProfileSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProfileSegmentImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProgramExtentsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ToolpathSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ToolpathSegmentImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(Viewport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ViewportImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Char(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self, serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(BoundsViolation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BoundsViolationImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(Cut self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(CutMethod self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutMethodImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(CutterSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CutterSettingsImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(Feature self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as FeatureImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(Gcode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GcodeImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(GrblSimulator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GrblSimulatorImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(JobFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as JobFileImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(JobPlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as JobPlanImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(LaserCalibration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LaserCalibrationImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(MachineConnection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineConnectionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(MachineSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineSettingsImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MachineStatusImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ModalState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ModalStateImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Pos2DImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(Profile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProfileImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(ProfileSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProfileSegmentImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProgramExtentsImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(SegmentKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SegmentKindImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ToolpathSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ToolpathSegmentImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(Viewport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ViewportImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_job_progress_Sse(RustStreamSink<JobProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_job_progress,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_machine_position_Sse(RustStreamSink<MachinePosition> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_machine_position,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_accessories(Accessories self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.spindleCw, serializer);
+sse_encode_bool(self.spindleCcw, serializer);
+sse_encode_bool(self.floodCoolant, serializer);
+sse_encode_bool(self.mistCoolant, serializer);
+sse_encode_bool(self.toolChangePending, serializer);
+ }
+
+@protected void sse_encode_axis(Axis self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_bounds_report(BoundsReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(self.extents, serializer);
+sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(self.violations, serializer);
+ }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(self, serializer); }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(self, serializer); }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(self, serializer); }
+
+@protected void sse_encode_box_autoadd_accessories(Accessories self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_accessories(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bounds_report(BoundsReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bounds_report(self, serializer); }
+
+@protected void sse_encode_box_autoadd_buffer_state(BufferState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_buffer_state(self, serializer); }
+
+@protected void sse_encode_box_autoadd_consumable_ratings(ConsumableRatings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_consumable_ratings(self, serializer); }
+
+@protected void sse_encode_box_autoadd_coordinate_offsets(CoordinateOffsets self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_coordinate_offsets(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cut_parameters(CutParameters self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cut_parameters(self, serializer); }
+
+@protected void sse_encode_box_autoadd_export_header(ExportHeader self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_export_header(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_fixture(Fixture self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_fixture(self, serializer); }
+
+@protected void sse_encode_box_autoadd_job_report(JobReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_job_report(self, serializer); }
+
+@protected void sse_encode_box_autoadd_laser_output(LaserOutput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_laser_output(self, serializer); }
+
+@protected void sse_encode_box_autoadd_lint_options(LintOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lint_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_machine_capabilities(MachineCapabilities self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_machine_capabilities(self, serializer); }
+
+@protected void sse_encode_box_autoadd_machine_reference(MachineReference self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_machine_reference(self, serializer); }
+
+@protected void sse_encode_box_autoadd_machine_setting(MachineSetting self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_machine_setting(self, serializer); }
+
+@protected void sse_encode_box_autoadd_motion_limits(MotionLimits self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_motion_limits(self, serializer); }
+
+@protected void sse_encode_box_autoadd_overrides(Overrides self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_overrides(self, serializer); }
+
+@protected void sse_encode_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d((Pos2D,Pos2D) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(self, serializer); }
+
+@protected void sse_encode_box_autoadd_render_options(RenderOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_render_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_setting_description(SettingDescription self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_setting_description(self, serializer); }
+
+@protected void sse_encode_box_autoadd_simulator_config(SimulatorConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_simulator_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stock(Stock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stock(self, serializer); }
+
+@protected void sse_encode_box_autoadd_travel_limits(TravelLimits self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_travel_limits(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_work_coordinate_system(WorkCoordinateSystem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_work_coordinate_system(self, serializer); }
+
+@protected void sse_encode_box_autoadd_work_offset(WorkOffset self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_work_offset(self, serializer); }
+
+@protected void sse_encode_buffer_state(BufferState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.plannerBlocks, serializer);
+sse_encode_u_32(self.rxBytes, serializer);
+ }
+
+@protected void sse_encode_connection_settings(ConnectionSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_transport_kind(self.transport, serializer);
+sse_encode_String(self.serialPort, serializer);
+sse_encode_u_32(self.baudrate, serializer);
+sse_encode_serial_flow_control(self.flowControl, serializer);
+sse_encode_control_line_on_open(self.dtrOnOpen, serializer);
+sse_encode_control_line_on_open(self.rtsOnOpen, serializer);
+sse_encode_u_32(self.readTimeoutMs, serializer);
+sse_encode_line_ending(self.lineEnding, serializer);
+sse_encode_bool(self.lineNumbers, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.networkPort, serializer);
+ }
+
+@protected void sse_encode_connection_state(ConnectionState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_consumable_ratings(ConsumableRatings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pierces, serializer);
+sse_encode_f_32(self.arcTime, serializer);
+ }
+
+@protected void sse_encode_contour(Contour self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(self.segments, serializer);
+sse_encode_bool(self.closed, serializer);
+ }
+
+@protected void sse_encode_control_line_on_open(ControlLineOnOpen self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_coordinate_offsets(CoordinateOffsets self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_work_offset(self.workOffsets, serializer);
+sse_encode_opt_list_prim_f_32_strict(self.g92, serializer);
+sse_encode_opt_box_autoadd_work_coordinate_system(self.active, serializer);
+ }
+
+@protected void sse_encode_cut_parameters(CutParameters self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.feedrate, serializer);
+sse_encode_f_32(self.pierceDelay, serializer);
+ }
+
+@protected void sse_encode_cut_report(CutReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.position, serializer);
+sse_encode_f_32(self.angle, serializer);
+sse_encode_f_32(self.feedrate, serializer);
+sse_encode_f_32(self.pierceDelay, serializer);
+sse_encode_f_32(self.pierceDelay2, serializer);
+sse_encode_f_32(self.estimatedTime, serializer);
+ }
+
+@protected void sse_encode_dist_unit(DistUnit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_export_header(ExportHeader self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.jobName, serializer);
+sse_encode_opt_box_autoadd_stock(self.stock, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_feature_report(FeatureReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.contours, serializer);
+sse_encode_f_32(self.feedrate, serializer);
+sse_encode_f_32(self.pierceDelay, serializer);
+sse_encode_f_32(self.estimatedTime, serializer);
+ }
+
+@protected void sse_encode_fixture(Fixture self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_work_coordinate_system(self.wcs, serializer);
+sse_encode_f_32(self.x, serializer);
+sse_encode_f_32(self.y, serializer);
+ }
+
+@protected void sse_encode_homing_axes(HomingAxes self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_job_estimate(JobEstimate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.totalTime, serializer);
+sse_encode_f_32(self.cutTime, serializer);
+sse_encode_f_32(self.rapidTime, serializer);
+sse_encode_f_32(self.dwellTime, serializer);
+sse_encode_f_32(self.cutLength, serializer);
+sse_encode_f_32(self.rapidLength, serializer);
+sse_encode_u_32(self.pierceCount, serializer);
+sse_encode_f_32(self.arcTime, serializer);
+sse_encode_f_32(self.consumableWear, serializer);
+ }
+
+@protected void sse_encode_job_progress(JobProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_job_state(self.state, serializer);
+sse_encode_u_32(self.currentLine, serializer);
+sse_encode_u_32(self.linesAcknowledged, serializer);
+sse_encode_u_32(self.totalLines, serializer);
+sse_encode_f_32(self.percent, serializer);
+sse_encode_f_32(self.elapsedSeconds, serializer);
+sse_encode_opt_box_autoadd_f_32(self.remainingSeconds, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_job_report(JobReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.material, serializer);
+sse_encode_f_32(self.tubeWidth, serializer);
+sse_encode_f_32(self.stockLength, serializer);
+sse_encode_list_cut_report(self.cuts, serializer);
+sse_encode_list_feature_report(self.features, serializer);
+sse_encode_list_part(self.parts, serializer);
+sse_encode_f_32(self.usedLength, serializer);
+sse_encode_f_32(self.wasteLength, serializer);
+sse_encode_job_estimate(self.estimate, serializer);
+sse_encode_String(self.toolpathSvg, serializer);
+ }
+
+@protected void sse_encode_job_state(JobState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_jog_step(JogStep self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_laser_output(LaserOutput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case LaserOutput_Flood(): sse_encode_i_32(0, serializer); case LaserOutput_Mist(): sse_encode_i_32(1, serializer); case LaserOutput_Custom(onCommand: final onCommand,offCommand: final offCommand): sse_encode_i_32(2, serializer); sse_encode_String(onCommand, serializer);
+sse_encode_String(offCommand, serializer);
+  } }
+
+@protected void sse_encode_line_ending(LineEnding self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_lint_diagnostic(LintDiagnostic self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_severity(self.severity, serializer);
+sse_encode_lint_rule(self.rule, serializer);
+sse_encode_u_32(self.line, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_lint_options(LintOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.maxTorchDwell, serializer);
+ }
+
+@protected void sse_encode_lint_rule(LintRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(List<BoundsViolation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(item, serializer); } }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(List<Cut> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(item, serializer); } }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(List<Feature> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(item, serializer); } }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(List<Pos2D> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(item, serializer); } }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(List<ProfileSegment> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(item, serializer); } }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(List<ToolpathSegment> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(item, serializer); } }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_contour(List<Contour> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_contour(item, serializer); } }
+
+@protected void sse_encode_list_cut_report(List<CutReport> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cut_report(item, serializer); } }
+
+@protected void sse_encode_list_feature_report(List<FeatureReport> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_feature_report(item, serializer); } }
+
+@protected void sse_encode_list_fixture(List<Fixture> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_fixture(item, serializer); } }
+
+@protected void sse_encode_list_lint_diagnostic(List<LintDiagnostic> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lint_diagnostic(item, serializer); } }
+
+@protected void sse_encode_list_machine_setting(List<MachineSetting> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_machine_setting(item, serializer); } }
+
+@protected void sse_encode_list_part(List<Part> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_part(item, serializer); } }
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self); }
+
+@protected void sse_encode_list_prim_u_32_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self is Uint32List ? self : Uint32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_setting_group(List<SettingGroup> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_setting_group(item, serializer); } }
+
+@protected void sse_encode_list_word(List<Word> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_word(item, serializer); } }
+
+@protected void sse_encode_list_work_offset(List<WorkOffset> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_work_offset(item, serializer); } }
+
+@protected void sse_encode_machine_capabilities(MachineCapabilities self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.version, serializer);
+sse_encode_opt_String(self.firmware, serializer);
+sse_encode_String(self.options, serializer);
+sse_encode_opt_box_autoadd_u_32(self.plannerBlocks, serializer);
+sse_encode_opt_box_autoadd_u_32(self.rxBufferSize, serializer);
+sse_encode_String(self.axes, serializer);
+sse_encode_list_String(self.extendedOptions, serializer);
+ }
+
+@protected void sse_encode_machine_position(MachinePosition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.x, serializer);
+sse_encode_f_32(self.y, serializer);
+ }
+
+@protected void sse_encode_machine_reference(MachineReference self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_bool(self.useLaser, serializer);
+sse_encode_f_32(self.laserOffsetX, serializer);
+sse_encode_f_32(self.laserOffsetY, serializer);
+sse_encode_bool(self.homeAfterCut, serializer);
+ }
+
+@protected void sse_encode_machine_setting(MachineSetting self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.value, serializer);
+sse_encode_opt_box_autoadd_setting_description(self.description, serializer);
+ }
+
+@protected void sse_encode_machine_state(MachineState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_motion_limits(MotionLimits self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.xMaxRate, serializer);
+sse_encode_f_32(self.yMaxRate, serializer);
+sse_encode_f_32(self.xAcceleration, serializer);
+sse_encode_f_32(self.yAcceleration, serializer);
+ }
+
+@protected void sse_encode_motion_mode(MotionMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_accessories(Accessories? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_accessories(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_buffer_state(BufferState? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_buffer_state(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_export_header(ExportHeader? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_export_header(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_fixture(Fixture? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_fixture(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_machine_setting(MachineSetting? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_machine_setting(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_overrides(Overrides? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_overrides(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d((Pos2D,Pos2D)? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_setting_description(SettingDescription? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_setting_description(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_stock(Stock? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_stock(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_travel_limits(TravelLimits? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_travel_limits(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_work_coordinate_system(WorkCoordinateSystem? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_work_coordinate_system(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_work_offset(WorkOffset? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_work_offset(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_f_32_strict(Float32List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_f_32_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_overrides(Overrides self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.feed, serializer);
+sse_encode_u_32(self.rapid, serializer);
+sse_encode_u_32(self.spindle, serializer);
+ }
+
+@protected void sse_encode_part(Part self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.start, serializer);
+sse_encode_f_32(self.length, serializer);
+sse_encode_f_32(self.startAngle, serializer);
+sse_encode_f_32(self.endAngle, serializer);
+ }
+
+@protected void sse_encode_positioning_mode(PositioningMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_post_processor(PostProcessor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d((Pos2D,Pos2D) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(self.$1, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_f_32_f_32((double,double) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.$1, serializer);
+sse_encode_f_32(self.$2, serializer);
+ }
+
+@protected void sse_encode_render_options(RenderOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_f_32(self.margin, serializer);
+sse_encode_opt_box_autoadd_f_32(self.tubeWidth, serializer);
+sse_encode_bool(self.showRapids, serializer);
+sse_encode_bool(self.showLabels, serializer);
+ }
+
+@protected void sse_encode_rule(Rule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_serial_flow_control(SerialFlowControl self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_setting_description(SettingDescription self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_setting_type(self.settingType, serializer);
+sse_encode_opt_box_autoadd_u_32(self.group, serializer);
+sse_encode_opt_box_autoadd_f_32(self.min, serializer);
+sse_encode_opt_box_autoadd_f_32(self.max, serializer);
+ }
+
+@protected void sse_encode_setting_group(SettingGroup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_u_32(self.parent, serializer);
+sse_encode_String(self.name, serializer);
+ }
+
+@protected void sse_encode_setting_type(SettingType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_severity(Severity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_simulator_config(SimulatorConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.plannerBlocks, serializer);
+sse_encode_usize(self.rxBufferSize, serializer);
+sse_encode_f_32(self.rapidRate, serializer);
+sse_encode_f_32(self.defaultFeedrate, serializer);
+sse_encode_f_32(self.timeScale, serializer);
+sse_encode_bool(self.homingRequired, serializer);
+ }
+
+@protected void sse_encode_status_parse_error(StatusParseError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case StatusParseError_InvalidSyntax(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case StatusParseError_InvalidValue(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_stock(Stock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.material, serializer);
+sse_encode_f_32(self.tubeWidth, serializer);
+sse_encode_f_32(self.length, serializer);
+ }
+
+@protected void sse_encode_transport_kind(TransportKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_travel_limits(TravelLimits self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.xMin, serializer);
+sse_encode_f_32(self.xMax, serializer);
+sse_encode_f_32(self.yMin, serializer);
+sse_encode_f_32(self.yMax, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_word(Word self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Char(self.letter, serializer);
+sse_encode_f_32(self.value, serializer);
+ }
+
+@protected void sse_encode_work_coordinate_system(WorkCoordinateSystem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_work_offset(WorkOffset self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_work_coordinate_system(self.wcs, serializer);
+sse_encode_f_32(self.x, serializer);
+sse_encode_f_32(self.y, serializer);
+sse_encode_f_32(self.z, serializer);
+ }
+                }
+                
+
+            @sealed class BoundsViolationImpl extends RustOpaque implements BoundsViolation {
+                // Not to be used by end users
+                BoundsViolationImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                BoundsViolationImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_BoundsViolation,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_BoundsViolation,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_BoundsViolationPtr,
+                );
+
+                 int? get cut=>RustLib.instance.api.crateApiBoundsBoundsViolationAutoAccessorGetCut(that: this, );
+
+
+ int get line=>RustLib.instance.api.crateApiBoundsBoundsViolationAutoAccessorGetLine(that: this, );
+
+
+ String get message=>RustLib.instance.api.crateApiBoundsBoundsViolationAutoAccessorGetMessage(that: this, );
+
+
+ Pos2D get position=>RustLib.instance.api.crateApiBoundsBoundsViolationAutoAccessorGetPosition(that: this, );
+
+
+  set cut(int? cut)=>RustLib.instance.api.crateApiBoundsBoundsViolationAutoAccessorSetCut(that: this, cut: cut);
+
+
+  set line(int line)=>RustLib.instance.api.crateApiBoundsBoundsViolationAutoAccessorSetLine(that: this, line: line);
+
+
+  set message(String message)=>RustLib.instance.api.crateApiBoundsBoundsViolationAutoAccessorSetMessage(that: this, message: message);
+
+
+  set position(Pos2D position)=>RustLib.instance.api.crateApiBoundsBoundsViolationAutoAccessorSetPosition(that: this, position: position);
+
+
+            }
+            @sealed class CutImpl extends RustOpaque implements Cut {
+                // Not to be used by end users
+                CutImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CutImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Cut,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Cut,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CutPtr,
+                );
+
+                 double get cutAngle=>RustLib.instance.api.crateApiCutCutAutoAccessorGetCutAngle(that: this, );
+
+
+ double get cutFeedrate=>RustLib.instance.api.crateApiCutCutAutoAccessorGetCutFeedrate(that: this, );
+
+
+ Pos2D get endPosition=>RustLib.instance.api.crateApiCutCutAutoAccessorGetEndPosition(that: this, );
+
+
+ double get pierceDelay=>RustLib.instance.api.crateApiCutCutAutoAccessorGetPierceDelay(that: this, );
+
+
+ double get pierceDelay2=>RustLib.instance.api.crateApiCutCutAutoAccessorGetPierceDelay2(that: this, );
+
+
+ Pos2D get startPosition=>RustLib.instance.api.crateApiCutCutAutoAccessorGetStartPosition(that: this, );
+
+
+ double get tubeWidth=>RustLib.instance.api.crateApiCutCutAutoAccessorGetTubeWidth(that: this, );
+
+
+  set cutAngle(double cutAngle)=>RustLib.instance.api.crateApiCutCutAutoAccessorSetCutAngle(that: this, cutAngle: cutAngle);
+
+
+  set cutFeedrate(double cutFeedrate)=>RustLib.instance.api.crateApiCutCutAutoAccessorSetCutFeedrate(that: this, cutFeedrate: cutFeedrate);
+
+
+  set endPosition(Pos2D endPosition)=>RustLib.instance.api.crateApiCutCutAutoAccessorSetEndPosition(that: this, endPosition: endPosition);
+
+
+  set pierceDelay(double pierceDelay)=>RustLib.instance.api.crateApiCutCutAutoAccessorSetPierceDelay(that: this, pierceDelay: pierceDelay);
+
+
+  set pierceDelay2(double pierceDelay2)=>RustLib.instance.api.crateApiCutCutAutoAccessorSetPierceDelay2(that: this, pierceDelay2: pierceDelay2);
+
+
+  set startPosition(Pos2D startPosition)=>RustLib.instance.api.crateApiCutCutAutoAccessorSetStartPosition(that: this, startPosition: startPosition);
+
+
+  set tubeWidth(double tubeWidth)=>RustLib.instance.api.crateApiCutCutAutoAccessorSetTubeWidth(that: this, tubeWidth: tubeWidth);
+
+
+ (double,double)  getEndPos()=>RustLib.instance.api.crateApiCutCutGetEndPos(that: this, );
+
+
+ void  setCutAngle({required double newAngle })=>RustLib.instance.api.crateApiCutCutSetCutAngle(that: this, newAngle: newAngle);
+
+
+ void  setCutFeedrate({required double newFeedrate })=>RustLib.instance.api.crateApiCutCutSetCutFeedrate(that: this, newFeedrate: newFeedrate);
+
+
+ void  setPierceDelay({required double newDelay })=>RustLib.instance.api.crateApiCutCutSetPierceDelay(that: this, newDelay: newDelay);
+
+
+ void  setPierceDelay2({required double newDelay })=>RustLib.instance.api.crateApiCutCutSetPierceDelay2(that: this, newDelay: newDelay);
+
+
+ void  setTubeWidth({required double newWidth })=>RustLib.instance.api.crateApiCutCutSetTubeWidth(that: this, newWidth: newWidth);
+
+
+            }
+            @sealed class CutMethodImpl extends RustOpaque implements CutMethod {
+                // Not to be used by end users
+                CutMethodImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CutMethodImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CutMethod,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CutMethod,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CutMethodPtr,
+                );
+
+                
+            }
+            @sealed class CutterSettingsImpl extends RustOpaque implements CutterSettings {
+                // Not to be used by end users
+                CutterSettingsImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CutterSettingsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CutterSettings,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CutterSettings,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CutterSettingsPtr,
+                );
+
+                 bool get clearExistingGcode=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetClearExistingGcode(that: this, );
+
+
+ ConnectionSettings get connection=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetConnection(that: this, );
+
+
+ ConsumableRatings get consumableRatings=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetConsumableRatings(that: this, );
+
+
+ CutMethod get cutMethod=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetCutMethod(that: this, );
+
+
+ List<Fixture> get fixtures=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetFixtures(that: this, );
+
+
+ bool get homeAfterCut=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetHomeAfterCut(that: this, );
+
+
+ double get jogSpeed=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetJogSpeed(that: this, );
+
+
+ double get laserOffsetX=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetX(that: this, );
+
+
+ double get laserOffsetY=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetLaserOffsetY(that: this, );
+
+
+ LaserOutput get laserOutput=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetLaserOutput(that: this, );
+
+
+ MotionLimits get motionLimits=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetMotionLimits(that: this, );
+
+
+ bool get useLaser=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorGetUseLaser(that: this, );
+
+
+  set clearExistingGcode(bool clearExistingGcode)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetClearExistingGcode(that: this, clearExistingGcode: clearExistingGcode);
+
+
+  set connection(ConnectionSettings connection)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetConnection(that: this, connection: connection);
+
+
+  set consumableRatings(ConsumableRatings consumableRatings)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetConsumableRatings(that: this, consumableRatings: consumableRatings);
+
+
+  set cutMethod(CutMethod cutMethod)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetCutMethod(that: this, cutMethod: cutMethod);
+
+
+  set fixtures(List<Fixture> fixtures)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetFixtures(that: this, fixtures: fixtures);
+
+
+  set homeAfterCut(bool homeAfterCut)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetHomeAfterCut(that: this, homeAfterCut: homeAfterCut);
+
+
+  set jogSpeed(double jogSpeed)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetJogSpeed(that: this, jogSpeed: jogSpeed);
+
+
+  set laserOffsetX(double laserOffsetX)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetX(that: this, laserOffsetX: laserOffsetX);
+
+
+  set laserOffsetY(double laserOffsetY)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetLaserOffsetY(that: this, laserOffsetY: laserOffsetY);
+
+
+  set laserOutput(LaserOutput laserOutput)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetLaserOutput(that: this, laserOutput: laserOutput);
+
+
+  set motionLimits(MotionLimits motionLimits)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetMotionLimits(that: this, motionLimits: motionLimits);
+
+
+  set useLaser(bool useLaser)=>RustLib.instance.api.crateApiSettingsCutterSettingsAutoAccessorSetUseLaser(that: this, useLaser: useLaser);
+
+
+ Fixture?  getFixture({required String name })=>RustLib.instance.api.crateApiSettingsCutterSettingsGetFixture(that: this, name: name);
+
+
+ void  removeFixture({required String name })=>RustLib.instance.api.crateApiSettingsCutterSettingsRemoveFixture(that: this, name: name);
+
+
+ void  save()=>RustLib.instance.api.crateApiSettingsCutterSettingsSave(that: this, );
+
+
+ void  saveFixture({required Fixture fixture })=>RustLib.instance.api.crateApiSettingsCutterSettingsSaveFixture(that: this, fixture: fixture);
+
+
+ void  setBaudrate({required int newBaudrate })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetBaudrate(that: this, newBaudrate: newBaudrate);
+
+
+ void  setConsumableRatings({required ConsumableRatings ratings })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetConsumableRatings(that: this, ratings: ratings);
+
+
+ void  setDtrOnOpen({required ControlLineOnOpen behavior })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetDtrOnOpen(that: this, behavior: behavior);
+
+
+ void  setFlowControl({required SerialFlowControl flowControl })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetFlowControl(that: this, flowControl: flowControl);
+
+
+ void  setLaserOutput({required LaserOutput output })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetLaserOutput(that: this, output: output);
+
+
+ void  setLineEnding({required LineEnding lineEnding })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetLineEnding(that: this, lineEnding: lineEnding);
+
+
+ void  setLineNumbers({required bool enabled })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetLineNumbers(that: this, enabled: enabled);
+
+
+ void  setMotionLimits({required MotionLimits limits })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetMotionLimits(that: this, limits: limits);
+
+
+ void  setNetworkAddress({required String host , required int port })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetNetworkAddress(that: this, host: host, port: port);
+
+
+ void  setReadTimeoutMs({required int timeoutMs })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetReadTimeoutMs(that: this, timeoutMs: timeoutMs);
+
+
+ void  setRtsOnOpen({required ControlLineOnOpen behavior })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetRtsOnOpen(that: this, behavior: behavior);
+
+
+ void  setSerialPort({required String newPort })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetSerialPort(that: this, newPort: newPort);
+
+
+ void  setTransport({required TransportKind transport })=>RustLib.instance.api.crateApiSettingsCutterSettingsSetTransport(that: this, transport: transport);
+
+
+            }
+            @sealed class FeatureImpl extends RustOpaque implements Feature {
+                // Not to be used by end users
+                FeatureImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                FeatureImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Feature,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Feature,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_FeaturePtr,
+                );
+
+                 CutParameters get parameters=>RustLib.instance.api.crateApiJobPlanFeatureAutoAccessorGetParameters(that: this, );
+
+
+ Profile get profile=>RustLib.instance.api.crateApiJobPlanFeatureAutoAccessorGetProfile(that: this, );
+
+
+  set parameters(CutParameters parameters)=>RustLib.instance.api.crateApiJobPlanFeatureAutoAccessorSetParameters(that: this, parameters: parameters);
+
+
+  set profile(Profile profile)=>RustLib.instance.api.crateApiJobPlanFeatureAutoAccessorSetProfile(that: this, profile: profile);
+
+
+            }
+            @sealed class GcodeImpl extends RustOpaque implements Gcode {
+                // Not to be used by end users
+                GcodeImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                GcodeImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Gcode,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Gcode,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_GcodePtr,
+                );
+
+                 Future<void>  addCommand({required String gCommand })=>RustLib.instance.api.crateApiGcodeGcodeAddCommand(that: this, gCommand: gCommand);
+
+
+ Future<void>  addCommandComment({required String gCommand , required String gComment })=>RustLib.instance.api.crateApiGcodeGcodeAddCommandComment(that: this, gCommand: gCommand, gComment: gComment);
+
+
+ void  addCut({required Cut tubeCut , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiGcodeGcodeAddCut(that: this, tubeCut: tubeCut, cutterSettings: cutterSettings);
+
+
+ void  addProfile({required Profile profile , required CutParameters parameters })=>RustLib.instance.api.crateApiGcodeGcodeAddProfile(that: this, profile: profile, parameters: parameters);
+
+
+ Future<void>  arcXy({required Pos2D newPos , required Pos2D centerOffset , required bool clockwise , required double feedrate })=>RustLib.instance.api.crateApiGcodeGcodeArcXy(that: this, newPos: newPos, centerOffset: centerOffset, clockwise: clockwise, feedrate: feedrate);
+
+
+ String get gcodeString=>RustLib.instance.api.crateApiGcodeGcodeAutoAccessorGetGcodeString(that: this, );
+
+
+  set gcodeString(String gcodeString)=>RustLib.instance.api.crateApiGcodeGcodeAutoAccessorSetGcodeString(that: this, gcodeString: gcodeString);
+
+
+ BoundsReport  checkBounds({required TravelLimits limits , required Pos2D workOffset })=>RustLib.instance.api.crateApiGcodeGcodeCheckBounds(that: this, limits: limits, workOffset: workOffset);
+
+
+ Future<void>  checkCapabilities({required MachineCapabilities capabilities })=>RustLib.instance.api.crateApiGcodeGcodeCheckCapabilities(that: this, capabilities: capabilities);
+
+
+ Future<void>  dwell({required double dwellTime })=>RustLib.instance.api.crateApiGcodeGcodeDwell(that: this, dwellTime: dwellTime);
+
+
+ JobEstimate  estimate({required MotionLimits limits , required ConsumableRatings ratings })=>RustLib.instance.api.crateApiGcodeGcodeEstimate(that: this, limits: limits, ratings: ratings);
+
+
+ String  export_({required String path , required PostProcessor postProcessor , ExportHeader? header , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiGcodeGcodeExport(that: this, path: path, postProcessor: postProcessor, header: header, cutterSettings: cutterSettings);
+
+
+ int  getCutCount()=>RustLib.instance.api.crateApiGcodeGcodeGetCutCount(that: this, );
+
+
+ int?  getCutStartLine({required int cutIndex })=>RustLib.instance.api.crateApiGcodeGcodeGetCutStartLine(that: this, cutIndex: cutIndex);
+
+
+ ProgramExtents?  getExtents()=>RustLib.instance.api.crateApiGcodeGcodeGetExtents(that: this, );
+
+
+ String  getGcodeString()=>RustLib.instance.api.crateApiGcodeGcodeGetGcodeString(that: this, );
+
+
+ List<ToolpathSegment>  getToolpath()=>RustLib.instance.api.crateApiGcodeGcodeGetToolpath(that: this, );
+
+
+ Future<void>  home2D({required double feedrate })=>RustLib.instance.api.crateApiGcodeGcodeHome2D(that: this, feedrate: feedrate);
+
+
+ Future<int>  lineCount()=>RustLib.instance.api.crateApiGcodeGcodeLineCount(that: this, );
+
+
+ List<LintDiagnostic>  lint({required LintOptions options })=>RustLib.instance.api.crateApiGcodeGcodeLint(that: this, options: options);
+
+
+ Future<void>  moveXy({required Pos2D newPos , required double feedrate })=>RustLib.instance.api.crateApiGcodeGcodeMoveXy(that: this, newPos: newPos, feedrate: feedrate);
+
+
+ Uint8List  renderPng({required RenderOptions options })=>RustLib.instance.api.crateApiGcodeGcodeRenderPng(that: this, options: options);
+
+
+ String  renderSvg({required RenderOptions options })=>RustLib.instance.api.crateApiGcodeGcodeRenderSvg(that: this, options: options);
+
+
+ Future<void>  setPlasmaEnabled({required bool enabled })=>RustLib.instance.api.crateApiGcodeGcodeSetPlasmaEnabled(that: this, enabled: enabled);
+
+
+ Future<void>  setPositioningMode({required PositioningMode positioningMode })=>RustLib.instance.api.crateApiGcodeGcodeSetPositioningMode(that: this, positioningMode: positioningMode);
+
+
+ Future<void>  setWorkCoordinateSystem({required WorkCoordinateSystem wcs })=>RustLib.instance.api.crateApiGcodeGcodeSetWorkCoordinateSystem(that: this, wcs: wcs);
+
+
+ Future<void>  setZero()=>RustLib.instance.api.crateApiGcodeGcodeSetZero(that: this, );
+
+
+ void  useCapabilities({required MachineCapabilities capabilities })=>RustLib.instance.api.crateApiGcodeGcodeUseCapabilities(that: this, capabilities: capabilities);
+
+
+ Future<void>  writeToFile({required String filename })=>RustLib.instance.api.crateApiGcodeGcodeWriteToFile(that: this, filename: filename);
+
+
+            }
+            @sealed class GrblSimulatorImpl extends RustOpaque implements GrblSimulator {
+                // Not to be used by end users
+                GrblSimulatorImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                GrblSimulatorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_GrblSimulator,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_GrblSimulator,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_GrblSimulatorPtr,
+                );
+
+                 Future<bool>  inAlarm()=>RustLib.instance.api.crateApiSimulatorGrblSimulatorInAlarm(that: this, );
+
+
+ Future<void>  injectAlarm({required int code })=>RustLib.instance.api.crateApiSimulatorGrblSimulatorInjectAlarm(that: this, code: code);
+
+
+ Future<void>  injectError({required int code })=>RustLib.instance.api.crateApiSimulatorGrblSimulatorInjectError(that: this, code: code);
+
+
+ Future<Pos2D>  position()=>RustLib.instance.api.crateApiSimulatorGrblSimulatorPosition(that: this, );
+
+
+ Future<void>  receive({required List<int> bytes })=>RustLib.instance.api.crateApiSimulatorGrblSimulatorReceive(that: this, bytes: bytes);
+
+
+ Future<void>  receivedLines()=>RustLib.instance.api.crateApiSimulatorGrblSimulatorReceivedLines(that: this, );
+
+
+ Future<Uint8List>  takeOutput()=>RustLib.instance.api.crateApiSimulatorGrblSimulatorTakeOutput(that: this, );
+
+
+ Future<bool>  torchOn()=>RustLib.instance.api.crateApiSimulatorGrblSimulatorTorchOn(that: this, );
+
+
+ Future<void>  update()=>RustLib.instance.api.crateApiSimulatorGrblSimulatorUpdate(that: this, );
+
+
+            }
+            @sealed class JobFileImpl extends RustOpaque implements JobFile {
+                // Not to be used by end users
+                JobFileImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                JobFileImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_JobFile,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_JobFile,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_JobFilePtr,
+                );
+
+                 MachineReference get machine=>RustLib.instance.api.crateApiJobFileJobFileAutoAccessorGetMachine(that: this, );
+
+
+ JobPlan get plan=>RustLib.instance.api.crateApiJobFileJobFileAutoAccessorGetPlan(that: this, );
+
+
+ int get version=>RustLib.instance.api.crateApiJobFileJobFileAutoAccessorGetVersion(that: this, );
+
+
+  set machine(MachineReference machine)=>RustLib.instance.api.crateApiJobFileJobFileAutoAccessorSetMachine(that: this, machine: machine);
+
+
+  set plan(JobPlan plan)=>RustLib.instance.api.crateApiJobFileJobFileAutoAccessorSetPlan(that: this, plan: plan);
+
+
+  set version(int version)=>RustLib.instance.api.crateApiJobFileJobFileAutoAccessorSetVersion(that: this, version: version);
+
+
+ void  save({required String path })=>RustLib.instance.api.crateApiJobFileJobFileSave(that: this, path: path);
+
+
+ Gcode  toGcode()=>RustLib.instance.api.crateApiJobFileJobFileToGcode(that: this, );
+
+
+ String  toJson()=>RustLib.instance.api.crateApiJobFileJobFileToJson(that: this, );
+
+
+            }
+            @sealed class JobPlanImpl extends RustOpaque implements JobPlan {
+                // Not to be used by end users
+                JobPlanImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                JobPlanImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_JobPlan,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_JobPlan,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_JobPlanPtr,
+                );
+
+                 void  addCut({required Cut cut })=>RustLib.instance.api.crateApiJobPlanJobPlanAddCut(that: this, cut: cut);
+
+
+ void  addFeature({required Profile profile , required CutParameters parameters })=>RustLib.instance.api.crateApiJobPlanJobPlanAddFeature(that: this, profile: profile, parameters: parameters);
+
+
+ List<Cut> get cuts=>RustLib.instance.api.crateApiJobPlanJobPlanAutoAccessorGetCuts(that: this, );
+
+
+ List<Feature> get features=>RustLib.instance.api.crateApiJobPlanJobPlanAutoAccessorGetFeatures(that: this, );
+
+
+ String get name=>RustLib.instance.api.crateApiJobPlanJobPlanAutoAccessorGetName(that: this, );
+
+
+ Stock get stock=>RustLib.instance.api.crateApiJobPlanJobPlanAutoAccessorGetStock(that: this, );
+
+
+  set cuts(List<Cut> cuts)=>RustLib.instance.api.crateApiJobPlanJobPlanAutoAccessorSetCuts(that: this, cuts: cuts);
+
+
+  set features(List<Feature> features)=>RustLib.instance.api.crateApiJobPlanJobPlanAutoAccessorSetFeatures(that: this, features: features);
+
+
+  set name(String name)=>RustLib.instance.api.crateApiJobPlanJobPlanAutoAccessorSetName(that: this, name: name);
+
+
+  set stock(Stock stock)=>RustLib.instance.api.crateApiJobPlanJobPlanAutoAccessorSetStock(that: this, stock: stock);
+
+
+ String  export_({required String path , required PostProcessor postProcessor , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiJobPlanJobPlanExport(that: this, path: path, postProcessor: postProcessor, cutterSettings: cutterSettings);
+
+
+ List<Part>  parts()=>RustLib.instance.api.crateApiJobPlanJobPlanParts(that: this, );
+
+
+ void  removeCut({required int cutIndex })=>RustLib.instance.api.crateApiJobPlanJobPlanRemoveCut(that: this, cutIndex: cutIndex);
+
+
+ void  removeFeature({required int featureIndex })=>RustLib.instance.api.crateApiJobPlanJobPlanRemoveFeature(that: this, featureIndex: featureIndex);
+
+
+ JobReport  report({required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiJobPlanJobPlanReport(that: this, cutterSettings: cutterSettings);
+
+
+ String  saveReport({required String gcodePath , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiJobPlanJobPlanSaveReport(that: this, gcodePath: gcodePath, cutterSettings: cutterSettings);
+
+
+ Gcode  toGcode({required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiJobPlanJobPlanToGcode(that: this, cutterSettings: cutterSettings);
+
+
+            }
+            @sealed class LaserCalibrationImpl extends RustOpaque implements LaserCalibration {
+                // Not to be used by end users
+                LaserCalibrationImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                LaserCalibrationImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_LaserCalibration,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_LaserCalibration,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_LaserCalibrationPtr,
+                );
+
+                 Pos2D  finish({required MachineConnection connection , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiLaserLaserCalibrationFinish(that: this, connection: connection, cutterSettings: cutterSettings);
+
+
+ void  fireTestMark({required MachineConnection connection , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiLaserLaserCalibrationFireTestMark(that: this, connection: connection, cutterSettings: cutterSettings);
+
+
+ Pos2D?  markPosition()=>RustLib.instance.api.crateApiLaserLaserCalibrationMarkPosition(that: this, );
+
+
+            }
+            @sealed class MachineConnectionImpl extends RustOpaque implements MachineConnection {
+                // Not to be used by end users
+                MachineConnectionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                MachineConnectionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_MachineConnection,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_MachineConnection,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_MachineConnectionPtr,
+                );
+
+                 void  abortJob()=>RustLib.instance.api.crateApiSenderMachineConnectionAbortJob(that: this, );
+
+
+ Fixture  captureFixture({required String name , WorkCoordinateSystem? wcs })=>RustLib.instance.api.crateApiSenderMachineConnectionCaptureFixture(that: this, name: name, wcs: wcs);
+
+
+ void  disconnect()=>RustLib.instance.api.crateApiSenderMachineConnectionDisconnect(that: this, );
+
+
+ MachineCapabilities  getCapabilities()=>RustLib.instance.api.crateApiSenderMachineConnectionGetCapabilities(that: this, );
+
+
+ ConnectionState  getConnectionState()=>RustLib.instance.api.crateApiSenderMachineConnectionGetConnectionState(that: this, );
+
+
+ CoordinateOffsets  getCoordinateOffsets()=>RustLib.instance.api.crateApiSenderMachineConnectionGetCoordinateOffsets(that: this, );
+
+
+ MachineStatus?  getLastStatus()=>RustLib.instance.api.crateApiSenderMachineConnectionGetLastStatus(that: this, );
+
+
+ MachineSettings  getMachineSettings()=>RustLib.instance.api.crateApiSenderMachineConnectionGetMachineSettings(that: this, );
+
+
+ void  goToMachinePosition({required double x , required double y })=>RustLib.instance.api.crateApiSenderMachineConnectionGoToMachinePosition(that: this, x: x, y: y);
+
+
+ void  goToWorkZero()=>RustLib.instance.api.crateApiSenderMachineConnectionGoToWorkZero(that: this, );
+
+
+ void  home({required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiSenderMachineConnectionHome(that: this, cutterSettings: cutterSettings);
+
+
+ bool  isConnected()=>RustLib.instance.api.crateApiSenderMachineConnectionIsConnected(that: this, );
+
+
+ bool  isHomingRequired()=>RustLib.instance.api.crateApiSenderMachineConnectionIsHomingRequired(that: this, );
+
+
+ void  jogStep({required double xDirection , required double yDirection , required JogStep step , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiSenderMachineConnectionJogStep(that: this, xDirection: xDirection, yDirection: yDirection, step: step, cutterSettings: cutterSettings);
+
+
+ Stream<MachinePosition>  makeConnection()=>RustLib.instance.api.crateApiSenderMachineConnectionMakeConnection(that: this, );
+
+
+ void  pauseJob()=>RustLib.instance.api.crateApiSenderMachineConnectionPauseJob(that: this, );
+
+
+ void  readCoordinateOffsets()=>RustLib.instance.api.crateApiSenderMachineConnectionReadCoordinateOffsets(that: this, );
+
+
+ void  readMachineSettings()=>RustLib.instance.api.crateApiSenderMachineConnectionReadMachineSettings(that: this, );
+
+
+ void  recallFixture({required Fixture fixture })=>RustLib.instance.api.crateApiSenderMachineConnectionRecallFixture(that: this, fixture: fixture);
+
+
+ int  restoreMachineSettings({required String filename })=>RustLib.instance.api.crateApiSenderMachineConnectionRestoreMachineSettings(that: this, filename: filename);
+
+
+ void  resumeJob()=>RustLib.instance.api.crateApiSenderMachineConnectionResumeJob(that: this, );
+
+
+ void  runHomingCycle({required HomingAxes axes })=>RustLib.instance.api.crateApiSenderMachineConnectionRunHomingCycle(that: this, axes: axes);
+
+
+ void  selectWorkCoordinateSystem({required WorkCoordinateSystem wcs })=>RustLib.instance.api.crateApiSenderMachineConnectionSelectWorkCoordinateSystem(that: this, wcs: wcs);
+
+
+ void  sendGcodeCommand({required String command })=>RustLib.instance.api.crateApiSenderMachineConnectionSendGcodeCommand(that: this, command: command);
+
+
+ void  sendStringCommand({required String command })=>RustLib.instance.api.crateApiSenderMachineConnectionSendStringCommand(that: this, command: command);
+
+
+ void  sendStringCommandLowPriority({required String command })=>RustLib.instance.api.crateApiSenderMachineConnectionSendStringCommandLowPriority(that: this, command: command);
+
+
+ void  setBaudrate({required int newBaudrate })=>RustLib.instance.api.crateApiSenderMachineConnectionSetBaudrate(that: this, newBaudrate: newBaudrate);
+
+
+ void  setConnectionSettings({required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiSenderMachineConnectionSetConnectionSettings(that: this, cutterSettings: cutterSettings);
+
+
+ void  setLaserPointer({required bool on_ , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiSenderMachineConnectionSetLaserPointer(that: this, on_: on_, cutterSettings: cutterSettings);
+
+
+ void  setSerialPort({required String newPort })=>RustLib.instance.api.crateApiSenderMachineConnectionSetSerialPort(that: this, newPort: newPort);
+
+
+ void  setWorkOffset({required WorkCoordinateSystem wcs , required Axis axis , required double offset })=>RustLib.instance.api.crateApiSenderMachineConnectionSetWorkOffset(that: this, wcs: wcs, axis: axis, offset: offset);
+
+
+ Stream<JobProgress>  startJob({required Gcode gcode })=>RustLib.instance.api.crateApiSenderMachineConnectionStartJob(that: this, gcode: gcode);
+
+
+ Stream<JobProgress>  startJobFromCut({required Gcode gcode , required int cutIndex })=>RustLib.instance.api.crateApiSenderMachineConnectionStartJobFromCut(that: this, gcode: gcode, cutIndex: cutIndex);
+
+
+ Stream<JobProgress>  startJobFromLine({required Gcode gcode , required int startLine })=>RustLib.instance.api.crateApiSenderMachineConnectionStartJobFromLine(that: this, gcode: gcode, startLine: startLine);
+
+
+ void  startJog({required double xDirection , required double yDirection , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiSenderMachineConnectionStartJog(that: this, xDirection: xDirection, yDirection: yDirection, cutterSettings: cutterSettings);
+
+
+ void  stopJog()=>RustLib.instance.api.crateApiSenderMachineConnectionStopJog(that: this, );
+
+
+ void  unlock()=>RustLib.instance.api.crateApiSenderMachineConnectionUnlock(that: this, );
+
+
+ void  writeMachineSetting({required int id , required String value })=>RustLib.instance.api.crateApiSenderMachineConnectionWriteMachineSetting(that: this, id: id, value: value);
+
+
+ void  zeroAllAxes({WorkCoordinateSystem? wcs })=>RustLib.instance.api.crateApiSenderMachineConnectionZeroAllAxes(that: this, wcs: wcs);
+
+
+ void  zeroAtLaser({WorkCoordinateSystem? wcs , required CutterSettings cutterSettings })=>RustLib.instance.api.crateApiSenderMachineConnectionZeroAtLaser(that: this, wcs: wcs, cutterSettings: cutterSettings);
+
+
+ void  zeroAxis({WorkCoordinateSystem? wcs , required Axis axis })=>RustLib.instance.api.crateApiSenderMachineConnectionZeroAxis(that: this, wcs: wcs, axis: axis);
+
+
+            }
+            @sealed class MachineSettingsImpl extends RustOpaque implements MachineSettings {
+                // Not to be used by end users
+                MachineSettingsImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                MachineSettingsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_MachineSettings,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_MachineSettings,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_MachineSettingsPtr,
+                );
+
+                 List<SettingGroup> get groups=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsAutoAccessorGetGroups(that: this, );
+
+
+ List<MachineSetting> get settings=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsAutoAccessorGetSettings(that: this, );
+
+
+  set groups(List<SettingGroup> groups)=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsAutoAccessorSetGroups(that: this, groups: groups);
+
+
+  set settings(List<MachineSetting> settings)=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsAutoAccessorSetSettings(that: this, settings: settings);
+
+
+ Future<SettingDescription?>  description({required int id })=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsDescription(that: this, id: id);
+
+
+ Future<MachineSetting?>  get_({required int id })=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsGet(that: this, id: id);
+
+
+ Future<void>  saveBackup({required String filename })=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsSaveBackup(that: this, filename: filename);
+
+
+ Future<String>  toBackup()=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsToBackup(that: this, );
+
+
+ Future<String>  validate({required int id , required String value })=>RustLib.instance.api.crateApiMachineSettingsMachineSettingsValidate(that: this, id: id, value: value);
+
+
+            }
+            @sealed class MachineStatusImpl extends RustOpaque implements MachineStatus {
+                // Not to be used by end users
+                MachineStatusImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                MachineStatusImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_MachineStatus,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_MachineStatus,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_MachineStatusPtr,
+                );
+
+                 Accessories? get accessories=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetAccessories(that: this, );
+
+
+ BufferState? get buffer=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetBuffer(that: this, );
+
+
+ double? get feedRate=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetFeedRate(that: this, );
+
+
+ int? get lineNumber=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetLineNumber(that: this, );
+
+
+ Float32List? get machinePosition=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetMachinePosition(that: this, );
+
+
+ MachineState get machineState=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetMachineState(that: this, );
+
+
+ Overrides? get overrides=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetOverrides(that: this, );
+
+
+ String? get pins=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetPins(that: this, );
+
+
+ Pos2D get position=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetPosition(that: this, );
+
+
+ double? get spindleSpeed=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetSpindleSpeed(that: this, );
+
+
+ int? get subState=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetSubState(that: this, );
+
+
+ Float32List? get workCoordinateOffset=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetWorkCoordinateOffset(that: this, );
+
+
+ Float32List? get workPosition=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorGetWorkPosition(that: this, );
+
+
+  set accessories(Accessories? accessories)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetAccessories(that: this, accessories: accessories);
+
+
+  set buffer(BufferState? buffer)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetBuffer(that: this, buffer: buffer);
+
+
+  set feedRate(double? feedRate)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetFeedRate(that: this, feedRate: feedRate);
+
+
+  set lineNumber(int? lineNumber)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetLineNumber(that: this, lineNumber: lineNumber);
+
+
+  set machinePosition(Float32List? machinePosition)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetMachinePosition(that: this, machinePosition: machinePosition);
+
+
+  set machineState(MachineState machineState)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetMachineState(that: this, machineState: machineState);
+
+
+  set overrides(Overrides? overrides)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetOverrides(that: this, overrides: overrides);
+
+
+  set pins(String? pins)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetPins(that: this, pins: pins);
+
+
+  set position(Pos2D position)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetPosition(that: this, position: position);
+
+
+  set spindleSpeed(double? spindleSpeed)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetSpindleSpeed(that: this, spindleSpeed: spindleSpeed);
+
+
+  set subState(int? subState)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetSubState(that: this, subState: subState);
+
+
+  set workCoordinateOffset(Float32List? workCoordinateOffset)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetWorkCoordinateOffset(that: this, workCoordinateOffset: workCoordinateOffset);
+
+
+  set workPosition(Float32List? workPosition)=>RustLib.instance.api.crateApiStatusMachineStatusAutoAccessorSetWorkPosition(that: this, workPosition: workPosition);
+
+
+ Future<bool>  pinTriggered({required String pin })=>RustLib.instance.api.crateApiStatusMachineStatusPinTriggered(that: this, pin: pin);
+
+
+            }
+            @sealed class ModalStateImpl extends RustOpaque implements ModalState {
+                // Not to be used by end users
+                ModalStateImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ModalStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ModalState,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ModalState,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ModalStatePtr,
+                );
+
+                 Future<void>  applyLine({required String line })=>RustLib.instance.api.crateApiInterpreterModalStateApplyLine(that: this, line: line);
+
+
+ Future<void>  applyWords({required List<Word> words })=>RustLib.instance.api.crateApiInterpreterModalStateApplyWords(that: this, words: words);
+
+
+ double? get feedrate=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorGetFeedrate(that: this, );
+
+
+ MotionMode get motionMode=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorGetMotionMode(that: this, );
+
+
+ double? get pierceDelay=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorGetPierceDelay(that: this, );
+
+
+ Pos2D get position=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorGetPosition(that: this, );
+
+
+ PositioningMode get positioningMode=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorGetPositioningMode(that: this, );
+
+
+ bool get torchOn=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorGetTorchOn(that: this, );
+
+
+ DistUnit get units=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorGetUnits(that: this, );
+
+
+ Pos2D get workOffset=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorGetWorkOffset(that: this, );
+
+
+  set feedrate(double? feedrate)=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorSetFeedrate(that: this, feedrate: feedrate);
+
+
+  set motionMode(MotionMode motionMode)=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorSetMotionMode(that: this, motionMode: motionMode);
+
+
+  set pierceDelay(double? pierceDelay)=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorSetPierceDelay(that: this, pierceDelay: pierceDelay);
+
+
+  set position(Pos2D position)=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorSetPosition(that: this, position: position);
+
+
+  set positioningMode(PositioningMode positioningMode)=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorSetPositioningMode(that: this, positioningMode: positioningMode);
+
+
+  set torchOn(bool torchOn)=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorSetTorchOn(that: this, torchOn: torchOn);
+
+
+  set units(DistUnit units)=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorSetUnits(that: this, units: units);
+
+
+  set workOffset(Pos2D workOffset)=>RustLib.instance.api.crateApiInterpreterModalStateAutoAccessorSetWorkOffset(that: this, workOffset: workOffset);
+
+
+ Future<Pos2D>  targetPosition({required List<Word> words })=>RustLib.instance.api.crateApiInterpreterModalStateTargetPosition(that: this, words: words);
+
+
+            }
+            @sealed class Pos2DImpl extends RustOpaque implements Pos2D {
+                // Not to be used by end users
+                Pos2DImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Pos2DImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Pos2D,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Pos2D,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Pos2DPtr,
+                );
+
+                 double get x=>RustLib.instance.api.crateApiGcodePos2DAutoAccessorGetX(that: this, );
+
+
+ double get y=>RustLib.instance.api.crateApiGcodePos2DAutoAccessorGetY(that: this, );
+
+
+  set x(double x)=>RustLib.instance.api.crateApiGcodePos2DAutoAccessorSetX(that: this, x: x);
+
+
+  set y(double y)=>RustLib.instance.api.crateApiGcodePos2DAutoAccessorSetY(that: this, y: y);
+
+
+ Future<Pos2D>  toScreenSpace({required double tubeWidth , required double scaleFactor })=>RustLib.instance.api.crateApiGcodePos2DToScreenSpace(that: this, tubeWidth: tubeWidth, scaleFactor: scaleFactor);
+
+
+            }
+            @sealed class ProfileImpl extends RustOpaque implements Profile {
+                // Not to be used by end users
+                ProfileImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ProfileImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Profile,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Profile,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ProfilePtr,
+                );
+
+                 List<Contour> get contours=>RustLib.instance.api.crateApiProfileProfileAutoAccessorGetContours(that: this, );
+
+
+  set contours(List<Contour> contours)=>RustLib.instance.api.crateApiProfileProfileAutoAccessorSetContours(that: this, contours: contours);
+
+
+ (Pos2D,Pos2D)?  bounds()=>RustLib.instance.api.crateApiProfileProfileBounds(that: this, );
+
+
+ Profile  moved({required double dx , required double dy })=>RustLib.instance.api.crateApiProfileProfileMoved(that: this, dx: dx, dy: dy);
+
+
+ Profile  placeOnFlatStock({required double x , required double y })=>RustLib.instance.api.crateApiProfileProfilePlaceOnFlatStock(that: this, x: x, y: y);
+
+
+ Profile  placeOnTubeFace({required double tubeWidth , required double yPosition })=>RustLib.instance.api.crateApiProfileProfilePlaceOnTubeFace(that: this, tubeWidth: tubeWidth, yPosition: yPosition);
+
+
+ Profile  scaled({required double factor })=>RustLib.instance.api.crateApiProfileProfileScaled(that: this, factor: factor);
+
+
+            }
+            @sealed class ProfileSegmentImpl extends RustOpaque implements ProfileSegment {
+                // Not to be used by end users
+                ProfileSegmentImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ProfileSegmentImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ProfileSegment,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ProfileSegment,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ProfileSegmentPtr,
+                );
+
+                 Future<void>  end()=>RustLib.instance.api.crateApiProfileProfileSegmentEnd(that: this, );
+
+
+ Future<List<Pos2D>>  flatten({required double tolerance })=>RustLib.instance.api.crateApiProfileProfileSegmentFlatten(that: this, tolerance: tolerance);
+
+
+ Future<void>  start()=>RustLib.instance.api.crateApiProfileProfileSegmentStart(that: this, );
+
+
+            }
+            @sealed class ProgramExtentsImpl extends RustOpaque implements ProgramExtents {
+                // Not to be used by end users
+                ProgramExtentsImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ProgramExtentsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ProgramExtents,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ProgramExtents,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ProgramExtentsPtr,
+                );
+
+                 Pos2D get max=>RustLib.instance.api.crateApiBoundsProgramExtentsAutoAccessorGetMax(that: this, );
+
+
+ Pos2D get min=>RustLib.instance.api.crateApiBoundsProgramExtentsAutoAccessorGetMin(that: this, );
+
+
+  set max(Pos2D max)=>RustLib.instance.api.crateApiBoundsProgramExtentsAutoAccessorSetMax(that: this, max: max);
+
+
+  set min(Pos2D min)=>RustLib.instance.api.crateApiBoundsProgramExtentsAutoAccessorSetMin(that: this, min: min);
+
+
+            }
+            @sealed class SegmentKindImpl extends RustOpaque implements SegmentKind {
+                // Not to be used by end users
+                SegmentKindImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SegmentKindImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SegmentKind,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SegmentKind,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SegmentKindPtr,
+                );
+
+                
+            }
+            @sealed class ToolpathSegmentImpl extends RustOpaque implements ToolpathSegment {
+                // Not to be used by end users
+                ToolpathSegmentImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ToolpathSegmentImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ToolpathSegment,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ToolpathSegment,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ToolpathSegmentPtr,
+                );
+
+                 Pos2D get end=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorGetEnd(that: this, );
+
+
+ double? get feedrate=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorGetFeedrate(that: this, );
+
+
+ SegmentKind get kind=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorGetKind(that: this, );
+
+
+ int get line=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorGetLine(that: this, );
+
+
+ Pos2D get start=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorGetStart(that: this, );
+
+
+ bool get torchOn=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorGetTorchOn(that: this, );
+
+
+  set end(Pos2D end)=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorSetEnd(that: this, end: end);
+
+
+  set feedrate(double? feedrate)=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorSetFeedrate(that: this, feedrate: feedrate);
+
+
+  set kind(SegmentKind kind)=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorSetKind(that: this, kind: kind);
+
+
+  set line(int line)=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorSetLine(that: this, line: line);
+
+
+  set start(Pos2D start)=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorSetStart(that: this, start: start);
+
+
+  set torchOn(bool torchOn)=>RustLib.instance.api.crateApiToolpathToolpathSegmentAutoAccessorSetTorchOn(that: this, torchOn: torchOn);
+
+
+ Future<List<Pos2D>>  flatten({required double tolerance })=>RustLib.instance.api.crateApiToolpathToolpathSegmentFlatten(that: this, tolerance: tolerance);
+
+
+ Future<double>  length()=>RustLib.instance.api.crateApiToolpathToolpathSegmentLength(that: this, );
+
+
+            }
+            @sealed class ViewportImpl extends RustOpaque implements Viewport {
+                // Not to be used by end users
+                ViewportImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ViewportImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Viewport,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Viewport,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ViewportPtr,
+                );
+
+                 Pos2D get offset=>RustLib.instance.api.crateApiRenderViewportAutoAccessorGetOffset(that: this, );
+
+
+ double get scale=>RustLib.instance.api.crateApiRenderViewportAutoAccessorGetScale(that: this, );
+
+
+  set offset(Pos2D offset)=>RustLib.instance.api.crateApiRenderViewportAutoAccessorSetOffset(that: this, offset: offset);
+
+
+  set scale(double scale)=>RustLib.instance.api.crateApiRenderViewportAutoAccessorSetScale(that: this, scale: scale);
+
+
+ Pos2D  toScreen({required Pos2D position })=>RustLib.instance.api.crateApiRenderViewportToScreen(that: this, position: position);
+
+
+            }
\ No newline at end of file
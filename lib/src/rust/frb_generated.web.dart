@@ -3,905 +3,1577 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/bounds.dart';
+import 'api/capabilities.dart';
 import 'api/cut.dart';
+import 'api/estimate.dart';
+import 'api/export.dart';
 import 'api/gcode.dart';
+import 'api/homing.dart';
+import 'api/interpreter.dart';
+import 'api/job.dart';
+import 'api/job_file.dart';
+import 'api/job_plan.dart';
+import 'api/jog.dart';
+import 'api/laser.dart';
+import 'api/lint.dart';
+import 'api/machine_settings.dart';
+import 'api/profile.dart';
+import 'api/render.dart';
+import 'api/report.dart';
 import 'api/sender.dart';
 import 'api/settings.dart';
 import 'api/simple.dart';
+import 'api/simulator.dart';
 import 'api/status.dart';
+import 'api/toolpath.dart';
+import 'api/transport.dart';
+import 'api/wcs.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CutPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_CutMethodPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_CutterSettingsPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GcodePtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_MachineConnectionPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_MachineStatusPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Pos2DPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
-
-  @protected
-  Cut
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    dynamic raw,
-  );
-
-  @protected
-  CutMethod
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    dynamic raw,
-  );
-
-  @protected
-  CutterSettings
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    dynamic raw,
-  );
-
-  @protected
-  Gcode
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    dynamic raw,
-  );
-
-  @protected
-  MachineConnection
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    dynamic raw,
-  );
-
-  @protected
-  MachineStatus
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    dynamic raw,
-  );
-
-  @protected
-  Pos2D
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    dynamic raw,
-  );
-
-  @protected
-  Cut
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    dynamic raw,
-  );
-
-  @protected
-  CutterSettings
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    dynamic raw,
-  );
-
-  @protected
-  Gcode
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    dynamic raw,
-  );
-
-  @protected
-  MachineConnection
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    dynamic raw,
-  );
-
-  @protected
-  MachineStatus
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    dynamic raw,
-  );
-
-  @protected
-  Pos2D
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    dynamic raw,
-  );
-
-  @protected
-  Cut
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    dynamic raw,
-  );
-
-  @protected
-  CutterSettings
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    dynamic raw,
-  );
-
-  @protected
-  Gcode
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    dynamic raw,
-  );
-
-  @protected
-  MachineConnection
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    dynamic raw,
-  );
-
-  @protected
-  MachineStatus
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    dynamic raw,
-  );
-
-  @protected
-  Pos2D
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    dynamic raw,
-  );
-
-  @protected
-  Cut
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    dynamic raw,
-  );
-
-  @protected
-  CutMethod
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    dynamic raw,
-  );
-
-  @protected
-  CutterSettings
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    dynamic raw,
-  );
-
-  @protected
-  Gcode
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    dynamic raw,
-  );
-
-  @protected
-  MachineConnection
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    dynamic raw,
-  );
-
-  @protected
-  MachineStatus
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    dynamic raw,
-  );
-
-  @protected
-  Pos2D
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    dynamic raw,
-  );
-
-  @protected
-  RustStreamSink<MachinePosition> dco_decode_StreamSink_machine_position_Sse(
-    dynamic raw,
-  );
-
-  @protected
-  String dco_decode_String(dynamic raw);
-
-  @protected
-  bool dco_decode_bool(dynamic raw);
-
-  @protected
-  double dco_decode_f_32(dynamic raw);
-
-  @protected
-  int dco_decode_i_32(dynamic raw);
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
-
-  @protected
-  MachinePosition dco_decode_machine_position(dynamic raw);
-
-  @protected
-  MachineState dco_decode_machine_state(dynamic raw);
-
-  @protected
-  PositioningMode dco_decode_positioning_mode(dynamic raw);
-
-  @protected
-  (double, double) dco_decode_record_f_32_f_32(dynamic raw);
-
-  @protected
-  Rule dco_decode_rule(dynamic raw);
-
-  @protected
-  int dco_decode_u_8(dynamic raw);
-
-  @protected
-  void dco_decode_unit(dynamic raw);
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
-
-  @protected
-  Cut
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CutMethod
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CutterSettings
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Gcode
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MachineConnection
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MachineStatus
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Pos2D
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Cut
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CutterSettings
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Gcode
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MachineConnection
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MachineStatus
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Pos2D
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Cut
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CutterSettings
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Gcode
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MachineConnection
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MachineStatus
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Pos2D
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Cut
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CutMethod
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CutterSettings
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Gcode
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MachineConnection
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MachineStatus
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Pos2D
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  RustStreamSink<MachinePosition> sse_decode_StreamSink_machine_position_Sse(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
-
-  @protected
-  MachinePosition sse_decode_machine_position(SseDeserializer deserializer);
-
-  @protected
-  MachineState sse_decode_machine_state(SseDeserializer deserializer);
-
-  @protected
-  PositioningMode sse_decode_positioning_mode(SseDeserializer deserializer);
-
-  @protected
-  (double, double) sse_decode_record_f_32_f_32(SseDeserializer deserializer);
-
-  @protected
-  Rule sse_decode_rule(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    Cut self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    CutMethod self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    CutterSettings self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    Gcode self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    MachineConnection self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    MachineStatus self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    Pos2D self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    Cut self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    CutterSettings self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    Gcode self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    MachineConnection self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    MachineStatus self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    Pos2D self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    Cut self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    CutterSettings self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    Gcode self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    MachineConnection self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    MachineStatus self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    Pos2D self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    Cut self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    CutMethod self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    CutterSettings self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    Gcode self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    MachineConnection self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    MachineStatus self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    Pos2D self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_StreamSink_machine_position_Sse(
-    RustStreamSink<MachinePosition> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_machine_position(
-    MachinePosition self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_machine_state(MachineState self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_positioning_mode(
-    PositioningMode self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_record_f_32_f_32(
-    (double, double) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_rule(Rule self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
 
-// Section: wire_class
 
-class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-        ptr,
-      );
-}
-
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
-
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(
-    int ptr,
-  );
-}
+
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BoundsViolationPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CutPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CutMethodPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CutterSettingsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_FeaturePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GcodePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GrblSimulatorPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_JobFilePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_JobPlanPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LaserCalibrationPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MachineConnectionPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MachineSettingsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MachineStatusPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ModalStatePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Pos2DPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ProfilePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ProfileSegmentPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ProgramExtentsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SegmentKindPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ToolpathSegmentPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ViewportPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected BoundsViolation dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw);
+
+@protected Cut dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw);
+
+@protected CutMethod dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(dynamic raw);
+
+@protected CutterSettings dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(dynamic raw);
+
+@protected Feature dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw);
+
+@protected Gcode dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(dynamic raw);
+
+@protected GrblSimulator dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(dynamic raw);
+
+@protected JobFile dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(dynamic raw);
+
+@protected JobPlan dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(dynamic raw);
+
+@protected LaserCalibration dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(dynamic raw);
+
+@protected MachineConnection dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(dynamic raw);
+
+@protected MachineSettings dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(dynamic raw);
+
+@protected MachineStatus dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw);
+
+@protected ModalState dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(dynamic raw);
+
+@protected Pos2D dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw);
+
+@protected Profile dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(dynamic raw);
+
+@protected ProfileSegment dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(dynamic raw);
+
+@protected ProgramExtents dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw);
+
+@protected SegmentKind dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(dynamic raw);
+
+@protected ToolpathSegment dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw);
+
+@protected Viewport dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(dynamic raw);
+
+@protected BoundsViolation dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw);
+
+@protected Cut dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw);
+
+@protected CutterSettings dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(dynamic raw);
+
+@protected Feature dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw);
+
+@protected Gcode dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(dynamic raw);
+
+@protected GrblSimulator dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(dynamic raw);
+
+@protected JobFile dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(dynamic raw);
+
+@protected JobPlan dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(dynamic raw);
+
+@protected LaserCalibration dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(dynamic raw);
+
+@protected MachineConnection dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(dynamic raw);
+
+@protected MachineSettings dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(dynamic raw);
+
+@protected MachineStatus dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw);
+
+@protected ModalState dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(dynamic raw);
+
+@protected Pos2D dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw);
+
+@protected Profile dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(dynamic raw);
+
+@protected ProgramExtents dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw);
+
+@protected ToolpathSegment dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw);
+
+@protected Viewport dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(dynamic raw);
+
+@protected BoundsViolation dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw);
+
+@protected Cut dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw);
+
+@protected CutterSettings dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(dynamic raw);
+
+@protected Feature dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw);
+
+@protected Gcode dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(dynamic raw);
+
+@protected GrblSimulator dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(dynamic raw);
+
+@protected JobFile dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(dynamic raw);
+
+@protected JobPlan dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(dynamic raw);
+
+@protected LaserCalibration dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(dynamic raw);
+
+@protected MachineConnection dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(dynamic raw);
+
+@protected MachineSettings dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(dynamic raw);
+
+@protected MachineStatus dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw);
+
+@protected ModalState dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(dynamic raw);
+
+@protected Pos2D dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw);
+
+@protected Profile dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(dynamic raw);
+
+@protected ProfileSegment dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(dynamic raw);
+
+@protected ProgramExtents dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw);
+
+@protected ToolpathSegment dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw);
+
+@protected Viewport dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(dynamic raw);
+
+@protected String dco_decode_Char(dynamic raw);
+
+@protected BoundsViolation dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw);
+
+@protected Cut dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw);
+
+@protected CutMethod dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(dynamic raw);
+
+@protected CutterSettings dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(dynamic raw);
+
+@protected Feature dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw);
+
+@protected Gcode dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(dynamic raw);
+
+@protected GrblSimulator dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(dynamic raw);
+
+@protected JobFile dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(dynamic raw);
+
+@protected JobPlan dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(dynamic raw);
+
+@protected LaserCalibration dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(dynamic raw);
+
+@protected MachineConnection dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(dynamic raw);
+
+@protected MachineSettings dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(dynamic raw);
+
+@protected MachineStatus dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw);
+
+@protected ModalState dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(dynamic raw);
+
+@protected Pos2D dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw);
+
+@protected Profile dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(dynamic raw);
+
+@protected ProfileSegment dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(dynamic raw);
+
+@protected ProgramExtents dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw);
+
+@protected SegmentKind dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(dynamic raw);
+
+@protected ToolpathSegment dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw);
+
+@protected Viewport dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(dynamic raw);
+
+@protected RustStreamSink<JobProgress> dco_decode_StreamSink_job_progress_Sse(dynamic raw);
+
+@protected RustStreamSink<MachinePosition> dco_decode_StreamSink_machine_position_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected Transport dco_decode_TraitDef_Transport(dynamic raw);
+
+@protected Accessories dco_decode_accessories(dynamic raw);
+
+@protected Axis dco_decode_axis(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected BoundsReport dco_decode_bounds_report(dynamic raw);
+
+@protected MachineStatus dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw);
+
+@protected Pos2D dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw);
+
+@protected ProgramExtents dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw);
+
+@protected Accessories dco_decode_box_autoadd_accessories(dynamic raw);
+
+@protected BoundsReport dco_decode_box_autoadd_bounds_report(dynamic raw);
+
+@protected BufferState dco_decode_box_autoadd_buffer_state(dynamic raw);
+
+@protected ConsumableRatings dco_decode_box_autoadd_consumable_ratings(dynamic raw);
+
+@protected CoordinateOffsets dco_decode_box_autoadd_coordinate_offsets(dynamic raw);
+
+@protected CutParameters dco_decode_box_autoadd_cut_parameters(dynamic raw);
+
+@protected ExportHeader dco_decode_box_autoadd_export_header(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_32(dynamic raw);
+
+@protected Fixture dco_decode_box_autoadd_fixture(dynamic raw);
+
+@protected JobReport dco_decode_box_autoadd_job_report(dynamic raw);
+
+@protected LaserOutput dco_decode_box_autoadd_laser_output(dynamic raw);
+
+@protected LintOptions dco_decode_box_autoadd_lint_options(dynamic raw);
+
+@protected MachineCapabilities dco_decode_box_autoadd_machine_capabilities(dynamic raw);
+
+@protected MachineReference dco_decode_box_autoadd_machine_reference(dynamic raw);
+
+@protected MachineSetting dco_decode_box_autoadd_machine_setting(dynamic raw);
+
+@protected MotionLimits dco_decode_box_autoadd_motion_limits(dynamic raw);
+
+@protected Overrides dco_decode_box_autoadd_overrides(dynamic raw);
+
+@protected (Pos2D,Pos2D) dco_decode_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(dynamic raw);
+
+@protected RenderOptions dco_decode_box_autoadd_render_options(dynamic raw);
+
+@protected SettingDescription dco_decode_box_autoadd_setting_description(dynamic raw);
+
+@protected SimulatorConfig dco_decode_box_autoadd_simulator_config(dynamic raw);
+
+@protected Stock dco_decode_box_autoadd_stock(dynamic raw);
+
+@protected TravelLimits dco_decode_box_autoadd_travel_limits(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected WorkCoordinateSystem dco_decode_box_autoadd_work_coordinate_system(dynamic raw);
+
+@protected WorkOffset dco_decode_box_autoadd_work_offset(dynamic raw);
+
+@protected BufferState dco_decode_buffer_state(dynamic raw);
+
+@protected ConnectionSettings dco_decode_connection_settings(dynamic raw);
+
+@protected ConnectionState dco_decode_connection_state(dynamic raw);
+
+@protected ConsumableRatings dco_decode_consumable_ratings(dynamic raw);
+
+@protected Contour dco_decode_contour(dynamic raw);
+
+@protected ControlLineOnOpen dco_decode_control_line_on_open(dynamic raw);
+
+@protected CoordinateOffsets dco_decode_coordinate_offsets(dynamic raw);
+
+@protected CutParameters dco_decode_cut_parameters(dynamic raw);
+
+@protected CutReport dco_decode_cut_report(dynamic raw);
+
+@protected DistUnit dco_decode_dist_unit(dynamic raw);
+
+@protected ExportHeader dco_decode_export_header(dynamic raw);
+
+@protected double dco_decode_f_32(dynamic raw);
+
+@protected FeatureReport dco_decode_feature_report(dynamic raw);
+
+@protected Fixture dco_decode_fixture(dynamic raw);
+
+@protected HomingAxes dco_decode_homing_axes(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected JobEstimate dco_decode_job_estimate(dynamic raw);
+
+@protected JobProgress dco_decode_job_progress(dynamic raw);
+
+@protected JobReport dco_decode_job_report(dynamic raw);
+
+@protected JobState dco_decode_job_state(dynamic raw);
+
+@protected JogStep dco_decode_jog_step(dynamic raw);
+
+@protected LaserOutput dco_decode_laser_output(dynamic raw);
+
+@protected LineEnding dco_decode_line_ending(dynamic raw);
+
+@protected LintDiagnostic dco_decode_lint_diagnostic(dynamic raw);
+
+@protected LintOptions dco_decode_lint_options(dynamic raw);
+
+@protected LintRule dco_decode_lint_rule(dynamic raw);
+
+@protected List<BoundsViolation> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(dynamic raw);
+
+@protected List<Cut> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(dynamic raw);
+
+@protected List<Feature> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(dynamic raw);
+
+@protected List<Pos2D> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw);
+
+@protected List<ProfileSegment> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(dynamic raw);
+
+@protected List<ToolpathSegment> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<Contour> dco_decode_list_contour(dynamic raw);
+
+@protected List<CutReport> dco_decode_list_cut_report(dynamic raw);
+
+@protected List<FeatureReport> dco_decode_list_feature_report(dynamic raw);
+
+@protected List<Fixture> dco_decode_list_fixture(dynamic raw);
+
+@protected List<LintDiagnostic> dco_decode_list_lint_diagnostic(dynamic raw);
+
+@protected List<MachineSetting> dco_decode_list_machine_setting(dynamic raw);
+
+@protected List<Part> dco_decode_list_part(dynamic raw);
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<SettingGroup> dco_decode_list_setting_group(dynamic raw);
+
+@protected List<Word> dco_decode_list_word(dynamic raw);
+
+@protected List<WorkOffset> dco_decode_list_work_offset(dynamic raw);
+
+@protected MachineCapabilities dco_decode_machine_capabilities(dynamic raw);
+
+@protected MachinePosition dco_decode_machine_position(dynamic raw);
+
+@protected MachineReference dco_decode_machine_reference(dynamic raw);
+
+@protected MachineSetting dco_decode_machine_setting(dynamic raw);
+
+@protected MachineState dco_decode_machine_state(dynamic raw);
+
+@protected MotionLimits dco_decode_motion_limits(dynamic raw);
+
+@protected MotionMode dco_decode_motion_mode(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected MachineStatus? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(dynamic raw);
+
+@protected Pos2D? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(dynamic raw);
+
+@protected ProgramExtents? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(dynamic raw);
+
+@protected Accessories? dco_decode_opt_box_autoadd_accessories(dynamic raw);
+
+@protected BufferState? dco_decode_opt_box_autoadd_buffer_state(dynamic raw);
+
+@protected ExportHeader? dco_decode_opt_box_autoadd_export_header(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+@protected Fixture? dco_decode_opt_box_autoadd_fixture(dynamic raw);
+
+@protected MachineSetting? dco_decode_opt_box_autoadd_machine_setting(dynamic raw);
+
+@protected Overrides? dco_decode_opt_box_autoadd_overrides(dynamic raw);
+
+@protected (Pos2D,Pos2D)? dco_decode_opt_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(dynamic raw);
+
+@protected SettingDescription? dco_decode_opt_box_autoadd_setting_description(dynamic raw);
+
+@protected Stock? dco_decode_opt_box_autoadd_stock(dynamic raw);
+
+@protected TravelLimits? dco_decode_opt_box_autoadd_travel_limits(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected WorkCoordinateSystem? dco_decode_opt_box_autoadd_work_coordinate_system(dynamic raw);
+
+@protected WorkOffset? dco_decode_opt_box_autoadd_work_offset(dynamic raw);
+
+@protected Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw);
+
+@protected Overrides dco_decode_overrides(dynamic raw);
+
+@protected Part dco_decode_part(dynamic raw);
+
+@protected PositioningMode dco_decode_positioning_mode(dynamic raw);
+
+@protected PostProcessor dco_decode_post_processor(dynamic raw);
+
+@protected (Pos2D,Pos2D) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(dynamic raw);
+
+@protected (double,double) dco_decode_record_f_32_f_32(dynamic raw);
+
+@protected RenderOptions dco_decode_render_options(dynamic raw);
+
+@protected Rule dco_decode_rule(dynamic raw);
+
+@protected SerialFlowControl dco_decode_serial_flow_control(dynamic raw);
+
+@protected SettingDescription dco_decode_setting_description(dynamic raw);
+
+@protected SettingGroup dco_decode_setting_group(dynamic raw);
+
+@protected SettingType dco_decode_setting_type(dynamic raw);
+
+@protected Severity dco_decode_severity(dynamic raw);
+
+@protected SimulatorConfig dco_decode_simulator_config(dynamic raw);
+
+@protected StatusParseError dco_decode_status_parse_error(dynamic raw);
+
+@protected Stock dco_decode_stock(dynamic raw);
+
+@protected TransportKind dco_decode_transport_kind(dynamic raw);
+
+@protected TravelLimits dco_decode_travel_limits(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected Word dco_decode_word(dynamic raw);
+
+@protected WorkCoordinateSystem dco_decode_work_coordinate_system(dynamic raw);
+
+@protected WorkOffset dco_decode_work_offset(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected BoundsViolation sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer);
+
+@protected Cut sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer);
+
+@protected CutMethod sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(SseDeserializer deserializer);
+
+@protected CutterSettings sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(SseDeserializer deserializer);
+
+@protected Feature sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer);
+
+@protected Gcode sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(SseDeserializer deserializer);
+
+@protected GrblSimulator sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(SseDeserializer deserializer);
+
+@protected JobFile sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(SseDeserializer deserializer);
+
+@protected JobPlan sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(SseDeserializer deserializer);
+
+@protected LaserCalibration sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(SseDeserializer deserializer);
+
+@protected MachineConnection sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(SseDeserializer deserializer);
+
+@protected MachineSettings sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(SseDeserializer deserializer);
+
+@protected MachineStatus sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer);
+
+@protected ModalState sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(SseDeserializer deserializer);
+
+@protected Pos2D sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer);
+
+@protected Profile sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(SseDeserializer deserializer);
+
+@protected ProfileSegment sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(SseDeserializer deserializer);
+
+@protected ProgramExtents sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer);
+
+@protected SegmentKind sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(SseDeserializer deserializer);
+
+@protected ToolpathSegment sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer);
+
+@protected Viewport sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(SseDeserializer deserializer);
+
+@protected BoundsViolation sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer);
+
+@protected Cut sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer);
+
+@protected CutterSettings sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(SseDeserializer deserializer);
+
+@protected Feature sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer);
+
+@protected Gcode sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(SseDeserializer deserializer);
+
+@protected GrblSimulator sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(SseDeserializer deserializer);
+
+@protected JobFile sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(SseDeserializer deserializer);
+
+@protected JobPlan sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(SseDeserializer deserializer);
+
+@protected LaserCalibration sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(SseDeserializer deserializer);
+
+@protected MachineConnection sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(SseDeserializer deserializer);
+
+@protected MachineSettings sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(SseDeserializer deserializer);
+
+@protected MachineStatus sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer);
+
+@protected ModalState sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(SseDeserializer deserializer);
+
+@protected Pos2D sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer);
+
+@protected Profile sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(SseDeserializer deserializer);
+
+@protected ProgramExtents sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer);
+
+@protected ToolpathSegment sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer);
+
+@protected Viewport sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(SseDeserializer deserializer);
+
+@protected BoundsViolation sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer);
+
+@protected Cut sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer);
+
+@protected CutterSettings sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(SseDeserializer deserializer);
+
+@protected Feature sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer);
+
+@protected Gcode sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(SseDeserializer deserializer);
+
+@protected GrblSimulator sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(SseDeserializer deserializer);
+
+@protected JobFile sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(SseDeserializer deserializer);
+
+@protected JobPlan sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(SseDeserializer deserializer);
+
+@protected LaserCalibration sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(SseDeserializer deserializer);
+
+@protected MachineConnection sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(SseDeserializer deserializer);
+
+@protected MachineSettings sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(SseDeserializer deserializer);
+
+@protected MachineStatus sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer);
+
+@protected ModalState sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(SseDeserializer deserializer);
+
+@protected Pos2D sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer);
+
+@protected Profile sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(SseDeserializer deserializer);
+
+@protected ProfileSegment sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(SseDeserializer deserializer);
+
+@protected ProgramExtents sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer);
+
+@protected ToolpathSegment sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer);
+
+@protected Viewport sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(SseDeserializer deserializer);
+
+@protected String sse_decode_Char(SseDeserializer deserializer);
+
+@protected BoundsViolation sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer);
+
+@protected Cut sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer);
+
+@protected CutMethod sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(SseDeserializer deserializer);
+
+@protected CutterSettings sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(SseDeserializer deserializer);
+
+@protected Feature sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer);
+
+@protected Gcode sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(SseDeserializer deserializer);
+
+@protected GrblSimulator sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(SseDeserializer deserializer);
+
+@protected JobFile sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(SseDeserializer deserializer);
+
+@protected JobPlan sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(SseDeserializer deserializer);
+
+@protected LaserCalibration sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(SseDeserializer deserializer);
+
+@protected MachineConnection sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(SseDeserializer deserializer);
+
+@protected MachineSettings sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(SseDeserializer deserializer);
+
+@protected MachineStatus sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer);
+
+@protected ModalState sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(SseDeserializer deserializer);
+
+@protected Pos2D sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer);
+
+@protected Profile sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(SseDeserializer deserializer);
+
+@protected ProfileSegment sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(SseDeserializer deserializer);
+
+@protected ProgramExtents sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer);
+
+@protected SegmentKind sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(SseDeserializer deserializer);
+
+@protected ToolpathSegment sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer);
+
+@protected Viewport sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(SseDeserializer deserializer);
+
+@protected RustStreamSink<JobProgress> sse_decode_StreamSink_job_progress_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<MachinePosition> sse_decode_StreamSink_machine_position_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected Accessories sse_decode_accessories(SseDeserializer deserializer);
+
+@protected Axis sse_decode_axis(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected BoundsReport sse_decode_bounds_report(SseDeserializer deserializer);
+
+@protected MachineStatus sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer);
+
+@protected Pos2D sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer);
+
+@protected ProgramExtents sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer);
+
+@protected Accessories sse_decode_box_autoadd_accessories(SseDeserializer deserializer);
+
+@protected BoundsReport sse_decode_box_autoadd_bounds_report(SseDeserializer deserializer);
+
+@protected BufferState sse_decode_box_autoadd_buffer_state(SseDeserializer deserializer);
+
+@protected ConsumableRatings sse_decode_box_autoadd_consumable_ratings(SseDeserializer deserializer);
+
+@protected CoordinateOffsets sse_decode_box_autoadd_coordinate_offsets(SseDeserializer deserializer);
+
+@protected CutParameters sse_decode_box_autoadd_cut_parameters(SseDeserializer deserializer);
+
+@protected ExportHeader sse_decode_box_autoadd_export_header(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected Fixture sse_decode_box_autoadd_fixture(SseDeserializer deserializer);
+
+@protected JobReport sse_decode_box_autoadd_job_report(SseDeserializer deserializer);
+
+@protected LaserOutput sse_decode_box_autoadd_laser_output(SseDeserializer deserializer);
+
+@protected LintOptions sse_decode_box_autoadd_lint_options(SseDeserializer deserializer);
+
+@protected MachineCapabilities sse_decode_box_autoadd_machine_capabilities(SseDeserializer deserializer);
+
+@protected MachineReference sse_decode_box_autoadd_machine_reference(SseDeserializer deserializer);
+
+@protected MachineSetting sse_decode_box_autoadd_machine_setting(SseDeserializer deserializer);
+
+@protected MotionLimits sse_decode_box_autoadd_motion_limits(SseDeserializer deserializer);
+
+@protected Overrides sse_decode_box_autoadd_overrides(SseDeserializer deserializer);
+
+@protected (Pos2D,Pos2D) sse_decode_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(SseDeserializer deserializer);
+
+@protected RenderOptions sse_decode_box_autoadd_render_options(SseDeserializer deserializer);
+
+@protected SettingDescription sse_decode_box_autoadd_setting_description(SseDeserializer deserializer);
+
+@protected SimulatorConfig sse_decode_box_autoadd_simulator_config(SseDeserializer deserializer);
+
+@protected Stock sse_decode_box_autoadd_stock(SseDeserializer deserializer);
+
+@protected TravelLimits sse_decode_box_autoadd_travel_limits(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected WorkCoordinateSystem sse_decode_box_autoadd_work_coordinate_system(SseDeserializer deserializer);
+
+@protected WorkOffset sse_decode_box_autoadd_work_offset(SseDeserializer deserializer);
+
+@protected BufferState sse_decode_buffer_state(SseDeserializer deserializer);
+
+@protected ConnectionSettings sse_decode_connection_settings(SseDeserializer deserializer);
+
+@protected ConnectionState sse_decode_connection_state(SseDeserializer deserializer);
+
+@protected ConsumableRatings sse_decode_consumable_ratings(SseDeserializer deserializer);
+
+@protected Contour sse_decode_contour(SseDeserializer deserializer);
+
+@protected ControlLineOnOpen sse_decode_control_line_on_open(SseDeserializer deserializer);
+
+@protected CoordinateOffsets sse_decode_coordinate_offsets(SseDeserializer deserializer);
+
+@protected CutParameters sse_decode_cut_parameters(SseDeserializer deserializer);
+
+@protected CutReport sse_decode_cut_report(SseDeserializer deserializer);
+
+@protected DistUnit sse_decode_dist_unit(SseDeserializer deserializer);
+
+@protected ExportHeader sse_decode_export_header(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected FeatureReport sse_decode_feature_report(SseDeserializer deserializer);
+
+@protected Fixture sse_decode_fixture(SseDeserializer deserializer);
+
+@protected HomingAxes sse_decode_homing_axes(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected JobEstimate sse_decode_job_estimate(SseDeserializer deserializer);
+
+@protected JobProgress sse_decode_job_progress(SseDeserializer deserializer);
+
+@protected JobReport sse_decode_job_report(SseDeserializer deserializer);
+
+@protected JobState sse_decode_job_state(SseDeserializer deserializer);
+
+@protected JogStep sse_decode_jog_step(SseDeserializer deserializer);
+
+@protected LaserOutput sse_decode_laser_output(SseDeserializer deserializer);
+
+@protected LineEnding sse_decode_line_ending(SseDeserializer deserializer);
+
+@protected LintDiagnostic sse_decode_lint_diagnostic(SseDeserializer deserializer);
+
+@protected LintOptions sse_decode_lint_options(SseDeserializer deserializer);
+
+@protected LintRule sse_decode_lint_rule(SseDeserializer deserializer);
+
+@protected List<BoundsViolation> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(SseDeserializer deserializer);
+
+@protected List<Cut> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(SseDeserializer deserializer);
+
+@protected List<Feature> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(SseDeserializer deserializer);
+
+@protected List<Pos2D> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer);
+
+@protected List<ProfileSegment> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(SseDeserializer deserializer);
+
+@protected List<ToolpathSegment> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<Contour> sse_decode_list_contour(SseDeserializer deserializer);
+
+@protected List<CutReport> sse_decode_list_cut_report(SseDeserializer deserializer);
+
+@protected List<FeatureReport> sse_decode_list_feature_report(SseDeserializer deserializer);
+
+@protected List<Fixture> sse_decode_list_fixture(SseDeserializer deserializer);
+
+@protected List<LintDiagnostic> sse_decode_list_lint_diagnostic(SseDeserializer deserializer);
+
+@protected List<MachineSetting> sse_decode_list_machine_setting(SseDeserializer deserializer);
+
+@protected List<Part> sse_decode_list_part(SseDeserializer deserializer);
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<SettingGroup> sse_decode_list_setting_group(SseDeserializer deserializer);
+
+@protected List<Word> sse_decode_list_word(SseDeserializer deserializer);
+
+@protected List<WorkOffset> sse_decode_list_work_offset(SseDeserializer deserializer);
+
+@protected MachineCapabilities sse_decode_machine_capabilities(SseDeserializer deserializer);
+
+@protected MachinePosition sse_decode_machine_position(SseDeserializer deserializer);
+
+@protected MachineReference sse_decode_machine_reference(SseDeserializer deserializer);
+
+@protected MachineSetting sse_decode_machine_setting(SseDeserializer deserializer);
+
+@protected MachineState sse_decode_machine_state(SseDeserializer deserializer);
+
+@protected MotionLimits sse_decode_motion_limits(SseDeserializer deserializer);
+
+@protected MotionMode sse_decode_motion_mode(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected MachineStatus? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(SseDeserializer deserializer);
+
+@protected Pos2D? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(SseDeserializer deserializer);
+
+@protected ProgramExtents? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(SseDeserializer deserializer);
+
+@protected Accessories? sse_decode_opt_box_autoadd_accessories(SseDeserializer deserializer);
+
+@protected BufferState? sse_decode_opt_box_autoadd_buffer_state(SseDeserializer deserializer);
+
+@protected ExportHeader? sse_decode_opt_box_autoadd_export_header(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected Fixture? sse_decode_opt_box_autoadd_fixture(SseDeserializer deserializer);
+
+@protected MachineSetting? sse_decode_opt_box_autoadd_machine_setting(SseDeserializer deserializer);
+
+@protected Overrides? sse_decode_opt_box_autoadd_overrides(SseDeserializer deserializer);
+
+@protected (Pos2D,Pos2D)? sse_decode_opt_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(SseDeserializer deserializer);
+
+@protected SettingDescription? sse_decode_opt_box_autoadd_setting_description(SseDeserializer deserializer);
+
+@protected Stock? sse_decode_opt_box_autoadd_stock(SseDeserializer deserializer);
+
+@protected TravelLimits? sse_decode_opt_box_autoadd_travel_limits(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected WorkCoordinateSystem? sse_decode_opt_box_autoadd_work_coordinate_system(SseDeserializer deserializer);
+
+@protected WorkOffset? sse_decode_opt_box_autoadd_work_offset(SseDeserializer deserializer);
+
+@protected Float32List? sse_decode_opt_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected Overrides sse_decode_overrides(SseDeserializer deserializer);
+
+@protected Part sse_decode_part(SseDeserializer deserializer);
+
+@protected PositioningMode sse_decode_positioning_mode(SseDeserializer deserializer);
+
+@protected PostProcessor sse_decode_post_processor(SseDeserializer deserializer);
+
+@protected (Pos2D,Pos2D) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d(SseDeserializer deserializer);
+
+@protected (double,double) sse_decode_record_f_32_f_32(SseDeserializer deserializer);
+
+@protected RenderOptions sse_decode_render_options(SseDeserializer deserializer);
+
+@protected Rule sse_decode_rule(SseDeserializer deserializer);
+
+@protected SerialFlowControl sse_decode_serial_flow_control(SseDeserializer deserializer);
+
+@protected SettingDescription sse_decode_setting_description(SseDeserializer deserializer);
+
+@protected SettingGroup sse_decode_setting_group(SseDeserializer deserializer);
+
+@protected SettingType sse_decode_setting_type(SseDeserializer deserializer);
+
+@protected Severity sse_decode_severity(SseDeserializer deserializer);
+
+@protected SimulatorConfig sse_decode_simulator_config(SseDeserializer deserializer);
+
+@protected StatusParseError sse_decode_status_parse_error(SseDeserializer deserializer);
+
+@protected Stock sse_decode_stock(SseDeserializer deserializer);
+
+@protected TransportKind sse_decode_transport_kind(SseDeserializer deserializer);
+
+@protected TravelLimits sse_decode_travel_limits(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected Word sse_decode_word(SseDeserializer deserializer);
+
+@protected WorkCoordinateSystem sse_decode_work_coordinate_system(SseDeserializer deserializer);
+
+@protected WorkOffset sse_decode_work_offset(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(BoundsViolation self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(Cut self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(CutMethod self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(CutterSettings self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(Feature self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(Gcode self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(GrblSimulator self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(JobFile self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(JobPlan self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(LaserCalibration self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(MachineConnection self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(MachineSettings self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ModalState self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(Profile self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(ProfileSegment self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(SegmentKind self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ToolpathSegment self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(Viewport self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(BoundsViolation self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(Cut self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(CutterSettings self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(Feature self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(Gcode self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(GrblSimulator self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(JobFile self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(JobPlan self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(LaserCalibration self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(MachineConnection self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(MachineSettings self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ModalState self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(Profile self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ToolpathSegment self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(Viewport self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(BoundsViolation self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(Cut self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(CutterSettings self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(Feature self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(Gcode self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(GrblSimulator self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(JobFile self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(JobPlan self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(LaserCalibration self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(MachineConnection self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(MachineSettings self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ModalState self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(Profile self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(ProfileSegment self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ToolpathSegment self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(Viewport self, SseSerializer serializer);
+
+@protected void sse_encode_Char(String self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(BoundsViolation self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(Cut self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(CutMethod self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(CutterSettings self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(Feature self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(Gcode self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(GrblSimulator self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(JobFile self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(JobPlan self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(LaserCalibration self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(MachineConnection self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(MachineSettings self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ModalState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(Profile self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(ProfileSegment self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(SegmentKind self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ToolpathSegment self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(Viewport self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_job_progress_Sse(RustStreamSink<JobProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_machine_position_Sse(RustStreamSink<MachinePosition> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_accessories(Accessories self, SseSerializer serializer);
+
+@protected void sse_encode_axis(Axis self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_bounds_report(BoundsReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_accessories(Accessories self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bounds_report(BoundsReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_buffer_state(BufferState self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_consumable_ratings(ConsumableRatings self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_coordinate_offsets(CoordinateOffsets self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cut_parameters(CutParameters self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_export_header(ExportHeader self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_fixture(Fixture self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_job_report(JobReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_laser_output(LaserOutput self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_lint_options(LintOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_machine_capabilities(MachineCapabilities self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_machine_reference(MachineReference self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_machine_setting(MachineSetting self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_motion_limits(MotionLimits self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_overrides(Overrides self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d((Pos2D,Pos2D) self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_render_options(RenderOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_setting_description(SettingDescription self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_simulator_config(SimulatorConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stock(Stock self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_travel_limits(TravelLimits self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_work_coordinate_system(WorkCoordinateSystem self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_work_offset(WorkOffset self, SseSerializer serializer);
+
+@protected void sse_encode_buffer_state(BufferState self, SseSerializer serializer);
+
+@protected void sse_encode_connection_settings(ConnectionSettings self, SseSerializer serializer);
+
+@protected void sse_encode_connection_state(ConnectionState self, SseSerializer serializer);
+
+@protected void sse_encode_consumable_ratings(ConsumableRatings self, SseSerializer serializer);
+
+@protected void sse_encode_contour(Contour self, SseSerializer serializer);
+
+@protected void sse_encode_control_line_on_open(ControlLineOnOpen self, SseSerializer serializer);
+
+@protected void sse_encode_coordinate_offsets(CoordinateOffsets self, SseSerializer serializer);
+
+@protected void sse_encode_cut_parameters(CutParameters self, SseSerializer serializer);
+
+@protected void sse_encode_cut_report(CutReport self, SseSerializer serializer);
+
+@protected void sse_encode_dist_unit(DistUnit self, SseSerializer serializer);
+
+@protected void sse_encode_export_header(ExportHeader self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_feature_report(FeatureReport self, SseSerializer serializer);
+
+@protected void sse_encode_fixture(Fixture self, SseSerializer serializer);
+
+@protected void sse_encode_homing_axes(HomingAxes self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_job_estimate(JobEstimate self, SseSerializer serializer);
+
+@protected void sse_encode_job_progress(JobProgress self, SseSerializer serializer);
+
+@protected void sse_encode_job_report(JobReport self, SseSerializer serializer);
+
+@protected void sse_encode_job_state(JobState self, SseSerializer serializer);
+
+@protected void sse_encode_jog_step(JogStep self, SseSerializer serializer);
+
+@protected void sse_encode_laser_output(LaserOutput self, SseSerializer serializer);
+
+@protected void sse_encode_line_ending(LineEnding self, SseSerializer serializer);
+
+@protected void sse_encode_lint_diagnostic(LintDiagnostic self, SseSerializer serializer);
+
+@protected void sse_encode_lint_options(LintOptions self, SseSerializer serializer);
+
+@protected void sse_encode_lint_rule(LintRule self, SseSerializer serializer);
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(List<BoundsViolation> self, SseSerializer serializer);
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(List<Cut> self, SseSerializer serializer);
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(List<Feature> self, SseSerializer serializer);
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(List<Pos2D> self, SseSerializer serializer);
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(List<ProfileSegment> self, SseSerializer serializer);
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(List<ToolpathSegment> self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_contour(List<Contour> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cut_report(List<CutReport> self, SseSerializer serializer);
+
+@protected void sse_encode_list_feature_report(List<FeatureReport> self, SseSerializer serializer);
+
+@protected void sse_encode_list_fixture(List<Fixture> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lint_diagnostic(List<LintDiagnostic> self, SseSerializer serializer);
+
+@protected void sse_encode_list_machine_setting(List<MachineSetting> self, SseSerializer serializer);
+
+@protected void sse_encode_list_part(List<Part> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_setting_group(List<SettingGroup> self, SseSerializer serializer);
+
+@protected void sse_encode_list_word(List<Word> self, SseSerializer serializer);
+
+@protected void sse_encode_list_work_offset(List<WorkOffset> self, SseSerializer serializer);
+
+@protected void sse_encode_machine_capabilities(MachineCapabilities self, SseSerializer serializer);
+
+@protected void sse_encode_machine_position(MachinePosition self, SseSerializer serializer);
+
+@protected void sse_encode_machine_reference(MachineReference self, SseSerializer serializer);
+
+@protected void sse_encode_machine_setting(MachineSetting self, SseSerializer serializer);
+
+@protected void sse_encode_machine_state(MachineState self, SseSerializer serializer);
+
+@protected void sse_encode_motion_limits(MotionLimits self, SseSerializer serializer);
+
+@protected void sse_encode_motion_mode(MotionMode self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(MachineStatus? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(Pos2D? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ProgramExtents? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_accessories(Accessories? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_buffer_state(BufferState? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_export_header(ExportHeader? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_fixture(Fixture? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_machine_setting(MachineSetting? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_overrides(Overrides? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d((Pos2D,Pos2D)? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_setting_description(SettingDescription? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_stock(Stock? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_travel_limits(TravelLimits? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_work_coordinate_system(WorkCoordinateSystem? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_work_offset(WorkOffset? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_f_32_strict(Float32List? self, SseSerializer serializer);
+
+@protected void sse_encode_overrides(Overrides self, SseSerializer serializer);
+
+@protected void sse_encode_part(Part self, SseSerializer serializer);
+
+@protected void sse_encode_positioning_mode(PositioningMode self, SseSerializer serializer);
+
+@protected void sse_encode_post_processor(PostProcessor self, SseSerializer serializer);
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_pos_2_d((Pos2D,Pos2D) self, SseSerializer serializer);
+
+@protected void sse_encode_record_f_32_f_32((double,double) self, SseSerializer serializer);
+
+@protected void sse_encode_render_options(RenderOptions self, SseSerializer serializer);
+
+@protected void sse_encode_rule(Rule self, SseSerializer serializer);
+
+@protected void sse_encode_serial_flow_control(SerialFlowControl self, SseSerializer serializer);
+
+@protected void sse_encode_setting_description(SettingDescription self, SseSerializer serializer);
+
+@protected void sse_encode_setting_group(SettingGroup self, SseSerializer serializer);
+
+@protected void sse_encode_setting_type(SettingType self, SseSerializer serializer);
+
+@protected void sse_encode_severity(Severity self, SseSerializer serializer);
+
+@protected void sse_encode_simulator_config(SimulatorConfig self, SseSerializer serializer);
+
+@protected void sse_encode_status_parse_error(StatusParseError self, SseSerializer serializer);
+
+@protected void sse_encode_stock(Stock self, SseSerializer serializer);
+
+@protected void sse_encode_transport_kind(TransportKind self, SseSerializer serializer);
+
+@protected void sse_encode_travel_limits(TravelLimits self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_word(Word self, SseSerializer serializer);
+
+@protected void sse_encode_work_coordinate_system(WorkCoordinateSystem self, SseSerializer serializer);
+
+@protected void sse_encode_work_offset(WorkOffset self, SseSerializer serializer);
+                }
+                
+
+
+// Section: wire_class
+
+class RustLibWire implements BaseWire {
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoundsViolation(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCut(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutMethod(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCutterSettings(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFeature(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGcode(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGrblSimulator(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobFile(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJobPlan(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLaserCalibration(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineConnection(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineSettings(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMachineStatus(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerModalState(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPos2D(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfile(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProfileSegment(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgramExtents(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSegmentKind(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerToolpathSegment(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerViewport(int ptr);
+        }
+        
\ No newline at end of file
@@ -0,0 +1,310 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::gcode::{Gcode, Pos2D};
+use crate::api::interpreter::parse_words;
+use crate::api::job::JobLine;
+use crate::api::machine_settings::MachineSettings;
+use crate::api::toolpath::{simulate_toolpath, simulate_toolpath_at, ToolpathSegment};
+use crate::api::wcs::{CoordinateOffsets, WorkCoordinateSystem};
+
+// how far an arc may stray from the chords it is checked with
+const ARC_TOLERANCE: f32 = 0.05;
+
+// how far the machine can travel, in machine coordinates (mm)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TravelLimits {
+    pub x_min: f32,
+    pub x_max: f32,
+    pub y_min: f32,
+    pub y_max: f32,
+}
+
+impl TravelLimits {
+    // grbl's soft limits from $20, $23 and $130/$131, None if soft limits are off or weren't read yet
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn from_machine_settings(machine_settings: &MachineSettings) -> Option<TravelLimits> {
+        let value = |id: u32| machine_settings.get(id).and_then(|setting| setting.value.parse::<f32>().ok());
+        if value(20)? == 0.0 {
+            return None;
+        }
+        let homing_direction_invert = value(23).unwrap_or(0.0) as u32;
+        // grbl homes towards +, so machine space runs from -max travel to 0 unless the homing direction is inverted
+        let axis_range = |bit: u32, travel: f32| {
+            if homing_direction_invert & bit != 0 { (0.0, travel) } else { (-travel, 0.0) }
+        };
+        let (x_min, x_max) = axis_range(1, value(130)?);
+        let (y_min, y_max) = axis_range(2, value(131)?);
+        Some(TravelLimits { x_min, x_max, y_min, y_max })
+    }
+
+    pub fn contains(&self, position: &Pos2D) -> bool {
+        position.x >= self.x_min && position.x <= self.x_max && position.y >= self.y_min && position.y <= self.y_max
+    }
+
+    // what's wrong with a position that isn't within the limits
+    fn describe_violation(&self, position: &Pos2D) -> String {
+        let mut problems = Vec::new();
+        if position.x < self.x_min || position.x > self.x_max {
+            problems.push(format!("X{:.3} is outside {:.3} to {:.3}", position.x, self.x_min, self.x_max));
+        }
+        if position.y < self.y_min || position.y > self.y_max {
+            problems.push(format!("Y{:.3} is outside {:.3} to {:.3}", position.y, self.y_min, self.y_max));
+        }
+        problems.join(" and ")
+    }
+}
+
+// the smallest box the program's moves fit in, in work coordinates (mm)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgramExtents {
+    pub min: Pos2D,
+    pub max: Pos2D,
+}
+
+// a move that leaves the travel limits
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundsViolation {
+    // the line (0 based) of the program
+    pub line: u32,
+    // the cut (0 based) the line belongs to, if the program was built from cuts
+    pub cut: Option<u32>,
+    // the first point outside the limits, in machine coordinates
+    pub position: Pos2D,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BoundsReport {
+    // None if the program doesn't move
+    pub extents: Option<ProgramExtents>,
+    pub violations: Vec<BoundsViolation>,
+}
+
+impl BoundsReport {
+    pub fn is_within_limits(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+pub fn program_extents(segments: &[ToolpathSegment]) -> Option<ProgramExtents> {
+    let mut points = segments.iter().filter(|segment| segment.length() > 0.0).flat_map(|segment| segment.flatten(ARC_TOLERANCE));
+    let first = points.next()?;
+    let mut extents = ProgramExtents { min: first.clone(), max: first };
+    for point in points {
+        extents.min.x = extents.min.x.min(point.x);
+        extents.min.y = extents.min.y.min(point.y);
+        extents.max.x = extents.max.x.max(point.x);
+        extents.max.y = extents.max.y.max(point.y);
+    }
+    Some(extents)
+}
+
+// check every move against the limits, work_offset is where work zero is in machine coordinates
+pub fn check_segments(segments: &[ToolpathSegment], limits: &TravelLimits, work_offset: &Pos2D, cut_start_lines: &[u32]) -> BoundsReport {
+    let mut violations = Vec::new();
+    for segment in segments.iter().filter(|segment| segment.length() > 0.0) {
+        let outside = segment
+            .flatten(ARC_TOLERANCE)
+            .into_iter()
+            .map(|point| Pos2D::new(point.x + work_offset.x, point.y + work_offset.y))
+            .find(|point| !limits.contains(point));
+        let Some(position) = outside else {
+            continue;
+        };
+        // the cut that started last before this line
+        let cut = cut_start_lines.iter().rposition(|start| *start <= segment.line).map(|cut| cut as u32);
+        let message = match cut {
+            Some(cut) => format!("Line {} (cut {}) leaves the travel limits: {}", segment.line + 1, cut + 1, limits.describe_violation(&position)),
+            None => format!("Line {} leaves the travel limits: {}", segment.line + 1, limits.describe_violation(&position)),
+        };
+        violations.push(BoundsViolation { line: segment.line, cut, position, message });
+    }
+    BoundsReport { extents: program_extents(segments), violations }
+}
+
+// where work zero of the active coordinate system is, None if $# and $G weren't read
+fn active_work_offset(offsets: &CoordinateOffsets) -> Option<Pos2D> {
+    let offset = offsets.offset(offsets.active.unwrap_or(WorkCoordinateSystem::G54))?;
+    let g92 = offsets.g92.clone().unwrap_or_default();
+    Some(Pos2D::new(offset.x + g92.first().copied().unwrap_or(0.0), offset.y + g92.get(1).copied().unwrap_or(0.0)))
+}
+
+// the offsets read before the job started only hold if the program doesn't change them itself
+fn check_offset_changes(lines: &[JobLine], active: WorkCoordinateSystem) -> Result<(), String> {
+    for line in lines {
+        for word in parse_words(&line.text).into_iter().filter(|word| word.letter == 'G') {
+            let changes_offsets = match (word.value * 10.0).round() as u32 {
+                // G10 and G92 to G92.3
+                100 | 920..=923 => true,
+                // grblHAL's G59.1 to G59.3
+                591..=593 => true,
+                code @ (540 | 550 | 560 | 570 | 580 | 590) => WorkCoordinateSystem::from_gcode(&format!("G{}", code / 10)) != Some(active),
+                _ => false,
+            };
+            if changes_offsets {
+                return Err(format!("Line {} changes the work offsets, the travel limits can't be checked", line.source_line + 1));
+            }
+        }
+    }
+    Ok(())
+}
+
+// check the lines of a job before it's streamed, the error names the first line that leaves the limits
+pub(crate) fn check_job_lines(lines: &[JobLine], limits: &TravelLimits, offsets: &CoordinateOffsets) -> Result<(), String> {
+    let (Some(active), Some(work_offset)) = (offsets.active, active_work_offset(offsets)) else {
+        return Err("The travel limits can't be checked, the work offsets are unknown".to_string());
+    };
+    check_offset_changes(lines, active)?;
+    let program: Vec<&str> = lines.iter().map(|line| line.text.as_str()).collect();
+    let report = check_segments(&simulate_toolpath_at(&program.join("\n"), &work_offset), limits, &work_offset, &[]);
+    match report.violations.first() {
+        Some(violation) => {
+            // report the line of the original program
+            let source_line = lines[violation.line as usize].source_line;
+            Err(format!("Line {} leaves the travel limits: {}", source_line + 1, limits.describe_violation(&violation.position)))
+        },
+        None => Ok(()),
+    }
+}
+
+impl Gcode {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_extents(&self) -> Option<ProgramExtents> {
+        program_extents(&simulate_toolpath(self.get_gcode_string()))
+    }
+
+    // check the program stays within the limits with work zero at work_offset (machine coordinates)
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn check_bounds(&self, limits: &TravelLimits, work_offset: &Pos2D) -> BoundsReport {
+        check_segments(&simulate_toolpath_at(&self.get_gcode_string(), work_offset), limits, work_offset, self.cut_start_lines())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cut::Cut;
+    use crate::api::job::{job_lines, JobProgress, JobState};
+    use crate::api::settings::CutterSettings;
+    use crate::api::simulator::{connect_to_simulator, GrblSimulator, SimulatorConfig};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    fn table() -> TravelLimits {
+        TravelLimits { x_min: 0.0, x_max: 100.0, y_min: 0.0, y_max: 100.0 }
+    }
+
+    #[test]
+    fn finds_the_extents_including_arcs() {
+        let segments = simulate_toolpath("G0 X10 Y50\nG2 X30 Y50 I10 J0 F500\nG1 X40\n".to_string());
+        let extents = program_extents(&segments).unwrap();
+        assert_eq!((extents.min.x, extents.max.x, extents.min.y), (0.0, 40.0, 0.0));
+        // the top of the arc, not just its end points
+        assert!((extents.max.y - 60.0).abs() < 0.01);
+        assert_eq!(program_extents(&simulate_toolpath("G21\nM3\n".to_string())), None);
+    }
+
+    #[test]
+    fn names_the_cut_that_leaves_the_table() {
+        let settings = CutterSettings { clear_existing_gcode: false, home_after_cut: false, ..Default::default() };
+        let mut gcode = Gcode::new();
+        gcode.add_cut(Cut::new(), &settings);
+        // a shallow angle sends the end of the cut far down the tube
+        let mut shallow = Cut::new();
+        shallow.start_position = Pos2D::new(0.0, 20.0);
+        shallow.cut_angle = 5.0;
+        gcode.add_cut(shallow, &settings);
+
+        let report = gcode.check_bounds(&table(), &Pos2D::new(10.0, 10.0));
+        assert!(!report.is_within_limits());
+        let violation = &report.violations[0];
+        assert_eq!(violation.cut, Some(1));
+        assert_eq!(violation.line, gcode.get_cut_start_line(1).unwrap() + 1);
+        assert!(violation.message.starts_with(&format!("Line {} (cut 2) leaves the travel limits: Y", violation.line + 1)), "{}", violation.message);
+        assert!(report.extents.unwrap().max.y > 200.0);
+
+        // the first cut on its own fits
+        let mut gcode = Gcode::new();
+        gcode.add_cut(Cut::new(), &settings);
+        assert!(gcode.check_bounds(&table(), &Pos2D::new(10.0, 10.0)).is_within_limits());
+    }
+
+    // G54 at X10 Y10 and G55 at the machine's zero
+    fn offsets() -> CoordinateOffsets {
+        let mut offsets = CoordinateOffsets::default();
+        for line in ["[G54:10.000,10.000,0.000]", "[G55:0.000,0.000,0.000]", "[GC:G0 G54 G17 G21 G90 G94 M5 M9 T0 F0 S0]"] {
+            offsets.apply_report_line(line);
+        }
+        offsets
+    }
+
+    #[test]
+    fn checks_moves_in_machine_coordinates() {
+        // X95 in work coordinates is off the table, in machine coordinates it isn't
+        assert!(check_job_lines(&job_lines("G53 G0 X95 Y5\n"), &table(), &offsets()).is_ok());
+        assert_eq!(
+            check_job_lines(&job_lines("G0 X10 Y10\nG53 G0 X105\n"), &table(), &offsets()),
+            Err("Line 2 leaves the travel limits: X105.000 is outside 0.000 to 100.000".to_string())
+        );
+        assert!(check_job_lines(&job_lines("G0 X1\n"), &table(), &CoordinateOffsets::default()).is_err());
+    }
+
+    #[test]
+    fn refuses_programs_that_move_work_zero() {
+        // selecting the system that is active anyway is fine
+        assert!(check_job_lines(&job_lines("G21 G54\nG0 X1\n"), &table(), &offsets()).is_ok());
+        for program in ["G0 X1\nG55\nG0 X95", "G10 L20 P1 X0\n", "G92 X0 Y0\n", "G59.1\n"] {
+            assert!(
+                check_job_lines(&job_lines(program), &table(), &offsets()).unwrap_err().ends_with("changes the work offsets, the travel limits can't be checked"),
+                "{:?}",
+                program
+            );
+        }
+        // arc center mode isn't G92
+        assert!(check_job_lines(&job_lines("G91.1\nG0 X1\n"), &table(), &offsets()).is_ok());
+    }
+
+    #[test]
+    fn reads_grbl_soft_limits() {
+        let mut machine_settings = MachineSettings::default();
+        for line in ["$20=0", "$23=2", "$130=500.000", "$131=300.000"] {
+            machine_settings.apply_report_line(line);
+        }
+        assert_eq!(TravelLimits::from_machine_settings(&machine_settings), None);
+        machine_settings.apply_report_line("$20=1");
+        assert_eq!(
+            TravelLimits::from_machine_settings(&machine_settings),
+            Some(TravelLimits { x_min: -500.0, x_max: 0.0, y_min: 0.0, y_max: 300.0 })
+        );
+    }
+
+    #[test]
+    fn refuses_to_stream_a_program_off_the_table() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+        for command in ["$20=1", "$23=3", "$130=100", "$131=100", "G10 L2 P1 X10 Y10"] {
+            connection.send_string_command(command.to_string());
+        }
+        connection.read_machine_settings();
+        connection.read_coordinate_offsets();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while (TravelLimits::from_machine_settings(&connection.get_machine_settings()).is_none() || connection.get_coordinate_offsets().active.is_none()) && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        let (progress_tx, progress_rx) = crossbeam_channel::unbounded::<JobProgress>();
+        connection.start_job_with(job_lines("G0 X10 Y10\n(off the table)\nG1 X95 F500\n"), move |progress| {
+            let _ = progress_tx.send(progress);
+        });
+        let progress = loop {
+            let progress = progress_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+            if progress.state != JobState::Running {
+                break progress;
+            }
+        };
+        assert_eq!(progress.state, JobState::Failed);
+        assert_eq!(progress.error.as_deref(), Some("Line 3 leaves the travel limits: X105.000 is outside 0.000 to 100.000"));
+        assert!(!simulator.lock().unwrap().received_lines().iter().any(|line| line.starts_with("G0X10")));
+        connection.disconnect();
+    }
+}
@@ -0,0 +1,255 @@
+use crate::api::interpreter::parse_words;
+
+// the letters gcode uses for axes
+const AXIS_LETTERS: [char; 9] = ['X', 'Y', 'Z', 'A', 'B', 'C', 'U', 'V', 'W'];
+
+// what the controller reported about itself in its $I reply
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MachineCapabilities {
+    // e.g. 1.1h.20190825, None until $I was answered
+    pub version: Option<String>,
+    // e.g. grblHAL, plain grbl doesn't say
+    pub firmware: Option<String>,
+    // the compile time option letters of [OPT:], e.g. V for variable spindle
+    pub options: String,
+    pub planner_blocks: Option<u32>,
+    pub rx_buffer_size: Option<u32>,
+    // the axis letters, XYZ unless the controller says otherwise
+    pub axes: String,
+    // grblHAL's [NEWOPT:] and [PLUGIN:] entries, e.g. ETH, SD or THC
+    pub extended_options: Vec<String>,
+}
+
+impl MachineCapabilities {
+    // true once the controller answered $I
+    pub fn is_known(&self) -> bool {
+        self.version.is_some()
+    }
+
+    pub fn is_grblhal(&self) -> bool {
+        self.firmware.as_deref() == Some("grblHAL")
+    }
+
+    pub fn has_axis(&self, axis: char) -> bool {
+        self.axes.contains(axis.to_ascii_uppercase())
+    }
+
+    // a 4th axis that isn't linear, e.g. A
+    pub fn has_rotary_axis(&self) -> bool {
+        self.axes.chars().any(|axis| matches!(axis, 'A' | 'B' | 'C'))
+    }
+
+    pub fn has_variable_spindle(&self) -> bool {
+        self.options.contains('V')
+    }
+
+    pub fn has_mist_coolant(&self) -> bool {
+        self.options.contains('M')
+    }
+
+    // grblHAL builds can leave probing out, plain grbl always has it
+    pub fn has_probe(&self) -> bool {
+        !self.extended_options.iter().any(|option| option == "NOPROBE")
+    }
+
+    // torch height control
+    pub fn has_thc(&self) -> bool {
+        self.extended_options.iter().any(|option| {
+            let option = option.to_ascii_uppercase();
+            option == "THC" || option.contains("PLASMA")
+        })
+    }
+
+    // $I can be read more than once, e.g. after a reconnect
+    fn add_extended_option(&mut self, option: &str) {
+        if !self.extended_options.iter().any(|existing| existing == option) {
+            self.extended_options.push(option.to_string());
+        }
+    }
+
+    // update with a line of the $I reply, returns false if the line isn't about the build
+    pub(crate) fn apply_report_line(&mut self, line: &str) -> bool {
+        let Some(report) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) else {
+            return false;
+        };
+        let Some((name, value)) = report.split_once(':') else {
+            return false;
+        };
+        match name {
+            // [VER:1.1h.20190825:some build info]
+            "VER" => {
+                let version = value.split(':').next().unwrap_or(value);
+                self.version = Some(version.to_string());
+                if self.axes.is_empty() {
+                    self.axes = "XYZ".to_string();
+                }
+            },
+            // [OPT:VNM,15,128] and on grblHAL [OPT:VNMSL,35,1024,4,0] where the 4th field is the axis count
+            "OPT" => {
+                let mut fields = value.split(',');
+                self.options = fields.next().unwrap_or("").to_string();
+                self.planner_blocks = fields.next().and_then(|field| field.parse().ok());
+                self.rx_buffer_size = fields.next().and_then(|field| field.parse().ok());
+                if let Some(count) = fields.next().and_then(|field| field.parse::<usize>().ok()) {
+                    // [AXS:] has the real letters, this is only a guess
+                    if self.axes.len() != count {
+                        self.axes = AXIS_LETTERS[..count.min(AXIS_LETTERS.len())].iter().collect();
+                    }
+                }
+            },
+            // [AXS:4:XYZA]
+            "AXS" => {
+                let letters = value.split(':').nth(1).unwrap_or("");
+                self.axes = letters.to_ascii_uppercase();
+            },
+            "NEWOPT" => {
+                for option in value.split(',').filter(|option| !option.is_empty()) {
+                    self.add_extended_option(option);
+                }
+            },
+            // [PLUGIN:Plasma THC v0.01]
+            "PLUGIN" => self.add_extended_option(value),
+            "FIRMWARE" => self.firmware = Some(value.to_string()),
+            _ => return false,
+        }
+        true
+    }
+
+    // check that a program only uses what the controller has, lines are (0 based line, text)
+    pub fn check_program<'a>(&self, lines: impl IntoIterator<Item = (u32, &'a str)>) -> Result<(), String> {
+        // nothing to go by before $I was answered
+        if !self.is_known() {
+            return Ok(());
+        }
+        for (line_number, line) in lines {
+            for word in parse_words(line) {
+                if AXIS_LETTERS.contains(&word.letter) && !self.has_axis(word.letter) {
+                    return Err(format!("Line {} uses the {} axis, the controller only has {}", line_number + 1, word.letter, self.axes));
+                }
+                if word.letter == 'M' && word.value == 7.0 && !self.has_mist_coolant() {
+                    return Err(format!("Line {} uses mist coolant (M7), which the controller wasn't built with", line_number + 1));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::gcode::Gcode;
+    use crate::api::job::{job_lines, JobProgress, JobState};
+    use crate::api::simulator::{connect_to_simulator, GrblSimulator, SimulatorConfig};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    fn grbl_hal() -> MachineCapabilities {
+        let mut capabilities = MachineCapabilities::default();
+        for line in [
+            "[VER:1.1f.20230125:my cutter]",
+            "[OPT:VNMSL,35,1024,4,0]",
+            "[AXS:4:XYZA]",
+            "[NEWOPT:ENUMS,RT+,HOME,TC,ETH,SD]",
+            "[FIRMWARE:grblHAL]",
+            "[PLUGIN:Plasma THC v0.01]",
+        ] {
+            assert!(capabilities.apply_report_line(line));
+        }
+        capabilities
+    }
+
+    #[test]
+    fn parses_plain_grbl() {
+        let mut capabilities = MachineCapabilities::default();
+        assert!(capabilities.apply_report_line("[VER:1.1h.20190825:]"));
+        assert!(capabilities.apply_report_line("[OPT:V,15,128]"));
+        assert!(!capabilities.apply_report_line("[MSG:Caution: Unlocked]"));
+
+        assert_eq!(capabilities.version.as_deref(), Some("1.1h.20190825"));
+        assert_eq!(capabilities.axes, "XYZ");
+        assert_eq!((capabilities.planner_blocks, capabilities.rx_buffer_size), (Some(15), Some(128)));
+        assert!(capabilities.has_variable_spindle() && capabilities.has_probe());
+        assert!(!capabilities.has_rotary_axis() && !capabilities.has_mist_coolant() && !capabilities.has_thc() && !capabilities.is_grblhal());
+    }
+
+    #[test]
+    fn parses_grblhal() {
+        let capabilities = grbl_hal();
+        assert!(capabilities.is_grblhal());
+        assert_eq!(capabilities.axes, "XYZA");
+        assert!(capabilities.has_rotary_axis() && capabilities.has_mist_coolant() && capabilities.has_thc());
+        assert!(capabilities.extended_options.contains(&"ETH".to_string()));
+    }
+
+    #[test]
+    fn reading_the_build_info_again_adds_nothing() {
+        let mut capabilities = grbl_hal();
+        let options = capabilities.extended_options.clone();
+        assert!(capabilities.apply_report_line("[NEWOPT:ENUMS,RT+,HOME,TC,ETH,SD]"));
+        assert!(capabilities.apply_report_line("[PLUGIN:Plasma THC v0.01]"));
+        assert_eq!(capabilities.extended_options, options);
+    }
+
+    #[test]
+    fn refuses_programs_for_axes_the_board_lacks() {
+        let mut three_axis = MachineCapabilities::default();
+        three_axis.apply_report_line("[VER:1.1h.20190825:]");
+        three_axis.apply_report_line("[OPT:V,15,128]");
+
+        let rotary = ["G0 X0 Y0", "G1 X10 A90 F500"];
+        assert_eq!(
+            three_axis.check_program(rotary.iter().enumerate().map(|(i, line)| (i as u32, *line))),
+            Err("Line 2 uses the A axis, the controller only has XYZ".to_string())
+        );
+        assert!(grbl_hal().check_program(rotary.iter().enumerate().map(|(i, line)| (i as u32, *line))).is_ok());
+        assert!(three_axis.check_program([(0, "M7")]).is_err());
+        // nothing is known before $I was answered
+        assert!(MachineCapabilities::default().check_program([(0, "G0 A1")]).is_ok());
+
+        let gcode = Gcode::new();
+        assert!(gcode.check_capabilities(&three_axis).is_ok());
+    }
+
+    #[test]
+    fn turns_a_variable_spindle_fully_on() {
+        let mut gcode = Gcode::new();
+        gcode.set_plasma_enabled(true);
+        gcode.use_capabilities(&grbl_hal());
+        gcode.set_plasma_enabled(true);
+        let mut on_off = MachineCapabilities::default();
+        on_off.apply_report_line("[VER:1.1h.20190825:]");
+        on_off.apply_report_line("[OPT:N,15,128]");
+        gcode.use_capabilities(&on_off);
+        gcode.set_plasma_enabled(true);
+
+        let commands: Vec<&str> = gcode.gcode_string.lines().filter(|line| line.starts_with("M3")).map(|line| line.split("  ").next().unwrap()).collect();
+        assert_eq!(commands, ["M3", "M3 S1000", "M3"]);
+    }
+
+    #[test]
+    fn detects_capabilities_after_connecting_and_refuses_rotary_jobs() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !connection.get_capabilities().is_known() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(connection.get_capabilities().planner_blocks, Some(15));
+
+        let (progress_tx, progress_rx) = crossbeam_channel::unbounded::<JobProgress>();
+        connection.start_job_with(job_lines("G0 X1\nG1 A90 F100\n"), move |progress| {
+            let _ = progress_tx.send(progress);
+        });
+        let progress = loop {
+            let progress = progress_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+            if progress.state != JobState::Running {
+                break progress;
+            }
+        };
+        assert_eq!(progress.state, JobState::Failed);
+        assert_eq!(progress.error.as_deref(), Some("Line 2 uses the A axis, the controller only has XYZ"));
+        assert!(!simulator.lock().unwrap().received_lines().iter().any(|line| line.starts_with("G0X1")));
+        connection.disconnect();
+    }
+}
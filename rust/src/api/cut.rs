@@ -1,9 +1,12 @@
+use serde::{Deserialize, Serialize};
+
 use crate::api::gcode::calculate_end_pos;
 
 use super::gcode::Pos2D;
 
 
 // the cut struct has info like start and end positions, cut angle and so on
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[flutter_rust_bridge::frb(opaque)]
 pub struct Cut {
     pub start_position: Pos2D,
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crate::api::gcode::Pos2D;
+use crate::api::profile::{Profile, ProfileSegment};
+
+// mm per drawing unit for the $INSUNITS codes we expect to see, unitless drawings are taken as mm
+fn unit_scale(insunits: i32) -> Result<f32, String> {
+    match insunits {
+        0 | 4 => Ok(1.0),
+        1 => Ok(25.4),
+        2 => Ok(304.8),
+        5 => Ok(10.0),
+        6 => Ok(1000.0),
+        other => Err(format!("Unsupported drawing units ($INSUNITS {})", other)),
+    }
+}
+
+// the group code / value pairs of an ascii dxf file, with the line (0 based) each value is on
+fn read_pairs(contents: &str) -> Result<Vec<(i32, String, usize)>, String> {
+    let lines: Vec<&str> = contents.lines().map(|line| line.trim()).collect();
+    let mut pairs = Vec::new();
+    for (index, pair) in lines.chunks(2).enumerate() {
+        let line = index * 2;
+        // a trailing empty line is fine
+        if pair.len() < 2 && pair[0].is_empty() {
+            break;
+        }
+        let code = pair[0].parse::<i32>().map_err(|_| format!("Line {}: \"{}\" isn't a group code, is this an ascii DXF file?", line + 1, pair[0]))?;
+        let value = pair.get(1).ok_or(format!("Line {}: group code without a value", line + 1))?;
+        pairs.push((code, value.to_string(), line + 1));
+    }
+    Ok(pairs)
+}
+
+// one entity from the ENTITIES section, the values by group code in the order they appeared
+struct Entity {
+    kind: String,
+    values: Vec<(i32, String, usize)>,
+}
+
+impl Entity {
+    fn number(&self, code: i32) -> Result<Option<f32>, String> {
+        match self.values.iter().find(|(found, _, _)| *found == code) {
+            Some((_, value, line)) => value.parse::<f32>().map(Some).map_err(|_| format!("Line {}: \"{}\" isn't a number", line + 1, value)),
+            None => Ok(None),
+        }
+    }
+
+    fn required(&self, code: i32) -> Result<f32, String> {
+        let line = self.values.first().map_or(0, |(_, _, line)| *line);
+        self.number(code)?.ok_or(format!("Line {}: {} without group code {}", line + 1, self.kind, code))
+    }
+
+    // entities drawn from below have their x axis mirrored
+    fn mirrored(&self) -> Result<bool, String> {
+        Ok(self.number(230)?.unwrap_or(1.0) < 0.0)
+    }
+}
+
+fn arc_point(center: &Pos2D, radius: f32, degrees: f32) -> Pos2D {
+    let angle = degrees.to_radians();
+    Pos2D::new(center.x + radius * angle.cos(), center.y + radius * angle.sin())
+}
+
+// the segment between two polyline vertices, bulge is tan(included angle / 4), positive counterclockwise
+fn bulge_segment(start: &Pos2D, end: &Pos2D, bulge: f32) -> ProfileSegment {
+    if bulge.abs() < 1e-6 {
+        return ProfileSegment::Line { start: start.clone(), end: end.clone() };
+    }
+    // the center is off the middle of the chord, to the left for a positive bulge
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let factor = (1.0 - bulge * bulge) / (4.0 * bulge);
+    let center = Pos2D::new((start.x + end.x) / 2.0 - dy * factor, (start.y + end.y) / 2.0 + dx * factor);
+    ProfileSegment::Arc { start: start.clone(), end: end.clone(), center, clockwise: bulge < 0.0 }
+}
+
+fn entity_segments(entity: &Entity) -> Result<Vec<ProfileSegment>, String> {
+    let mirror = if entity.mirrored()? { -1.0 } else { 1.0 };
+    let point = |x: f32, y: f32| Pos2D::new(x * mirror, y);
+    // mirroring turns arcs the other way
+    let clockwise = mirror < 0.0;
+
+    match entity.kind.as_str() {
+        "LINE" => Ok(vec![ProfileSegment::Line {
+            start: point(entity.required(10)?, entity.required(20)?),
+            end: point(entity.required(11)?, entity.required(21)?),
+        }]),
+        "ARC" => {
+            let center = Pos2D::new(entity.required(10)?, entity.required(20)?);
+            let radius = entity.required(40)?;
+            let start = arc_point(&center, radius, entity.required(50)?);
+            let end = arc_point(&center, radius, entity.required(51)?);
+            Ok(vec![ProfileSegment::Arc { start: point(start.x, start.y), end: point(end.x, end.y), center: point(center.x, center.y), clockwise }])
+        },
+        "CIRCLE" => {
+            let center = Pos2D::new(entity.required(10)?, entity.required(20)?);
+            let start = arc_point(&center, entity.required(40)?, 0.0);
+            Ok(vec![ProfileSegment::Arc { start: point(start.x, start.y), end: point(start.x, start.y), center: point(center.x, center.y), clockwise }])
+        },
+        "LWPOLYLINE" => {
+            let closed = entity.number(70)?.unwrap_or(0.0) as u32 & 1 != 0;
+            // every 10 starts a vertex, the 20 and 42 after it belong to it
+            let mut vertices: Vec<(Pos2D, f32)> = Vec::new();
+            for (code, value, line) in &entity.values {
+                let number = || value.parse::<f32>().map_err(|_| format!("Line {}: \"{}\" isn't a number", line + 1, value));
+                match code {
+                    10 => vertices.push((point(number()?, 0.0), 0.0)),
+                    20 => {
+                        if let Some(vertex) = vertices.last_mut() {
+                            vertex.0.y = number()?;
+                        }
+                    },
+                    42 => {
+                        if let Some(vertex) = vertices.last_mut() {
+                            vertex.1 = number()? * mirror;
+                        }
+                    },
+                    _ => {},
+                }
+            }
+            let mut segments: Vec<ProfileSegment> = vertices.windows(2).map(|pair| bulge_segment(&pair[0].0, &pair[1].0, pair[0].1)).collect();
+            if closed && vertices.len() > 2 {
+                let (last, first) = (&vertices[vertices.len() - 1], &vertices[0]);
+                segments.push(bulge_segment(&last.0, &first.0, last.1));
+            }
+            Ok(segments)
+        },
+        // text, dimensions and the like aren't cut
+        _ => Ok(Vec::new()),
+    }
+}
+
+impl Profile {
+    // read the LINE, ARC, CIRCLE and LWPOLYLINE entities of an ascii dxf drawing, in mm
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn from_dxf(contents: String) -> Result<Profile, String> {
+        let pairs = read_pairs(&contents)?;
+
+        let mut section = String::new();
+        let mut header_variable = String::new();
+        let mut header: HashMap<String, String> = HashMap::new();
+        let mut entities: Vec<Entity> = Vec::new();
+        let mut found_entities = false;
+        for (index, (code, value, _)) in pairs.iter().enumerate() {
+            match (*code, value.as_str()) {
+                (0, "SECTION") => {
+                    section = pairs.get(index + 1).map(|(_, name, _)| name.clone()).unwrap_or_default();
+                    found_entities |= section == "ENTITIES";
+                },
+                (0, "ENDSEC") => section.clear(),
+                (9, variable) if section == "HEADER" => header_variable = variable.to_string(),
+                (_, value) if section == "HEADER" => {
+                    header.entry(header_variable.clone()).or_insert(value.to_string());
+                },
+                (0, kind) if section == "ENTITIES" => entities.push(Entity { kind: kind.to_string(), values: Vec::new() }),
+                _ if section == "ENTITIES" => {
+                    if let Some(entity) = entities.last_mut() {
+                        entity.values.push(pairs[index].clone());
+                    }
+                },
+                _ => {},
+            }
+        }
+        if !found_entities {
+            return Err("The file has no ENTITIES section".to_string());
+        }
+
+        let insunits = header.get("$INSUNITS").and_then(|value| value.parse::<i32>().ok()).unwrap_or(0);
+        let scale = unit_scale(insunits)?;
+        let mut segments = Vec::new();
+        for entity in &entities {
+            segments.extend(entity_segments(entity)?);
+        }
+        if segments.is_empty() {
+            return Err("The drawing has no lines, arcs, circles or polylines".to_string());
+        }
+
+        let profile = Profile::from_segments(segments);
+        Ok(if scale == 1.0 { profile } else { profile.scaled(scale) })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_dxf(path: String) -> Result<Profile, String> {
+        let contents = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        Profile::from_dxf(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::gcode::Gcode;
+    use crate::api::profile::CutParameters;
+    use crate::api::toolpath::SegmentKind;
+
+    // a dxf file with the given header variables and entities, each entity as (group code, value) pairs
+    fn dxf(header: &[(i32, &str)], entities: &[&[(i32, &str)]]) -> String {
+        let mut pairs: Vec<(i32, String)> = vec![(0, "SECTION".into()), (2, "HEADER".into())];
+        pairs.extend(header.iter().map(|(code, value)| (*code, value.to_string())));
+        pairs.extend([(0, "ENDSEC".into()), (0, "SECTION".into()), (2, "ENTITIES".into())]);
+        for entity in entities {
+            pairs.extend(entity.iter().map(|(code, value)| (*code, value.to_string())));
+        }
+        pairs.extend([(0, "ENDSEC".into()), (0, "EOF".into())]);
+        pairs.iter().map(|(code, value)| format!("{:>3}\r\n{}\r\n", code, value)).collect()
+    }
+
+    fn close(a: &Pos2D, b: &Pos2D) -> bool {
+        (a.x - b.x).abs() < 0.001 && (a.y - b.y).abs() < 0.001
+    }
+
+    #[test]
+    fn reads_lines_arcs_circles_and_polylines() {
+        // a 20 x 20 plate with rounded top corners drawn as loose lines and arcs, a round hole and a slot
+        let contents = dxf(
+            &[(9, "$INSUNITS"), (70, "4")],
+            &[
+                &[(0, "LINE"), (8, "0"), (10, "0"), (20, "0"), (11, "20"), (21, "0")],
+                &[(0, "LINE"), (10, "20"), (20, "0"), (11, "20"), (21, "15")],
+                &[(0, "ARC"), (10, "15"), (20, "15"), (40, "5"), (50, "0"), (51, "90")],
+                &[(0, "LINE"), (10, "15"), (20, "20"), (11, "5"), (21, "20")],
+                &[(0, "ARC"), (10, "5"), (20, "15"), (40, "5"), (50, "90"), (51, "180")],
+                &[(0, "LINE"), (10, "0"), (20, "15"), (11, "0"), (21, "0")],
+                &[(0, "CIRCLE"), (10, "10"), (20, "6"), (40, "2")],
+                &[(0, "LWPOLYLINE"), (90, "4"), (70, "1"), (10, "6"), (20, "12"), (10, "14"), (20, "12"), (42, "1"), (10, "14"), (20, "14"), (10, "6"), (20, "14"), (42, "1")],
+                &[(0, "TEXT"), (10, "0"), (20, "0"), (1, "part 7")],
+            ],
+        );
+        let profile = Profile::from_dxf(contents).unwrap();
+        assert_eq!(profile.contours.len(), 3);
+        assert!(profile.contours.iter().all(|contour| contour.closed));
+        // the outline goes last
+        assert_eq!(profile.contours[2].segments.len(), 6);
+        let (min, max) = profile.bounds().unwrap();
+        assert!(close(&min, &Pos2D::new(0.0, 0.0)) && close(&max, &Pos2D::new(20.0, 20.0)));
+
+        // the slot's ends are half circles
+        let slot = profile.contours.iter().find(|contour| contour.segments.len() == 4).unwrap();
+        let ProfileSegment::Arc { center, clockwise, .. } = &slot.segments[1] else { panic!("not an arc") };
+        assert!(close(center, &Pos2D::new(14.0, 13.0)) && !clockwise);
+
+        let mut gcode = Gcode::new();
+        gcode.add_profile(&profile, &CutParameters { feedrate: 1000.0, pierce_delay: 0.5 });
+        let toolpath = gcode.get_toolpath();
+        assert_eq!(toolpath.iter().filter(|segment| segment.kind == SegmentKind::TorchOn).count(), 3);
+        assert_eq!(toolpath.iter().filter(|segment| matches!(segment.kind, SegmentKind::Arc { .. })).count(), 5);
+    }
+
+    #[test]
+    fn scales_inches_and_mirrors_entities_drawn_from_below() {
+        let contents = dxf(
+            &[(9, "$ACADVER"), (1, "AC1015"), (9, "$INSUNITS"), (70, "1")],
+            &[&[(0, "ARC"), (10, "1"), (20, "0"), (40, "1"), (50, "0"), (51, "90"), (210, "0"), (220, "0"), (230, "-1")]],
+        );
+        let profile = Profile::from_dxf(contents).unwrap();
+        let ProfileSegment::Arc { start, end, center, clockwise } = &profile.contours[0].segments[0] else { panic!("not an arc") };
+        assert!(close(center, &Pos2D::new(-25.4, 0.0)));
+        assert!(close(start, &Pos2D::new(-50.8, 0.0)) && close(end, &Pos2D::new(-25.4, 25.4)));
+        assert!(clockwise);
+    }
+
+    #[test]
+    fn explains_what_it_cant_read() {
+        assert_eq!(Profile::from_dxf("0\nSECTION\n2\nHEADER\n0\nENDSEC\n".to_string()), Err("The file has no ENTITIES section".to_string()));
+        assert!(Profile::from_dxf("AutoCAD Binary DXF".to_string()).unwrap_err().contains("ascii DXF"));
+        assert_eq!(
+            Profile::from_dxf(dxf(&[], &[&[(0, "TEXT"), (1, "hello")]])),
+            Err("The drawing has no lines, arcs, circles or polylines".to_string())
+        );
+        assert!(Profile::from_dxf(dxf(&[], &[&[(0, "LINE"), (10, "zero")]])).unwrap_err().contains("isn't a number"));
+    }
+}
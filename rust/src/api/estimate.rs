@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::gcode::Gcode;
+use crate::api::machine_settings::MachineSettings;
+use crate::api::toolpath::{simulate_toolpath, SegmentKind, ToolpathSegment};
+
+// how fast each axis can go, the same numbers as grbl's $110/$111 and $120/$121
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MotionLimits {
+    // mm/min
+    pub x_max_rate: f32,
+    pub y_max_rate: f32,
+    // mm/s²
+    pub x_acceleration: f32,
+    pub y_acceleration: f32,
+}
+
+impl Default for MotionLimits {
+    fn default() -> Self {
+        Self { x_max_rate: 5000.0, y_max_rate: 5000.0, x_acceleration: 500.0, y_acceleration: 500.0 }
+    }
+}
+
+impl MotionLimits {
+    // the limits from the controller's $$ reply, anything it didn't send keeps the default
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn from_machine_settings(machine_settings: &MachineSettings) -> Self {
+        let value = |id: u32, default: f32| {
+            machine_settings.get(id).and_then(|setting| setting.value.parse::<f32>().ok()).filter(|value| *value > 0.0).unwrap_or(default)
+        };
+        let defaults = Self::default();
+        Self {
+            x_max_rate: value(110, defaults.x_max_rate),
+            y_max_rate: value(111, defaults.y_max_rate),
+            x_acceleration: value(120, defaults.x_acceleration),
+            y_acceleration: value(121, defaults.y_acceleration),
+        }
+    }
+
+    // the speed (mm/s) and acceleration (mm/s²) along a direction, the slowest axis that moves sets the pace
+    fn along(&self, dx: f32, dy: f32) -> (f32, f32) {
+        let length = (dx * dx + dy * dy).sqrt();
+        // arcs turn through every direction
+        let (ux, uy) = if length > 0.0 { (dx.abs() / length, dy.abs() / length) } else { (1.0, 1.0) };
+        let limit = |x_limit: f32, y_limit: f32| {
+            let x = if ux > 0.0 { x_limit / ux } else { f32::INFINITY };
+            let y = if uy > 0.0 { y_limit / uy } else { f32::INFINITY };
+            x.min(y)
+        };
+        (limit(self.x_max_rate, self.y_max_rate) / 60.0, limit(self.x_acceleration, self.y_acceleration))
+    }
+}
+
+// how long a set of consumables (electrode and nozzle) lasts, from the torch's datasheet
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ConsumableRatings {
+    pub pierces: u32,
+    // seconds of arc time
+    pub arc_time: f32,
+}
+
+impl Default for ConsumableRatings {
+    fn default() -> Self {
+        Self { pierces: 300, arc_time: 3600.0 }
+    }
+}
+
+// what running a program takes, times are in seconds and lengths in mm
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JobEstimate {
+    pub total_time: f32,
+    // moves with the torch on
+    pub cut_time: f32,
+    // moves with the torch off, G0 or not
+    pub rapid_time: f32,
+    pub dwell_time: f32,
+    pub cut_length: f32,
+    pub rapid_length: f32,
+    pub pierce_count: u32,
+    // how long the torch is on, including the pierce delays
+    pub arc_time: f32,
+    // the share of a set of consumables the job uses up, 1.0 is a whole set
+    pub consumable_wear: f32,
+}
+
+// every move is taken to start and end standing still, so the estimate errs on the long side
+fn move_time(distance: f32, speed: f32, acceleration: f32) -> f32 {
+    if distance <= 0.0 || speed <= 0.0 {
+        return 0.0;
+    }
+    // the distance it takes to get up to speed and back down
+    let ramps = speed * speed / acceleration;
+    if distance >= ramps {
+        distance / speed + speed / acceleration
+    } else {
+        // never gets to full speed
+        2.0 * (distance / acceleration).sqrt()
+    }
+}
+
+pub fn estimate_segments(segments: &[ToolpathSegment], limits: &MotionLimits, ratings: &ConsumableRatings) -> JobEstimate {
+    let mut estimate = JobEstimate::default();
+
+    for segment in segments {
+        let (max_speed, acceleration) = match segment.kind {
+            SegmentKind::Arc { .. } => limits.along(0.0, 0.0),
+            _ => limits.along(segment.end.x - segment.start.x, segment.end.y - segment.start.y),
+        };
+        let length = segment.length();
+        let time = match &segment.kind {
+            SegmentKind::Rapid | SegmentKind::Cut | SegmentKind::Arc { .. } => {
+                let speed = match segment.kind {
+                    SegmentKind::Rapid => max_speed,
+                    // grbl refuses feed moves without a feedrate, so the fallback only matters for broken programs
+                    _ => segment.feedrate.map_or(max_speed, |feedrate| (feedrate / 60.0).min(max_speed)),
+                };
+                let time = move_time(length, speed, acceleration);
+                // feed moves with the torch off are only getting somewhere, like a rapid
+                if segment.torch_on {
+                    estimate.cut_time += time;
+                    estimate.cut_length += length;
+                } else {
+                    estimate.rapid_time += time;
+                    estimate.rapid_length += length;
+                }
+                time
+            },
+            SegmentKind::Dwell { seconds } => {
+                estimate.dwell_time += seconds;
+                *seconds
+            },
+            SegmentKind::TorchOn => {
+                estimate.pierce_count += 1;
+                0.0
+            },
+            SegmentKind::TorchOff => 0.0,
+        };
+        estimate.total_time += time;
+        if segment.torch_on {
+            estimate.arc_time += time;
+        }
+    }
+
+    // both pierces and arc time wear the electrode, each uses up its share of the rated life
+    if ratings.pierces > 0 {
+        estimate.consumable_wear += estimate.pierce_count as f32 / ratings.pierces as f32;
+    }
+    if ratings.arc_time > 0.0 {
+        estimate.consumable_wear += estimate.arc_time / ratings.arc_time;
+    }
+    estimate
+}
+
+impl Gcode {
+    // how long the program runs and what it costs in consumables
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn estimate(&self, limits: &MotionLimits, ratings: &ConsumableRatings) -> JobEstimate {
+        estimate_segments(&simulate_toolpath(self.get_gcode_string()), limits, ratings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cut::Cut;
+    use crate::api::settings::CutterSettings;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 0.001
+    }
+
+    #[test]
+    fn adds_up_moves_dwells_and_pierces() {
+        let limits = MotionLimits { x_max_rate: 6000.0, y_max_rate: 3000.0, x_acceleration: 100.0, y_acceleration: 50.0 };
+        let ratings = ConsumableRatings { pierces: 100, arc_time: 100.0 };
+        let segments = simulate_toolpath("G0 X200\nM3\nG4 P0.5\nG1 X260 F600\nM5\nG0 Y1\n".to_string());
+        let estimate = estimate_segments(&segments, &limits, &ratings);
+
+        // 100mm/s with 100mm of ramps loses 1s on top of the 2s at full speed, the 1mm in Y never gets up to speed
+        assert!(close(estimate.rapid_time, 3.0 + 2.0 * (1.0 / 50.0_f32).sqrt()));
+        // 10mm/s, 1mm of ramps
+        assert!(close(estimate.cut_time, 6.1));
+        assert!(close(estimate.dwell_time, 0.5));
+        assert!(close(estimate.total_time, estimate.rapid_time + 6.6));
+        assert_eq!((estimate.cut_length, estimate.rapid_length, estimate.pierce_count), (60.0, 201.0, 1));
+        assert!(close(estimate.arc_time, 6.6));
+        assert!(close(estimate.consumable_wear, 0.01 + 0.066));
+    }
+
+    #[test]
+    fn reads_the_limits_from_the_controller() {
+        let mut machine_settings = MachineSettings::default();
+        machine_settings.apply_report_line("$110=3000.000");
+        machine_settings.apply_report_line("$121=250.000");
+        let limits = MotionLimits::from_machine_settings(&machine_settings);
+        assert_eq!(limits, MotionLimits { x_max_rate: 3000.0, y_acceleration: 250.0, ..Default::default() });
+    }
+
+    #[test]
+    fn estimates_a_generated_cut() {
+        let mut gcode = Gcode::new();
+        gcode.add_cut(Cut::new(), &CutterSettings::default());
+        let estimate = gcode.estimate(&MotionLimits::default(), &ConsumableRatings::default());
+
+        // a split cut pierces in the middle twice and cuts out to each side, with 1mm of overshoot
+        assert_eq!(estimate.pierce_count, 2);
+        assert!(close(estimate.cut_length, 26.0));
+        assert!(close(estimate.rapid_length, 26.0));
+        assert!(estimate.dwell_time >= 2.75);
+        assert!(estimate.total_time > estimate.cut_time + estimate.dwell_time);
+    }
+}
@@ -0,0 +1,221 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::api::gcode::Gcode;
+use crate::api::job_plan::{JobPlan, Stock};
+use crate::api::report::format_duration;
+use crate::api::settings::CutterSettings;
+
+// the controller the program is exported for, which decides the file extension
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PostProcessor {
+    // grbl senders, .nc
+    Grbl,
+    // mach3 and mach4, .tap
+    Mach3,
+    // linuxcnc, .ngc
+    LinuxCnc,
+}
+
+impl PostProcessor {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn extension(&self) -> String {
+        match self {
+            PostProcessor::Grbl => "nc",
+            PostProcessor::Mach3 => "tap",
+            PostProcessor::LinuxCnc => "ngc",
+        }
+        .to_string()
+    }
+}
+
+// what goes in the comment block at the top of an exported program
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportHeader {
+    pub job_name: String,
+    pub stock: Option<Stock>,
+}
+
+// the path with the post-processor's extension, e.g. frame -> frame.nc
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_path(path: String, post_processor: PostProcessor) -> String {
+    Path::new(&path).with_extension(post_processor.extension()).to_string_lossy().to_string()
+}
+
+// write to a temporary file next to the target and rename it over, so a failed write never leaves half a file
+pub(crate) fn write_atomically(path: &str, contents: &str) -> Result<(), String> {
+    let temporary = format!("{}.tmp", path);
+    let written = fs::File::create(&temporary)
+        .and_then(|mut file| {
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&temporary, path));
+    written.map_err(|e| {
+        let _ = fs::remove_file(&temporary);
+        format!("Failed to write {}: {}", path, e)
+    })
+}
+
+// e.g. 2024-03-05 14:07 UTC
+fn format_date(time: SystemTime) -> String {
+    let seconds = time.duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0);
+    let (days, minutes) = ((seconds / 86400) as i64, (seconds % 86400) / 60);
+    // days since 1970 to a civil date, from howard hinnant's date algorithms
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02} {:02}:{:02} UTC", year, month, day, minutes / 60, minutes % 60)
+}
+
+// parentheses would end the comment early
+fn comment(text: &str) -> String {
+    format!("({})\n", text.replace('(', "[").replace(')', "]").replace(['\n', '\r'], " "))
+}
+
+impl Gcode {
+    // the comment block describing the program
+    fn header_block(&self, header: &ExportHeader, cutter_settings: &CutterSettings, date: &str) -> String {
+        let estimate = self.estimate(&cutter_settings.motion_limits, &cutter_settings.consumable_ratings);
+        let mut block = comment(&format!("Generated by Tube Cutter {}", env!("CARGO_PKG_VERSION")));
+        block += &comment(&format!("Date: {}", date));
+        block += &comment(&format!("Job: {}", header.job_name));
+        if let Some(stock) = &header.stock {
+            block += &comment(&format!("Stock: {}, {}mm wide, {}mm long", stock.material, stock.tube_width, stock.length));
+        }
+        block += &comment(&format!("Estimated time: {}", format_duration(estimate.total_time)));
+        if let Some(extents) = self.get_extents() {
+            block += &comment(&format!(
+                "Bounds: X {:.3} to {:.3}, Y {:.3} to {:.3}",
+                extents.min.x, extents.max.x, extents.min.y, extents.max.y
+            ));
+        }
+        block
+    }
+
+    // write the gcode to a specified file
+    pub fn write_to_file(&self, filename: String) -> Result<(), String> {
+        write_atomically(&filename, &self.gcode_string)
+    }
+
+    // write the program with the post-processor's extension and optionally a header, returns where it went
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export(&self, path: String, post_processor: PostProcessor, header: Option<ExportHeader>, cutter_settings: &CutterSettings) -> Result<String, String> {
+        let path = export_path(path, post_processor);
+        let mut contents = match header {
+            Some(header) => self.header_block(&header, cutter_settings, &format_date(SystemTime::now())),
+            None => String::new(),
+        };
+        contents += &self.gcode_string;
+        write_atomically(&path, &contents)?;
+        Ok(path)
+    }
+}
+
+impl JobPlan {
+    // export the job's program with a header naming the job and its stock
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export(&self, path: String, post_processor: PostProcessor, cutter_settings: &CutterSettings) -> Result<String, String> {
+        let header = ExportHeader { job_name: self.name.clone(), stock: Some(self.stock.clone()) };
+        self.to_gcode(cutter_settings).export(path, post_processor, Some(header), cutter_settings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cut::Cut;
+    use crate::api::gcode::Pos2D;
+    use std::time::Duration;
+
+    fn plan() -> JobPlan {
+        let mut plan = JobPlan::new("Rail (left)".to_string(), Stock { material: "30x30 steel".to_string(), tube_width: 30.0, length: 600.0 });
+        for y in [0.0, 500.0] {
+            let mut cut = Cut::new();
+            cut.start_position = Pos2D::new(0.0, y);
+            plan.add_cut(cut);
+        }
+        plan
+    }
+
+    fn directory(name: &str) -> std::path::PathBuf {
+        let directory = std::env::temp_dir().join(format!("tube_cutter_export_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn picks_the_extension_for_the_post_processor() {
+        assert_eq!(export_path("jobs/rail".to_string(), PostProcessor::Grbl), "jobs/rail.nc");
+        assert_eq!(export_path("jobs/rail.nc".to_string(), PostProcessor::Mach3), "jobs/rail.tap");
+        assert_eq!(export_path("rail.txt".to_string(), PostProcessor::LinuxCnc), "rail.ngc");
+    }
+
+    #[test]
+    fn formats_dates_in_utc() {
+        assert_eq!(format_date(UNIX_EPOCH), "1970-01-01 00:00 UTC");
+        assert_eq!(format_date(UNIX_EPOCH + Duration::from_secs(1709647620)), "2024-03-05 14:07 UTC");
+        assert_eq!(format_date(UNIX_EPOCH + Duration::from_secs(951782400)), "2000-02-29 00:00 UTC");
+    }
+
+    #[test]
+    fn describes_the_program_in_a_header() {
+        let plan = plan();
+        let settings = CutterSettings::default();
+        let gcode = plan.to_gcode(&settings);
+        let header = ExportHeader { job_name: plan.name.clone(), stock: Some(plan.stock.clone()) };
+        let block = gcode.header_block(&header, &settings, "2024-03-05 14:07 UTC");
+        let lines: Vec<&str> = block.lines().collect();
+        assert_eq!(lines[0], format!("(Generated by Tube Cutter {})", env!("CARGO_PKG_VERSION")));
+        assert_eq!(lines[1], "(Date: 2024-03-05 14:07 UTC)");
+        assert_eq!(lines[2], "(Job: Rail [left])");
+        assert_eq!(lines[3], "(Stock: 30x30 steel, 30mm wide, 600mm long)");
+        assert!(lines[4].starts_with("(Estimated time: "));
+        assert!(lines[5].starts_with("(Bounds: X "));
+        assert!(lines.iter().all(|line| line.starts_with('(') && line.ends_with(')') && line.matches(')').count() == 1));
+    }
+
+    #[test]
+    fn exports_with_and_without_a_header() {
+        let directory = directory("header");
+        let plan = plan();
+        let settings = CutterSettings::default();
+        let base = directory.join("rail").to_string_lossy().to_string();
+
+        let exported = plan.export(base.clone(), PostProcessor::LinuxCnc, &settings).unwrap();
+        assert!(exported.ends_with("rail.ngc"));
+        let contents = fs::read_to_string(&exported).unwrap();
+        assert!(contents.starts_with("(Generated by Tube Cutter"));
+        assert!(contents.ends_with(&plan.to_gcode(&settings).get_gcode_string()));
+
+        let gcode = plan.to_gcode(&settings);
+        let plain = gcode.export(base, PostProcessor::Grbl, None, &settings).unwrap();
+        assert_eq!(fs::read_to_string(&plain).unwrap(), gcode.get_gcode_string());
+        // no temporary files left behind
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 2);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn reports_failed_writes() {
+        let gcode = plan().to_gcode(&CutterSettings::default());
+        assert!(gcode.write_to_file("/nonexistent/dir/rail.nc".to_string()).unwrap_err().starts_with("Failed to write /nonexistent/dir/rail.nc"));
+
+        // a failed write leaves what was there before
+        let directory = directory("atomic");
+        let path = directory.join("rail.nc").to_string_lossy().to_string();
+        gcode.write_to_file(path.clone()).unwrap();
+        fs::create_dir(format!("{}.tmp", path)).unwrap();
+        assert!(Gcode::new().write_to_file(path.clone()).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), gcode.get_gcode_string());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -1,40 +1,43 @@
-use std::{fs, path::PathBuf};
+use serde::{Deserialize, Serialize};
 
+use super::capabilities::MachineCapabilities;
 use super::cut::Cut;
-use super::settings::{CutterSettings, CutMethod};
+use super::jog::jog_line;
+use super::render::Viewport;
+use super::wcs::{set_position_command, Axis, WorkCoordinateSystem};
+use super::settings::CutterSettings;
 
 pub fn calculate_end_pos(start_pos: &Pos2D, tube_width: f32, cut_angle: f32, overshoot_amount: f32, cut_right: bool) -> Pos2D {
-    let mut end_x = 0.0;
-    if cut_right {
-        end_x = start_pos.x + tube_width + overshoot_amount;
+    let end_x = if cut_right {
+        start_pos.x + tube_width + overshoot_amount
     } else {
-        end_x = start_pos.x - tube_width - overshoot_amount;
-    } 
+        start_pos.x - tube_width - overshoot_amount
+    };
     
-    let mut end_y = start_pos.y.clone();
+    let mut end_y = start_pos.y;
     
     if cut_angle != 0.0 {
         end_y = (tube_width + overshoot_amount) / cut_angle.to_radians().tan();
     }
 
-    return Pos2D::new(end_x, end_y);
+    Pos2D::new(end_x, end_y)
 }
 
 pub fn get_midpoint(start_pos: &Pos2D, end_pos: &Pos2D) -> Pos2D {
     let avg_x = (start_pos.x + end_pos.x) / 2.0;
     let avg_y = (start_pos.y + end_pos.y) / 2.0;
 
-    return Pos2D::new(avg_x, avg_y);
+    Pos2D::new(avg_x, avg_y)
 }
 // units enum
-#[derive(PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum DistUnit {
     Metric, // mm
     Imperial, // inches
 }
 
 // positioning mode enum, has absolute and relative
-
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PositioningMode {
     Absolute,
     Relative,
@@ -43,9 +46,16 @@ pub enum PositioningMode {
 #[flutter_rust_bridge::frb(opaque)]
 pub struct Gcode {
     pub gcode_string: String,
+    // the line (0 based) each cut starts on, so a job can be restarted from a cut
+    cut_start_lines: Vec<u32>,
+    // the S word to turn the torch on with, only for controllers with a variable spindle
+    torch_on_speed: Option<u32>,
 }
 
-#[derive(Clone)]
+// grbl's default $30, so the spindle PWM output is fully on
+const TORCH_ON_SPEED: u32 = 1000;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[flutter_rust_bridge::frb(opaque)]
 pub struct Pos2D {
     pub x: f32,
@@ -57,17 +67,14 @@ impl Pos2D {
         Self { x, y }
     }
     pub fn to_screen_space(&self, tube_width: &f32, scale_factor: &f32) -> Pos2D {
-        // the origin is offset to the left by half the tube with * the scale factor
-        let origin = Pos2D::new(-(tube_width/2.0) *scale_factor, 0.0); // origin screen space
-        let screen_position = Pos2D::new((self.x * scale_factor) + origin.x, self.y*scale_factor);
-        return screen_position;
+        Viewport::centered_on_tube(*tube_width, *scale_factor).to_screen(self)
     }
 }
 
 impl Gcode {
     #[flutter_rust_bridge::frb(sync)]
     pub fn new() -> Self {
-        let mut gcode = Gcode { gcode_string: String::new() };
+        let mut gcode = Gcode { gcode_string: String::new(), cut_start_lines: Vec::new(), torch_on_speed: None };
         gcode.set_units_to_mm();
         gcode.set_positioning_mode(PositioningMode::Absolute);
         return gcode;
@@ -108,7 +115,16 @@ impl Gcode {
         self.add_command_comment(g_command, g_comment);
     }
 
+    // arc to the given position around a center given relative to the current position
+    pub fn arc_xy(&mut self, new_pos: &Pos2D, center_offset: &Pos2D, clockwise: bool, feedrate: f32) {
+        let g_word = if clockwise { "G2" } else { "G3" };
+        let g_command = format!("{} X{} Y{} I{} J{} F{}", g_word, new_pos.x, new_pos.y, center_offset.x, center_offset.y, feedrate);
+        let g_comment = format!("arc to X: {}, Y: {} with feedrate: {}", new_pos.x, new_pos.y, feedrate);
+        self.add_command_comment(g_command, g_comment);
+    }
+
     // home command, moves toolhead to 0, 0
+    #[allow(non_snake_case)]
     pub fn home2D(&mut self, feedrate: f32) {
         self.move_xy(&Pos2D::new(0.0, 0.0), feedrate);
     }
@@ -122,20 +138,62 @@ impl Gcode {
 
     pub fn set_plasma_enabled(&mut self, enabled: bool) {
         if enabled {
-            self.add_command_comment("M3".to_owned(), "set plasma enabled".to_owned());
+            // with a variable spindle M3 alone runs at the last S, which is 0 after a reset
+            let command = match self.torch_on_speed {
+                Some(speed) => format!("M3 S{}", speed),
+                None => "M3".to_owned(),
+            };
+            self.add_command_comment(command, "set plasma enabled".to_owned());
         } else {
             self.add_command_comment("M5".to_owned(), "set plasma disabled".to_owned());
         }
     }
 
-    // sets machine's 0 to current position
+    // sets the active work coordinate system's 0 to current position
     pub fn set_zero(&mut self) {
-        self.add_command_comment("G10 P0 L20 X0 Y0 Z0".to_owned(), "set machine zero".to_owned());
+        let command = set_position_command(None, &[(Axis::X, 0.0), (Axis::Y, 0.0), (Axis::Z, 0.0)]);
+        self.add_command_comment(command, "set work zero".to_owned());
+    }
+
+    // run the rest of the program in another work coordinate system
+    pub fn set_work_coordinate_system(&mut self, wcs: WorkCoordinateSystem) {
+        self.add_command_comment(wcs.gcode().to_owned(), "select work coordinates".to_owned());
     }
     
-    // write the gcode to a specified file
-    pub fn write_to_file(&self, filename: String) {
-        fs::write(filename, &self.gcode_string);
+    // generate for the connected controller from here on
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn use_capabilities(&mut self, capabilities: &MachineCapabilities) {
+        self.torch_on_speed = capabilities.has_variable_spindle().then_some(TORCH_ON_SPEED);
+    }
+
+    // check the program only uses what the controller has
+    pub fn check_capabilities(&self, capabilities: &MachineCapabilities) -> Result<(), String> {
+        capabilities.check_program(self.gcode_string.lines().enumerate().map(|(i, line)| (i as u32, line)))
+    }
+
+    // number of lines in the program
+    pub fn line_count(&self) -> u32 {
+        self.gcode_string.lines().count() as u32
+    }
+
+    // the line (0 based) the given cut starts on
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_cut_start_line(&self, cut_index: u32) -> Option<u32> {
+        self.cut_start_lines.get(cut_index as usize).copied()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_cut_count(&self) -> u32 {
+        self.cut_start_lines.len() as u32
+    }
+
+    // the lines added from here on belong to a new cut
+    pub(crate) fn begin_cut(&mut self) {
+        self.cut_start_lines.push(self.line_count());
+    }
+
+    pub(crate) fn cut_start_lines(&self) -> &[u32] {
+        &self.cut_start_lines
     }
 
     // add a cut to the gcode
@@ -143,9 +201,14 @@ impl Gcode {
     #[flutter_rust_bridge::frb(sync)]
     pub fn add_cut(&mut self, tube_cut: Cut, cutter_settings: &CutterSettings) {
         if cutter_settings.clear_existing_gcode {
+            // the controller stays the same
+            let torch_on_speed = self.torch_on_speed;
             *self = Gcode::new();
+            self.torch_on_speed = torch_on_speed;
 
         }
+        self.begin_cut();
+
         // calculate the end position
 
         let mut real_start = Pos2D::new(tube_cut.start_position.x, tube_cut.start_position.y);
@@ -154,10 +217,10 @@ impl Gcode {
 
         // if using the laser pointer, adjust the start position
         if cutter_settings.use_laser {
-            real_start.x = real_start.x + cutter_settings.laser_offset_x;
-            real_start.y = real_start.y + cutter_settings.laser_offset_y;
-            end_position.x = end_position.x + cutter_settings.laser_offset_x;
-            end_position.y = end_position.y + cutter_settings.laser_offset_y;
+            real_start.x += cutter_settings.laser_offset_x;
+            real_start.y += cutter_settings.laser_offset_y;
+            end_position.x += cutter_settings.laser_offset_x;
+            end_position.y += cutter_settings.laser_offset_y;
         }
 
 
@@ -203,5 +266,5 @@ impl Gcode {
 
 #[flutter_rust_bridge::frb(sync)]
 pub fn jog(x_dist: f32, y_dist: f32, cutter_settings: &CutterSettings) -> String {
-    format!("$J=G91 G21 X{} Y{} F{}\n?", x_dist, y_dist, cutter_settings.jog_speed)
+    format!("{}\n?", jog_line(x_dist, y_dist, cutter_settings.jog_speed))
 }
\ No newline at end of file
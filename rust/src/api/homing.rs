@@ -0,0 +1,108 @@
+use crate::api::status::MachineState;
+
+// which axes a homing cycle runs on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HomingAxes {
+    All,
+    X,
+    Y,
+}
+
+impl HomingAxes {
+    pub(crate) fn command(&self) -> &'static str {
+        match self {
+            HomingAxes::All => "$H",
+            HomingAxes::X => "$HX",
+            HomingAxes::Y => "$HY",
+        }
+    }
+}
+
+// a rapid move that is only sent once the machine reports it's idle
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MoveTarget {
+    // X0 Y0 of the active work coordinate system
+    WorkZero,
+    // a position in machine coordinates (relative to the home position)
+    MachinePosition(f32, f32),
+}
+
+impl MoveTarget {
+    pub(crate) fn command(&self) -> String {
+        match self {
+            MoveTarget::WorkZero => "G90 G0 X0 Y0".to_string(),
+            // G53 is always absolute and only applies to this line
+            MoveTarget::MachinePosition(x, y) => format!("G53 G0 X{} Y{}", x, y),
+        }
+    }
+}
+
+// grbl prints this after a reset (and grblHAL raises alarm 11) while it's locked until the machine is homed
+pub(crate) fn is_homing_required(line: &str) -> bool {
+    line == "ALARM:11" || line.starts_with("[MSG:'$H'|'$X' to unlock]")
+}
+
+// why the machine can't move right now, None if it can
+pub(crate) fn move_blocked_reason(state: &MachineState, homing_required: bool) -> Option<String> {
+    match state {
+        MachineState::Idle => None,
+        MachineState::Alarm if homing_required => Some("The machine has to be homed first".to_string()),
+        MachineState::Alarm => Some("The machine is in an alarm state, unlock it first".to_string()),
+        MachineState::Door => Some("The safety door is open".to_string()),
+        MachineState::Home => Some("The machine is homing".to_string()),
+        _ => Some("The machine is busy".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simulator::{connect_to_simulator, GrblSimulator, SimulatorConfig};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    fn wait_until(condition: impl Fn() -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if condition() {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        false
+    }
+
+    #[test]
+    fn refuses_to_move_until_homed() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig { homing_required: true, time_scale: 100.0, ..Default::default() })));
+        let mut connection = connect_to_simulator(simulator.clone());
+        assert!(wait_until(|| connection.is_homing_required()));
+
+        connection.go_to_machine_position(10.0, 20.0);
+        std::thread::sleep(Duration::from_millis(200));
+        assert!(!simulator.lock().unwrap().received_lines().iter().any(|line| line.starts_with("G53")));
+
+        connection.run_homing_cycle(HomingAxes::All);
+        assert!(wait_until(|| !connection.is_homing_required()));
+
+        connection.go_to_machine_position(10.0, 20.0);
+        assert!(wait_until(|| simulator.lock().unwrap().position().y == 20.0));
+        connection.disconnect();
+    }
+
+    #[test]
+    fn homes_a_single_axis() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig { time_scale: 100.0, ..Default::default() })));
+        simulator.lock().unwrap().receive(b"G0 X5 Y5\n");
+        let mut connection = connect_to_simulator(simulator.clone());
+
+        std::thread::sleep(Duration::from_millis(100));
+        connection.run_homing_cycle(HomingAxes::Y);
+        assert!(wait_until(|| simulator.lock().unwrap().position().y == 0.0));
+        assert_eq!(simulator.lock().unwrap().position().x, 5.0);
+
+        connection.go_to_work_zero();
+        assert!(wait_until(|| simulator.lock().unwrap().position().x == 0.0));
+        connection.disconnect();
+    }
+}
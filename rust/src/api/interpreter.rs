@@ -0,0 +1,282 @@
+use super::gcode::{DistUnit, Pos2D, PositioningMode};
+
+const MM_PER_INCH: f32 = 25.4;
+
+// a single word of a gcode line, e.g. G1 or X10.5
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Word {
+    pub letter: char,
+    pub value: f32,
+}
+
+// remove (comments) and ; comments from a line
+pub fn strip_comments(line: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_comment = false;
+    for ch in line.chars() {
+        match ch {
+            '(' => in_comment = true,
+            ')' if in_comment => in_comment = false,
+            ';' if !in_comment => break,
+            _ if !in_comment => stripped.push(ch),
+            _ => {}
+        }
+    }
+    stripped
+}
+
+// split a line into its words, anything that isn't a letter followed by a number is skipped
+pub fn parse_words(line: &str) -> Vec<Word> {
+    let line = strip_comments(line);
+    // system commands like $H or $J= aren't gcode
+    if line.trim_start().starts_with('$') {
+        return Vec::new();
+    }
+
+    let mut words = Vec::new();
+    let mut chars = line.chars().filter(|c| !c.is_whitespace()).peekable();
+    while let Some(ch) = chars.next() {
+        if !ch.is_ascii_alphabetic() {
+            continue;
+        }
+        let mut number = String::new();
+        while let Some(&next) = chars.peek() {
+            if next.is_ascii_digit() || next == '.' || next == '-' || next == '+' {
+                number.push(next);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if let Ok(value) = number.parse() {
+            words.push(Word { letter: ch.to_ascii_uppercase(), value });
+        }
+    }
+    words
+}
+
+pub(crate) fn value_to_mm(value: f32, units: DistUnit) -> f32 {
+    match units {
+        DistUnit::Metric => value,
+        DistUnit::Imperial => value * MM_PER_INCH,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MotionMode {
+    Rapid,
+    Linear,
+    ArcClockwise,
+    ArcCounterClockwise,
+}
+
+// the modal state of the machine after running a program up to some line
+#[derive(Clone)]
+pub struct ModalState {
+    pub units: DistUnit,
+    pub positioning_mode: PositioningMode,
+    pub motion_mode: MotionMode,
+    pub feedrate: Option<f32>,
+    // absolute position in mm, whatever units the program uses
+    pub position: Pos2D,
+    pub torch_on: bool,
+    // the dwell that followed the last torch on, used to pierce again when resuming
+    pub pierce_delay: Option<f32>,
+    // where work zero is in machine coordinates (mm), used for G53 moves
+    pub work_offset: Pos2D,
+    moved_since_torch_on: bool,
+}
+
+impl ModalState {
+    pub fn new() -> Self {
+        Self {
+            units: DistUnit::Metric,
+            positioning_mode: PositioningMode::Absolute,
+            motion_mode: MotionMode::Rapid,
+            feedrate: None,
+            position: Pos2D::new(0.0, 0.0),
+            torch_on: false,
+            pierce_delay: None,
+            work_offset: Pos2D::new(0.0, 0.0),
+            moved_since_torch_on: false,
+        }
+    }
+
+    // the position (mm) a move with these words ends at, axis words are in the current units
+    pub fn target_position(&self, words: &[Word]) -> Pos2D {
+        let mut target = self.position.clone();
+        for word in words {
+            let value = value_to_mm(word.value, self.units);
+            match (word.letter, &self.positioning_mode) {
+                ('X', PositioningMode::Absolute) => target.x = value,
+                ('Y', PositioningMode::Absolute) => target.y = value,
+                ('X', PositioningMode::Relative) => target.x += value,
+                ('Y', PositioningMode::Relative) => target.y += value,
+                _ => {}
+            }
+        }
+        target
+    }
+
+    // the position (mm, work coordinates) a G53 move with these words ends at, G53 is always absolute
+    fn machine_target_position(&self, words: &[Word]) -> Pos2D {
+        let mut target = Pos2D::new(self.position.x + self.work_offset.x, self.position.y + self.work_offset.y);
+        for word in words {
+            match word.letter {
+                'X' => target.x = value_to_mm(word.value, self.units),
+                'Y' => target.y = value_to_mm(word.value, self.units),
+                _ => {}
+            }
+        }
+        Pos2D::new(target.x - self.work_offset.x, target.y - self.work_offset.y)
+    }
+
+    // update the state with the words of one line
+    pub fn apply_words(&mut self, words: &[Word]) {
+        let mut has_axis_words = false;
+        let mut dwell = false;
+        // G10, G28, G30 and G92 use axis words for something other than a move
+        let mut non_modal_axes = false;
+        let mut machine_coordinates = false;
+
+        for word in words {
+            match word.letter {
+                // match the tenths too so e.g. G91.1 isn't taken for G91
+                'G' => match (word.value * 10.0).round() as u32 {
+                    0 => self.motion_mode = MotionMode::Rapid,
+                    10 => self.motion_mode = MotionMode::Linear,
+                    20 => self.motion_mode = MotionMode::ArcClockwise,
+                    30 => self.motion_mode = MotionMode::ArcCounterClockwise,
+                    40 => dwell = true,
+                    100 | 280 | 281 | 300 | 301 | 920 | 921 => non_modal_axes = true,
+                    530 => machine_coordinates = true,
+                    200 => self.units = DistUnit::Imperial,
+                    210 => self.units = DistUnit::Metric,
+                    900 => self.positioning_mode = PositioningMode::Absolute,
+                    910 => self.positioning_mode = PositioningMode::Relative,
+                    // G90.1 and G91.1 only change how arc centers are given
+                    _ => {}
+                },
+                'M' => match word.value as u32 {
+                    3 | 4 => {
+                        self.torch_on = true;
+                        self.moved_since_torch_on = false;
+                    },
+                    // the end of the program turns the spindle off too
+                    2 | 5 | 30 => self.torch_on = false,
+                    _ => {}
+                },
+                'F' => self.feedrate = Some(word.value),
+                'X' | 'Y' => has_axis_words = true,
+                _ => {}
+            }
+        }
+
+        if dwell {
+            // a dwell right after the torch came on is the pierce delay
+            if self.torch_on && !self.moved_since_torch_on {
+                if let Some(delay) = words.iter().find(|w| w.letter == 'P') {
+                    self.pierce_delay = Some(delay.value);
+                }
+            }
+        } else if has_axis_words && !non_modal_axes {
+            self.position = if machine_coordinates { self.machine_target_position(words) } else { self.target_position(words) };
+            self.moved_since_torch_on = true;
+        }
+    }
+
+    pub fn apply_line(&mut self, line: &str) {
+        self.apply_words(&parse_words(line));
+    }
+}
+
+impl Default for ModalState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_both_kinds_of_comments() {
+        assert_eq!(strip_comments("G0 X1 (rapid) Y2 ; to the start"), "G0 X1  Y2 ");
+        assert_eq!(strip_comments("(only a comment)"), "");
+    }
+
+    #[test]
+    fn splits_lines_into_words() {
+        assert_eq!(
+            parse_words("g1x-1.5 Y+2 F100"),
+            vec![
+                Word { letter: 'G', value: 1.0 },
+                Word { letter: 'X', value: -1.5 },
+                Word { letter: 'Y', value: 2.0 },
+                Word { letter: 'F', value: 100.0 },
+            ]
+        );
+        assert!(parse_words("$J=G91 X10 F100").is_empty());
+        // a letter without a number isn't a word
+        assert_eq!(parse_words("X G0"), vec![Word { letter: 'G', value: 0.0 }]);
+    }
+
+    #[test]
+    fn tracks_the_modal_state() {
+        let mut state = ModalState::new();
+        for line in ["G20 G91", "G1 X1 Y2 F30", "X1", "M3", "G4 P1.2", "G92 X0 Y0", "G0 X1"] {
+            state.apply_line(line);
+        }
+        assert_eq!(state.units, DistUnit::Imperial);
+        assert_eq!(state.positioning_mode, PositioningMode::Relative);
+        assert_eq!(state.motion_mode, MotionMode::Rapid);
+        assert_eq!(state.feedrate, Some(30.0));
+        // G92 doesn't move the machine, the position is kept in mm
+        assert_eq!((state.position.x, state.position.y), (76.2, 50.8));
+        assert!(state.torch_on);
+        assert_eq!(state.pierce_delay, Some(1.2));
+
+        // a dwell after moving isn't a pierce
+        state.apply_line("G4 P3");
+        assert_eq!(state.pierce_delay, Some(1.2));
+        state.apply_line("M5");
+        assert!(!state.torch_on);
+    }
+
+    #[test]
+    fn keeps_the_position_in_mm_across_unit_changes() {
+        let mut state = ModalState::new();
+        state.apply_line("G21 G0 X25.4");
+        let before = state.position.clone();
+        state.apply_line("G20 G0 X1");
+        assert_eq!((state.position.x, state.position.y), (before.x, before.y));
+        state.apply_line("G91 G0 Y0.5");
+        assert_eq!((state.position.x, state.position.y), (25.4, 12.7));
+    }
+
+    #[test]
+    fn moves_in_machine_coordinates_with_g53() {
+        let mut state = ModalState::new();
+        state.work_offset = Pos2D::new(-100.0, -50.0);
+        state.apply_line("G91 G0 X10 Y10");
+        // G53 ignores G91 and the work offset, Y stays where it was
+        state.apply_line("G53 G0 X-5");
+        assert_eq!((state.position.x, state.position.y), (95.0, 10.0));
+    }
+
+    #[test]
+    fn tells_decimal_g_codes_apart() {
+        let mut state = ModalState::new();
+        state.apply_line("G90 G91.1");
+        assert_eq!(state.positioning_mode, PositioningMode::Absolute);
+        state.apply_line("G0 X5 Y5");
+        // storing or clearing positions doesn't move anything
+        for line in ["G28.1 X1 Y1", "G30.1 X2", "G92.1 X0"] {
+            state.apply_line(line);
+        }
+        assert_eq!((state.position.x, state.position.y), (5.0, 5.0));
+        state.apply_line("G91 G90.1");
+        assert_eq!(state.positioning_mode, PositioningMode::Relative);
+    }
+}
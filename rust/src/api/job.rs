@@ -0,0 +1,451 @@
+use std::time::{Duration, Instant};
+
+use super::gcode::{DistUnit, Gcode, PositioningMode};
+use super::interpreter::{strip_comments, ModalState, MotionMode};
+
+// used when resuming in the middle of a cut and the program never dwelled after turning the torch on
+const DEFAULT_PIERCE_DELAY: f32 = 0.5;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobState {
+    Running,
+    // feed hold was sent and no new lines are fed to the machine
+    Paused,
+    // waiting for the machine to stop before the queue is cleared
+    Aborting,
+    Completed,
+    Aborted,
+    // the machine reported an error or the connection was lost
+    Failed,
+}
+
+// progress of the running job, sent to the gui every time a line is acknowledged
+#[derive(Clone, Debug)]
+pub struct JobProgress {
+    pub state: JobState,
+    // the line of the program that was sent last (1 based, 0 before anything was sent)
+    pub current_line: u32,
+    pub lines_acknowledged: u32,
+    pub total_lines: u32,
+    pub percent: f32,
+    pub elapsed_seconds: f32,
+    // estimated from the average time per line so far
+    pub remaining_seconds: Option<f32>,
+    pub error: Option<String>,
+}
+
+// a line to send and the line (0 based) of the original program it came from
+#[derive(Clone, Debug)]
+pub struct JobLine {
+    pub text: String,
+    pub source_line: u32,
+}
+
+// every line of the program, as is
+pub(crate) fn job_lines(program: &str) -> Vec<JobLine> {
+    program
+        .lines()
+        .enumerate()
+        .map(|(i, line)| JobLine { text: line.to_string(), source_line: i as u32 })
+        .collect()
+}
+
+// strip comments and whitespace, drop empty lines and optionally number the lines,
+// every line keeps its source line for progress and error reporting
+pub(crate) fn prepare_for_transmission(lines: Vec<JobLine>, line_numbers: bool) -> Vec<JobLine> {
+    let mut prepared = Vec::new();
+    for line in lines {
+        let text: String = strip_comments(&line.text).chars().filter(|c| !c.is_whitespace()).collect();
+        // % only marks the start and end of a program
+        if text.is_empty() || text == "%" {
+            continue;
+        }
+        // grbl doesn't accept line numbers on system commands
+        let text = if line_numbers && !text.starts_with('$') {
+            format!("N{}{}", prepared.len() + 1, text)
+        } else {
+            text
+        };
+        prepared.push(JobLine { text, source_line: line.source_line });
+    }
+    prepared
+}
+
+// the lines needed to pick a program up at start_line (0 based): restore the modal state,
+// move there with the torch off and pierce again if the torch was on at that point
+pub(crate) fn resume_job_lines(program: &str, start_line: u32) -> Vec<JobLine> {
+    let lines = job_lines(program);
+    let start = (start_line as usize).min(lines.len());
+
+    let mut state = ModalState::new();
+    for line in &lines[..start] {
+        state.apply_line(&line.text);
+    }
+
+    // move back in absolute mm, then restore whatever the program was using
+    let mut preamble = vec!["M5".to_string(), "G21".to_string(), "G90".to_string()];
+    preamble.push(format!("G0 X{} Y{}", state.position.x, state.position.y));
+    if state.units == DistUnit::Imperial {
+        preamble.push("G20".to_string());
+    }
+    if state.positioning_mode == PositioningMode::Relative {
+        preamble.push("G91".to_string());
+    }
+    // the G0 above changed the motion mode, the next lines may rely on the program's one (even G2/G3,
+    // a motion word without axis words only sets the mode)
+    let motion = match state.motion_mode {
+        MotionMode::Rapid => "G0",
+        MotionMode::Linear => "G1",
+        MotionMode::ArcClockwise => "G2",
+        MotionMode::ArcCounterClockwise => "G3",
+    };
+    match state.feedrate {
+        Some(feedrate) => preamble.push(format!("{} F{}", motion, feedrate)),
+        None => preamble.push(motion.to_string()),
+    }
+    if state.torch_on {
+        preamble.push("M3".to_string());
+        preamble.push(format!("G4 P{}", state.pierce_delay.unwrap_or(DEFAULT_PIERCE_DELAY)));
+    }
+
+    let mut resumed: Vec<JobLine> = preamble
+        .into_iter()
+        .map(|text| JobLine { text, source_line: start as u32 })
+        .collect();
+    resumed.extend_from_slice(&lines[start..]);
+    resumed
+}
+
+// the lines needed to pick a program up at the start of a cut (0 based)
+pub(crate) fn resume_cut_job_lines(gcode: &Gcode, cut_index: u32) -> Result<Vec<JobLine>, String> {
+    match gcode.get_cut_start_line(cut_index) {
+        Some(start_line) => Ok(resume_job_lines(&gcode.get_gcode_string(), start_line)),
+        None => Err(format!("There is no cut {}", cut_index)),
+    }
+}
+
+// a program being streamed to the machine, keeps track of which lines were sent and acknowledged
+pub(crate) struct Job {
+    lines: Vec<JobLine>,
+    lines_sent: usize,
+    lines_acknowledged: usize,
+    state: JobState,
+    started: Instant,
+    error: Option<String>,
+    on_progress: Box<dyn FnMut(JobProgress) + Send>,
+}
+
+impl Job {
+    pub(crate) fn new(lines: Vec<JobLine>, on_progress: Box<dyn FnMut(JobProgress) + Send>) -> Self {
+        let mut job = Self {
+            lines,
+            lines_sent: 0,
+            lines_acknowledged: 0,
+            state: JobState::Running,
+            started: Instant::now(),
+            error: None,
+            on_progress,
+        };
+        job.finish_if_done();
+        job.report();
+        job
+    }
+
+    pub(crate) fn state(&self) -> &JobState {
+        &self.state
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        matches!(self.state, JobState::Completed | JobState::Aborted | JobState::Failed)
+    }
+
+    pub(crate) fn lines(&self) -> &[JobLine] {
+        &self.lines
+    }
+
+    // true if a sent line hasn't been acknowledged yet
+    pub(crate) fn line_in_flight(&self) -> bool {
+        self.lines_sent > self.lines_acknowledged
+    }
+
+    // the line of the original program (1 based) that is waiting for its ok
+    pub(crate) fn in_flight_source_line(&self) -> Option<u32> {
+        if !self.line_in_flight() {
+            return None;
+        }
+        Some(self.lines[self.lines_acknowledged].source_line + 1)
+    }
+
+    // the next line to send to the machine, if the job is running and the previous line was acknowledged
+    pub(crate) fn next_line(&mut self) -> Option<String> {
+        if self.state != JobState::Running || self.line_in_flight() {
+            return None;
+        }
+        let line = self.lines.get(self.lines_sent)?.text.clone();
+        self.lines_sent += 1;
+        Some(line)
+    }
+
+    // the machine answered ok to the line in flight
+    pub(crate) fn acknowledge(&mut self) {
+        if !self.line_in_flight() {
+            return;
+        }
+        self.lines_acknowledged += 1;
+        self.finish_if_done();
+        self.report();
+    }
+
+    pub(crate) fn pause(&mut self) {
+        if self.state == JobState::Running {
+            self.state = JobState::Paused;
+            self.report();
+        }
+    }
+
+    pub(crate) fn resume(&mut self) {
+        if self.state == JobState::Paused {
+            self.state = JobState::Running;
+            self.report();
+        }
+    }
+
+    // stop feeding lines, the sender finishes the abort once the machine has stopped
+    pub(crate) fn begin_abort(&mut self) {
+        if !self.is_finished() {
+            self.state = JobState::Aborting;
+            self.report();
+        }
+    }
+
+    pub(crate) fn finish_abort(&mut self) {
+        self.lines_sent = self.lines_acknowledged;
+        if self.state == JobState::Aborting {
+            self.state = JobState::Aborted;
+        }
+        self.report();
+    }
+
+    pub(crate) fn fail(&mut self, error: String) {
+        if !self.is_finished() {
+            self.state = JobState::Failed;
+            self.error = Some(error);
+            self.report();
+        }
+    }
+
+    fn finish_if_done(&mut self) {
+        if self.state == JobState::Running && self.lines_acknowledged >= self.lines.len() {
+            self.state = JobState::Completed;
+        }
+    }
+
+    pub(crate) fn progress(&self) -> JobProgress {
+        let total_lines = self.lines.len();
+        let elapsed = self.started.elapsed();
+
+        let percent = if total_lines == 0 {
+            100.0
+        } else {
+            self.lines_acknowledged as f32 / total_lines as f32 * 100.0
+        };
+
+        let remaining_seconds = if self.lines_acknowledged == 0 {
+            None
+        } else {
+            let per_line = elapsed.as_secs_f32() / self.lines_acknowledged as f32;
+            Some(per_line * (total_lines - self.lines_acknowledged) as f32)
+        };
+
+        // report the line of the original program, not of what was streamed
+        let current_line = match self.lines_sent {
+            0 => 0,
+            sent => self.lines[sent - 1].source_line + 1,
+        };
+
+        JobProgress {
+            state: self.state.clone(),
+            current_line,
+            lines_acknowledged: self.lines_acknowledged as u32,
+            total_lines: total_lines as u32,
+            percent,
+            elapsed_seconds: elapsed.as_secs_f32(),
+            remaining_seconds,
+            error: self.error.clone(),
+        }
+    }
+
+    fn report(&mut self) {
+        let progress = self.progress();
+        (self.on_progress)(progress);
+    }
+}
+
+// how often the machine status is polled while a job runs
+pub(crate) const JOB_STATUS_INTERVAL: Duration = Duration::from_millis(250);
+
+// how long to wait for the machine to come to a stop before the queue is cleared anyway
+pub(crate) const ABORT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cut::Cut;
+    use crate::api::settings::CutterSettings;
+    use crate::api::toolpath::{simulate_toolpath, SegmentKind};
+    use std::sync::{Arc, Mutex};
+
+    // a job over the given lines that records every progress report
+    fn recorded_job(lines: &[&str]) -> (Job, Arc<Mutex<Vec<JobProgress>>>) {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let recorder = reports.clone();
+        let job = Job::new(job_lines(&lines.join("\n")), Box::new(move |progress| recorder.lock().unwrap().push(progress)));
+        (job, reports)
+    }
+
+    #[test]
+    fn sends_one_line_at_a_time_until_completed() {
+        let (mut job, reports) = recorded_job(&["G0 X1", "G1 X2 F100"]);
+        assert_eq!(job.next_line(), Some("G0 X1".to_string()));
+        // the next line waits for the ok
+        assert_eq!(job.next_line(), None);
+        job.acknowledge();
+        assert_eq!(job.next_line(), Some("G1 X2 F100".to_string()));
+        job.acknowledge();
+        assert_eq!(*job.state(), JobState::Completed);
+        assert!(job.is_finished());
+        assert_eq!(job.next_line(), None);
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 3);
+        assert_eq!((reports[1].current_line, reports[1].lines_acknowledged, reports[1].percent), (1, 1, 50.0));
+        assert!(reports[1].remaining_seconds.is_some());
+        assert_eq!((reports[2].state.clone(), reports[2].percent), (JobState::Completed, 100.0));
+    }
+
+    #[test]
+    fn an_empty_program_is_done_right_away() {
+        let (job, reports) = recorded_job(&[]);
+        assert_eq!(*job.state(), JobState::Completed);
+        assert_eq!(reports.lock().unwrap()[0].percent, 100.0);
+    }
+
+    #[test]
+    fn pausing_holds_back_the_next_line() {
+        let (mut job, _) = recorded_job(&["G0 X1", "G0 X2"]);
+        job.next_line();
+        job.pause();
+        // the line already sent still gets its ok
+        job.acknowledge();
+        assert_eq!(job.next_line(), None);
+        // resuming only works from paused
+        job.resume();
+        assert_eq!(*job.state(), JobState::Running);
+        job.resume();
+        assert_eq!(job.next_line(), Some("G0 X2".to_string()));
+    }
+
+    #[test]
+    fn aborting_and_failing_finish_the_job() {
+        let (mut job, reports) = recorded_job(&["G0 X1", "G0 X2"]);
+        job.next_line();
+        job.begin_abort();
+        assert_eq!((job.state().clone(), job.is_finished()), (JobState::Aborting, false));
+        assert_eq!(job.next_line(), None);
+        job.finish_abort();
+        assert_eq!(*job.state(), JobState::Aborted);
+        assert!(!job.line_in_flight());
+        // a finished job stays finished
+        job.fail("too late".to_string());
+        assert_eq!(*job.state(), JobState::Aborted);
+
+        let (mut job, failed) = recorded_job(&["G0 X1"]);
+        job.fail("Machine reported error:20".to_string());
+        assert_eq!(*job.state(), JobState::Failed);
+        assert_eq!(failed.lock().unwrap().last().unwrap().error.as_deref(), Some("Machine reported error:20"));
+        assert!(reports.lock().unwrap().iter().all(|progress| progress.error.is_none()));
+    }
+
+    fn texts(lines: &[JobLine]) -> Vec<&str> {
+        lines.iter().map(|line| line.text.as_str()).collect()
+    }
+
+    #[test]
+    fn resuming_restores_the_state_and_pierces_again() {
+        let program = "G20\nG90\nG0 X1 Y2\nM3\nG4 P0.8\nG1 X3 F40\nG91\nG1 X1\nM5";
+        let resumed = resume_job_lines(program, 8);
+        assert_eq!(
+            texts(&resumed),
+            vec!["M5", "G21", "G90", "G0 X101.6 Y50.8", "G20", "G91", "G1 F40", "M3", "G4 P0.8", "M5"]
+        );
+        // the preamble is reported as the line the job restarts on
+        assert!(resumed.iter().all(|line| line.source_line == 8));
+    }
+
+    #[test]
+    fn resuming_with_the_torch_off_just_moves_there() {
+        let program = "G21\nG0 X5 Y6\nG0 X7";
+        let resumed = resume_job_lines(program, 2);
+        assert_eq!(texts(&resumed), vec!["M5", "G21", "G90", "G0 X5 Y6", "G0", "G0 X7"]);
+
+        // without a dwell after M3 the default pierce delay is used
+        let resumed = resume_job_lines("M3\nG1 X1 F10\nG1 X2", 2);
+        assert_eq!(&texts(&resumed)[5..], ["M3", "G4 P0.5", "G1 X2"]);
+        // past the end there is nothing left to run after the preamble
+        assert_eq!(texts(&resume_job_lines("G0 X1", 10)), vec!["M5", "G21", "G90", "G0 X1 Y0", "G0"]);
+        // arc center mode isn't relative positioning
+        assert!(!texts(&resume_job_lines("G90 G91.1\nG0 X1\nG0 X2", 2)).contains(&"G91"));
+    }
+
+    #[test]
+    fn resuming_between_arcs_keeps_the_arc_mode() {
+        let program = "G0 X0 Y0\nM3\nG2 X10 Y0 I5 J0 F300\nX0 Y0 I-5 J0\nM5";
+        let resumed = resume_job_lines(program, 3);
+        assert_eq!(texts(&resumed), vec!["M5", "G21", "G90", "G0 X10 Y0", "G2 F300", "M3", "G4 P0.5", "X0 Y0 I-5 J0", "M5"]);
+
+        // the line after the preamble is still cut as an arc, not a rapid with the torch on
+        let segments = simulate_toolpath(texts(&resumed).join("\n"));
+        let arc = segments.iter().find(|segment| segment.line == 7).unwrap();
+        assert!(matches!(arc.kind, SegmentKind::Arc { clockwise: true, .. }) && arc.torch_on);
+    }
+
+    #[test]
+    fn prepares_lines_for_the_machine() {
+        let program = "%\nG0 X1 (rapid) ; to the start\n\n   \n(just a comment)\n$H\nG1 X2 F100\n%";
+        let prepared = prepare_for_transmission(job_lines(program), false);
+        assert_eq!(texts(&prepared), vec!["G0X1", "$H", "G1X2F100"]);
+        // every line still knows where it came from
+        assert_eq!(prepared.iter().map(|line| line.source_line).collect::<Vec<_>>(), vec![1, 5, 6]);
+
+        // numbering counts the lines that are sent and skips system commands
+        let numbered = prepare_for_transmission(job_lines(program), true);
+        assert_eq!(texts(&numbered), vec!["N1G0X1", "$H", "N3G1X2F100"]);
+    }
+
+    #[test]
+    fn reports_the_source_line_waiting_for_its_ok() {
+        let (job, _) = recorded_job(&[]);
+        assert_eq!(job.in_flight_source_line(), None);
+
+        let lines = prepare_for_transmission(job_lines("; header\n\nG0 X1\nG0 X2"), false);
+        let mut job = Job::new(lines, Box::new(|_| {}));
+        job.next_line();
+        assert_eq!(job.in_flight_source_line(), Some(3));
+        job.acknowledge();
+        assert_eq!(job.in_flight_source_line(), None);
+        job.next_line();
+        assert_eq!(job.in_flight_source_line(), Some(4));
+    }
+
+    #[test]
+    fn resuming_from_a_missing_cut_is_an_error() {
+        let mut gcode = Gcode::new();
+        assert_eq!(resume_cut_job_lines(&gcode, 0).unwrap_err(), "There is no cut 0");
+
+        gcode.add_cut(Cut::new(), &CutterSettings::default());
+        let resumed = resume_cut_job_lines(&gcode, 0).unwrap();
+        assert_eq!(resumed[0].text, "M5");
+        assert_eq!(resumed.last().unwrap().source_line, gcode.line_count() - 1);
+        assert_eq!(resume_cut_job_lines(&gcode, 1).unwrap_err(), "There is no cut 1");
+    }
+}
@@ -0,0 +1,188 @@
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::gcode::Gcode;
+use crate::api::job_plan::JobPlan;
+use crate::api::settings::CutterSettings;
+
+// bump when the format changes in a way older versions can't read
+pub const JOB_FILE_VERSION: u32 = 1;
+
+// the machine a job was made for and its settings that change the program
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MachineReference {
+    // e.g. "Workshop cutter"
+    pub name: String,
+    pub use_laser: bool,
+    pub laser_offset_x: f32,
+    pub laser_offset_y: f32,
+    pub home_after_cut: bool,
+}
+
+impl MachineReference {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn from_settings(name: String, cutter_settings: &CutterSettings) -> Self {
+        Self {
+            name,
+            use_laser: cutter_settings.use_laser,
+            laser_offset_x: cutter_settings.laser_offset_x,
+            laser_offset_y: cutter_settings.laser_offset_y,
+            home_after_cut: cutter_settings.home_after_cut,
+        }
+    }
+
+    // false if the machine has been set up differently since the job was saved
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn matches(&self, cutter_settings: &CutterSettings) -> bool {
+        *self == MachineReference::from_settings(self.name.clone(), cutter_settings)
+    }
+
+    fn cutter_settings(&self) -> CutterSettings {
+        CutterSettings {
+            use_laser: self.use_laser,
+            laser_offset_x: self.laser_offset_x,
+            laser_offset_y: self.laser_offset_y,
+            home_after_cut: self.home_after_cut,
+            ..CutterSettings::default()
+        }
+    }
+}
+
+// a saved job, everything needed to generate its program again
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct JobFile {
+    pub version: u32,
+    pub machine: MachineReference,
+    #[serde(flatten)]
+    pub plan: JobPlan,
+}
+
+impl JobFile {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(plan: &JobPlan, machine: MachineReference) -> Self {
+        Self { version: JOB_FILE_VERSION, machine, plan: plan.clone() }
+    }
+
+    // the program the job was saved with, whatever the settings are now
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn to_gcode(&self) -> Gcode {
+        self.plan.to_gcode(&self.machine.cutter_settings())
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize job!")
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn from_json(contents: String) -> Result<JobFile, String> {
+        // check the version first, a newer file might not parse at all
+        let value: serde_json::Value = serde_json::from_str(&contents).map_err(|e| format!("The job file isn't valid JSON: {}", e))?;
+        let version = value.get("version").and_then(|version| version.as_u64()).ok_or("The job file has no version".to_string())?;
+        if version > JOB_FILE_VERSION as u64 {
+            return Err(format!("The job file is version {}, this version of the app reads up to {}", version, JOB_FILE_VERSION));
+        }
+        let job: JobFile = serde_json::from_value(value).map_err(|e| format!("Failed to read the job file: {}", e))?;
+        let empty_contour = job.plan.features.iter().any(|feature| feature.profile.contours.iter().any(|contour| contour.segments.is_empty()));
+        if empty_contour {
+            return Err("The job file has a contour without any segments".to_string());
+        }
+        Ok(job)
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn save(&self, path: String) -> Result<(), String> {
+        fs::write(&path, self.to_json()).map_err(|e| format!("Failed to write {}: {}", path, e))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn load(path: String) -> Result<JobFile, String> {
+        let contents = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        JobFile::from_json(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cut::Cut;
+    use crate::api::gcode::Pos2D;
+    use crate::api::job_plan::Stock;
+    use crate::api::profile::{Contour, CutParameters, Profile, ProfileSegment};
+
+    fn settings() -> CutterSettings {
+        CutterSettings { use_laser: true, laser_offset_x: 12.5, laser_offset_y: -3.25, home_after_cut: false, ..CutterSettings::default() }
+    }
+
+    fn job() -> JobFile {
+        let mut plan = JobPlan::new("Sign frame".to_string(), Stock { material: "50x50x2 steel".to_string(), tube_width: 50.0, length: 2000.0 });
+        for (y, angle) in [(0.0, 90.0), (733.3, 45.0), (1466.7, 45.0)] {
+            let mut cut = Cut::new();
+            cut.start_position = Pos2D::new(0.0, y);
+            cut.cut_angle = angle;
+            cut.cut_feedrate = 850.0;
+            cut.pierce_delay = 0.35;
+            plan.add_cut(cut);
+        }
+        let circle = Pos2D::new(10.0, 10.0);
+        let profile = Profile::from_segments(vec![
+            ProfileSegment::Arc { start: Pos2D::new(13.0, 10.0), end: Pos2D::new(13.0, 10.0), center: circle, clockwise: true },
+            ProfileSegment::Line { start: Pos2D::new(0.0, 0.0), end: Pos2D::new(1.0 / 3.0, 20.0) },
+        ]);
+        plan.add_feature(profile.place_on_tube_face(50.0, 300.0).unwrap(), CutParameters { feedrate: 600.0, pierce_delay: 0.2 });
+        JobFile::new(&plan, MachineReference::from_settings("Workshop cutter".to_string(), &settings()))
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let job = job();
+        let json = job.to_json();
+        assert!(json.contains("\"version\": 1"));
+        let loaded = JobFile::from_json(json).unwrap();
+        assert_eq!(loaded, job);
+        assert_eq!(loaded.to_json(), job.to_json());
+    }
+
+    #[test]
+    fn regenerates_the_identical_program() {
+        let job = job();
+        let original = job.plan.to_gcode(&settings());
+        assert_eq!(original.get_cut_count(), 4);
+
+        let loaded = JobFile::from_json(job.to_json()).unwrap();
+        // the settings have changed since, the job still makes the program it was saved with
+        assert!(!loaded.machine.matches(&CutterSettings::default()));
+        assert!(loaded.machine.matches(&settings()));
+        assert_eq!(loaded.to_gcode().get_gcode_string(), original.get_gcode_string());
+    }
+
+    #[test]
+    fn saves_and_loads_files() {
+        let directory = std::env::temp_dir().join(format!("tube_cutter_job_file_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("sign.tcjob").to_string_lossy().to_string();
+        job().save(path.clone()).unwrap();
+        assert_eq!(JobFile::load(path).unwrap(), job());
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert!(JobFile::load("/nonexistent/sign.tcjob".to_string()).is_err());
+    }
+
+    #[test]
+    fn refuses_files_it_cant_read() {
+        assert!(JobFile::from_json("not json".to_string()).unwrap_err().contains("isn't valid JSON"));
+        assert_eq!(JobFile::from_json("{}".to_string()), Err("The job file has no version".to_string()));
+        let newer = job().to_json().replace("\"version\": 1", "\"version\": 2");
+        assert!(JobFile::from_json(newer).unwrap_err().contains("version 2"));
+        let broken = job().to_json().replace("\"cuts\"", "\"kuts\"");
+        assert!(JobFile::from_json(broken).unwrap_err().starts_with("Failed to read the job file"));
+
+        let mut empty = job();
+        empty.plan.features[0].profile.contours.push(Contour { segments: Vec::new(), closed: false });
+        assert_eq!(JobFile::from_json(empty.to_json()), Err("The job file has a contour without any segments".to_string()));
+        // built in code it's skipped instead of panicking
+        assert_eq!(empty.to_gcode().get_gcode_string(), job().to_gcode().get_gcode_string());
+    }
+}
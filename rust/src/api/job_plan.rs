@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::cut::Cut;
+use crate::api::gcode::Gcode;
+use crate::api::profile::{CutParameters, Profile};
+use crate::api::settings::CutterSettings;
+
+// the tube a job is cut from, lengths in mm
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Stock {
+    // e.g. "25x25x1.5 mild steel"
+    pub material: String,
+    pub tube_width: f32,
+    pub length: f32,
+}
+
+// a piece of tube between two cuts
+#[derive(Clone, Debug, PartialEq)]
+pub struct Part {
+    // where the part starts along the tube
+    pub start: f32,
+    pub length: f32,
+    // the angles of the cuts at either end, in degrees
+    pub start_angle: f32,
+    pub end_angle: f32,
+}
+
+// a profile placed where it's cut, e.g. a logo on a tube face
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Feature {
+    pub profile: Profile,
+    pub parameters: CutParameters,
+}
+
+// what a job is made of: the stock and the cuts along it, the program is generated from this
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct JobPlan {
+    pub name: String,
+    pub stock: Stock,
+    pub cuts: Vec<Cut>,
+    #[serde(default)]
+    pub features: Vec<Feature>,
+}
+
+impl JobPlan {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(name: String, stock: Stock) -> Self {
+        Self { name, stock, cuts: Vec::new(), features: Vec::new() }
+    }
+
+    // add a cut across the stock
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn add_cut(&mut self, mut cut: Cut) {
+        cut.tube_width = self.stock.tube_width;
+        self.cuts.push(cut);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn remove_cut(&mut self, cut_index: u32) {
+        if (cut_index as usize) < self.cuts.len() {
+            self.cuts.remove(cut_index as usize);
+        }
+    }
+
+    // add a profile that's already placed on the stock
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn add_feature(&mut self, profile: Profile, parameters: CutParameters) {
+        self.features.push(Feature { profile, parameters });
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn remove_feature(&mut self, feature_index: u32) {
+        if (feature_index as usize) < self.features.len() {
+            self.features.remove(feature_index as usize);
+        }
+    }
+
+    // the program for all the cuts in the order they were added, then the features
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn to_gcode(&self, cutter_settings: &CutterSettings) -> Gcode {
+        let settings = CutterSettings { clear_existing_gcode: false, ..cutter_settings.clone() };
+        let mut gcode = Gcode::new();
+        for cut in &self.cuts {
+            gcode.add_cut(cut.clone(), &settings);
+        }
+        for feature in &self.features {
+            gcode.add_profile(&feature.profile, &feature.parameters);
+        }
+        gcode
+    }
+
+    // the parts between neighbouring cuts, in order along the tube
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn parts(&self) -> Vec<Part> {
+        let mut cuts: Vec<&Cut> = self.cuts.iter().collect();
+        cuts.sort_by(|a, b| a.start_position.y.total_cmp(&b.start_position.y));
+        cuts.windows(2)
+            .map(|ends| Part {
+                start: ends[0].start_position.y,
+                length: ends[1].start_position.y - ends[0].start_position.y,
+                start_angle: ends[0].cut_angle,
+                end_angle: ends[1].cut_angle,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::gcode::Pos2D;
+
+    fn three_cut_plan() -> JobPlan {
+        let mut plan = JobPlan::new("Frame".to_string(), Stock { material: "25x25x1.5 mild steel".to_string(), tube_width: 25.0, length: 1000.0 });
+        for (y, angle) in [(10.0, 90.0), (410.0, 45.0), (810.0, 90.0)] {
+            let mut cut = Cut::new();
+            cut.start_position = Pos2D::new(0.0, y);
+            cut.cut_angle = angle;
+            plan.add_cut(cut);
+        }
+        plan
+    }
+
+    #[test]
+    fn lists_the_parts_between_cuts() {
+        let mut plan = three_cut_plan();
+        // the order the cuts were added in doesn't matter
+        plan.cuts.swap(0, 2);
+        let parts = plan.parts();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0], Part { start: 10.0, length: 400.0, start_angle: 90.0, end_angle: 45.0 });
+        assert_eq!(parts[1].length, 400.0);
+    }
+
+    #[test]
+    fn generates_every_cut() {
+        let plan = three_cut_plan();
+        let gcode = plan.to_gcode(&CutterSettings::default());
+        assert_eq!(gcode.get_cut_count(), 3);
+
+        let mut without_middle = plan.clone();
+        without_middle.remove_cut(1);
+        assert_eq!(without_middle.to_gcode(&CutterSettings::default()).get_cut_count(), 2);
+    }
+}
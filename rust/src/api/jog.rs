@@ -0,0 +1,168 @@
+use std::time::{Duration, Instant};
+
+// how much motion one continuous jog increment covers, short enough that a jog cancel
+// doesn't have much to throw away and long enough that the serial round trip doesn't starve the planner
+const JOG_INCREMENT_TIME: Duration = Duration::from_millis(100);
+// how far ahead of the machine the increments are queued
+const JOG_LOOKAHEAD: Duration = Duration::from_millis(250);
+
+// the distance a single press of a jog button moves
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JogStep {
+    // 0.1mm
+    Fine,
+    // 1mm
+    Normal,
+    // 10mm
+    Coarse,
+}
+
+impl JogStep {
+    pub fn distance(&self) -> f32 {
+        match self {
+            JogStep::Fine => 0.1,
+            JogStep::Normal => 1.0,
+            JogStep::Coarse => 10.0,
+        }
+    }
+}
+
+// a relative jog move in mm
+pub(crate) fn jog_line(x_dist: f32, y_dist: f32, feedrate: f32) -> String {
+    format!("$J=G91 G21 X{} Y{} F{}", x_dist, y_dist, feedrate)
+}
+
+// keeps the machine jogging in one direction by feeding it short moves until it's cancelled
+pub(crate) struct ContinuousJog {
+    // unit vector of the jog direction
+    x: f32,
+    y: f32,
+    feedrate: f32,
+    // when the increments sent so far will have been executed
+    queued_until: Instant,
+}
+
+impl ContinuousJog {
+    // None if the direction has no length
+    pub(crate) fn new(x_direction: f32, y_direction: f32, feedrate: f32) -> Option<Self> {
+        let length = (x_direction * x_direction + y_direction * y_direction).sqrt();
+        if length == 0.0 || !length.is_finite() || feedrate <= 0.0 {
+            return None;
+        }
+        Some(Self { x: x_direction / length, y: y_direction / length, feedrate, queued_until: Instant::now() })
+    }
+
+    // the next increment to send, if the machine is running low on queued motion
+    pub(crate) fn next_increment(&mut self) -> Option<String> {
+        let now = Instant::now();
+        if self.queued_until > now + JOG_LOOKAHEAD {
+            return None;
+        }
+        let distance = self.feedrate / 60.0 * JOG_INCREMENT_TIME.as_secs_f32();
+        self.queued_until = self.queued_until.max(now) + JOG_INCREMENT_TIME;
+        Some(jog_line(round_to_micron(self.x * distance), round_to_micron(self.y * distance), self.feedrate))
+    }
+}
+
+// keep the lines short, grbl only has a small line buffer
+fn round_to_micron(value: f32) -> f32 {
+    (value * 1000.0).round() / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::job::job_lines;
+    use crate::api::settings::CutterSettings;
+    use crate::api::simulator::{connect_to_simulator, GrblSimulator, SimulatorConfig};
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
+
+    fn settings() -> CutterSettings {
+        CutterSettings { jog_speed: 1200.0, ..Default::default() }
+    }
+
+    #[test]
+    fn keeps_only_a_short_distance_queued() {
+        let mut jog = ContinuousJog::new(3.0, 4.0, 600.0).unwrap();
+        assert_eq!(jog.next_increment().as_deref(), Some("$J=G91 G21 X0.6 Y0.8 F600"));
+        assert!(jog.next_increment().is_some());
+        assert!(jog.next_increment().is_some());
+        // 300ms queued now, more than the lookahead
+        assert_eq!(jog.next_increment(), None);
+
+        assert!(ContinuousJog::new(0.0, 0.0, 600.0).is_none());
+    }
+
+    #[test]
+    fn jogs_until_cancelled() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+
+        connection.start_jog(1.0, 0.0, &settings());
+        std::thread::sleep(Duration::from_millis(500));
+        connection.stop_jog();
+        std::thread::sleep(Duration::from_millis(100));
+
+        // 20mm/s for about half a second, stopped right away instead of running out the queue
+        let stopped_at = simulator.lock().unwrap().position();
+        assert!(stopped_at.x > 5.0 && stopped_at.x < 15.0, "stopped at {}", stopped_at.x);
+        assert_eq!(stopped_at.y, 0.0);
+        std::thread::sleep(Duration::from_millis(200));
+        assert_eq!(simulator.lock().unwrap().position().x, stopped_at.x);
+        connection.disconnect();
+    }
+
+    #[test]
+    fn changing_direction_cancels_the_old_jog() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+
+        connection.start_jog(1.0, 0.0, &settings());
+        std::thread::sleep(Duration::from_millis(300));
+        let turned_at = simulator.lock().unwrap().position();
+        connection.start_jog(0.0, 1.0, &settings());
+        std::thread::sleep(Duration::from_millis(400));
+        connection.stop_jog();
+
+        // the queued X moves were dropped instead of running out first
+        let position = simulator.lock().unwrap().position();
+        assert!(position.x - turned_at.x < 2.0, "went on to {} after turning at {}", position.x, turned_at.x);
+        assert!(position.y > 4.0, "only got to {}", position.y);
+        connection.disconnect();
+    }
+
+    #[test]
+    fn steps_by_the_selected_distance() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig { time_scale: 100.0, ..Default::default() })));
+        let mut connection = connect_to_simulator(simulator.clone());
+
+        connection.jog_step(1.0, 0.0, JogStep::Coarse, &settings()).unwrap();
+        connection.jog_step(0.0, -1.0, JogStep::Normal, &settings()).unwrap();
+        connection.jog_step(1.0, 0.0, JogStep::Fine, &settings()).unwrap();
+        std::thread::sleep(Duration::from_millis(300));
+
+        let position = simulator.lock().unwrap().position();
+        assert!((position.x - 10.1).abs() < 0.001);
+        assert_eq!(position.y, -1.0);
+        connection.disconnect();
+    }
+
+    #[test]
+    fn doesnt_step_during_a_job() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+        let program: String = (1..=40).map(|x| format!("G1 X{} F100\n", x)).collect();
+        connection.start_job_with(job_lines(&program), |_| {});
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !simulator.lock().unwrap().received_lines().iter().any(|line| line == "G1X1F100") && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert!(connection.jog_step(0.0, 1.0, JogStep::Coarse, &settings()).is_err());
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(!simulator.lock().unwrap().received_lines().iter().any(|line| line.starts_with("$J=")));
+        connection.abort_job();
+        connection.disconnect();
+    }
+}
@@ -0,0 +1,226 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::gcode::Pos2D;
+use crate::api::sender::MachineConnection;
+use crate::api::settings::CutterSettings;
+use crate::api::status::MachineState;
+
+// how long the torch fires for the calibration mark
+const TEST_MARK_TIME: f32 = 0.3;
+
+// the output the laser pointer is wired to
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub enum LaserOutput {
+    // the flood coolant pin (M8/M9)
+    #[default]
+    Flood,
+    // the mist coolant pin (M7/M9), not every grbl build has it
+    Mist,
+    // any other commands, e.g. M62 P0 / M63 P0 for a grblHAL aux output
+    Custom { on_command: String, off_command: String },
+}
+
+impl LaserOutput {
+    pub(crate) fn command(&self, on: bool) -> String {
+        match (self, on) {
+            (LaserOutput::Flood, true) => "M8".to_string(),
+            (LaserOutput::Mist, true) => "M7".to_string(),
+            (LaserOutput::Flood | LaserOutput::Mist, false) => "M9".to_string(),
+            (LaserOutput::Custom { on_command, .. }, true) => on_command.clone(),
+            (LaserOutput::Custom { off_command, .. }, false) => off_command.clone(),
+        }
+    }
+}
+
+// finds the offset between the torch and the laser pointer:
+// the torch marks the material, then the laser dot is jogged onto the mark
+#[flutter_rust_bridge::frb(opaque)]
+pub struct LaserCalibration {
+    // where the machine was when the torch made the mark
+    mark_position: Option<Pos2D>,
+}
+
+impl LaserCalibration {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { mark_position: None }
+    }
+
+    // fire the torch briefly where the machine is, then turn the laser on so it can be jogged onto the mark
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fire_test_mark(&mut self, connection: &MachineConnection, cutter_settings: &CutterSettings) -> Result<(), String> {
+        let position = idle_position(connection)?;
+        let output = &cutter_settings.laser_output;
+        let commands = vec![
+            output.command(false),
+            "M3".to_string(),
+            format!("G4 P{}", TEST_MARK_TIME),
+            "M5".to_string(),
+            output.command(true),
+        ];
+        // the last report might be stale, the mark is only fired once a fresh one says the machine is idle
+        connection.send_manual_commands_when_idle(commands)?;
+        self.mark_position = Some(position);
+        Ok(())
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn mark_position(&self) -> Option<Pos2D> {
+        self.mark_position.clone()
+    }
+
+    // the laser dot is on the mark now: work out the offset, turn the laser off and save it in the settings
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finish(&mut self, connection: &MachineConnection, cutter_settings: &mut CutterSettings) -> Result<Pos2D, String> {
+        self.finish_and_save_to(connection, cutter_settings, Path::new("prefs.json"))
+    }
+
+    pub(crate) fn finish_and_save_to(&mut self, connection: &MachineConnection, cutter_settings: &mut CutterSettings, settings_path: &Path) -> Result<Pos2D, String> {
+        let offset = self.measure_offset(connection)?;
+        connection.set_laser_pointer(false, cutter_settings)?;
+        cutter_settings.laser_offset_x = offset.x;
+        cutter_settings.laser_offset_y = offset.y;
+        cutter_settings.save_to(settings_path)?;
+        Ok(offset)
+    }
+
+    // a cut lined up with the laser has to move by this much to put the torch where the dot was
+    pub(crate) fn measure_offset(&self, connection: &MachineConnection) -> Result<Pos2D, String> {
+        let mark = self.mark_position.as_ref().ok_or("No test mark was fired yet".to_string())?;
+        let laser = idle_position(connection)?;
+        Ok(Pos2D::new(mark.x - laser.x, mark.y - laser.y))
+    }
+}
+
+impl Default for LaserCalibration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// the machine position, if the machine is standing still
+fn idle_position(connection: &MachineConnection) -> Result<Pos2D, String> {
+    let status = connection.get_last_status().ok_or("No status from the machine yet".to_string())?;
+    if !matches!(status.machine_state, MachineState::Idle) {
+        return Err("The machine has to be idle".to_string());
+    }
+    Ok(status.position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::job::job_lines;
+    use crate::api::simulator::{connect_to_simulator, GrblSimulator, SimulatorConfig};
+    use crate::api::wcs::WorkCoordinateSystem;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    // wait for the machine to report standing still at a position
+    fn wait_for_idle_at(connection: &MachineConnection, x: f32, y: f32) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            connection.send_string_command("?".to_string());
+            std::thread::sleep(Duration::from_millis(20));
+            if let Some(status) = connection.get_last_status() {
+                if matches!(status.machine_state, MachineState::Idle) && status.position.x == x && status.position.y == y {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn switches_the_configured_output() {
+        assert_eq!(LaserOutput::Flood.command(true), "M8");
+        assert_eq!(LaserOutput::Mist.command(false), "M9");
+        let custom = LaserOutput::Custom { on_command: "M62 P0".to_string(), off_command: "M63 P0".to_string() };
+        assert_eq!(custom.command(true), "M62 P0");
+        assert_eq!(custom.command(false), "M63 P0");
+    }
+
+    #[test]
+    fn measures_the_offset_from_the_mark() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig { time_scale: 100.0, ..Default::default() })));
+        let mut connection = connect_to_simulator(simulator.clone());
+        let mut settings = CutterSettings::default();
+        let mut calibration = LaserCalibration::new();
+        assert!(calibration.measure_offset(&connection).is_err());
+
+        connection.send_gcode_command("G0 X10 Y5".to_string());
+        assert!(wait_for_idle_at(&connection, 10.0, 5.0));
+        calibration.fire_test_mark(&connection, &settings).unwrap();
+
+        // jog the laser dot onto the mark
+        connection.send_gcode_command("G0 X13 Y1".to_string());
+        assert!(wait_for_idle_at(&connection, 13.0, 1.0));
+        let offset = calibration.measure_offset(&connection).unwrap();
+        assert_eq!((offset.x, offset.y), (-3.0, 4.0));
+
+        let received = simulator.lock().unwrap().received_lines().to_vec();
+        let mark_start = received.iter().position(|line| line == "M3").unwrap();
+        assert_eq!(received[mark_start - 1..mark_start + 4], ["M9", "M3", "G4 P0.3", "M5", "M8"]);
+
+        // a settings file that can't be written is an error, not a panic
+        let error = calibration.finish_and_save_to(&connection, &mut settings, Path::new("/nonexistent/dir/prefs.json")).unwrap_err();
+        assert!(error.starts_with("Failed to write /nonexistent/dir/prefs.json"), "{}", error);
+
+        let settings_path = std::env::temp_dir().join(format!("tube_cutter_laser_{}.json", std::process::id()));
+        let offset = calibration.finish_and_save_to(&connection, &mut settings, &settings_path).unwrap();
+        assert_eq!((offset.x, offset.y), (-3.0, 4.0));
+        let saved: CutterSettings = serde_json::from_str(&std::fs::read_to_string(&settings_path).unwrap()).unwrap();
+        assert_eq!((saved.laser_offset_x, saved.laser_offset_y), (-3.0, 4.0));
+        std::fs::remove_file(&settings_path).unwrap();
+        connection.disconnect();
+    }
+
+    #[test]
+    fn keeps_the_laser_out_of_a_running_job() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+        let settings = CutterSettings::default();
+        assert!(wait_for_idle_at(&connection, 0.0, 0.0));
+
+        let program: String = (1..=40).map(|x| format!("G1 X{} F100\n", x)).collect();
+        connection.start_job_with(job_lines(&program), |_| {});
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !simulator.lock().unwrap().received_lines().iter().any(|line| line == "G1X1F100") && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        // the last report still says idle, that isn't enough to fire the torch
+        assert!(connection.set_laser_pointer(true, &settings).is_err());
+        assert!(LaserCalibration::new().fire_test_mark(&connection, &settings).is_err());
+
+        std::thread::sleep(Duration::from_millis(100));
+        let received = simulator.lock().unwrap().received_lines().to_vec();
+        assert!(!received.iter().any(|line| line == "M8" || line == "M3"));
+        connection.abort_job();
+        connection.disconnect();
+    }
+
+    #[test]
+    fn zeroes_where_the_torch_reaches_the_laser_dot() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig { time_scale: 100.0, ..Default::default() })));
+        let mut connection = connect_to_simulator(simulator.clone());
+        // the torch is 3mm left of and 4mm above the dot
+        let settings = CutterSettings { laser_offset_x: -3.0, laser_offset_y: 4.0, ..Default::default() };
+
+        connection.send_gcode_command("G0 X10 Y5".to_string());
+        assert!(wait_for_idle_at(&connection, 10.0, 5.0));
+        connection.zero_at_laser(Some(WorkCoordinateSystem::G55), &settings).unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut g55 = None;
+        while g55.is_none() && Instant::now() < deadline {
+            connection.read_coordinate_offsets();
+            std::thread::sleep(Duration::from_millis(20));
+            g55 = connection.get_coordinate_offsets().offset(WorkCoordinateSystem::G55).filter(|offset| offset.x != 0.0);
+        }
+        let g55 = g55.unwrap();
+        assert_eq!((g55.x, g55.y), (7.0, 9.0));
+        connection.disconnect();
+    }
+}
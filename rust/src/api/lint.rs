@@ -0,0 +1,243 @@
+use crate::api::gcode::Gcode;
+use crate::api::interpreter::{parse_words, strip_comments, ModalState, MotionMode, Word};
+
+// the letters grbl knows
+const KNOWN_LETTERS: &str = "FGIJKLMNPRSTXYZABC";
+// the G codes grbl 1.1 supports, G38.x and G43.1 included
+const KNOWN_G_CODES: [f32; 39] = [
+    0.0, 1.0, 2.0, 3.0, 4.0, 10.0, 17.0, 18.0, 19.0, 20.0, 21.0, 28.0, 28.1, 30.0, 30.1, 38.2, 38.3, 38.4, 38.5, 40.0, 43.1, 49.0, 53.0,
+    54.0, 55.0, 56.0, 57.0, 58.0, 59.0, 61.0, 80.0, 90.0, 90.1, 91.0, 91.1, 92.0, 92.1, 93.0, 94.0,
+];
+const KNOWN_M_CODES: [f32; 10] = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 7.0, 8.0, 9.0, 30.0];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Severity {
+    // the program shouldn't be run
+    Error,
+    // probably a mistake
+    Warning,
+}
+
+// which check a diagnostic came from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LintRule {
+    // M3 without an M5 before the program ends
+    TorchLeftOn,
+    // G0 with the torch on
+    RapidWithTorchOn,
+    // a feed move without a feedrate, or with F0
+    MissingFeedrate,
+    // a dwell with the torch on that's longer than LintOptions::max_torch_dwell
+    LongTorchDwell,
+    // motion before G20/G21
+    UnitsNotSet,
+    // motion before G90/G91
+    PositioningModeNotSet,
+    // a word grbl doesn't understand
+    UnknownWord,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintDiagnostic {
+    pub severity: Severity,
+    pub rule: LintRule,
+    // the line (0 based) of the program
+    pub line: u32,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintOptions {
+    // seconds, a longer dwell with the torch on burns through more than it pierces
+    pub max_torch_dwell: f32,
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        Self { max_torch_dwell: 3.0 }
+    }
+}
+
+fn diagnostic(severity: Severity, rule: LintRule, line: u32, message: String) -> LintDiagnostic {
+    LintDiagnostic { severity, rule, line, message: format!("Line {}: {}", line + 1, message) }
+}
+
+// the rest of a line from the first piece that isn't a letter followed by a number
+fn unreadable_text(line: &str) -> Option<String> {
+    let text: String = strip_comments(line).chars().filter(|c| !c.is_whitespace()).collect();
+    if text.is_empty() || text == "%" || text.starts_with('$') {
+        return None;
+    }
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, ch)) = chars.next() {
+        let mut has_number = false;
+        while let Some(&(_, next)) = chars.peek() {
+            if next.is_ascii_digit() || next == '.' || next == '-' || next == '+' {
+                has_number = true;
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if !ch.is_ascii_alphabetic() || !has_number {
+            return Some(text[start..].to_string());
+        }
+    }
+    None
+}
+
+fn unknown_word(word: &Word) -> bool {
+    let letter = word.letter;
+    if !KNOWN_LETTERS.contains(letter) {
+        return true;
+    }
+    // compare with a little slack, the values went through f32
+    let known = |codes: &[f32]| codes.iter().any(|code| (code - word.value).abs() < 0.001);
+    match letter {
+        'G' => !known(&KNOWN_G_CODES),
+        'M' => !known(&KNOWN_M_CODES),
+        _ => false,
+    }
+}
+
+// look for dangerous patterns, a torch left on is reported last at the line that turned it on
+pub fn lint_program(program: String, options: &LintOptions) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut state = ModalState::new();
+    let mut units_set = false;
+    let mut positioning_set = false;
+    let mut warned_units = false;
+    let mut warned_positioning = false;
+    // the line the torch was last turned on, if it's still on
+    let mut torch_on_line = None;
+
+    for (line_index, line) in program.lines().enumerate() {
+        let line_index = line_index as u32;
+        if let Some(text) = unreadable_text(line) {
+            diagnostics.push(diagnostic(Severity::Error, LintRule::UnknownWord, line_index, format!("can't read \"{}\"", text)));
+        }
+        let words = parse_words(line);
+        for word in words.iter().filter(|word| unknown_word(word)) {
+            diagnostics.push(diagnostic(Severity::Error, LintRule::UnknownWord, line_index, format!("{}{} isn't supported", word.letter, word.value)));
+        }
+
+        let has_g = |value: f32| words.iter().any(|word| word.letter == 'G' && word.value == value);
+        units_set |= has_g(20.0) || has_g(21.0);
+        positioning_set |= has_g(90.0) || has_g(91.0);
+
+        let start = state.position.clone();
+        state.apply_words(&words);
+        if state.torch_on && torch_on_line.is_none() {
+            torch_on_line = Some(line_index);
+        } else if !state.torch_on {
+            torch_on_line = None;
+        }
+
+        if has_g(4.0) {
+            let seconds = words.iter().find(|word| word.letter == 'P').map_or(0.0, |word| word.value);
+            if state.torch_on && seconds > options.max_torch_dwell {
+                let message = format!("the torch stays on for a {}s dwell, more than {}s", seconds, options.max_torch_dwell);
+                diagnostics.push(diagnostic(Severity::Warning, LintRule::LongTorchDwell, line_index, message));
+            }
+            continue;
+        }
+
+        let moves = state.position.x != start.x
+            || state.position.y != start.y
+            || (matches!(state.motion_mode, MotionMode::ArcClockwise | MotionMode::ArcCounterClockwise) && words.iter().any(|word| matches!(word.letter, 'I' | 'J' | 'R')));
+        if !moves {
+            continue;
+        }
+        if !units_set && !warned_units {
+            warned_units = true;
+            diagnostics.push(diagnostic(Severity::Warning, LintRule::UnitsNotSet, line_index, "moves before the units are set with G20 or G21".to_string()));
+        }
+        if !positioning_set && !warned_positioning {
+            warned_positioning = true;
+            let message = "moves before the positioning mode is set with G90 or G91".to_string();
+            diagnostics.push(diagnostic(Severity::Warning, LintRule::PositioningModeNotSet, line_index, message));
+        }
+        match state.motion_mode {
+            MotionMode::Rapid if state.torch_on => {
+                diagnostics.push(diagnostic(Severity::Error, LintRule::RapidWithTorchOn, line_index, "rapid move (G0) with the torch on".to_string()));
+            },
+            MotionMode::Linear | MotionMode::ArcClockwise | MotionMode::ArcCounterClockwise if state.feedrate.unwrap_or(0.0) <= 0.0 => {
+                diagnostics.push(diagnostic(Severity::Error, LintRule::MissingFeedrate, line_index, "feed move without a feedrate".to_string()));
+            },
+            _ => {}
+        }
+    }
+
+    if let Some(line) = torch_on_line {
+        diagnostics.push(diagnostic(Severity::Error, LintRule::TorchLeftOn, line, "the torch is turned on and never turned off (M5)".to_string()));
+    }
+    diagnostics
+}
+
+impl Gcode {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn lint(&self, options: &LintOptions) -> Vec<LintDiagnostic> {
+        lint_program(self.get_gcode_string(), options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cut::Cut;
+    use crate::api::settings::CutterSettings;
+
+    fn rules(program: &str) -> Vec<(LintRule, u32)> {
+        lint_program(program.to_string(), &LintOptions::default()).into_iter().map(|diagnostic| (diagnostic.rule, diagnostic.line)).collect()
+    }
+
+    #[test]
+    fn generated_programs_are_clean() {
+        let mut gcode = Gcode::new();
+        gcode.add_cut(Cut::new(), &CutterSettings::default());
+        assert_eq!(gcode.lint(&LintOptions::default()), vec![]);
+    }
+
+    #[test]
+    fn flags_the_torch() {
+        assert_eq!(
+            rules("G21 G90\nM3\nG4 P5\nG0 X10\nG1 X20 F500\n"),
+            vec![(LintRule::LongTorchDwell, 2), (LintRule::RapidWithTorchOn, 3), (LintRule::TorchLeftOn, 1)]
+        );
+        assert_eq!(rules("G21 G90\nM3\nG1 X1 F10\nM5\nM3\nM5\n"), vec![]);
+    }
+
+    #[test]
+    fn program_end_turns_the_torch_off() {
+        assert_eq!(rules("G21 G90\nM3\nG1 X1 F10\nM2\n"), vec![]);
+        assert_eq!(rules("G21 G90\nM3\nG1 X1 F10\nM30\n"), vec![]);
+    }
+
+    #[test]
+    fn flags_feedrates_and_modes() {
+        let diagnostics = lint_program("G0 X10\nG1 X20\nG1 X30 F0\nG2 I5 F100\n".to_string(), &LintOptions::default());
+        let found: Vec<(Severity, LintRule, u32)> = diagnostics.iter().map(|d| (d.severity, d.rule, d.line)).collect();
+        assert_eq!(
+            found,
+            vec![
+                (Severity::Warning, LintRule::UnitsNotSet, 0),
+                (Severity::Warning, LintRule::PositioningModeNotSet, 0),
+                (Severity::Error, LintRule::MissingFeedrate, 1),
+                (Severity::Error, LintRule::MissingFeedrate, 2),
+            ]
+        );
+        assert_eq!(diagnostics[2].message, "Line 2: feed move without a feedrate");
+    }
+
+    #[test]
+    fn flags_unknown_words() {
+        let diagnostics = lint_program("G21 G90\nG1 X1 F10 Q5\nG41\nM6 (tool change)\nHELLO\n%\n$H\n".to_string(), &LintOptions::default());
+        let lines: Vec<u32> = diagnostics.iter().map(|diagnostic| diagnostic.line).collect();
+        assert!(diagnostics.iter().all(|diagnostic| diagnostic.rule == LintRule::UnknownWord));
+        assert_eq!(lines, vec![1, 2, 3, 4]);
+        assert_eq!(diagnostics[3].message, "Line 5: can't read \"HELLO\"");
+
+        // arc center modes and inverse time feed are grbl codes too
+        assert_eq!(rules("G21 G90 G90.1\nG91.1\nG93 G1 X1 F2\nG94\n"), vec![]);
+    }
+}
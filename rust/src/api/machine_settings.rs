@@ -0,0 +1,378 @@
+use std::fs;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SettingType {
+    // 0 or 1
+    Boolean,
+    // a mask of bits, e.g. one per axis
+    Bitfield,
+    Integer,
+    Decimal,
+    // strings, passwords, ip addresses
+    Text,
+}
+
+// what a setting is, from $ES on grblHAL or the built in table for plain grbl
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettingDescription {
+    pub name: String,
+    pub unit: String,
+    pub setting_type: SettingType,
+    pub group: Option<u32>,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MachineSetting {
+    pub id: u32,
+    pub value: String,
+    pub description: Option<SettingDescription>,
+}
+
+// a group from $EG, e.g. "Homing"
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettingGroup {
+    pub id: u32,
+    pub parent: u32,
+    pub name: String,
+}
+
+// the controller's $ settings, opaque to dart because of the descriptions kept for later values
+#[derive(Clone, Debug, Default, PartialEq)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct MachineSettings {
+    pub settings: Vec<MachineSetting>,
+    pub groups: Vec<SettingGroup>,
+    // descriptions from $ES, they can arrive before or after the values
+    descriptions: Vec<(u32, SettingDescription)>,
+}
+
+impl MachineSettings {
+    pub fn get(&self, id: u32) -> Option<MachineSetting> {
+        self.settings.iter().find(|setting| setting.id == id).cloned()
+    }
+
+    // the description of a setting, grblHAL's own if it sent one
+    pub fn description(&self, id: u32) -> Option<SettingDescription> {
+        self.descriptions
+            .iter()
+            .find(|(described, _)| *described == id)
+            .map(|(_, description)| description.clone())
+            .or_else(|| builtin_description(id))
+    }
+
+    // update with a line of the controller's reply, returns false if the line isn't about settings
+    pub(crate) fn apply_report_line(&mut self, line: &str) -> bool {
+        if let Some((id, value)) = parse_setting_line(line) {
+            let description = self.description(id);
+            let setting = MachineSetting { id, value, description };
+            match self.settings.iter_mut().find(|existing| existing.id == id) {
+                Some(existing) => *existing = setting,
+                None => {
+                    self.settings.push(setting);
+                    self.settings.sort_by_key(|setting| setting.id);
+                },
+            }
+            return true;
+        }
+
+        let Some(report) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) else {
+            return false;
+        };
+        if let Some(fields) = report.strip_prefix("SETTING:") {
+            let Some((id, description)) = parse_setting_description(fields) else {
+                return false;
+            };
+            if let Some(setting) = self.settings.iter_mut().find(|setting| setting.id == id) {
+                setting.description = Some(description.clone());
+            }
+            self.descriptions.retain(|(described, _)| *described != id);
+            self.descriptions.push((id, description));
+            true
+        } else if let Some(fields) = report.strip_prefix("SETTINGGROUP:") {
+            let fields: Vec<&str> = fields.split('|').collect();
+            let (Some(Ok(id)), Some(Ok(parent)), Some(name)) = (fields.first().map(|f| f.parse()), fields.get(1).map(|f| f.parse()), fields.get(2)) else {
+                return false;
+            };
+            self.groups.retain(|group| group.id != id);
+            self.groups.push(SettingGroup { id, parent, name: name.to_string() });
+            true
+        } else {
+            false
+        }
+    }
+
+    // check a value before it's written, returns it the way it should be sent
+    pub fn validate(&self, id: u32, value: &str) -> Result<String, String> {
+        let value = value.trim();
+        if value.is_empty() || value.contains(['\n', '\r', '$']) {
+            return Err(format!("Invalid value for ${}", id));
+        }
+        let Some(description) = self.description(id) else {
+            if self.get(id).is_some() {
+                // the controller has it, but we don't know anything about it
+                return Ok(value.to_string());
+            }
+            return Err(format!("Unknown setting ${}", id));
+        };
+
+        let number = match description.setting_type {
+            SettingType::Text => return Ok(value.to_string()),
+            SettingType::Boolean => match value {
+                "0" | "1" => return Ok(value.to_string()),
+                _ => return Err(format!("${} ({}) has to be 0 or 1", id, description.name)),
+            },
+            SettingType::Bitfield | SettingType::Integer => value
+                .parse::<u32>()
+                .map(|number| number as f32)
+                .map_err(|_| format!("${} ({}) has to be a whole number", id, description.name))?,
+            SettingType::Decimal => value
+                .parse::<f32>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .ok_or(format!("${} ({}) has to be a number", id, description.name))?,
+        };
+
+        if description.min.is_some_and(|min| number < min) || description.max.is_some_and(|max| number > max) {
+            let range = match (description.min, description.max) {
+                (Some(min), Some(max)) => format!("between {} and {}", min, max),
+                (Some(min), None) => format!("at least {}", min),
+                (_, _) => format!("at most {}", description.max.unwrap_or(0.0)),
+            };
+            return Err(format!("${} ({}) has to be {}", id, description.name, range));
+        }
+        Ok(value.to_string())
+    }
+
+    // every setting as $n=value lines
+    pub fn to_backup(&self) -> String {
+        let mut backup = String::from("; grbl settings backup\n");
+        for setting in &self.settings {
+            match &setting.description {
+                Some(description) => backup += &format!("${}={} ; {}\n", setting.id, setting.value, description.name),
+                None => backup += &format!("${}={}\n", setting.id, setting.value),
+            }
+        }
+        backup
+    }
+
+    pub fn save_backup(&self, filename: String) -> Result<(), String> {
+        if self.settings.is_empty() {
+            return Err("No settings were read from the machine yet".to_string());
+        }
+        fs::write(&filename, self.to_backup()).map_err(|e| format!("Failed to write {}: {}", filename, e))
+    }
+
+    // the $n=value commands needed to bring the machine to the backup, only for values that differ
+    pub(crate) fn restore_commands(&self, backup: &str) -> Result<Vec<String>, String> {
+        let mut commands = Vec::new();
+        for (i, line) in backup.lines().enumerate() {
+            let line = line.split(';').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (id, value) = parse_setting_line(line).ok_or(format!("Line {} of the backup isn't a setting: {}", i + 1, line))?;
+            let value = self.validate(id, &value)?;
+            if self.get(id).is_some_and(|current| current.value == value) {
+                continue;
+            }
+            commands.push(format!("${}={}", id, value));
+        }
+        Ok(commands)
+    }
+}
+
+// $n=value
+fn parse_setting_line(line: &str) -> Option<(u32, String)> {
+    let (id, value) = line.strip_prefix('$')?.split_once('=')?;
+    Some((id.trim().parse().ok()?, value.trim().to_string()))
+}
+
+// id|group|name|unit|data type|format|min|max
+fn parse_setting_description(fields: &str) -> Option<(u32, SettingDescription)> {
+    let fields: Vec<&str> = fields.split('|').collect();
+    let id = fields.first()?.parse().ok()?;
+    let setting_type = match fields.get(4)?.parse::<u32>().ok()? {
+        0 => SettingType::Boolean,
+        1 | 2 | 4 => SettingType::Bitfield,
+        3 | 5 => SettingType::Integer,
+        6 => SettingType::Decimal,
+        _ => SettingType::Text,
+    };
+    // text settings use min and max for the length
+    let limit = |index: usize| match setting_type {
+        SettingType::Text => None,
+        _ => fields.get(index).and_then(|field| field.parse().ok()),
+    };
+    let description = SettingDescription {
+        name: fields.get(2)?.to_string(),
+        unit: fields.get(3).unwrap_or(&"").to_string(),
+        setting_type,
+        group: fields.get(1).and_then(|field| field.parse().ok()),
+        min: limit(6),
+        max: limit(7),
+    };
+    Some((id, description))
+}
+
+// the settings of grbl 1.1, which can't describe them itself
+fn builtin_description(id: u32) -> Option<SettingDescription> {
+    use SettingType::*;
+    let (name, unit, setting_type): (&str, &str, SettingType) = match id {
+        0 => ("Step pulse time", "microseconds", Integer),
+        1 => ("Step idle delay", "milliseconds", Integer),
+        2 => ("Step pulse invert", "mask", Bitfield),
+        3 => ("Step direction invert", "mask", Bitfield),
+        4 => ("Invert step enable pin", "boolean", Boolean),
+        5 => ("Invert limit pins", "boolean", Boolean),
+        6 => ("Invert probe pin", "boolean", Boolean),
+        10 => ("Status report options", "mask", Bitfield),
+        11 => ("Junction deviation", "mm", Decimal),
+        12 => ("Arc tolerance", "mm", Decimal),
+        13 => ("Report in inches", "boolean", Boolean),
+        20 => ("Soft limits enable", "boolean", Boolean),
+        21 => ("Hard limits enable", "boolean", Boolean),
+        22 => ("Homing cycle enable", "boolean", Boolean),
+        23 => ("Homing direction invert", "mask", Bitfield),
+        24 => ("Homing locate feed rate", "mm/min", Decimal),
+        25 => ("Homing search seek rate", "mm/min", Decimal),
+        26 => ("Homing switch debounce delay", "milliseconds", Integer),
+        27 => ("Homing switch pull-off distance", "mm", Decimal),
+        30 => ("Maximum spindle speed", "RPM", Decimal),
+        31 => ("Minimum spindle speed", "RPM", Decimal),
+        32 => ("Laser-mode enable", "boolean", Boolean),
+        100..=102 => ("steps/mm", "steps/mm", Decimal),
+        110..=112 => ("maximum rate", "mm/min", Decimal),
+        120..=122 => ("acceleration", "mm/sec^2", Decimal),
+        130..=132 => ("maximum travel", "mm", Decimal),
+        _ => return None,
+    };
+    // the per axis settings get their axis in front
+    let name = match id {
+        100.. => format!("{} {}", ["X", "Y", "Z"][(id % 10) as usize], name),
+        _ => name.to_string(),
+    };
+    let max = match setting_type {
+        Boolean => Some(1.0),
+        Bitfield => Some(255.0),
+        _ => None,
+    };
+    Some(SettingDescription { name, unit: unit.to_string(), setting_type, group: None, min: Some(0.0), max })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::job::job_lines;
+    use crate::api::sender::MachineConnection;
+    use crate::api::simulator::{connect_to_simulator, GrblSimulator, SimulatorConfig};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    fn wait_for_setting(connection: &MachineConnection, id: u32, value: &str) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if connection.get_machine_settings().get(id).is_some_and(|setting| setting.value == value) {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        false
+    }
+
+    #[test]
+    fn parses_values_and_grblhal_descriptions() {
+        let mut settings = MachineSettings::default();
+        assert!(settings.apply_report_line("$110=5000.000"));
+        assert!(settings.apply_report_line("[SETTINGGROUP:5|0|Homing]"));
+        assert!(settings.apply_report_line("[SETTING:24|5|Homing locate feed rate|mm/min|6|###0.0|1|10000]"));
+        assert!(settings.apply_report_line("$24=25.000"));
+        assert!(!settings.apply_report_line("[MSG:Caution: Unlocked]"));
+        assert!(!settings.apply_report_line("ok"));
+
+        assert_eq!(settings.get(110).unwrap().description.unwrap().name, "X maximum rate");
+        let homing_feed = settings.get(24).unwrap();
+        assert_eq!(homing_feed.value, "25.000");
+        assert_eq!(homing_feed.description.as_ref().unwrap().max, Some(10000.0));
+        assert_eq!(homing_feed.description.unwrap().group, Some(5));
+        assert_eq!(settings.groups, vec![SettingGroup { id: 5, parent: 0, name: "Homing".to_string() }]);
+    }
+
+    #[test]
+    fn validates_writes() {
+        let mut settings = MachineSettings::default();
+        settings.apply_report_line("[SETTING:24|5|Homing locate feed rate|mm/min|6|###0.0|1|10000]");
+
+        assert_eq!(settings.validate(24, " 100.5 "), Ok("100.5".to_string()));
+        assert!(settings.validate(24, "0.5").is_err());
+        assert!(settings.validate(24, "fast").is_err());
+        assert!(settings.validate(20, "2").is_err());
+        assert!(settings.validate(23, "3.5").is_err());
+        assert!(settings.validate(100, "80\n$H").is_err());
+        assert!(settings.validate(999, "1").is_err());
+        assert_eq!(settings.validate(100, "80"), Ok("80".to_string()));
+    }
+
+    #[test]
+    fn restores_only_what_changed() {
+        let mut settings = MachineSettings::default();
+        settings.apply_report_line("$100=80.000");
+        settings.apply_report_line("$110=5000.000");
+
+        let backup = settings.to_backup().replace("$110=5000.000", "$110=4000.000");
+        assert_eq!(settings.restore_commands(&backup), Ok(vec!["$110=4000.000".to_string()]));
+        assert!(settings.restore_commands("$20=7\n").is_err());
+        assert!(settings.restore_commands("G0 X1\n").is_err());
+    }
+
+    #[test]
+    fn reads_writes_and_restores_the_machine_settings() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+        connection.read_machine_settings();
+        assert!(wait_for_setting(&connection, 110, "5000.000"));
+
+        let backup_path = std::env::temp_dir().join(format!("grbl_settings_{}.txt", std::process::id()));
+        let backup_path = backup_path.to_string_lossy().to_string();
+        connection.get_machine_settings().save_backup(backup_path.clone()).unwrap();
+
+        assert!(connection.write_machine_setting(110, "abc".to_string()).is_err());
+        connection.write_machine_setting(110, "3000".to_string()).unwrap();
+        assert!(wait_for_setting(&connection, 110, "3000.000"));
+
+        assert_eq!(connection.restore_machine_settings(backup_path.clone()), Ok(1));
+        assert!(wait_for_setting(&connection, 110, "5000.000"));
+        let _ = fs::remove_file(backup_path);
+        connection.disconnect();
+    }
+
+    #[test]
+    fn only_writes_settings_while_the_machine_is_idle() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+        connection.read_machine_settings();
+        assert!(wait_for_setting(&connection, 110, "5000.000"));
+
+        // a slow move keeps the machine busy
+        connection.send_gcode_command("G1 X50 F100".to_string());
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !simulator.lock().unwrap().received_lines().iter().any(|line| line == "G1X50F100") && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        connection.write_machine_setting(110, "3000".to_string()).unwrap();
+
+        // a job owns the machine until it's done
+        let program: String = (1..=40).map(|x| format!("G1 Y{} F100\n", x)).collect();
+        connection.start_job_with(job_lines(&program), |_| {});
+        while !simulator.lock().unwrap().received_lines().iter().any(|line| line == "G1Y1F100") && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(connection.write_machine_setting(110, "3000".to_string()), Err("Not possible while a job is running".to_string()));
+
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(!simulator.lock().unwrap().received_lines().iter().any(|line| line.starts_with("$110=")));
+        connection.abort_job();
+        connection.disconnect();
+    }
+}
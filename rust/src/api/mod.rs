@@ -1,6 +1,28 @@
 pub mod simple;
 pub mod gcode;
+pub mod interpreter;
+pub mod toolpath;
+pub mod estimate;
+pub mod bounds;
+pub mod lint;
+pub mod render;
+pub mod profile;
+pub mod dxf;
+pub mod svg;
+pub mod job_plan;
+pub mod job_file;
+pub mod report;
+pub mod export;
 pub mod cut;
 pub mod sender;
+pub mod job;
+pub mod jog;
+pub mod homing;
+pub mod wcs;
+pub mod laser;
+pub mod machine_settings;
+pub mod capabilities;
 pub mod status;
-pub mod settings;
\ No newline at end of file
+pub mod settings;
+pub mod transport;
+pub mod simulator;
\ No newline at end of file
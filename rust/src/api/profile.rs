@@ -0,0 +1,320 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::gcode::{Gcode, Pos2D};
+use crate::api::toolpath::{distance, flatten_arc};
+
+// end points closer than this (mm) are taken to be the same point when chaining
+const CHAIN_TOLERANCE: f32 = 0.01;
+// how closely arcs are followed when checking which contour is inside which
+const NESTING_TOLERANCE: f32 = 0.05;
+
+// a piece of a drawn profile, in mm
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum ProfileSegment {
+    Line { start: Pos2D, end: Pos2D },
+    // start and end are the same point for a full circle
+    Arc { start: Pos2D, end: Pos2D, center: Pos2D, clockwise: bool },
+}
+
+impl ProfileSegment {
+    pub fn start(&self) -> &Pos2D {
+        match self {
+            ProfileSegment::Line { start, .. } | ProfileSegment::Arc { start, .. } => start,
+        }
+    }
+
+    pub fn end(&self) -> &Pos2D {
+        match self {
+            ProfileSegment::Line { end, .. } | ProfileSegment::Arc { end, .. } => end,
+        }
+    }
+
+    fn reversed(&self) -> Self {
+        match self {
+            ProfileSegment::Line { start, end } => ProfileSegment::Line { start: end.clone(), end: start.clone() },
+            ProfileSegment::Arc { start, end, center, clockwise } => {
+                ProfileSegment::Arc { start: end.clone(), end: start.clone(), center: center.clone(), clockwise: !clockwise }
+            },
+        }
+    }
+
+    // the same segment with every point mapped, the mapping mustn't mirror
+    fn transformed(&self, map: impl Fn(&Pos2D) -> Pos2D) -> Self {
+        match self {
+            ProfileSegment::Line { start, end } => ProfileSegment::Line { start: map(start), end: map(end) },
+            ProfileSegment::Arc { start, end, center, clockwise } => {
+                ProfileSegment::Arc { start: map(start), end: map(end), center: map(center), clockwise: *clockwise }
+            },
+        }
+    }
+
+    // the points along the segment, arcs are split into chords within tolerance (mm) of the arc
+    pub fn flatten(&self, tolerance: f32) -> Vec<Pos2D> {
+        match self {
+            ProfileSegment::Line { start, end } => vec![start.clone(), end.clone()],
+            ProfileSegment::Arc { start, end, center, clockwise } => flatten_arc(start, end, center, *clockwise, tolerance),
+        }
+    }
+}
+
+// segments joined end to end
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Contour {
+    pub segments: Vec<ProfileSegment>,
+    // ends where it starts
+    pub closed: bool,
+}
+
+impl Contour {
+    fn points(&self) -> Vec<Pos2D> {
+        let mut points: Vec<Pos2D> = Vec::new();
+        for segment in &self.segments {
+            let flattened = segment.flatten(NESTING_TOLERANCE);
+            let skip = if points.is_empty() { 0 } else { 1 };
+            points.extend(flattened.into_iter().skip(skip));
+        }
+        points
+    }
+
+    // even-odd test against the flattened outline, only closed contours have an inside
+    fn contains(&self, point: &Pos2D) -> bool {
+        if !self.closed {
+            return false;
+        }
+        let outline = self.points();
+        let mut inside = false;
+        for edge in outline.windows(2) {
+            let (a, b) = (&edge[0], &edge[1]);
+            if (a.y > point.y) != (b.y > point.y) && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+// a 2D shape to cut, made of contours in the order they're cut
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Profile {
+    pub contours: Vec<Contour>,
+}
+
+// what a profile is cut with
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CutParameters {
+    // mm/min
+    pub feedrate: f32,
+    // seconds
+    pub pierce_delay: f32,
+}
+
+impl Profile {
+    // join loose segments into contours and order them so holes are cut before the part around them falls out
+    pub fn from_segments(segments: Vec<ProfileSegment>) -> Self {
+        let mut profile = Profile { contours: chain(segments) };
+        profile.order_inside_first();
+        profile
+    }
+
+    fn order_inside_first(&mut self) {
+        let depth = |contour: &Contour| match contour.segments.first() {
+            Some(segment) => self.contours.iter().filter(|other| *other != contour && other.contains(segment.start())).count(),
+            None => 0,
+        };
+        let depths: Vec<usize> = self.contours.iter().map(depth).collect();
+        let mut ordered: Vec<(usize, Contour)> = depths.into_iter().zip(self.contours.drain(..)).collect();
+        // the deepest first, otherwise in drawing order
+        ordered.sort_by_key(|(depth, _)| std::cmp::Reverse(*depth));
+        self.contours = ordered.into_iter().map(|(_, contour)| contour).collect();
+    }
+
+    // (min, max) corners of the box around the profile, None if it's empty
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn bounds(&self) -> Option<(Pos2D, Pos2D)> {
+        let mut points = self.contours.iter().flat_map(|contour| contour.points());
+        let first = points.next()?;
+        let (mut min, mut max) = (first.clone(), first);
+        for point in points {
+            min = Pos2D::new(min.x.min(point.x), min.y.min(point.y));
+            max = Pos2D::new(max.x.max(point.x), max.y.max(point.y));
+        }
+        Some((min, max))
+    }
+
+    fn transformed(&self, map: impl Fn(&Pos2D) -> Pos2D) -> Profile {
+        let contours = self
+            .contours
+            .iter()
+            .map(|contour| Contour { segments: contour.segments.iter().map(|segment| segment.transformed(&map)).collect(), closed: contour.closed })
+            .collect();
+        Profile { contours }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn moved(&self, dx: f32, dy: f32) -> Profile {
+        self.transformed(|point| Pos2D::new(point.x + dx, point.y + dy))
+    }
+
+    // e.g. to turn inches into mm
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn scaled(&self, factor: f32) -> Profile {
+        self.transformed(|point| Pos2D::new(point.x * factor, point.y * factor))
+    }
+
+    // center the profile across a tube face (X 0 to tube_width) with its lowest point at y_position
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn place_on_tube_face(&self, tube_width: f32, y_position: f32) -> Result<Profile, String> {
+        let (min, max) = self.bounds().ok_or("The profile is empty".to_string())?;
+        let width = max.x - min.x;
+        if width > tube_width {
+            return Err(format!("The profile is {:.1}mm wide, the tube face only {:.1}mm", width, tube_width));
+        }
+        Ok(self.moved((tube_width - width) / 2.0 - min.x, y_position - min.y))
+    }
+
+    // put the lower left corner of the profile at (x, y), for cutting flat stock
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn place_on_flat_stock(&self, x: f32, y: f32) -> Profile {
+        match self.bounds() {
+            Some((min, _)) => self.moved(x - min.x, y - min.y),
+            None => self.clone(),
+        }
+    }
+}
+
+// join segments whose ends meet, flipping them where needed
+fn chain(mut loose: Vec<ProfileSegment>) -> Vec<Contour> {
+    let mut contours = Vec::new();
+    while !loose.is_empty() {
+        let mut segments = vec![loose.remove(0)];
+        let meets = |a: &Pos2D, b: &Pos2D| distance(a, b) <= CHAIN_TOLERANCE;
+
+        // grow the end, then the start
+        loop {
+            let closed = meets(segments[segments.len() - 1].end(), segments[0].start());
+            if (closed && segments.len() > 1) || meets(segments[0].start(), segments[0].end()) {
+                break;
+            }
+            let end = segments[segments.len() - 1].end().clone();
+            if let Some(index) = loose.iter().position(|segment| meets(segment.start(), &end)) {
+                segments.push(loose.remove(index));
+            } else if let Some(index) = loose.iter().position(|segment| meets(segment.end(), &end)) {
+                segments.push(loose.remove(index).reversed());
+            } else {
+                break;
+            }
+        }
+        loop {
+            let start = segments[0].start().clone();
+            if meets(segments[segments.len() - 1].end(), &start) {
+                break;
+            }
+            if let Some(index) = loose.iter().position(|segment| meets(segment.end(), &start)) {
+                segments.insert(0, loose.remove(index));
+            } else if let Some(index) = loose.iter().position(|segment| meets(segment.start(), &start)) {
+                segments.insert(0, loose.remove(index).reversed());
+            } else {
+                break;
+            }
+        }
+
+        let closed = meets(segments[segments.len() - 1].end(), segments[0].start());
+        contours.push(Contour { segments, closed });
+    }
+    contours
+}
+
+impl Gcode {
+    // cut every contour of a profile that was already placed where it should go
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn add_profile(&mut self, profile: &Profile, parameters: &CutParameters) {
+        self.begin_cut();
+        for contour in &profile.contours {
+            // a contour without segments has nothing to cut
+            let Some(first) = contour.segments.first() else {
+                continue;
+            };
+            // get there with the torch off
+            self.move_xy(first.start(), parameters.feedrate);
+            self.set_plasma_enabled(true);
+            self.dwell(parameters.pierce_delay);
+            for segment in &contour.segments {
+                match segment {
+                    ProfileSegment::Line { end, .. } => self.move_xy(end, parameters.feedrate),
+                    ProfileSegment::Arc { start, end, center, clockwise } => {
+                        self.arc_xy(end, &Pos2D::new(center.x - start.x, center.y - start.y), *clockwise, parameters.feedrate)
+                    },
+                }
+            }
+            self.set_plasma_enabled(false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::toolpath::SegmentKind;
+    use std::f32::consts::PI;
+
+    fn line(x1: f32, y1: f32, x2: f32, y2: f32) -> ProfileSegment {
+        ProfileSegment::Line { start: Pos2D::new(x1, y1), end: Pos2D::new(x2, y2) }
+    }
+
+    fn square(x: f32, y: f32, size: f32) -> Vec<ProfileSegment> {
+        vec![line(x, y, x + size, y), line(x + size, y, x + size, y + size), line(x + size, y + size, x, y + size), line(x, y + size, x, y)]
+    }
+
+    #[test]
+    fn chains_segments_in_any_order_and_direction() {
+        let mut segments = square(0.0, 0.0, 10.0);
+        segments.swap(0, 2);
+        segments[1] = segments[1].reversed();
+        segments.push(line(20.0, 0.0, 30.0, 0.0));
+        segments.insert(0, line(30.0, 0.0, 30.0, 5.0));
+
+        let profile = Profile::from_segments(segments);
+        assert_eq!(profile.contours.len(), 2);
+        let closed = profile.contours.iter().find(|contour| contour.closed).unwrap();
+        assert_eq!(closed.segments.len(), 4);
+        for pair in closed.segments.windows(2) {
+            assert_eq!(pair[0].end(), pair[1].start());
+        }
+        let open = profile.contours.iter().find(|contour| !contour.closed).unwrap();
+        assert_eq!((open.segments[0].start(), open.segments[1].end()), (&Pos2D::new(20.0, 0.0), &Pos2D::new(30.0, 5.0)));
+    }
+
+    #[test]
+    fn cuts_holes_before_the_outline() {
+        let mut segments = square(0.0, 0.0, 30.0);
+        segments.extend(square(5.0, 5.0, 20.0));
+        let circle = Pos2D::new(15.0, 15.0);
+        segments.push(ProfileSegment::Arc { start: Pos2D::new(18.0, 15.0), end: Pos2D::new(18.0, 15.0), center: circle, clockwise: false });
+
+        let profile = Profile::from_segments(segments);
+        let firsts: Vec<Pos2D> = profile.contours.iter().map(|contour| contour.segments[0].start().clone()).collect();
+        assert_eq!(firsts, vec![Pos2D::new(18.0, 15.0), Pos2D::new(5.0, 5.0), Pos2D::new(0.0, 0.0)]);
+    }
+
+    #[test]
+    fn places_and_cuts_a_profile_on_the_tube_face() {
+        let mut segments = square(100.0, 100.0, 10.0);
+        segments.push(ProfileSegment::Arc { start: Pos2D::new(107.0, 105.0), end: Pos2D::new(107.0, 105.0), center: Pos2D::new(105.0, 105.0), clockwise: true });
+        let profile = Profile::from_segments(segments);
+        assert!(profile.place_on_tube_face(8.0, 0.0).is_err());
+        let placed = profile.place_on_tube_face(20.0, 50.0).unwrap();
+        assert_eq!(placed.bounds().unwrap(), (Pos2D::new(5.0, 50.0), Pos2D::new(15.0, 60.0)));
+
+        let mut gcode = Gcode::new();
+        gcode.add_profile(&placed, &CutParameters { feedrate: 800.0, pierce_delay: 0.4 });
+        assert_eq!(gcode.get_cut_count(), 1);
+        let toolpath = gcode.get_toolpath();
+        assert_eq!(toolpath.iter().filter(|segment| segment.kind == SegmentKind::TorchOn).count(), 2);
+        // the hole comes first and is a full circle
+        let hole = toolpath.iter().find(|segment| matches!(segment.kind, SegmentKind::Arc { .. })).unwrap();
+        assert!(hole.torch_on);
+        assert_eq!(hole.kind, SegmentKind::Arc { center: Pos2D::new(10.0, 55.0), clockwise: true });
+        assert!((hole.length() - 4.0 * PI).abs() < 0.001);
+    }
+}
@@ -0,0 +1,463 @@
+use miniz_oxide::deflate::compress_to_vec_zlib;
+
+use crate::api::bounds::program_extents;
+use crate::api::gcode::{Gcode, Pos2D};
+use crate::api::toolpath::{simulate_toolpath, SegmentKind, ToolpathSegment};
+
+const BACKGROUND: [u8; 3] = [255, 255, 255];
+const TUBE_COLOR: [u8; 3] = [136, 136, 136];
+const RAPID_COLOR: [u8; 3] = [120, 170, 220];
+const CUT_COLOR: [u8; 3] = [210, 30, 30];
+const PIERCE_COLOR: [u8; 3] = [240, 140, 0];
+const LABEL_COLOR: [u8; 3] = [0, 0, 0];
+// radius of the pierce markers, in pixels
+const PIERCE_RADIUS: f32 = 3.0;
+
+fn hex(color: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+// maps mm onto the screen: scaled by pixels per mm, then moved by the offset (in pixels)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Viewport {
+    pub scale: f32,
+    pub offset: Pos2D,
+}
+
+impl Viewport {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(scale: f32, offset: Pos2D) -> Self {
+        Self { scale, offset }
+    }
+
+    // the origin is offset to the left by half the tube width, so the tube is centered on screen x 0
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn centered_on_tube(tube_width: f32, scale: f32) -> Self {
+        Self { scale, offset: Pos2D::new(-(tube_width / 2.0) * scale, 0.0) }
+    }
+
+    // the largest scale that fits the box from min to max into width x height pixels, with a margin on every side
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fit(min: &Pos2D, max: &Pos2D, width: f32, height: f32, margin: f32) -> Self {
+        let span_x = (max.x - min.x).max(f32::EPSILON);
+        let span_y = (max.y - min.y).max(f32::EPSILON);
+        let scale = ((width - 2.0 * margin) / span_x).min((height - 2.0 * margin) / span_y).max(f32::EPSILON);
+        // center the box
+        let offset = Pos2D::new((width - span_x * scale) / 2.0 - min.x * scale, (height - span_y * scale) / 2.0 - min.y * scale);
+        Self { scale, offset }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn to_screen(&self, position: &Pos2D) -> Pos2D {
+        Pos2D::new(position.x * self.scale + self.offset.x, position.y * self.scale + self.offset.y)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderOptions {
+    // pixels
+    pub width: u32,
+    pub height: u32,
+    pub margin: f32,
+    // the tube runs along Y from X 0 to this, None to leave the outline out
+    pub tube_width: Option<f32>,
+    // moves with the torch off
+    pub show_rapids: bool,
+    // number the parts between the cuts (SVG only, the PNG has no text)
+    pub show_labels: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self { width: 800, height: 1000, margin: 40.0, tube_width: None, show_rapids: true, show_labels: true }
+    }
+}
+
+// everything that ends up in the picture, in screen coordinates
+struct Drawing {
+    tube: Option<(Pos2D, Pos2D)>,
+    rapids: Vec<Vec<Pos2D>>,
+    cuts: Vec<Vec<Pos2D>>,
+    pierces: Vec<Pos2D>,
+    labels: Vec<(Pos2D, String)>,
+}
+
+fn layout(segments: &[ToolpathSegment], cut_start_lines: &[u32], options: &RenderOptions) -> Drawing {
+    let extents = program_extents(segments);
+    let (mut min, mut max) = match &extents {
+        Some(extents) => (extents.min.clone(), extents.max.clone()),
+        None => (Pos2D::new(0.0, 0.0), Pos2D::new(0.0, 0.0)),
+    };
+    if let Some(tube_width) = options.tube_width {
+        min.x = min.x.min(0.0);
+        max.x = max.x.max(tube_width);
+    }
+    let viewport = Viewport::fit(&min, &max, options.width as f32, options.height as f32, options.margin);
+    // half a pixel is as good as the picture gets
+    let tolerance = 0.5 / viewport.scale;
+
+    let mut drawing = Drawing {
+        tube: options.tube_width.map(|tube_width| (viewport.to_screen(&Pos2D::new(0.0, min.y)), viewport.to_screen(&Pos2D::new(tube_width, max.y)))),
+        rapids: Vec::new(),
+        cuts: Vec::new(),
+        pierces: Vec::new(),
+        labels: Vec::new(),
+    };
+    // where each cut pierces first, to place the part labels
+    let mut cut_pierces: Vec<Option<Pos2D>> = vec![None; cut_start_lines.len()];
+
+    for segment in segments {
+        match segment.kind {
+            SegmentKind::TorchOn => {
+                drawing.pierces.push(viewport.to_screen(&segment.start));
+                if let Some(cut) = cut_start_lines.iter().rposition(|start| *start <= segment.line) {
+                    cut_pierces[cut].get_or_insert(segment.start.clone());
+                }
+            },
+            SegmentKind::Rapid | SegmentKind::Cut | SegmentKind::Arc { .. } if segment.length() > 0.0 => {
+                let points = segment.flatten(tolerance).iter().map(|point| viewport.to_screen(point)).collect();
+                if segment.torch_on {
+                    drawing.cuts.push(points);
+                } else if options.show_rapids {
+                    drawing.rapids.push(points);
+                }
+            },
+            _ => {}
+        }
+    }
+
+    if options.show_labels {
+        // the parts are the pieces of tube between two cuts, in order along the tube
+        let mut cut_ys: Vec<f32> = cut_pierces.iter().flatten().map(|pierce| pierce.y).collect();
+        cut_ys.sort_by(|a, b| a.total_cmp(b));
+        let center_x = options.tube_width.map_or((min.x + max.x) / 2.0, |tube_width| tube_width / 2.0);
+        for (part, ends) in cut_ys.windows(2).enumerate() {
+            let position = viewport.to_screen(&Pos2D::new(center_x, (ends[0] + ends[1]) / 2.0));
+            drawing.labels.push((position, format!("Part {}", part + 1)));
+        }
+    }
+    drawing
+}
+
+fn svg_points(points: &[Pos2D]) -> String {
+    points.iter().map(|point| format!("{:.2},{:.2}", point.x, point.y)).collect::<Vec<String>>().join(" ")
+}
+
+pub fn render_svg(segments: &[ToolpathSegment], cut_start_lines: &[u32], options: &RenderOptions) -> String {
+    let drawing = layout(segments, cut_start_lines, options);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n",
+        options.width, options.height
+    );
+    svg += &format!("<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n", hex(BACKGROUND));
+    if let Some((corner, opposite)) = &drawing.tube {
+        svg += &format!(
+            "<rect class=\"tube\" x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\"/>\n",
+            corner.x,
+            corner.y,
+            opposite.x - corner.x,
+            opposite.y - corner.y,
+            hex(TUBE_COLOR)
+        );
+    }
+    for rapid in &drawing.rapids {
+        svg += &format!(
+            "<polyline class=\"rapid\" points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"1\" stroke-dasharray=\"4 3\"/>\n",
+            svg_points(rapid),
+            hex(RAPID_COLOR)
+        );
+    }
+    for cut in &drawing.cuts {
+        svg += &format!("<polyline class=\"cut\" points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\"/>\n", svg_points(cut), hex(CUT_COLOR));
+    }
+    for pierce in &drawing.pierces {
+        svg += &format!("<circle class=\"pierce\" cx=\"{:.2}\" cy=\"{:.2}\" r=\"{}\" fill=\"{}\"/>\n", pierce.x, pierce.y, PIERCE_RADIUS, hex(PIERCE_COLOR));
+    }
+    for (position, text) in &drawing.labels {
+        svg += &format!(
+            "<text class=\"label\" x=\"{:.2}\" y=\"{:.2}\" font-family=\"sans-serif\" font-size=\"14\" text-anchor=\"middle\">{}</text>\n",
+            position.x, position.y, text
+        );
+    }
+    svg += "</svg>\n";
+    svg
+}
+
+// 5x7 pixel glyphs for the part labels, a row per byte with the leftmost pixel in bit 4
+fn glyph(character: char) -> [u8; 7] {
+    match character {
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        'P' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000],
+        'a' => [0b00000, 0b00000, 0b01110, 0b00001, 0b01111, 0b10001, 0b01111],
+        'r' => [0b00000, 0b00000, 0b10110, 0b11001, 0b10000, 0b10000, 0b10000],
+        't' => [0b01000, 0b01000, 0b11100, 0b01000, 0b01000, 0b01001, 0b00110],
+        // the labels don't use anything else
+        _ => [0; 7],
+    }
+}
+
+// a plain rgb picture to draw the toolpath into
+struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32) -> Result<Self, String> {
+        // a png has at least one pixel each way
+        let (width, height) = (width.max(1), height.max(1));
+        let too_big = || format!("A {} x {} pixel picture is too big", width, height);
+        let length = (width as usize).checked_mul(height as usize).and_then(|pixels| pixels.checked_mul(3)).ok_or_else(too_big)?;
+        let mut pixels = Vec::new();
+        pixels.try_reserve_exact(length).map_err(|_| too_big())?;
+        pixels.extend(BACKGROUND.iter().copied().cycle().take(length));
+        Ok(Self { width, height, pixels })
+    }
+
+    fn set(&mut self, x: i64, y: i64, color: [u8; 3]) {
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return;
+        }
+        let index = (y as usize * self.width as usize + x as usize) * 3;
+        self.pixels[index..index + 3].copy_from_slice(&color);
+    }
+
+    fn dot(&mut self, center: &Pos2D, radius: f32, color: [u8; 3]) {
+        let reach = radius.ceil() as i64;
+        let (cx, cy) = (center.x.round() as i64, center.y.round() as i64);
+        for y in -reach..=reach {
+            for x in -reach..=reach {
+                if ((x * x + y * y) as f32) <= radius * radius {
+                    self.set(cx + x, cy + y, color);
+                }
+            }
+        }
+    }
+
+    fn line(&mut self, from: &Pos2D, to: &Pos2D, thickness: f32, color: [u8; 3]) {
+        let steps = (to.x - from.x).abs().max((to.y - from.y).abs()).ceil().max(1.0) as u32;
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            let point = Pos2D::new(from.x + (to.x - from.x) * t, from.y + (to.y - from.y) * t);
+            self.dot(&point, thickness / 2.0, color);
+        }
+    }
+
+    fn polyline(&mut self, points: &[Pos2D], thickness: f32, color: [u8; 3]) {
+        for pair in points.windows(2) {
+            self.line(&pair[0], &pair[1], thickness, color);
+        }
+    }
+
+    // text centered on x with its baseline at y, like the svg labels
+    fn text(&mut self, anchor: &Pos2D, text: &str, scale: i64, color: [u8; 3]) {
+        // a pixel of space between the glyphs
+        let advance = 6 * scale;
+        let width = text.chars().count() as i64 * advance - scale;
+        let left = anchor.x.round() as i64 - width / 2;
+        let top = anchor.y.round() as i64 - 7 * scale;
+        for (index, character) in text.chars().enumerate() {
+            for (row, bits) in glyph(character).iter().enumerate() {
+                for column in (0..5).filter(|column| bits & (0b10000 >> column) != 0) {
+                    let (x, y) = (left + index as i64 * advance + column * scale, top + row as i64 * scale);
+                    for dy in 0..scale {
+                        for dx in 0..scale {
+                            self.set(x + dx, y + dy, color);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn to_png(&self) -> Vec<u8> {
+        // every row starts with its filter type, 0 is none
+        let mut raw = Vec::with_capacity((self.width as usize * 3 + 1) * self.height as usize);
+        for row in self.pixels.chunks(self.width as usize * 3) {
+            raw.push(0);
+            raw.extend_from_slice(row);
+        }
+
+        let mut header = Vec::new();
+        header.extend_from_slice(&self.width.to_be_bytes());
+        header.extend_from_slice(&self.height.to_be_bytes());
+        // 8 bit rgb, default compression and filtering, not interlaced
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &compress_to_vec_zlib(&raw, 6));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+// the crc png uses for its chunks
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+pub fn render_png(segments: &[ToolpathSegment], cut_start_lines: &[u32], options: &RenderOptions) -> Result<Vec<u8>, String> {
+    let drawing = layout(segments, cut_start_lines, options);
+    let mut canvas = Canvas::new(options.width, options.height)?;
+    if let Some((corner, opposite)) = &drawing.tube {
+        let outline = [
+            corner.clone(),
+            Pos2D::new(opposite.x, corner.y),
+            opposite.clone(),
+            Pos2D::new(corner.x, opposite.y),
+            corner.clone(),
+        ];
+        canvas.polyline(&outline, 2.0, TUBE_COLOR);
+    }
+    for rapid in &drawing.rapids {
+        canvas.polyline(rapid, 1.0, RAPID_COLOR);
+    }
+    for cut in &drawing.cuts {
+        canvas.polyline(cut, 2.0, CUT_COLOR);
+    }
+    for pierce in &drawing.pierces {
+        canvas.dot(pierce, PIERCE_RADIUS, PIERCE_COLOR);
+    }
+    // twice the glyph size comes close to the svg's 14 pixel font
+    for (position, text) in &drawing.labels {
+        canvas.text(position, text, 2, LABEL_COLOR);
+    }
+    Ok(canvas.to_png())
+}
+
+impl Gcode {
+    // a picture of the program for the job sheet
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn render_svg(&self, options: &RenderOptions) -> String {
+        render_svg(&simulate_toolpath(self.get_gcode_string()), self.cut_start_lines(), options)
+    }
+
+    // the same picture as a png
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn render_png(&self, options: &RenderOptions) -> Result<Vec<u8>, String> {
+        render_png(&simulate_toolpath(self.get_gcode_string()), self.cut_start_lines(), options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cut::Cut;
+    use crate::api::settings::CutterSettings;
+    use miniz_oxide::inflate::decompress_to_vec_zlib;
+
+    fn two_cuts() -> Gcode {
+        let settings = CutterSettings { clear_existing_gcode: false, ..Default::default() };
+        let mut gcode = Gcode::new();
+        for y in [0.0, 100.0] {
+            let mut cut = Cut::new();
+            cut.start_position = Pos2D::new(0.0, y);
+            // straight across
+            cut.cut_angle = 0.0;
+            gcode.add_cut(cut, &settings);
+        }
+        gcode
+    }
+
+    #[test]
+    fn generalizes_the_screen_space_transform() {
+        let position = Pos2D::new(10.0, 20.0);
+        assert_eq!(position.to_screen_space(&25.0, &2.0), Pos2D::new(-5.0, 40.0));
+        assert_eq!(Viewport::centered_on_tube(25.0, 2.0).to_screen(&position), Pos2D::new(-5.0, 40.0));
+
+        // 100 x 50 mm into 220 x 220 pixels with a 10 pixel margin, centered vertically
+        let viewport = Viewport::fit(&Pos2D::new(0.0, 0.0), &Pos2D::new(100.0, 50.0), 220.0, 220.0, 10.0);
+        assert_eq!(viewport.scale, 2.0);
+        assert_eq!(viewport.to_screen(&Pos2D::new(0.0, 0.0)), Pos2D::new(10.0, 60.0));
+        assert_eq!(viewport.to_screen(&Pos2D::new(100.0, 50.0)), Pos2D::new(210.0, 160.0));
+    }
+
+    #[test]
+    fn draws_the_tube_cuts_pierces_and_labels() {
+        let options = RenderOptions { tube_width: Some(25.0), ..Default::default() };
+        let svg = two_cuts().render_svg(&options);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("class=\"tube\"").count(), 1);
+        // every cut pierces twice and cuts out to both sides
+        assert_eq!(svg.matches("class=\"pierce\"").count(), 4);
+        assert_eq!(svg.matches("class=\"cut\"").count(), 4);
+        assert!(svg.contains(">Part 1</text>"));
+        assert!(!svg.contains("Part 2"));
+
+        let hidden = RenderOptions { show_rapids: false, show_labels: false, ..options };
+        let svg = two_cuts().render_svg(&hidden);
+        assert!(!svg.contains("class=\"rapid\"") && !svg.contains("<text"));
+    }
+
+    #[test]
+    fn rasterizes_to_png() {
+        let options = RenderOptions { width: 200, height: 300, tube_width: Some(25.0), ..Default::default() };
+        let png = two_cuts().render_png(&options).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 200);
+        assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), 300);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+
+        // find the image data and check the cuts made it in
+        let data_start = png.windows(4).position(|window| window == b"IDAT").unwrap();
+        let length = u32::from_be_bytes(png[data_start - 4..data_start].try_into().unwrap()) as usize;
+        let raw = decompress_to_vec_zlib(&png[data_start + 4..data_start + 4 + length]).unwrap();
+        assert_eq!(raw.len(), (200 * 3 + 1) * 300);
+        let pixels: Vec<&[u8]> = raw.chunks(200 * 3 + 1).flat_map(|row| row[1..].chunks(3)).collect();
+        assert!(pixels.contains(&&CUT_COLOR[..]));
+        assert!(pixels.contains(&&PIERCE_COLOR[..]));
+        assert!(pixels.contains(&&LABEL_COLOR[..]));
+        assert_eq!(pixels[0], BACKGROUND);
+
+        // an empty size still makes a valid picture
+        let png = two_cuts().render_png(&RenderOptions { width: 0, height: 0, ..Default::default() }).unwrap();
+        assert_eq!((u32::from_be_bytes(png[16..20].try_into().unwrap()), u32::from_be_bytes(png[20..24].try_into().unwrap())), (1, 1));
+    }
+
+    #[test]
+    fn draws_the_labels_like_the_svg_does() {
+        let mut canvas = Canvas::new(40, 20).unwrap();
+        canvas.text(&Pos2D::new(20.0, 17.0), "P1", 2, LABEL_COLOR);
+        let dark = |x: usize, y: usize| canvas.pixels[(y * 40 + x) * 3..][..3] == LABEL_COLOR;
+        // 22 pixels wide centered on x 20, from y 3 down to the baseline
+        assert!(dark(9, 3) && dark(9, 16) && !dark(8, 3) && !dark(9, 2) && !dark(9, 17));
+        // the bottom of the 1 ends a glyph column early
+        assert!(dark(23, 16) && dark(28, 16) && !dark(29, 16));
+    }
+
+    #[test]
+    fn refuses_pictures_too_big_to_hold() {
+        assert_eq!(
+            two_cuts().render_png(&RenderOptions { width: u32::MAX, height: u32::MAX, ..Default::default() }),
+            Err("A 4294967295 x 4294967295 pixel picture is too big".to_string())
+        );
+    }
+}
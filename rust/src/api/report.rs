@@ -0,0 +1,287 @@
+use std::fs;
+use std::path::Path;
+
+use crate::api::estimate::{estimate_segments, JobEstimate};
+use crate::api::job_plan::{JobPlan, Part};
+use crate::api::render::{render_svg, RenderOptions};
+use crate::api::settings::CutterSettings;
+use crate::api::toolpath::simulate_toolpath;
+
+// a cut and the parameters it runs with
+#[derive(Clone, Debug, PartialEq)]
+pub struct CutReport {
+    // where the cut is along the tube
+    pub position: f32,
+    pub angle: f32,
+    pub feedrate: f32,
+    pub pierce_delay: f32,
+    pub pierce_delay_2: f32,
+    // seconds
+    pub estimated_time: f32,
+}
+
+// a profile cut into the tube face and the parameters it runs with
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureReport {
+    pub contours: u32,
+    pub feedrate: f32,
+    pub pierce_delay: f32,
+    // seconds
+    pub estimated_time: f32,
+}
+
+// everything the shop floor needs to know about a job
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobReport {
+    pub name: String,
+    pub material: String,
+    pub tube_width: f32,
+    pub stock_length: f32,
+    pub cuts: Vec<CutReport>,
+    pub features: Vec<FeatureReport>,
+    pub parts: Vec<Part>,
+    // the length of all the parts together
+    pub used_length: f32,
+    // what's left of the stock
+    pub waste_length: f32,
+    pub estimate: JobEstimate,
+    // a picture of the toolpath
+    pub toolpath_svg: String,
+}
+
+// the report that goes with a program, e.g. frame.nc -> frame.html
+pub fn report_path(gcode_path: &str) -> String {
+    Path::new(gcode_path).with_extension("html").to_string_lossy().to_string()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// e.g. 1m 05s
+pub(crate) fn format_duration(seconds: f32) -> String {
+    let seconds = seconds.round() as u32;
+    if seconds >= 60 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+impl JobReport {
+    // a page that prints on its own, no scripts or external files
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        html += "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n";
+        html += &format!("<title>{}</title>\n", escape_html(&self.name));
+        html += "<style>\n";
+        html += "body { font-family: sans-serif; margin: 2em; }\n";
+        html += "table { border-collapse: collapse; margin-bottom: 1.5em; }\n";
+        html += "th, td { border: 1px solid #888; padding: 4px 10px; text-align: right; }\n";
+        html += "th { background: #eee; }\n";
+        html += "@media print { body { margin: 0; } .toolpath { page-break-before: always; } }\n";
+        html += "</style>\n</head>\n<body>\n";
+
+        html += &format!("<h1>{}</h1>\n", escape_html(&self.name));
+        html += "<table class=\"summary\">\n";
+        html += &format!("<tr><th>Material</th><td>{}</td></tr>\n", escape_html(&self.material));
+        html += &format!("<tr><th>Tube width</th><td>{:.1} mm</td></tr>\n", self.tube_width);
+        html += &format!("<tr><th>Stock length</th><td>{:.1} mm</td></tr>\n", self.stock_length);
+        html += &format!("<tr><th>Used</th><td>{:.1} mm</td></tr>\n", self.used_length);
+        html += &format!("<tr><th>Waste</th><td>{:.1} mm</td></tr>\n", self.waste_length);
+        html += &format!("<tr><th>Estimated time</th><td>{}</td></tr>\n", format_duration(self.estimate.total_time));
+        html += &format!("<tr><th>Pierces</th><td>{}</td></tr>\n", self.estimate.pierce_count);
+        html += &format!("<tr><th>Consumable wear</th><td>{:.1} %</td></tr>\n", self.estimate.consumable_wear * 100.0);
+        html += "</table>\n";
+
+        html += "<h2>Parts</h2>\n<table class=\"parts\">\n";
+        html += "<tr><th>Part</th><th>Length (mm)</th><th>Start angle</th><th>End angle</th></tr>\n";
+        for (index, part) in self.parts.iter().enumerate() {
+            html += &format!(
+                "<tr><td>{}</td><td>{:.1}</td><td>{:.1}°</td><td>{:.1}°</td></tr>\n",
+                index + 1,
+                part.length,
+                part.start_angle,
+                part.end_angle
+            );
+        }
+        html += "</table>\n";
+
+        html += "<h2>Cuts</h2>\n<table class=\"cuts\">\n";
+        html += "<tr><th>Cut</th><th>Position (mm)</th><th>Angle</th><th>Feedrate (mm/min)</th><th>Pierce delays (s)</th><th>Time</th></tr>\n";
+        for (index, cut) in self.cuts.iter().enumerate() {
+            html += &format!(
+                "<tr><td>{}</td><td>{:.1}</td><td>{:.1}°</td><td>{}</td><td>{} / {}</td><td>{}</td></tr>\n",
+                index + 1,
+                cut.position,
+                cut.angle,
+                cut.feedrate,
+                cut.pierce_delay,
+                cut.pierce_delay_2,
+                format_duration(cut.estimated_time)
+            );
+        }
+        html += "</table>\n";
+
+        if !self.features.is_empty() {
+            html += "<h2>Features</h2>\n<table class=\"features\">\n";
+            html += "<tr><th>Feature</th><th>Contours</th><th>Feedrate (mm/min)</th><th>Pierce delay (s)</th><th>Time</th></tr>\n";
+            for (index, feature) in self.features.iter().enumerate() {
+                html += &format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    index + 1,
+                    feature.contours,
+                    feature.feedrate,
+                    feature.pierce_delay,
+                    format_duration(feature.estimated_time)
+                );
+            }
+            html += "</table>\n";
+        }
+
+        html += "<div class=\"toolpath\">\n";
+        html += &self.toolpath_svg;
+        html += "</div>\n</body>\n</html>\n";
+        html
+    }
+}
+
+impl JobPlan {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn report(&self, cutter_settings: &CutterSettings) -> JobReport {
+        let gcode = self.to_gcode(cutter_settings);
+        let segments = simulate_toolpath(gcode.get_gcode_string());
+        let estimate = estimate_segments(&segments, &cutter_settings.motion_limits, &cutter_settings.consumable_ratings);
+
+        // the cuts come first in the program, then the features
+        let cut_start_lines = gcode.cut_start_lines();
+        let time_of = |index: usize| {
+            // the segments from this cut's first line up to the next cut
+            let first = cut_start_lines[index];
+            let next = cut_start_lines.get(index + 1).copied().unwrap_or(u32::MAX);
+            let cut_segments: Vec<_> = segments.iter().filter(|segment| segment.line >= first && segment.line < next).cloned().collect();
+            estimate_segments(&cut_segments, &cutter_settings.motion_limits, &cutter_settings.consumable_ratings).total_time
+        };
+        let cuts = self
+            .cuts
+            .iter()
+            .enumerate()
+            .map(|(index, cut)| CutReport {
+                position: cut.start_position.y,
+                angle: cut.cut_angle,
+                feedrate: cut.cut_feedrate,
+                pierce_delay: cut.pierce_delay,
+                pierce_delay_2: cut.pierce_delay_2,
+                estimated_time: time_of(index),
+            })
+            .collect();
+        let features = self
+            .features
+            .iter()
+            .enumerate()
+            .map(|(index, feature)| FeatureReport {
+                contours: feature.profile.contours.len() as u32,
+                feedrate: feature.parameters.feedrate,
+                pierce_delay: feature.parameters.pierce_delay,
+                estimated_time: time_of(self.cuts.len() + index),
+            })
+            .collect();
+
+        let parts = self.parts();
+        let used_length: f32 = parts.iter().map(|part| part.length).sum();
+        let options = RenderOptions { tube_width: Some(self.stock.tube_width), width: 600, height: 800, ..Default::default() };
+        JobReport {
+            name: self.name.clone(),
+            material: self.stock.material.clone(),
+            tube_width: self.stock.tube_width,
+            stock_length: self.stock.length,
+            cuts,
+            features,
+            parts,
+            used_length,
+            waste_length: (self.stock.length - used_length).max(0.0),
+            estimate,
+            toolpath_svg: render_svg(&segments, cut_start_lines, &options),
+        }
+    }
+
+    // write the report next to the exported program, returns where it went
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn save_report(&self, gcode_path: String, cutter_settings: &CutterSettings) -> Result<String, String> {
+        let path = report_path(&gcode_path);
+        fs::write(&path, self.report(cutter_settings).to_html()).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cut::Cut;
+    use crate::api::gcode::Pos2D;
+    use crate::api::job_plan::Stock;
+    use crate::api::profile::{CutParameters, Profile, ProfileSegment};
+
+    fn plan() -> JobPlan {
+        let mut plan = JobPlan::new("Gate <left>".to_string(), Stock { material: "40x40 steel".to_string(), tube_width: 40.0, length: 1200.0 });
+        for (y, angle) in [(0.0, 45.0), (500.0, 90.0), (1000.0, 45.0)] {
+            let mut cut = Cut::new();
+            cut.start_position = Pos2D::new(0.0, y);
+            cut.cut_angle = angle;
+            plan.add_cut(cut);
+        }
+        plan
+    }
+
+    #[test]
+    fn sums_up_the_parts_and_cuts() {
+        let report = plan().report(&CutterSettings::default());
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!((report.used_length, report.waste_length), (1000.0, 200.0));
+        assert_eq!(report.cuts.len(), 3);
+        assert_eq!((report.cuts[1].position, report.cuts[1].angle, report.cuts[1].feedrate), (500.0, 90.0, 1000.0));
+        // the cuts share the whole program's time
+        let cut_time: f32 = report.cuts.iter().map(|cut| cut.estimated_time).sum();
+        assert!((cut_time - report.estimate.total_time).abs() < 0.01);
+        assert_eq!(report.estimate.pierce_count, 6);
+
+        let html = report.to_html();
+        assert!(html.contains("<h1>Gate &lt;left&gt;</h1>"));
+        assert!(html.contains("<td>40x40 steel</td>"));
+        assert_eq!(html.matches("<tr><td>").count(), 5);
+        assert!(html.contains("<svg"));
+        assert!(!html.contains("<h2>Features</h2>"));
+    }
+
+    #[test]
+    fn adds_the_features_to_the_times() {
+        let mut plan = plan();
+        let corners = [Pos2D::new(10.0, 600.0), Pos2D::new(30.0, 600.0), Pos2D::new(30.0, 620.0), Pos2D::new(10.0, 620.0)];
+        let square = (0..4).map(|i| ProfileSegment::Line { start: corners[i].clone(), end: corners[(i + 1) % 4].clone() }).collect();
+        plan.add_feature(Profile::from_segments(square), CutParameters { feedrate: 600.0, pierce_delay: 0.4 });
+
+        let report = plan.report(&CutterSettings::default());
+        assert_eq!(report.features, vec![FeatureReport { contours: 1, feedrate: 600.0, pierce_delay: 0.4, estimated_time: report.features[0].estimated_time }]);
+        // 80 mm at 600 mm/min and the pierce take at least 8.4s
+        assert!(report.features[0].estimated_time > 8.4);
+        let time: f32 = report.cuts.iter().map(|cut| cut.estimated_time).chain(report.features.iter().map(|feature| feature.estimated_time)).sum();
+        assert!((time - report.estimate.total_time).abs() < 0.01);
+        assert!(report.to_html().contains("<h2>Features</h2>"));
+    }
+
+    #[test]
+    fn saves_next_to_the_gcode() {
+        assert_eq!(report_path("jobs/gate.nc"), "jobs/gate.html");
+
+        let directory = std::env::temp_dir().join(format!("tube_cutter_report_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let gcode_path = directory.join("gate.nc").to_string_lossy().to_string();
+        let saved = plan().save_report(gcode_path, &CutterSettings::default()).unwrap();
+        assert!(saved.ends_with("gate.html"));
+        assert!(fs::read_to_string(&saved).unwrap().starts_with("<!DOCTYPE html>"));
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert!(plan().save_report("/nonexistent/dir/gate.nc".to_string(), &CutterSettings::default()).is_err());
+    }
+}
@@ -1,12 +1,43 @@
-use crossbeam_channel::{Sender, Receiver};
-use serial2::SerialPort;
-use std::{collections::LinkedList, task, thread::spawn};
+use crossbeam_channel::{Sender, Receiver, RecvTimeoutError};
+use std::collections::LinkedList;
+use std::io::ErrorKind;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, Arc};
+use std::thread::{spawn, JoinHandle};
+use std::time::{Duration, Instant};
 
-use crate::api::settings::CutterSettings;
-use crate::api::status::parse_status;
+use crate::api::bounds::{check_job_lines, TravelLimits};
+use crate::api::gcode::Gcode;
+use crate::api::homing::{is_homing_required, move_blocked_reason, HomingAxes, MoveTarget};
+use crate::api::capabilities::MachineCapabilities;
+use crate::api::machine_settings::MachineSettings;
+use crate::api::jog::{jog_line, ContinuousJog, JogStep};
+use crate::api::job::{job_lines, prepare_for_transmission, resume_cut_job_lines, resume_job_lines, Job, JobLine, JobProgress, JobState, ABORT_TIMEOUT, JOB_STATUS_INTERVAL};
+use crate::api::settings::{ConnectionSettings, CutterSettings};
+use crate::api::status::{parse_status, MachineState, MachineStatus};
+use crate::api::wcs::{set_offset_command, set_position_command, Axis, CoordinateOffsets, Fixture, WorkCoordinateSystem};
+use crate::api::transport::{open_transport, OpenTransport, Transport};
 use crate::frb_generated::StreamSink;
 
+// grbl's realtime soft reset, stops motion and turns the torch off
+const SOFT_RESET: u8 = 0x18;
+// other realtime commands, these are acted on right away and don't get an ok
+const FEED_HOLD: &[u8] = b"!";
+const CYCLE_START: &[u8] = b"~";
+const STATUS_QUERY: &[u8] = b"?";
+// throws away the queued jog motion and stops right away
+const JOG_CANCEL: u8 = 0x85;
+
+// how long to wait before trying to reopen a port that failed or got lost
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_millis(500);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(8);
+
+// how many unread events are kept before new ones get dropped
+const EVENT_QUEUE_SIZE: usize = 256;
+
+// manual commands would end up between the lines of a running job
+const JOB_RUNNING: &str = "Not possible while a job is running";
+
 // struct to store just the machine's position
 #[flutter_rust_bridge::frb]
 pub struct MachinePosition {
@@ -20,155 +51,956 @@ impl MachinePosition {
     }
 }
 
-// struct to store everything related to the serial connection to the machine
+// the state of the link to the machine
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConnectionState {
+    Disconnected,
+    Connecting,
+    Connected,
+    // the port failed to open or got lost, we keep retrying until disconnect is called
+    Reconnecting,
+}
+
+// things the connection thread reports back
+pub enum MachineEvent {
+    ConnectionChanged(ConnectionState),
+    Status(MachineStatus),
+    // any other line from the machine (ok, error:n, ALARM:n, [MSG:...] and so on)
+    Response(String),
+    // a command that wasn't sent because the machine isn't in a state to run it
+    CommandRejected(String),
+}
+
+// struct to store everything related to the connection to the machine (serial or network)
 #[flutter_rust_bridge::frb(opaque)]
 pub struct MachineConnection{
-    serial_port: String,
-    baudrate: u32,
+    connection_settings: ConnectionSettings,
     serial_tx: Option<Sender<MachineCommand>>,
-    serial_rx: Option<Receiver<String>>,
+    serial_rx: Option<Receiver<MachineEvent>>,
+
+    shared: SharedState,
+    connection_thread: Option<JoinHandle<()>>,
+}
 
-    sink: Option<StreamSink<MachinePosition>>,
+// what the connection thread keeps up to date for the gui to read at any time
+#[derive(Clone)]
+struct SharedState {
+    connection_state: Arc<Mutex<ConnectionState>>,
+    // set while the machine is locked until it's homed
+    homing_required: Arc<AtomicBool>,
+    // the offsets from the last $# and $G reply
+    coordinate_offsets: Arc<Mutex<CoordinateOffsets>>,
+    last_status: Arc<Mutex<Option<MachineStatus>>>,
+    // the $ settings from the last $$ reply
+    machine_settings: Arc<Mutex<MachineSettings>>,
+    // what the controller said about its build after connecting
+    capabilities: Arc<Mutex<MachineCapabilities>>,
+    // set while a job runs or the machine is being stopped after one
+    job_running: Arc<AtomicBool>,
 }
 
 impl MachineConnection {
     #[flutter_rust_bridge::frb(sync)]
     pub fn new() -> Self {
-        Self { serial_port: "/dev/ttyUSB0".to_string(), baudrate: 115200, serial_tx: None, serial_rx: None, sink: None }
+        Self {
+            connection_settings: ConnectionSettings::default(),
+            serial_tx: None,
+            serial_rx: None,
+            shared: SharedState {
+                connection_state: Arc::new(Mutex::new(ConnectionState::Disconnected)),
+                homing_required: Arc::new(AtomicBool::new(false)),
+                coordinate_offsets: Arc::new(Mutex::new(CoordinateOffsets::default())),
+                last_status: Arc::new(Mutex::new(None)),
+                machine_settings: Arc::new(Mutex::new(MachineSettings::default())),
+                capabilities: Arc::new(Mutex::new(MachineCapabilities::default())),
+                job_running: Arc::new(AtomicBool::new(false)),
+            },
+            connection_thread: None,
+        }
     }
 
     #[flutter_rust_bridge::frb(sync)]
     pub fn set_serial_port(&mut self, new_port: String) {
-        self.serial_port = new_port;
+        self.connection_settings.serial_port = new_port;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_baudrate(&mut self, new_baudrate: u32) {
+        self.connection_settings.baudrate = new_baudrate;
+    }
+
+    // use the saved connection settings, takes effect on the next connect
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_connection_settings(&mut self, cutter_settings: &CutterSettings) {
+        self.connection_settings = cutter_settings.connection.clone();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_connection_state(&self) -> ConnectionState {
+        self.shared.connection_state.lock().unwrap().clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn is_connected(&self) -> bool {
+        self.get_connection_state() == ConnectionState::Connected
     }
 
     #[flutter_rust_bridge::frb(sync)]
     pub fn send_string_command(&self, command: String) {
-        if self.serial_tx.is_some() {
-            let command = MachineCommand::StringCommand(command);
-            self.serial_tx.as_ref().unwrap().send(command);
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::StringCommand(command));
         }
 
     }
 
     #[flutter_rust_bridge::frb(sync)]
     pub fn send_string_command_low_priority(&self, command: String) {
-        if self.serial_tx.is_some() {
-            let command = MachineCommand::StringCommandLowPriority(command);
-            self.serial_tx.as_ref().unwrap().send(command);
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::StringCommandLowPriority(command));
         }
 
     }
 
     #[flutter_rust_bridge::frb(sync)]
     pub fn send_gcode_command(&self, command: String) {
-        if self.serial_tx.is_some() {
-            let command = MachineCommand::GcodeCommand(command);
-            self.serial_tx.as_ref().unwrap().send(command);
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::GcodeCommand(command));
+        }
+    }
+
+    // commands that change the machine's setup instead of running a program (offsets, settings, outputs),
+    // refused while a job runs so they can't change it halfway through
+    pub(crate) fn send_manual_commands(&self, commands: Vec<String>) -> Result<(), String> {
+        self.send_manual(MachineCommand::Manual(commands))
+    }
+
+    // like send_manual_commands, but only sent once a fresh status report says the machine is idle
+    pub(crate) fn send_manual_commands_when_idle(&self, commands: Vec<String>) -> Result<(), String> {
+        self.send_manual(MachineCommand::ManualWhenIdle(commands))
+    }
+
+    fn send_manual(&self, command: MachineCommand) -> Result<(), String> {
+        let serial_tx = self.serial_tx.as_ref().ok_or("Not connected to the machine".to_string())?;
+        if self.shared.job_running.load(Ordering::Relaxed) {
+            return Err(JOB_RUNNING.to_string());
         }
+        let _ = serial_tx.send(command);
+        Ok(())
     }
 
+    // go back to the origin of the work, this isn't a homing cycle (see run_homing_cycle)
     #[flutter_rust_bridge::frb(sync)]
-    pub fn home(&self, cutter_settings: &CutterSettings) {
-        self.send_string_command(format!("G1 X0 Y0 F{}", cutter_settings.jog_speed));
+    pub fn home(&self, _cutter_settings: &CutterSettings) {
+        self.go_to_work_zero();
     }
 
+    // find the machine's home position with the limit switches
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn run_homing_cycle(&self, axes: HomingAxes) {
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::Home(axes));
+        }
+    }
+
+    // clear an alarm without homing, the machine position may be wrong afterwards
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn unlock(&self) {
+        self.send_string_command("$X".to_string());
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn is_homing_required(&self) -> bool {
+        self.shared.homing_required.load(Ordering::Relaxed)
+    }
+
+    // rapid to X0 Y0 of the work coordinates, only if the machine is idle
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn go_to_work_zero(&self) {
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::MoveTo(MoveTarget::WorkZero));
+        }
+    }
+
+    // rapid to a position in machine coordinates, only if the machine is idle
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn go_to_machine_position(&self, x: f32, y: f32) {
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::MoveTo(MoveTarget::MachinePosition(x, y)));
+        }
+    }
+
+    // keep jogging in a direction (e.g. while an arrow key is held) until stop_jog is called
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn start_jog(&self, x_direction: f32, y_direction: f32, cutter_settings: &CutterSettings) {
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::StartJog(x_direction, y_direction, cutter_settings.jog_speed));
+        }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn stop_jog(&self) {
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::StopJog);
+        }
+    }
+
+    // move a fixed distance in a direction (-1, 0 or 1 per axis)
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn jog_step(&self, x_direction: f32, y_direction: f32, step: JogStep, cutter_settings: &CutterSettings) -> Result<(), String> {
+        let distance = step.distance();
+        // like continuous jogging, the program owns the machine while a job runs
+        self.send_manual_commands(vec![jog_line(x_direction * distance, y_direction * distance, cutter_settings.jog_speed)])
+    }
+
+    // the most recent status report
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_last_status(&self) -> Option<MachineStatus> {
+        self.shared.last_status.lock().unwrap().clone()
+    }
+
+    // switch the laser pointer on the output set in the settings
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_laser_pointer(&self, on: bool, cutter_settings: &CutterSettings) -> Result<(), String> {
+        self.send_manual_commands(vec![cutter_settings.laser_output.command(on)])
+    }
+
+    // the laser dot is on the work's origin: make the spot the torch reaches at that dot (the laser offset
+    // away) work zero of wcs (or the active system if None), so the cuts don't need use_laser anymore
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn zero_at_laser(&self, wcs: Option<WorkCoordinateSystem>, cutter_settings: &CutterSettings) -> Result<(), String> {
+        let position = [(Axis::X, -cutter_settings.laser_offset_x), (Axis::Y, -cutter_settings.laser_offset_y)];
+        self.send_manual_commands_when_idle(vec![set_position_command(wcs, &position)])
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_capabilities(&self) -> MachineCapabilities {
+        self.shared.capabilities.lock().unwrap().clone()
+    }
+
+    // ask the machine for its $ settings (and their descriptions on grblHAL), see get_machine_settings
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn read_machine_settings(&self) {
+        // plain grbl answers $EG and $ES with an error, that's fine
+        self.send_string_command("$EG".to_string());
+        self.send_string_command("$ES".to_string());
+        self.send_string_command("$$".to_string());
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_machine_settings(&self) -> MachineSettings {
+        self.shared.machine_settings.lock().unwrap().clone()
+    }
+
+    // check the value against what we know about the setting and write it once the machine is idle
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn write_machine_setting(&self, id: u32, value: String) -> Result<(), String> {
+        let value = self.get_machine_settings().validate(id, &value)?;
+        self.send_manual_commands_when_idle(vec![format!("${}={}", id, value), "$$".to_string()])
+    }
+
+    // write every setting of a backup that differs from the machine, returns how many were written
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn restore_machine_settings(&self, filename: String) -> Result<u32, String> {
+        let backup = std::fs::read_to_string(&filename).map_err(|e| format!("Failed to read {}: {}", filename, e))?;
+        let mut commands = self.get_machine_settings().restore_commands(&backup)?;
+        let written = commands.len() as u32;
+        commands.push("$$".to_string());
+        self.send_manual_commands_when_idle(commands)?;
+        Ok(written)
+    }
+
+    // ask the machine for its work offsets and active work coordinate system, see get_coordinate_offsets
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn read_coordinate_offsets(&self) {
+        self.send_string_command("$#".to_string());
+        self.send_string_command("$G".to_string());
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_coordinate_offsets(&self) -> CoordinateOffsets {
+        self.shared.coordinate_offsets.lock().unwrap().clone()
+    }
+
+    // make the current position zero on an axis of wcs (or the active system if None)
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn zero_axis(&self, wcs: Option<WorkCoordinateSystem>, axis: Axis) -> Result<(), String> {
+        self.send_manual_commands(vec![set_position_command(wcs, &[(axis, 0.0)])])
+    }
+
+    // make the current position zero on every axis of wcs (or the active system if None)
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn zero_all_axes(&self, wcs: Option<WorkCoordinateSystem>) -> Result<(), String> {
+        self.send_manual_commands(vec![set_position_command(wcs, &[(Axis::X, 0.0), (Axis::Y, 0.0), (Axis::Z, 0.0)])])
+    }
+
+    // set the offset of an axis from machine zero
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_work_offset(&self, wcs: WorkCoordinateSystem, axis: Axis, offset: f32) -> Result<(), String> {
+        self.send_manual_commands(vec![set_offset_command(wcs, &[(axis, offset)])])
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn select_work_coordinate_system(&self, wcs: WorkCoordinateSystem) -> Result<(), String> {
+        self.send_manual_commands(vec![wcs.gcode().to_string()])
+    }
+
+    // save the current offset of a system (the active one if None) as a fixture, once $# and $G were read
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn capture_fixture(&self, name: String, wcs: Option<WorkCoordinateSystem>) -> Result<Fixture, String> {
+        self.get_coordinate_offsets().capture_fixture(name, wcs)
+    }
+
+    // load a saved fixture into its work coordinate system and make it the active one
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn recall_fixture(&self, fixture: &Fixture) -> Result<(), String> {
+        self.send_manual_commands(fixture.recall_commands())
+    }
 
     #[flutter_rust_bridge::frb(sync)]
     pub fn make_connection(&mut self, sink: StreamSink<MachinePosition>) {
-        if self.serial_rx.is_none() {
-            let (to_gui_tx, from_machine_rx) = crossbeam_channel::unbounded();
-            
-            self.serial_rx = Some(from_machine_rx);
-
-            let (to_machine_tx, from_gui_rx) = crossbeam_channel::unbounded();
-
-            self.serial_tx = Some(to_machine_tx);
-
-            let serial_path = self.serial_port.clone();
-            let serial_baudrate = self.baudrate.clone();
-
-            // a linked list to store the buffered commands (the commands that are waiting to be sent)
-            let mut buffered_commands = LinkedList::new();
-            
-            // a flag if the current command has been completed
-            let mut command_completed = true;
-
-            spawn(move || {
-
-                let mut port = SerialPort::open(serial_path, serial_baudrate).unwrap();
-                let mut buf = [0u8; 1024];
-                let mut line_buf = String::new();
-
-                loop {
-                    // check if the gui sent any commands
-                    if let Ok(msg) = from_gui_rx.try_recv() {
-                        match msg {
-                            MachineCommand::StringCommand(cmd) => {
-                                buffered_commands.push_back(cmd);
-                            },
-                            MachineCommand::StringCommandLowPriority(cmd) => {
-                                if buffered_commands.len() == 0 {
-                                    buffered_commands.push_back(cmd);
-                                }
-                            },
-                            MachineCommand::GcodeCommand(cmd) => {
-                                for c in cmd.split("\n") {
-                                    buffered_commands.push_back(c.to_string());
-                                }
+        self.connect(move |pos| {
+            let _ = sink.add(pos);
+        });
+    }
+
+    // start the connection thread, on_position gets called with every parsed status report
+    pub(crate) fn connect(&mut self, on_position: impl FnMut(MachinePosition) + Send + 'static) {
+        self.connect_with_transport(open_transport, on_position);
+    }
+
+    // like connect, but the port is opened by the given function instead of from the settings,
+    // e.g. to talk to a simulator the caller keeps a handle to
+    pub(crate) fn connect_with_transport(&mut self, open_transport: impl FnMut(&ConnectionSettings) -> std::io::Result<Box<dyn Transport>> + Send + 'static, on_position: impl FnMut(MachinePosition) + Send + 'static) {
+        if self.connection_thread.is_some() {
+            return;
+        }
+
+        let (to_gui_tx, from_machine_rx) = crossbeam_channel::bounded(EVENT_QUEUE_SIZE);
+        self.serial_rx = Some(from_machine_rx);
+
+        let (to_machine_tx, from_gui_rx) = crossbeam_channel::unbounded();
+        self.serial_tx = Some(to_machine_tx);
+
+        let link = MachineLink::new(self.connection_settings.clone(), Box::new(open_transport), from_gui_rx, to_gui_tx, self.shared.clone(), Box::new(on_position));
+
+        self.connection_thread = Some(spawn(move || link.run()));
+    }
+
+    // stream the program to the machine, progress is reported on the sink
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn start_job(&self, gcode: &Gcode, sink: StreamSink<JobProgress>) {
+        self.start_job_with(job_lines(&gcode.get_gcode_string()), move |progress| {
+            let _ = sink.add(progress);
+        });
+    }
+
+    // pick the program up at a line (0 based), e.g. after the torch lost its arc
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn start_job_from_line(&self, gcode: &Gcode, start_line: u32, sink: StreamSink<JobProgress>) {
+        self.start_job_with(resume_job_lines(&gcode.get_gcode_string(), start_line), move |progress| {
+            let _ = sink.add(progress);
+        });
+    }
+
+    // pick the program up at the start of a cut (0 based)
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn start_job_from_cut(&self, gcode: &Gcode, cut_index: u32, sink: StreamSink<JobProgress>) {
+        let on_progress = move |progress| {
+            let _ = sink.add(progress);
+        };
+        match resume_cut_job_lines(gcode, cut_index) {
+            Ok(lines) => self.start_job_with(lines, on_progress),
+            Err(error) => Job::new(Vec::new(), Box::new(on_progress)).fail(error),
+        }
+    }
+
+    pub(crate) fn start_job_with(&self, lines: Vec<JobLine>, on_progress: impl FnMut(JobProgress) + Send + 'static) {
+        match &self.serial_tx {
+            Some(serial_tx) => {
+                let _ = serial_tx.send(MachineCommand::StartJob(lines, Box::new(on_progress)));
+            },
+            None => Job::new(lines, Box::new(on_progress)).fail("Not connected to the machine".to_string()),
+        }
+    }
+
+    // feed hold and stop sending lines
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn pause_job(&self) {
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::PauseJob);
+        }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn resume_job(&self) {
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::ResumeJob);
+        }
+    }
+
+    // bring the machine to a stop, clear the queue and turn the torch off
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn abort_job(&self) {
+        if let Some(serial_tx) = &self.serial_tx {
+            let _ = serial_tx.send(MachineCommand::AbortJob);
+        }
+    }
+
+    // stop the connection thread and close the port
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn disconnect(&mut self) {
+        if let Some(serial_tx) = self.serial_tx.take() {
+            let _ = serial_tx.send(MachineCommand::Disconnect);
+        }
+        if let Some(thread) = self.connection_thread.take() {
+            let _ = thread.join();
+        }
+        self.serial_rx = None;
+        *self.shared.connection_state.lock().unwrap() = ConnectionState::Disconnected;
+        self.shared.job_running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl Drop for MachineConnection {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+// why the streaming loop returned
+enum StreamExit {
+    Disconnect,
+    // the port stopped responding, job_interrupted is set if there was still work queued
+    PortLost { job_interrupted: bool },
+}
+
+// everything the connection thread owns
+struct MachineLink {
+    settings: ConnectionSettings,
+    open_transport: OpenTransport,
+    from_gui_rx: Receiver<MachineCommand>,
+    to_gui_tx: Sender<MachineEvent>,
+    shared: SharedState,
+    on_position: Box<dyn FnMut(MachinePosition) + Send>,
+
+    // a linked list to store the buffered commands (the commands that are waiting to be sent)
+    buffered_commands: LinkedList<String>,
+    // a flag if the current command has been completed
+    command_completed: bool,
+
+    job: Option<Job>,
+    // the job is checked against the travel limits once the queries sent before it were answered
+    job_limits_checked: bool,
+    jog: Option<ContinuousJog>,
+    // moves and manual commands waiting for a status report to tell if the machine is idle
+    pending_when_idle: Vec<String>,
+    last_status_poll: Instant,
+    // set while we wait for the machine to stop after an abort or error
+    stopping_since: Option<Instant>,
+    // set after a soft reset until the controller prints its welcome message
+    resetting_since: Option<Instant>,
+    // the WCO of the last report that had one, to work out WPos for the reports in between
+    work_coordinate_offset: Option<Vec<f32>>,
+}
+
+impl MachineLink {
+    fn new(settings: ConnectionSettings, open_transport: OpenTransport, from_gui_rx: Receiver<MachineCommand>, to_gui_tx: Sender<MachineEvent>, shared: SharedState, on_position: Box<dyn FnMut(MachinePosition) + Send>) -> Self {
+        Self {
+            settings,
+            open_transport,
+            from_gui_rx,
+            to_gui_tx,
+            shared,
+            on_position,
+            buffered_commands: LinkedList::new(),
+            command_completed: true,
+            job: None,
+            job_limits_checked: false,
+            jog: None,
+            pending_when_idle: Vec::new(),
+            last_status_poll: Instant::now(),
+            stopping_since: None,
+            resetting_since: None,
+            work_coordinate_offset: None,
+        }
+    }
+
+    fn set_state(&self, state: ConnectionState) {
+        *self.shared.connection_state.lock().unwrap() = state.clone();
+        self.send_event(MachineEvent::ConnectionChanged(state));
+    }
+
+    fn reject(&self, reason: String) {
+        println!("Command rejected: {}", reason);
+        self.send_event(MachineEvent::CommandRejected(reason));
+    }
+
+    fn send_event(&self, event: MachineEvent) {
+        // nobody might be reading the events, so drop them instead of letting them pile up
+        let _ = self.to_gui_tx.try_send(event);
+    }
+
+    // keep the port open until disconnect is requested, reconnecting with a growing delay if it fails
+    fn run(mut self) {
+        let mut reconnect_delay = INITIAL_RECONNECT_DELAY;
+        let mut stop_machine = false;
+
+        self.set_state(ConnectionState::Connecting);
+
+        loop {
+            match (self.open_transport)(&self.settings) {
+                Ok(mut port) => {
+                    reconnect_delay = INITIAL_RECONNECT_DELAY;
+                    self.set_state(ConnectionState::Connected);
+
+                    // the link dropped mid-job last time, make sure the machine isn't still cutting
+                    if std::mem::take(&mut stop_machine) {
+                        println!("Resetting machine after lost connection");
+                        let _ = port.write_all(&[SOFT_RESET]);
+                    }
+
+                    match self.stream(port.as_mut()) {
+                        StreamExit::Disconnect => break,
+                        StreamExit::PortLost { job_interrupted } => {
+                            println!("Lost connection to {}", self.settings.serial_port);
+                            stop_machine = job_interrupted;
+                            if let Some(job) = self.job.as_mut() {
+                                job.fail("Lost connection to the machine".to_string());
                             }
+                            self.job = None;
                         }
-                        // add a ? command to get the machine info after every command
-                        buffered_commands.push_back("?".to_string());
                     }
+                },
+                Err(e) => {
+                    println!("Failed to open {}: {}", self.settings.serial_port, e);
+                }
+            }
+
+            self.set_state(ConnectionState::Reconnecting);
+            if self.wait_for_reconnect(reconnect_delay) {
+                break;
+            }
+            reconnect_delay = (reconnect_delay * 2).min(MAX_RECONNECT_DELAY);
+        }
+
+        self.set_state(ConnectionState::Disconnected);
+    }
+
+    // wait before the next reconnect attempt, returns true if we should stop instead
+    fn wait_for_reconnect(&self, delay: Duration) -> bool {
+        let deadline = Instant::now() + delay;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.from_gui_rx.recv_timeout(remaining) {
+                Ok(MachineCommand::Disconnect) => return true,
+                // a job can't start without a machine, let it know right away
+                Ok(MachineCommand::StartJob(lines, on_progress)) => {
+                    Job::new(lines, on_progress).fail("Not connected to the machine".to_string());
+                },
+                // anything else sent while the link is down is dropped, the machine state is unknown
+                Ok(_) => {},
+                Err(RecvTimeoutError::Timeout) => return false,
+                Err(RecvTimeoutError::Disconnected) => return true,
+            }
+        }
+    }
+
+    // check a job against the soft limits, only possible once $$, $# and $G were read
+    fn check_travel_limits(&self, lines: &[JobLine]) -> Result<(), String> {
+        let machine_settings = self.shared.machine_settings.lock().unwrap().clone();
+        let soft_limits_enabled = match machine_settings.get(20) {
+            Some(setting) => setting.value != "0",
+            None => return Err("The travel limits are unknown, the machine settings weren't read".to_string()),
+        };
+        let limits = match TravelLimits::from_machine_settings(&machine_settings) {
+            Some(limits) => limits,
+            // without soft limits the machine has no travel limits to keep to
+            None if !soft_limits_enabled => return Ok(()),
+            None => return Err("The travel limits are unknown, the machine didn't report its max travel".to_string()),
+        };
+        check_job_lines(lines, &limits, &self.shared.coordinate_offsets.lock().unwrap())
+    }
+
+    // the next line of the job, the job is checked against the controller and the travel limits before its first line
+    fn next_job_line(&mut self) -> Option<String> {
+        let job = self.job.as_ref()?;
+        if !self.job_limits_checked && !job.is_finished() {
+            self.job_limits_checked = true;
+            // $I is sent before any job line, so the capabilities are known by now if the controller reports them
+            let supported = self.shared.capabilities.lock().unwrap().check_program(job.lines().iter().map(|line| (line.source_line, line.text.as_str())));
+            if let Err(e) = supported.and_then(|_| self.check_travel_limits(job.lines())) {
+                self.job.as_mut()?.fail(e);
+                return None;
+            }
+        }
+        self.job.as_mut()?.next_line()
+    }
+
+    fn job_active(&self) -> bool {
+        self.job.as_ref().is_some_and(|job| !job.is_finished())
+    }
+
+    // handle a command from the gui, returns Some if the streaming loop should stop
+    fn handle_command(&mut self, msg: MachineCommand, port: &mut dyn Transport) -> std::io::Result<Option<StreamExit>> {
+        match msg {
+            MachineCommand::StringCommand(cmd) => {
+                self.buffered_commands.push_back(cmd);
+            },
+            MachineCommand::StringCommandLowPriority(cmd) => {
+                if self.buffered_commands.is_empty() {
+                    self.buffered_commands.push_back(cmd);
+                }
+            },
+            MachineCommand::GcodeCommand(cmd) => {
+                for line in prepare_for_transmission(job_lines(&cmd), self.settings.line_numbers) {
+                    self.buffered_commands.push_back(line.text);
+                }
+            },
+            MachineCommand::Manual(commands) => {
+                // a job might have started since the gui checked
+                if self.job_active() || self.stopping_since.is_some() {
+                    self.reject(JOB_RUNNING.to_string());
+                    return Ok(None);
+                }
+                self.buffered_commands.extend(commands);
+            },
+            MachineCommand::ManualWhenIdle(commands) => {
+                if self.job_active() || self.stopping_since.is_some() {
+                    self.reject(JOB_RUNNING.to_string());
+                } else {
+                    self.pending_when_idle.extend(commands);
+                    port.write_all(STATUS_QUERY)?;
+                }
+                return Ok(None);
+            },
+            MachineCommand::StartJob(lines, on_progress) => {
+                let lines = prepare_for_transmission(lines, self.settings.line_numbers);
+                let mut job = Job::new(lines, on_progress);
+                if self.job_active() {
+                    job.fail("A job is already running".to_string());
+                } else {
+                    self.job = Some(job);
+                    self.job_limits_checked = false;
+                    // the offsets might have changed since connecting (e.g. from the gui or a fixture),
+                    // the job is checked with fresh ones once these are answered
+                    self.buffered_commands.push_back("$#".to_string());
+                    self.buffered_commands.push_back("$G".to_string());
+                }
+                return Ok(None);
+            },
+            MachineCommand::PauseJob => {
+                if let Some(job) = self.job.as_mut() {
+                    if *job.state() == JobState::Running {
+                        port.write_all(FEED_HOLD)?;
+                        job.pause();
+                    }
+                }
+                return Ok(None);
+            },
+            MachineCommand::ResumeJob => {
+                if let Some(job) = self.job.as_mut() {
+                    if *job.state() == JobState::Paused {
+                        port.write_all(CYCLE_START)?;
+                        job.resume();
+                    }
+                }
+                return Ok(None);
+            },
+            MachineCommand::StartJog(x_direction, y_direction, feedrate) => {
+                // the program owns the machine while a job runs
+                if !self.job_active() && self.stopping_since.is_none() {
+                    // drop what's queued of the old direction before going the new way
+                    if self.jog.take().is_some() {
+                        port.write_all(&[JOG_CANCEL])?;
+                    }
+                    self.jog = ContinuousJog::new(x_direction, y_direction, feedrate);
+                }
+                return Ok(None);
+            },
+            MachineCommand::StopJog => {
+                if self.jog.take().is_some() {
+                    port.write_all(&[JOG_CANCEL])?;
+                }
+            },
+            MachineCommand::Home(axes) => {
+                if self.job_active() {
+                    self.reject("Can't home while a job is running".to_string());
+                    return Ok(None);
+                }
+                self.buffered_commands.push_back(axes.command().to_string());
+            },
+            MachineCommand::MoveTo(target) => {
+                if self.job_active() {
+                    self.reject("Can't move while a job is running".to_string());
+                } else {
+                    // the last report might be stale, ask for a fresh one before moving
+                    self.pending_when_idle.push(target.command());
+                    port.write_all(STATUS_QUERY)?;
+                }
+                return Ok(None);
+            },
+            MachineCommand::AbortJob => {
+                if self.job_active() {
+                    if let Some(job) = self.job.as_mut() {
+                        job.begin_abort();
+                    }
+                    self.begin_stop(port)?;
+                }
+                return Ok(None);
+            },
+            MachineCommand::Disconnect => {
+                // we can't wait for the machine to stop, reset it so the torch goes off
+                if self.job_active() {
+                    port.write_all(&[SOFT_RESET])?;
+                    if let Some(job) = self.job.as_mut() {
+                        job.fail("Disconnected during the job".to_string());
+                    }
+                }
+                return Ok(Some(StreamExit::Disconnect));
+            }
+        }
+        // add a ? command to get the machine info after every command
+        self.buffered_commands.push_back("?".to_string());
+        Ok(None)
+    }
+
+    // hold the machine and stop feeding it, the queue gets cleared once it has come to a stop
+    fn begin_stop(&mut self, port: &mut dyn Transport) -> std::io::Result<()> {
+        port.write_all(FEED_HOLD)?;
+        self.buffered_commands.clear();
+        self.stopping_since = Some(Instant::now());
+        Ok(())
+    }
+
+    // the machine has stopped (or didn't in time), clear everything and make sure the torch is off
+    fn finish_stop(&mut self, port: &mut dyn Transport) -> std::io::Result<()> {
+        self.stopping_since = None;
+        port.write_all(&[SOFT_RESET])?;
+        self.buffered_commands.clear();
+        self.buffered_commands.push_back("M5".to_string());
+        // nothing will acknowledge the command that was in flight, wait for the welcome message instead
+        self.command_completed = false;
+        self.resetting_since = Some(Instant::now());
+        if let Some(job) = self.job.as_mut() {
+            job.finish_abort();
+        }
+        Ok(())
+    }
+
+    fn handle_status(&mut self, status: &MachineStatus, port: &mut dyn Transport) -> std::io::Result<()> {
+        // homed or unlocked
+        if !matches!(status.machine_state, MachineState::Alarm) {
+            self.shared.homing_required.store(false, Ordering::Relaxed);
+        }
+
+        if !self.pending_when_idle.is_empty() {
+            let commands = std::mem::take(&mut self.pending_when_idle);
+            // a job might have been started while we waited for the report
+            let blocked_reason = match self.job_active() {
+                true => Some(JOB_RUNNING.to_string()),
+                false => move_blocked_reason(&status.machine_state, self.shared.homing_required.load(Ordering::Relaxed)),
+            };
+            match blocked_reason {
+                None => {
+                    self.buffered_commands.extend(commands);
+                    self.buffered_commands.push_back("?".to_string());
+                },
+                Some(reason) => self.reject(reason),
+            }
+        }
 
-                    // check if there are any commands in buffered_commands to send to the machine
-                    if buffered_commands.len() > 0 && command_completed {
-                        let cmd = buffered_commands.pop_front().unwrap();
-                        println!("{}", cmd);
-                        let _ = port.write_all(format!("{}\n", cmd).as_bytes());
+        if self.stopping_since.is_some() {
+            let stopped = match status.machine_state {
+                // Hold:0 means the hold is complete, Hold:1 is still decelerating
+                MachineState::Hold => status.sub_state == Some(0),
+                MachineState::Idle | MachineState::Alarm | MachineState::Door => true,
+                _ => false,
+            };
+            if stopped {
+                self.finish_stop(port)?;
+            }
+        }
+        Ok(())
+    }
+
+    // handle a line that isn't a status report
+    fn handle_response(&mut self, line: &str, port: &mut dyn Transport) -> std::io::Result<()> {
+        if is_homing_required(line) {
+            self.shared.homing_required.store(true, Ordering::Relaxed);
+        }
+        self.shared.coordinate_offsets.lock().unwrap().apply_report_line(line);
+        self.shared.machine_settings.lock().unwrap().apply_report_line(line);
+        self.shared.capabilities.lock().unwrap().apply_report_line(line);
 
-                        command_completed = false;
+        if line == "ok" {
+            self.command_completed = true;
+            if let Some(job) = self.job.as_mut() {
+                if job.line_in_flight() {
+                    job.acknowledge();
+                }
+            }
+        } else if line.starts_with("error:") {
+            self.command_completed = true;
+            // e.g. a jog past the soft limits, don't keep trying
+            self.jog = None;
+            // a rejected line means the program isn't doing what it should anymore, stop the machine
+            let source_line = self.job.as_ref().and_then(|job| job.in_flight_source_line());
+            if let Some(source_line) = source_line {
+                if self.stopping_since.is_none() {
+                    if let Some(job) = self.job.as_mut() {
+                        job.fail(format!("Machine reported {} on line {}", line, source_line));
                     }
+                    self.begin_stop(port)?;
+                }
+            }
+        } else if line.starts_with("ALARM:") {
+            self.jog = None;
+            // an alarm already stops the machine and turns the torch off
+            if let Some(job) = self.job.as_mut() {
+                job.fail(format!("Machine reported {}", line));
+            }
+            self.buffered_commands.clear();
+            self.command_completed = true;
+        } else if line.starts_with("Grbl") && self.resetting_since.take().is_some() {
+            self.command_completed = true;
+        }
+        Ok(())
+    }
 
-                    // Read incoming serial data
-                    match port.read(&mut buf) {
-                        Ok(n) if n > 0 => {
-                            let chunk = String::from_utf8_lossy(&buf[..n]);
-                            for ch in chunk.chars() {
-                                if ch == '\r' {
-                                    // If the line ends with \r, process it
-                                    let line = line_buf.trim();
-                                    if line == "ok" {
-                                        println!("Ok!");
-                                        command_completed = true;
-
-                                    } else if line.contains("<") {
-                                        // parse the status
-                                        println!("{}", line);
-                                        let status = parse_status(line.trim().to_string());
-                                        let pos = MachinePosition{ x: status.position.x, y: status.position.y };
-                                        sink.add(pos);
-
-                                    }
-
-                                    line_buf.clear();
-                                } else if ch != '\n' {
-                                    // Append anything that's not a newline
-                                    line_buf.push(ch);
-                                } 
+    fn handle_line(&mut self, line: &str, port: &mut dyn Transport) -> std::io::Result<()> {
+        if line.contains("<") {
+            // parse the status
+            println!("{}", line);
+            match parse_status(line.to_string()) {
+                Ok(mut status) => {
+                    match &status.work_coordinate_offset {
+                        Some(wco) => self.work_coordinate_offset = Some(wco.clone()),
+                        None => {
+                            if let Some(wco) = self.work_coordinate_offset.clone() {
+                                status.apply_work_coordinate_offset(wco);
                             }
                         }
-                        _ => {
-                                // timeout or no data
-                                if buffered_commands.is_empty() {
-                                    std::thread::sleep(std::time::Duration::from_millis(1));
-                                }
-                        }
                     }
+                    let pos = MachinePosition{ x: status.position.x, y: status.position.y };
+                    (self.on_position)(pos);
+                    self.handle_status(&status, port)?;
+                    *self.shared.last_status.lock().unwrap() = Some(status.clone());
+                    self.send_event(MachineEvent::Status(status));
+                },
+                Err(e) => {
+                    // a garbled line (e.g. from plasma noise) shouldn't stop the connection, just skip it
+                    println!("Skipping status report: {}", e);
+                }
+            }
+        } else if !line.is_empty() {
+            println!("{}", line);
+            self.handle_response(line, port)?;
+            self.send_event(MachineEvent::Response(line.to_string()));
+        }
+        Ok(())
+    }
+
+    // run one round of the streaming loop, returns Some if the loop should stop
+    fn poll(&mut self, port: &mut dyn Transport, buf: &mut [u8], line_buf: &mut String) -> std::io::Result<Option<StreamExit>> {
+        self.shared.job_running.store(self.job_active() || self.stopping_since.is_some(), Ordering::Relaxed);
+
+        // check if the gui sent any commands
+        if let Ok(msg) = self.from_gui_rx.try_recv() {
+            if let Some(exit) = self.handle_command(msg, port)? {
+                return Ok(Some(exit));
+            }
+        }
+
+        // keep an eye on the machine while a job or jog runs or while we wait for it to stop
+        if (self.job_active() || self.jog.is_some() || self.stopping_since.is_some()) && self.last_status_poll.elapsed() >= JOB_STATUS_INTERVAL {
+            port.write_all(STATUS_QUERY)?;
+            self.last_status_poll = Instant::now();
+        }
+
+        if self.stopping_since.is_some_and(|since| since.elapsed() >= ABORT_TIMEOUT) {
+            println!("Machine didn't stop in time, resetting");
+            self.finish_stop(port)?;
+        }
+
+        if self.resetting_since.is_some_and(|since| since.elapsed() >= ABORT_TIMEOUT) {
+            self.resetting_since = None;
+            self.command_completed = true;
+        }
+
+        // check if there are any commands in buffered_commands (or the job) to send to the machine
+        if self.command_completed {
+            let next_command = match self.buffered_commands.pop_front() {
+                Some(cmd) => Some(cmd),
+                None => self.next_job_line()
+                    .or_else(|| self.jog.as_mut().and_then(|jog| jog.next_increment())),
+            };
+            if let Some(cmd) = next_command {
+                println!("{}", cmd);
+                port.write_all(format!("{}{}", cmd, self.settings.line_ending.as_str()).as_bytes())?;
+                self.command_completed = false;
+            }
+        }
+
+        // Read incoming data
+        match port.read(buf) {
+            Ok(n) if n > 0 => {
+                let chunk = String::from_utf8_lossy(&buf[..n]).into_owned();
+                for ch in chunk.chars() {
+                    if ch == '\r' {
+                        // If the line ends with \r, process it
+                        let line = line_buf.trim().to_string();
+                        line_buf.clear();
+                        self.handle_line(&line, port)?;
+                    } else if ch != '\n' {
+                        // Append anything that's not a newline
+                        line_buf.push(ch);
+                    }
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::TimedOut || e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::Interrupted => {
+                // timeout or no data
+                if self.buffered_commands.is_empty() {
+                    std::thread::sleep(std::time::Duration::from_millis(1));
                 }
-            });
+            }
+            // a read of 0 bytes means the port went away (e.g. USB unplugged)
+            Ok(_) => return Err(ErrorKind::UnexpectedEof.into()),
+            Err(e) => return Err(e),
+        }
+        Ok(None)
+    }
 
+    // send the queued commands and read the replies until the port is lost or disconnect is requested
+    fn stream(&mut self, port: &mut dyn Transport) -> StreamExit {
+        // start every connection with a clean slate, anything queued before it dropped is stale
+        self.buffered_commands.clear();
+        self.command_completed = true;
+        // find out what the controller can do, it might be a different board than last time
+        *self.shared.capabilities.lock().unwrap() = MachineCapabilities::default();
+        self.buffered_commands.push_back("$I".to_string());
+        // the settings, offsets and parser state are needed to check jobs against the travel limits
+        for query in ["$$", "$#", "$G"] {
+            self.buffered_commands.push_back(query.to_string());
+        }
+        self.jog = None;
+        self.pending_when_idle.clear();
+        self.stopping_since = None;
+        self.resetting_since = None;
+        self.work_coordinate_offset = None;
+
+        let mut buf = [0u8; 1024];
+        let mut line_buf = String::new();
+
+        loop {
+            match self.poll(port, &mut buf, &mut line_buf) {
+                Ok(None) => {},
+                Ok(Some(exit)) => return exit,
+                Err(e) => {
+                    println!("Connection error: {}", e);
+                    let job_interrupted = !self.buffered_commands.is_empty() || !self.command_completed || self.job_active();
+                    return StreamExit::PortLost { job_interrupted };
+                }
+            }
         }
     }
 }
@@ -179,4 +1011,306 @@ pub enum MachineCommand {
     StringCommandLowPriority(String),
     // just a bunch of commands together
     GcodeCommand(String),
-}
\ No newline at end of file
+    // commands that aren't sent while a job runs, see MachineConnection::send_manual_commands
+    Manual(Vec<String>),
+    // like Manual, once the machine reports it's idle
+    ManualWhenIdle(Vec<String>),
+    // stream a program line by line, reporting progress to the callback
+    StartJob(Vec<JobLine>, Box<dyn FnMut(JobProgress) + Send>),
+    PauseJob,
+    ResumeJob,
+    AbortJob,
+    // jog in a direction at a feed rate until StopJog
+    StartJog(f32, f32, f32),
+    StopJog,
+    Home(HomingAxes),
+    // move once the machine reports it's idle
+    MoveTo(MoveTarget),
+    // stop the connection thread and close the port
+    Disconnect,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::settings::TransportKind;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    const STATUS_REPORT: &str = "<Idle|MPos:1.000,2.000,0.000|FS:0,0>";
+
+    fn network_connection(transport: TransportKind, port: u16) -> MachineConnection {
+        let mut connection = MachineConnection::new();
+        connection.connection_settings.transport = transport;
+        connection.connection_settings.host = "127.0.0.1".to_string();
+        connection.connection_settings.network_port = port;
+        connection
+    }
+
+    // wait for an event matching the predicate, skipping the others
+    fn wait_for_event(connection: &MachineConnection, matches: impl Fn(&MachineEvent) -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let events = connection.serial_rx.as_ref().unwrap();
+        while let Ok(event) = events.recv_deadline(deadline) {
+            if matches(&event) {
+                return true;
+            }
+        }
+        false
+    }
+
+    // the reply a grbl controller would give to a line
+    fn reply_to(line: &str) -> String {
+        if line.contains('?') {
+            format!("{}\r\nok\r\n", STATUS_REPORT)
+        } else {
+            "ok\r\n".to_string()
+        }
+    }
+
+    // answer every line like a grbl controller would until the client goes away, returns the received lines
+    fn serve_tcp(stream: TcpStream) -> Vec<String> {
+        serve_tcp_with(stream, reply_to)
+    }
+
+    fn serve_tcp_with(stream: TcpStream, mut reply: impl FnMut(&str) -> String) -> Vec<String> {
+        let mut writer = stream.try_clone().unwrap();
+        let mut received = Vec::new();
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else { break };
+            let _ = writer.write_all(reply(&line).as_bytes());
+            received.push(line);
+        }
+        received
+    }
+
+    #[test]
+    fn streams_commands_over_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let controller = std::thread::spawn(move || serve_tcp(listener.accept().unwrap().0));
+
+        let mut connection = network_connection(TransportKind::Tcp, port);
+        connection.connect(|_| {});
+        assert!(wait_for_event(&connection, |e| matches!(e, MachineEvent::ConnectionChanged(ConnectionState::Connected))));
+
+        connection.send_string_command("G0 X1 Y2".to_string());
+        assert!(wait_for_event(&connection, |e| matches!(e, MachineEvent::Status(status) if status.position.x == 1.0 && status.position.y == 2.0)));
+
+        connection.disconnect();
+        assert_eq!(connection.get_connection_state(), ConnectionState::Disconnected);
+        assert_eq!(controller.join().unwrap(), vec!["$I", "$$", "$#", "$G", "G0 X1 Y2", "?"]);
+    }
+
+    #[test]
+    fn works_out_the_work_position_from_an_earlier_offset() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // like grbl, only the first report has the offset
+        let mut first_report = true;
+        let controller = std::thread::spawn(move || serve_tcp_with(listener.accept().unwrap().0, move |line| {
+            if line != "?" {
+                "ok\r\n".to_string()
+            } else if std::mem::take(&mut first_report) {
+                "<Idle|MPos:10.000,20.000,0.000|WCO:5.000,5.000,0.000>\r\nok\r\n".to_string()
+            } else {
+                "<Idle|MPos:11.000,20.000,0.000>\r\nok\r\n".to_string()
+            }
+        }));
+
+        let mut connection = network_connection(TransportKind::Tcp, port);
+        connection.connect(|_| {});
+        connection.send_string_command("?".to_string());
+        assert!(wait_for_event(&connection, |e| matches!(e, MachineEvent::Status(status) if status.position.x == 11.0)));
+
+        let status = connection.get_last_status().unwrap();
+        assert_eq!(status.work_position, Some(vec![6.0, 15.0, 0.0]));
+        assert_eq!(status.work_coordinate_offset, Some(vec![5.0, 5.0, 0.0]));
+        connection.disconnect();
+        controller.join().unwrap();
+    }
+
+    #[test]
+    fn refuses_a_job_while_the_travel_limits_are_unknown() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // a controller that answers ok to $$ without reporting any settings
+        let controller = std::thread::spawn(move || serve_tcp(listener.accept().unwrap().0));
+
+        let mut connection = network_connection(TransportKind::Tcp, port);
+        connection.connect(|_| {});
+        let (progress_tx, progress_rx) = crossbeam_channel::unbounded::<JobProgress>();
+        connection.start_job_with(job_lines("G0 X1\n"), move |progress| {
+            let _ = progress_tx.send(progress);
+        });
+        let progress = loop {
+            let progress = progress_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+            if progress.state != JobState::Running {
+                break progress;
+            }
+        };
+        assert_eq!(progress.state, JobState::Failed);
+        assert_eq!(progress.error.as_deref(), Some("The travel limits are unknown, the machine settings weren't read"));
+
+        connection.disconnect();
+        assert!(!controller.join().unwrap().iter().any(|line| line == "G0 X1"));
+    }
+
+    #[test]
+    fn refuses_a_rotary_job_started_right_after_connecting() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // a 3 axis board that is slow to answer $I
+        let controller = std::thread::spawn(move || serve_tcp_with(listener.accept().unwrap().0, |line| match line {
+            "$I" => {
+                std::thread::sleep(Duration::from_millis(200));
+                "[VER:1.1h.20190825:]\r\n[OPT:V,15,128]\r\nok\r\n".to_string()
+            },
+            "$$" => "$20=0\r\nok\r\n".to_string(),
+            _ => reply_to(line),
+        }));
+
+        let mut connection = network_connection(TransportKind::Tcp, port);
+        connection.connect(|_| {});
+        let (progress_tx, progress_rx) = crossbeam_channel::unbounded::<JobProgress>();
+        connection.start_job_with(job_lines("G1 A90 F100\n"), move |progress| {
+            let _ = progress_tx.send(progress);
+        });
+        let progress = loop {
+            let progress = progress_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+            if progress.state != JobState::Running {
+                break progress;
+            }
+        };
+        assert_eq!(progress.state, JobState::Failed);
+        assert_eq!(progress.error.as_deref(), Some("Line 1 uses the A axis, the controller only has XYZ"));
+
+        connection.disconnect();
+        assert!(!controller.join().unwrap().iter().any(|line| line == "G1 A90 F100"));
+    }
+
+    #[test]
+    fn checks_a_job_with_the_offsets_read_when_it_starts() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // 100mm of travel, work zero is moved to X-10 after connecting (e.g. from the pendant)
+        let mut offset_reads = 0;
+        let controller = std::thread::spawn(move || serve_tcp_with(listener.accept().unwrap().0, move |line| match line {
+            "$$" => "$20=1\r\n$130=100.000\r\n$131=100.000\r\nok\r\n".to_string(),
+            "$#" => {
+                offset_reads += 1;
+                let x = if offset_reads == 1 { -100.0 } else { -10.0 };
+                format!("[G54:{:.3},-100.000,0.000]\r\nok\r\n", x)
+            },
+            "$G" => "[GC:G0 G54 G17 G21 G90 G94 M5 M9 T0 F0 S0]\r\nok\r\n".to_string(),
+            _ => reply_to(line),
+        }));
+
+        let mut connection = network_connection(TransportKind::Tcp, port);
+        connection.connect(|_| {});
+        assert!(wait_for_event(&connection, |e| matches!(e, MachineEvent::ConnectionChanged(ConnectionState::Connected))));
+        let (progress_tx, progress_rx) = crossbeam_channel::unbounded::<JobProgress>();
+        connection.start_job_with(job_lines("G0 X20 Y20\n"), move |progress| {
+            let _ = progress_tx.send(progress);
+        });
+        let progress = loop {
+            let progress = progress_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+            if progress.state != JobState::Running {
+                break progress;
+            }
+        };
+        assert_eq!(progress.state, JobState::Failed);
+        assert_eq!(progress.error.as_deref(), Some("Line 1 leaves the travel limits: X10.000 is outside -100.000 to 0.000"));
+
+        connection.disconnect();
+        assert!(!controller.join().unwrap().iter().any(|line| line == "G0 X20 Y20"));
+    }
+
+    #[test]
+    fn reconnects_when_the_link_drops() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let controller = std::thread::spawn(move || {
+            // drop the first connection right away, then behave
+            drop(listener.accept().unwrap());
+            serve_tcp(listener.accept().unwrap().0)
+        });
+
+        let mut connection = network_connection(TransportKind::Tcp, port);
+        connection.connect(|_| {});
+        assert!(wait_for_event(&connection, |e| matches!(e, MachineEvent::ConnectionChanged(ConnectionState::Reconnecting))));
+        assert!(wait_for_event(&connection, |e| matches!(e, MachineEvent::ConnectionChanged(ConnectionState::Connected))));
+
+        connection.disconnect();
+        controller.join().unwrap();
+    }
+
+    #[test]
+    fn streams_commands_over_websocket() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let controller = std::thread::spawn(move || {
+            let mut socket = tungstenite::accept(listener.accept().unwrap().0).unwrap();
+            let mut received = String::new();
+            while let Ok(message) = socket.read() {
+                if !message.is_binary() {
+                    continue;
+                }
+                received += &String::from_utf8_lossy(&message.into_data());
+                while let Some(end) = received.find('\n') {
+                    let line: String = received.drain(..=end).collect();
+                    let _ = socket.send(tungstenite::Message::text(reply_to(line.trim())));
+                }
+            }
+        });
+
+        let mut connection = network_connection(TransportKind::WebSocket, port);
+        connection.connect(|_| {});
+        connection.send_string_command("G0 X1 Y2".to_string());
+        assert!(wait_for_event(&connection, |e| matches!(e, MachineEvent::Status(_))));
+
+        connection.disconnect();
+        controller.join().unwrap();
+    }
+
+    fn wait_for_state(connection: &MachineConnection, state: ConnectionState) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if connection.get_connection_state() == state {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        false
+    }
+
+    #[test]
+    fn keeps_retrying_a_missing_port_until_disconnected() {
+        let mut connection = MachineConnection::new();
+        connection.set_serial_port("/nonexistent/ttyUSB9".to_string());
+        connection.connect(|_| {});
+        assert!(wait_for_state(&connection, ConnectionState::Reconnecting));
+        assert!(!connection.is_connected());
+        // dropped, the machine isn't there to take it
+        connection.send_string_command("G0 X10".to_string());
+
+        let events: Vec<MachineEvent> = connection.serial_rx.as_ref().unwrap().try_iter().collect();
+        assert!(matches!(events[0], MachineEvent::ConnectionChanged(ConnectionState::Connecting)));
+        assert!(matches!(events[1], MachineEvent::ConnectionChanged(ConnectionState::Reconnecting)));
+
+        connection.disconnect();
+        assert_eq!(connection.get_connection_state(), ConnectionState::Disconnected);
+        assert!(connection.serial_tx.is_none() && connection.connection_thread.is_none());
+    }
+
+    #[test]
+    fn disconnecting_twice_is_harmless() {
+        let mut connection = MachineConnection::new();
+        connection.disconnect();
+        connection.set_serial_port("/nonexistent/ttyUSB9".to_string());
+        connection.connect(|_| {});
+        connection.disconnect();
+        connection.disconnect();
+        assert_eq!(connection.get_connection_state(), ConnectionState::Disconnected);
+    }
+}
@@ -1,11 +1,14 @@
 use std::{fs, path::Path};
 use serde::{Deserialize, Serialize};
+use crate::api::estimate::{ConsumableRatings, MotionLimits};
+use crate::api::laser::LaserOutput;
+use crate::api::wcs::Fixture;
 
 
 // settings needed:
 // cut method (straight/split)
 // use laser (on/off)
-// laser offset (x,y)
+// laser offset (x,y) and the output the laser is wired to
 // home after cut (on/off)
 // jog speed (num)
 // clear existing gcode when adding cut
@@ -20,6 +23,98 @@ pub enum CutMethod {
 }
 
 #[derive(Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum SerialFlowControl {
+    None,
+    // XON/XOFF
+    Software,
+    // RTS/CTS
+    Hardware,
+}
+
+// what to do with the DTR/RTS lines when the port is opened, some boards reset when DTR changes
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum ControlLineOnOpen {
+    Unchanged,
+    High,
+    Low,
+    // pull the line low and back high to force a reset
+    Pulse,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum LineEnding {
+    Lf,
+    Cr,
+    CrLf,
+}
+
+impl LineEnding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::Cr => "\r",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+// how the controller is connected
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum TransportKind {
+    Serial,
+    // raw tcp/telnet
+    Tcp,
+    WebSocket,
+    // an in-process grbl simulator, for trying things out without a machine
+    Simulator,
+}
+
+// everything needed to open the connection to the machine
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[serde(default)]
+pub struct ConnectionSettings {
+    pub transport: TransportKind,
+
+    pub serial_port: String,
+    pub baudrate: u32,
+    pub flow_control: SerialFlowControl,
+    pub dtr_on_open: ControlLineOnOpen,
+    pub rts_on_open: ControlLineOnOpen,
+    pub read_timeout_ms: u32,
+    pub line_ending: LineEnding,
+    // prefix every streamed line with N1, N2, ... so errors can be traced back
+    pub line_numbers: bool,
+
+    // address of networked boards, used by the tcp and websocket transports
+    pub host: String,
+    pub network_port: u16,
+}
+
+impl Default for ConnectionSettings {
+    fn default() -> Self {
+        Self {
+            transport: TransportKind::Serial,
+            serial_port: "/dev/ttyUSB0".to_string(),
+            baudrate: 115200,
+            flow_control: SerialFlowControl::None,
+            dtr_on_open: ControlLineOnOpen::Unchanged,
+            rts_on_open: ControlLineOnOpen::Unchanged,
+            read_timeout_ms: 50,
+            line_ending: LineEnding::Lf,
+            line_numbers: false,
+            host: "192.168.5.1".to_string(),
+            network_port: 23,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
 #[flutter_rust_bridge::frb(opaque)]
 #[flutter_rust_bridge::frb]
 pub struct CutterSettings {
@@ -27,46 +122,205 @@ pub struct CutterSettings {
     pub use_laser: bool,
     pub laser_offset_x: f32,
     pub laser_offset_y: f32,
+    #[serde(default)]
+    pub laser_output: LaserOutput,
 
     pub home_after_cut: bool,
     
     pub jog_speed: f32,
 
     pub clear_existing_gcode: bool,
+
+    // older settings files don't have this yet
+    #[serde(default)]
+    pub connection: ConnectionSettings,
+
+    #[serde(default)]
+    pub fixtures: Vec<Fixture>,
+
+    // used to estimate how long a job takes and what it wears out
+    #[serde(default)]
+    pub motion_limits: MotionLimits,
+    #[serde(default)]
+    pub consumable_ratings: ConsumableRatings,
 }
 
 
 impl CutterSettings {
     #[flutter_rust_bridge::frb(sync)]
     pub fn new() -> Self {
-        return CutterSettings::load();
-        
+        // a broken settings file shouldn't keep the app from starting, see load for the error
+        CutterSettings::load().unwrap_or_else(|e| {
+            println!("{}, using the default settings", e);
+            Self::default()
+        })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_transport(&mut self, transport: TransportKind) {
+        self.connection.transport = transport;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_network_address(&mut self, host: String, port: u16) {
+        self.connection.host = host;
+        self.connection.network_port = port;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_serial_port(&mut self, new_port: String) {
+        self.connection.serial_port = new_port;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_baudrate(&mut self, new_baudrate: u32) {
+        self.connection.baudrate = new_baudrate;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_flow_control(&mut self, flow_control: SerialFlowControl) {
+        self.connection.flow_control = flow_control;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_dtr_on_open(&mut self, behavior: ControlLineOnOpen) {
+        self.connection.dtr_on_open = behavior;
     }
 
     #[flutter_rust_bridge::frb(sync)]
-    pub fn save(&self) {
-        let settings_path = Path::new("prefs.json");
-        // save the file
-        let contents = serde_json::to_string(self).expect("Failed to serialize settings!");
-        fs::write(settings_path, contents).expect("Failed to write settings file!");
+    pub fn set_rts_on_open(&mut self, behavior: ControlLineOnOpen) {
+        self.connection.rts_on_open = behavior;
     }
 
     #[flutter_rust_bridge::frb(sync)]
-    pub fn load() -> Self {
+    pub fn set_read_timeout_ms(&mut self, timeout_ms: u32) {
+        self.connection.read_timeout_ms = timeout_ms;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.connection.line_ending = line_ending;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_line_numbers(&mut self, enabled: bool) {
+        self.connection.line_numbers = enabled;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_laser_output(&mut self, output: LaserOutput) {
+        self.laser_output = output;
+    }
+
+    // save a fixture, replacing the one with the same name
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn save_fixture(&mut self, fixture: Fixture) {
+        match self.fixtures.iter_mut().find(|existing| existing.name == fixture.name) {
+            Some(existing) => *existing = fixture,
+            None => self.fixtures.push(fixture),
+        }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn remove_fixture(&mut self, name: String) {
+        self.fixtures.retain(|fixture| fixture.name != name);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_fixture(&self, name: String) -> Option<Fixture> {
+        self.fixtures.iter().find(|fixture| fixture.name == name).cloned()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_motion_limits(&mut self, limits: MotionLimits) {
+        self.motion_limits = limits;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_consumable_ratings(&mut self, ratings: ConsumableRatings) {
+        self.consumable_ratings = ratings;
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn save(&self) -> Result<(), String> {
+        self.save_to(Path::new("prefs.json"))
+    }
+
+    pub(crate) fn save_to(&self, settings_path: &Path) -> Result<(), String> {
+        let contents = serde_json::to_string(self).map_err(|e| format!("Failed to serialize the settings: {}", e))?;
+        fs::write(settings_path, contents).map_err(|e| format!("Failed to write {}: {}", settings_path.display(), e))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn load() -> Result<Self, String> {
+        Self::load_from(Path::new("prefs.json"))
+    }
+
+    pub(crate) fn load_from(settings_path: &Path) -> Result<Self, String> {
         // check if file exists
-        let settings_path = Path::new("prefs.json");
         if settings_path.exists() {
-            let contents = fs::read_to_string(settings_path).expect("Failed to read settings file!");
-            let settings: Self = serde_json::from_str(&contents).expect("Failed to parse settings file!");
-            return settings;
+            let contents = fs::read_to_string(settings_path).map_err(|e| format!("Failed to read {}: {}", settings_path.display(), e))?;
+            serde_json::from_str(&contents).map_err(|e| format!("Failed to parse {}: {}", settings_path.display(), e))
         } else {
-            let settings = Self { cut_method: CutMethod::Split, use_laser: false, laser_offset_x: 0.0, laser_offset_y: 0.0, home_after_cut: true, jog_speed: 600.0, clear_existing_gcode: true };
+            let settings = Self::default();
             // save the new file
-            let contents = serde_json::to_string(&settings).expect("Failed to serialize settings!");
-            fs::write(settings_path, contents).expect("Failed to write settings file!");
-            return settings;
+            settings.save_to(settings_path)?;
+            Ok(settings)
         }
     }
 
 
-}
\ No newline at end of file
+}
+
+impl Default for CutterSettings {
+    fn default() -> Self {
+        Self { cut_method: CutMethod::Split, use_laser: false, laser_offset_x: 0.0, laser_offset_y: 0.0, laser_output: LaserOutput::default(), home_after_cut: true, jog_speed: 600.0, clear_existing_gcode: true, connection: ConnectionSettings::default(), fixtures: Vec::new(), motion_limits: MotionLimits::default(), consumable_ratings: ConsumableRatings::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_settings_saved_before_the_connection_options() {
+        let old = r#"{"cut_method":"Split","use_laser":false,"laser_offset_x":0.0,"laser_offset_y":0.0,"home_after_cut":true,"jog_speed":600.0,"clear_existing_gcode":true}"#;
+        let settings: CutterSettings = serde_json::from_str(old).unwrap();
+        assert_eq!(settings.connection.serial_port, "/dev/ttyUSB0");
+        assert_eq!(settings.connection.baudrate, 115200);
+        assert!(settings.connection.flow_control == SerialFlowControl::None);
+        assert!(settings.connection.line_ending == LineEnding::Lf);
+    }
+
+    #[test]
+    fn keeps_the_connection_options() {
+        let mut settings: CutterSettings = serde_json::from_str(r#"{"cut_method":"Split","use_laser":false,"laser_offset_x":0.0,"laser_offset_y":0.0,"home_after_cut":true,"jog_speed":600.0,"clear_existing_gcode":true}"#).unwrap();
+        settings.set_serial_port("COM3".to_string());
+        settings.set_baudrate(250000);
+        settings.set_flow_control(SerialFlowControl::Hardware);
+        settings.set_dtr_on_open(ControlLineOnOpen::Pulse);
+        settings.set_rts_on_open(ControlLineOnOpen::Low);
+        settings.set_read_timeout_ms(20);
+        settings.set_line_ending(LineEnding::CrLf);
+
+        let reloaded: CutterSettings = serde_json::from_str(&serde_json::to_string(&settings).unwrap()).unwrap();
+        let connection = reloaded.connection;
+        assert_eq!((connection.serial_port.as_str(), connection.baudrate, connection.read_timeout_ms), ("COM3", 250000, 20));
+        assert!(connection.flow_control == SerialFlowControl::Hardware);
+        assert!(connection.dtr_on_open == ControlLineOnOpen::Pulse && connection.rts_on_open == ControlLineOnOpen::Low);
+        assert_eq!(connection.line_ending.as_str(), "\r\n");
+    }
+
+    #[test]
+    fn reports_a_broken_settings_file() {
+        let settings_path = std::env::temp_dir().join(format!("tube_cutter_settings_{}.json", std::process::id()));
+        // a missing file gets created with the defaults
+        assert_eq!(CutterSettings::load_from(&settings_path).unwrap().jog_speed, 600.0);
+        assert!(settings_path.exists());
+
+        fs::write(&settings_path, r#"{"cut_method":"Spl"#).unwrap();
+        let error = CutterSettings::load_from(&settings_path).map(|_| ()).unwrap_err();
+        assert!(error.starts_with(&format!("Failed to parse {}", settings_path.display())), "{}", error);
+        fs::remove_file(&settings_path).unwrap();
+    }
+}
@@ -0,0 +1,922 @@
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::gcode::{DistUnit, Pos2D, PositioningMode};
+use super::interpreter::{parse_words, strip_comments, value_to_mm, ModalState, MotionMode, Word};
+use super::transport::Transport;
+
+const WELCOME_MESSAGE: &str = "Grbl 1.1h ['$' for help]";
+// the $$ settings of a fresh grbl 1.1 board, the ones with a decimal point are printed with three decimals
+const DEFAULT_SETTINGS: [(u32, &str); 34] = [
+    (0, "10"), (1, "25"), (2, "0"), (3, "0"), (4, "0"), (5, "0"), (6, "0"), (10, "1"), (11, "0.010"), (12, "0.002"),
+    (13, "0"), (20, "0"), (21, "0"), (22, "0"), (23, "0"), (24, "25.000"), (25, "500.000"), (26, "250"), (27, "1.000"),
+    (30, "1000"), (31, "0"), (32, "0"), (100, "80.000"), (101, "80.000"), (102, "80.000"), (110, "5000.000"),
+    (111, "5000.000"), (112, "500.000"), (120, "500.000"), (121, "500.000"), (122, "100.000"), (130, "1200.000"),
+    (131, "600.000"), (132, "100.000"),
+];
+const HOMING_REQUIRED_MESSAGE: &str = "[MSG:'$H'|'$X' to unlock]";
+
+// the realtime commands the simulator understands
+const STATUS_QUERY: u8 = b'?';
+const FEED_HOLD: u8 = b'!';
+const CYCLE_START: u8 = b'~';
+const SOFT_RESET: u8 = 0x18;
+const JOG_CANCEL: u8 = 0x85;
+
+// how the simulated controller behaves
+#[derive(Clone)]
+pub struct SimulatorConfig {
+    // number of moves the planner can hold
+    pub planner_blocks: usize,
+    pub rx_buffer_size: usize,
+    // speed of G0 moves in mm/min
+    pub rapid_rate: f32,
+    // used for G1 moves that never set a feed rate
+    pub default_feedrate: f32,
+    // 10.0 runs every move ten times faster than the real machine would
+    pub time_scale: f32,
+    // start up locked in an alarm until homed or unlocked, like a board with homing enabled
+    pub homing_required: bool,
+}
+
+impl Default for SimulatorConfig {
+    fn default() -> Self {
+        Self {
+            planner_blocks: 15,
+            rx_buffer_size: 128,
+            rapid_rate: 5000.0,
+            default_feedrate: 1000.0,
+            time_scale: 1.0,
+            homing_required: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SimulatedState {
+    Idle,
+    Run,
+    Jog,
+    Hold,
+    Alarm,
+}
+
+// a single entry of the planner
+struct PlannedBlock {
+    target: Pos2D,
+    duration: Duration,
+    feedrate: f32,
+    // torch state while (and after) this block runs
+    torch_on: bool,
+    jog: bool,
+}
+
+// an in-process stand-in for a grbl controller, fed with bytes and answering like the real thing
+pub struct GrblSimulator {
+    config: SimulatorConfig,
+    modal: ModalState,
+
+    state: SimulatedState,
+    position: Pos2D,
+    torch_on: bool,
+    planner: VecDeque<PlannedBlock>,
+    // how far into the front block we are
+    block_elapsed: Duration,
+    last_update: Instant,
+
+    input: Vec<u8>,
+    last_was_cr: bool,
+    pending_lines: VecDeque<String>,
+    output: Vec<u8>,
+
+    settings: Vec<(u32, String)>,
+    // offsets of G54 to G59 from machine zero
+    work_offsets: [Pos2D; 6],
+    active_wcs: usize,
+
+    // the next gcode line gets this error instead of running
+    injected_error: Option<u32>,
+    received_lines: Vec<String>,
+}
+
+impl GrblSimulator {
+    pub fn new(config: SimulatorConfig) -> Self {
+        let mut simulator = Self {
+            config,
+            modal: ModalState::new(),
+            state: SimulatedState::Idle,
+            position: Pos2D::new(0.0, 0.0),
+            torch_on: false,
+            planner: VecDeque::new(),
+            block_elapsed: Duration::ZERO,
+            last_update: Instant::now(),
+            input: Vec::new(),
+            last_was_cr: false,
+            pending_lines: VecDeque::new(),
+            output: Vec::new(),
+            settings: DEFAULT_SETTINGS.iter().map(|(id, value)| (*id, value.to_string())).collect(),
+            work_offsets: std::array::from_fn(|_| Pos2D::new(0.0, 0.0)),
+            active_wcs: 0,
+            injected_error: None,
+            received_lines: Vec::new(),
+        };
+        simulator.write_line("");
+        simulator.write_line(WELCOME_MESSAGE);
+        if simulator.config.homing_required {
+            simulator.state = SimulatedState::Alarm;
+            simulator.write_line(HOMING_REQUIRED_MESSAGE);
+        }
+        simulator
+    }
+
+    // the current (interpolated) machine position
+    pub fn position(&self) -> Pos2D {
+        let Some(block) = self.planner.front() else {
+            return self.position.clone();
+        };
+        if block.duration.is_zero() {
+            return self.position.clone();
+        }
+        let t = (self.block_elapsed.as_secs_f32() / block.duration.as_secs_f32()).min(1.0);
+        Pos2D::new(
+            self.position.x + (block.target.x - self.position.x) * t,
+            self.position.y + (block.target.y - self.position.y) * t,
+        )
+    }
+
+    pub fn torch_on(&self) -> bool {
+        self.torch_on
+    }
+
+    pub fn in_alarm(&self) -> bool {
+        self.state == SimulatedState::Alarm
+    }
+
+    // every line that was received (realtime commands aren't included)
+    pub fn received_lines(&self) -> &[String] {
+        &self.received_lines
+    }
+
+    // answer the next gcode line with error:code
+    pub fn inject_error(&mut self, code: u32) {
+        self.injected_error = Some(code);
+    }
+
+    // raise an alarm right away, like a limit switch or e-stop would
+    pub fn inject_alarm(&mut self, code: u32) {
+        self.update();
+        self.enter_alarm(code);
+    }
+
+    // feed bytes from the sender into the controller
+    pub fn receive(&mut self, bytes: &[u8]) {
+        self.update();
+        for &byte in bytes {
+            let was_cr = std::mem::replace(&mut self.last_was_cr, byte == b'\r');
+            match byte {
+                STATUS_QUERY => self.write_status(),
+                FEED_HOLD => {
+                    if matches!(self.state, SimulatedState::Run | SimulatedState::Jog) {
+                        self.state = SimulatedState::Hold;
+                    }
+                },
+                CYCLE_START => {
+                    if self.state == SimulatedState::Hold {
+                        self.state = if self.planner.is_empty() { SimulatedState::Idle } else { SimulatedState::Run };
+                    }
+                },
+                SOFT_RESET => self.reset(),
+                JOG_CANCEL => self.cancel_jog(),
+                // a \r\n ends a single line
+                b'\n' if was_cr => {},
+                b'\n' | b'\r' => {
+                    let line = String::from_utf8_lossy(&self.input).into_owned();
+                    self.input.clear();
+                    self.pending_lines.push_back(line);
+                },
+                _ => self.input.push(byte),
+            }
+        }
+        self.process_pending_lines();
+    }
+
+    // everything the controller wrote since the last call
+    pub fn take_output(&mut self) -> Vec<u8> {
+        self.update();
+        std::mem::take(&mut self.output)
+    }
+
+    // advance the simulated motion to now
+    pub fn update(&mut self) {
+        let now = Instant::now();
+        let delta = now.duration_since(self.last_update).mul_f32(self.config.time_scale);
+        self.last_update = now;
+
+        if matches!(self.state, SimulatedState::Run | SimulatedState::Jog) {
+            self.block_elapsed += delta;
+        }
+
+        while let Some(block) = self.planner.front() {
+            if self.state == SimulatedState::Hold || self.block_elapsed < block.duration {
+                break;
+            }
+            self.block_elapsed -= block.duration;
+            self.position = block.target.clone();
+            self.torch_on = block.torch_on;
+            self.planner.pop_front();
+        }
+
+        if let Some(block) = self.planner.front() {
+            // the torch changes as soon as the block starts
+            self.torch_on = block.torch_on;
+        } else {
+            self.block_elapsed = Duration::ZERO;
+            if matches!(self.state, SimulatedState::Run | SimulatedState::Jog) {
+                self.state = SimulatedState::Idle;
+            }
+        }
+
+        self.process_pending_lines();
+    }
+
+    fn write_line(&mut self, line: &str) {
+        self.output.extend_from_slice(line.as_bytes());
+        self.output.extend_from_slice(b"\r\n");
+    }
+
+    fn write_status(&mut self) {
+        let state = match self.state {
+            SimulatedState::Idle => "Idle",
+            SimulatedState::Run => "Run",
+            SimulatedState::Jog => "Jog",
+            SimulatedState::Hold => "Hold:0",
+            SimulatedState::Alarm => "Alarm",
+        };
+        let position = self.position();
+        let feedrate = match self.planner.front() {
+            Some(block) if self.state != SimulatedState::Hold => block.feedrate,
+            _ => 0.0,
+        };
+        let free_blocks = self.config.planner_blocks - self.planner.len().min(self.config.planner_blocks);
+        let free_bytes = self.config.rx_buffer_size.saturating_sub(self.input.len());
+        let mut report = format!(
+            "<{}|MPos:{:.3},{:.3},0.000|Bf:{},{}|FS:{},0",
+            state, position.x, position.y, free_blocks, free_bytes, feedrate
+        );
+        let offset = self.active_offset();
+        if offset.x != 0.0 || offset.y != 0.0 {
+            report += &format!("|WCO:{:.3},{:.3},0.000", offset.x, offset.y);
+        }
+        if self.torch_on {
+            report += "|A:S";
+        }
+        report += ">";
+        self.write_line(&report);
+    }
+
+    fn enter_alarm(&mut self, code: u32) {
+        self.planner.clear();
+        self.pending_lines.clear();
+        self.block_elapsed = Duration::ZERO;
+        self.torch_on = false;
+        self.state = SimulatedState::Alarm;
+        self.write_line(&format!("ALARM:{}", code));
+    }
+
+    fn reset(&mut self) {
+        // stopping in the middle of a move loses the position, grbl raises an alarm for that
+        let was_moving = matches!(self.state, SimulatedState::Run | SimulatedState::Jog) && !self.planner.is_empty();
+        if !self.planner.is_empty() {
+            self.position = self.position();
+        }
+        self.planner.clear();
+        self.pending_lines.clear();
+        self.input.clear();
+        self.block_elapsed = Duration::ZERO;
+        self.torch_on = false;
+        self.modal.torch_on = false;
+        if self.state != SimulatedState::Alarm {
+            self.state = SimulatedState::Idle;
+        }
+        self.write_line("");
+        self.write_line(WELCOME_MESSAGE);
+        if was_moving {
+            self.enter_alarm(3);
+        }
+    }
+
+    fn cancel_jog(&mut self) {
+        if self.state != SimulatedState::Jog {
+            return;
+        }
+        self.position = self.position();
+        self.planner.retain(|block| !block.jog);
+        self.block_elapsed = Duration::ZERO;
+        // the next relative jog starts from where this one stopped
+        self.modal.position = self.to_work(&self.planned_position());
+        self.state = if self.planner.is_empty() { SimulatedState::Idle } else { SimulatedState::Run };
+    }
+
+    // run the received lines while there is room in the planner
+    fn process_pending_lines(&mut self) {
+        while !self.pending_lines.is_empty() {
+            if self.planner.len() >= self.config.planner_blocks {
+                // grbl holds back the ok until there is room
+                return;
+            }
+            let line = self.pending_lines.pop_front().unwrap();
+            let response = self.execute_line(&line);
+            self.write_line(&response);
+        }
+    }
+
+    // run one line, returns what grbl would answer
+    fn execute_line(&mut self, line: &str) -> String {
+        let line = strip_comments(line).trim().to_string();
+        if line.is_empty() {
+            return "ok".to_string();
+        }
+        self.received_lines.push(line.clone());
+
+        if let Some(command) = line.strip_prefix('$') {
+            return self.execute_system_command(command);
+        }
+
+        if self.state == SimulatedState::Alarm {
+            return "error:9".to_string();
+        }
+        if let Some(code) = self.injected_error.take() {
+            return format!("error:{}", code);
+        }
+
+        let words: Vec<Word> = parse_words(&line).into_iter().filter(|w| w.letter != 'N').collect();
+        if let Some(code) = unsupported_word(&words) {
+            return format!("error:{}", code);
+        }
+
+        self.plan_words(&words, false);
+        "ok".to_string()
+    }
+
+    fn execute_system_command(&mut self, command: &str) -> String {
+        match command {
+            // unlock
+            "X" => {
+                if self.state == SimulatedState::Alarm {
+                    self.state = SimulatedState::Idle;
+                    self.write_line("[MSG:Caution: Unlocked]");
+                }
+                "ok".to_string()
+            },
+            // the build info
+            "I" => {
+                self.write_line("[VER:1.1h.20190825:]");
+                self.write_line(&format!("[OPT:V,{},{}]", self.config.planner_blocks, self.config.rx_buffer_size));
+                "ok".to_string()
+            },
+            // the settings
+            "$" => {
+                for (id, value) in self.settings.clone() {
+                    self.write_line(&format!("${}={}", id, value));
+                }
+                "ok".to_string()
+            },
+            // the stored offsets
+            "#" => {
+                for index in 0..6 {
+                    let offset = self.work_offsets[index].clone();
+                    self.write_line(&format!("[G{}:{:.3},{:.3},0.000]", 54 + index, offset.x, offset.y));
+                }
+                for line in ["[G28:0.000,0.000,0.000]", "[G30:0.000,0.000,0.000]", "[G92:0.000,0.000,0.000]", "[TLO:0.000]", "[PRB:0.000,0.000,0.000:0]"] {
+                    self.write_line(line);
+                }
+                "ok".to_string()
+            },
+            // the parser state
+            "G" => {
+                let motion = match self.modal.motion_mode {
+                    MotionMode::Rapid => "G0",
+                    MotionMode::Linear => "G1",
+                    MotionMode::ArcClockwise => "G2",
+                    MotionMode::ArcCounterClockwise => "G3",
+                };
+                let units = if self.modal.units == DistUnit::Imperial { "G20" } else { "G21" };
+                let distance = if self.modal.positioning_mode == PositioningMode::Relative { "G91" } else { "G90" };
+                let spindle = if self.modal.torch_on { "M3" } else { "M5" };
+                let feedrate = self.modal.feedrate.unwrap_or(0.0);
+                self.write_line(&format!("[GC:{} G{} G17 {} {} G94 {} M9 T0 F{} S0]", motion, 54 + self.active_wcs, units, distance, spindle, feedrate));
+                "ok".to_string()
+            },
+            // homing puts the machine at machine zero
+            "H" | "HX" | "HY" => {
+                if !self.planner.is_empty() {
+                    return "error:8".to_string();
+                }
+                if command != "HY" {
+                    self.position.x = 0.0;
+                }
+                if command != "HX" {
+                    self.position.y = 0.0;
+                }
+                self.modal.position = self.to_work(&self.position.clone());
+                self.state = SimulatedState::Idle;
+                "ok".to_string()
+            },
+            _ => {
+                if let Some(jog) = command.strip_prefix("J=") {
+                    if !matches!(self.state, SimulatedState::Idle | SimulatedState::Jog) {
+                        return "error:8".to_string();
+                    }
+                    // jogs don't change the modal state of the program
+                    let saved_modal = self.modal.clone();
+                    self.plan_words(&parse_words(jog), true);
+                    self.modal = saved_modal;
+                    self.modal.position = self.to_work(&self.planned_position());
+                    return "ok".to_string();
+                }
+                if let Some((id, value)) = command.split_once('=') {
+                    return self.write_setting(id, value);
+                }
+                // unknown system command
+                "error:3".to_string()
+            }
+        }
+    }
+
+    // $n=value
+    fn write_setting(&mut self, id: &str, value: &str) -> String {
+        let Some(setting) = id.parse::<u32>().ok().and_then(|id| self.settings.iter_mut().find(|(setting, _)| *setting == id)) else {
+            return "error:3".to_string();
+        };
+        // grbl answers a value it can't read with "bad number format"
+        let Ok(number) = value.parse::<f32>() else {
+            return "error:2".to_string();
+        };
+        if number < 0.0 {
+            return "error:4".to_string();
+        }
+        setting.1 = if setting.1.contains('.') { format!("{:.3}", number) } else { format!("{}", number as u32) };
+        "ok".to_string()
+    }
+
+    // where the machine ends up once the planner is empty
+    fn planned_position(&self) -> Pos2D {
+        match self.planner.back() {
+            Some(block) => block.target.clone(),
+            None => self.position.clone(),
+        }
+    }
+
+    fn active_offset(&self) -> Pos2D {
+        self.work_offsets[self.active_wcs].clone()
+    }
+
+    // work coordinates of a machine position in the active system
+    fn to_work(&self, machine: &Pos2D) -> Pos2D {
+        let offset = self.active_offset();
+        Pos2D::new(machine.x - offset.x, machine.y - offset.y)
+    }
+
+    // G54-G59 and G10, returns true if the work coordinates changed
+    fn apply_coordinate_words(&mut self, words: &[Word], machine: &Pos2D) -> bool {
+        let has_g = |value: f32| words.iter().any(|w| w.letter == 'G' && w.value == value);
+        let mut changed = false;
+
+        for word in words.iter().filter(|w| w.letter == 'G') {
+            if (54.0..=59.0).contains(&word.value) && word.value.fract() == 0.0 {
+                self.active_wcs = word.value as usize - 54;
+                changed = true;
+            }
+        }
+
+        if has_g(10.0) {
+            let l = words.iter().find(|w| w.letter == 'L').map(|w| w.value as u32);
+            let p = words.iter().find(|w| w.letter == 'P').map(|w| w.value as usize).unwrap_or(0);
+            // P0 is the active system
+            let index = if p == 0 { self.active_wcs } else { (p - 1).min(5) };
+            for word in words {
+                let value = word.value;
+                let (offset_axis, machine_axis) = match word.letter {
+                    'X' => (&mut self.work_offsets[index].x, machine.x),
+                    'Y' => (&mut self.work_offsets[index].y, machine.y),
+                    _ => continue,
+                };
+                match l {
+                    // the offset itself
+                    Some(2) => *offset_axis = value,
+                    // the offset that makes the current position this value
+                    Some(20) => *offset_axis = machine_axis - value,
+                    _ => {}
+                }
+            }
+            changed = true;
+        }
+        changed
+    }
+
+    fn plan_words(&mut self, words: &[Word], jog: bool) {
+        let start = self.planned_position();
+        let was_torch_on = self.modal.torch_on;
+        self.modal.apply_words(words);
+
+        if self.apply_coordinate_words(words, &start) {
+            self.modal.position = self.to_work(&start);
+        }
+        // a move in machine coordinates, only for this line
+        if words.iter().any(|w| w.letter == 'G' && w.value == 53.0) {
+            let mut target = start.clone();
+            for word in words {
+                match word.letter {
+                    'X' => target.x = value_to_mm(word.value, self.modal.units),
+                    'Y' => target.y = value_to_mm(word.value, self.modal.units),
+                    _ => {}
+                }
+            }
+            self.modal.position = self.to_work(&target);
+        }
+        let offset = self.active_offset();
+        let target = Pos2D::new(self.modal.position.x + offset.x, self.modal.position.y + offset.y);
+
+        let has_dwell = words.iter().any(|w| w.letter == 'G' && w.value as u32 == 4);
+        let moved = target.x != start.x || target.y != start.y;
+
+        // torch changes take effect in order with the moves
+        if self.modal.torch_on != was_torch_on {
+            self.push_block(start.clone(), Duration::ZERO, 0.0, jog);
+        }
+
+        if has_dwell {
+            let seconds = words.iter().find(|w| w.letter == 'P').map(|w| w.value).unwrap_or(0.0);
+            self.push_block(start.clone(), Duration::from_secs_f32(seconds.max(0.0) / self.config.time_scale), 0.0, jog);
+        } else if moved {
+            let feedrate = match self.modal.motion_mode {
+                MotionMode::Rapid if !jog => self.config.rapid_rate,
+                _ => self.modal.feedrate.unwrap_or(self.config.default_feedrate),
+            };
+            let distance = ((target.x - start.x).powi(2) + (target.y - start.y).powi(2)).sqrt();
+            let seconds = distance / (feedrate / 60.0).max(f32::EPSILON);
+            self.push_block(target, Duration::from_secs_f32(seconds / self.config.time_scale), feedrate, jog);
+        }
+    }
+
+    fn push_block(&mut self, target: Pos2D, duration: Duration, feedrate: f32, jog: bool) {
+        self.planner.push_back(PlannedBlock { target, duration, feedrate, torch_on: self.modal.torch_on, jog });
+        if self.state == SimulatedState::Idle {
+            self.state = if jog { SimulatedState::Jog } else { SimulatedState::Run };
+            self.block_elapsed = Duration::ZERO;
+            self.last_update = Instant::now();
+        }
+    }
+}
+
+// the grbl error code for words the simulator (and grbl) doesn't support
+fn unsupported_word(words: &[Word]) -> Option<u32> {
+    for word in words {
+        match word.letter {
+            'G' => {
+                let supported = [0.0, 1.0, 2.0, 3.0, 4.0, 10.0, 17.0, 20.0, 21.0, 28.0, 30.0, 53.0, 54.0, 55.0, 56.0, 57.0, 58.0, 59.0, 90.0, 91.0, 92.0, 93.0, 94.0];
+                if !supported.contains(&word.value) {
+                    return Some(20);
+                }
+            },
+            'M' => {
+                if ![0.0, 2.0, 3.0, 4.0, 5.0, 7.0, 8.0, 9.0, 30.0].contains(&word.value) {
+                    return Some(20);
+                }
+            },
+            'X' | 'Y' | 'Z' | 'F' | 'P' | 'I' | 'J' | 'K' | 'L' | 'R' | 'S' | 'T' => {},
+            _ => return Some(20),
+        }
+    }
+    None
+}
+
+// connects the sender to a simulator running in the same process
+pub(crate) struct SimulatorTransport {
+    simulator: Arc<Mutex<GrblSimulator>>,
+    read_timeout: Duration,
+}
+
+impl SimulatorTransport {
+    pub(crate) fn new(simulator: Arc<Mutex<GrblSimulator>>, read_timeout: Duration) -> Self {
+        Self { simulator, read_timeout }
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+impl Transport for SimulatorTransport {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let deadline = Instant::now() + self.read_timeout;
+        loop {
+            {
+                let mut simulator = self.simulator.lock().unwrap();
+                simulator.update();
+                if !simulator.output.is_empty() {
+                    let n = buf.len().min(simulator.output.len());
+                    buf[..n].copy_from_slice(&simulator.output[..n]);
+                    simulator.output.drain(..n);
+                    return Ok(n);
+                }
+            }
+            if Instant::now() >= deadline {
+                return Err(ErrorKind::TimedOut.into());
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> std::io::Result<()> {
+        self.simulator.lock().unwrap().receive(data);
+        Ok(())
+    }
+}
+
+// a simulator behind a linux pseudo-terminal, so it can be opened like a real serial port
+// linux only, so it stays out of the bindings
+#[cfg(target_os = "linux")]
+#[flutter_rust_bridge::frb(ignore)]
+pub struct PtySimulator {
+    path: String,
+    simulator: Arc<Mutex<GrblSimulator>>,
+    running: Arc<std::sync::atomic::AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+#[cfg(target_os = "linux")]
+#[flutter_rust_bridge::frb(ignore)]
+impl PtySimulator {
+    pub fn start(config: SimulatorConfig) -> std::io::Result<Self> {
+        use std::os::fd::FromRawFd;
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        // open the master side and find out where the slave side lives
+        let (master, path) = unsafe {
+            let fd = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY | libc::O_NONBLOCK);
+            if fd < 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            let master = std::fs::File::from_raw_fd(fd);
+            if libc::grantpt(fd) != 0 || libc::unlockpt(fd) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            let mut name = [0 as libc::c_char; 128];
+            if libc::ptsname_r(fd, name.as_mut_ptr(), name.len()) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            let path = std::ffi::CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned();
+            (master, path)
+        };
+
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(config)));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread = {
+            let simulator = simulator.clone();
+            let running = running.clone();
+            std::thread::spawn(move || {
+                use std::io::{Read, Write};
+                let mut master = master;
+                let mut buf = [0u8; 256];
+                while running.load(Ordering::Relaxed) {
+                    match master.read(&mut buf) {
+                        Ok(n) if n > 0 => simulator.lock().unwrap().receive(&buf[..n]),
+                        // nothing to read, or nobody has the slave side open right now
+                        _ => std::thread::sleep(Duration::from_millis(1)),
+                    }
+                    let output = simulator.lock().unwrap().take_output();
+                    if !output.is_empty() {
+                        let _ = master.write_all(&output);
+                    }
+                }
+            })
+        };
+
+        Ok(Self { path, simulator, running, thread: Some(thread) })
+    }
+
+    // the device to open, e.g. /dev/pts/3
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn simulator(&self) -> Arc<Mutex<GrblSimulator>> {
+        self.simulator.clone()
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for PtySimulator {
+    fn drop(&mut self) {
+        self.running.store(false, std::sync::atomic::Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+// a connection to the simulator for tests, like a controller on a serial port
+#[cfg(test)]
+pub(crate) fn connect_to_simulator(simulator: Arc<Mutex<GrblSimulator>>) -> super::sender::MachineConnection {
+    let mut connection = super::sender::MachineConnection::new();
+    connection.connect_with_transport(move |_| Ok(Box::new(SimulatorTransport::new(simulator.clone(), Duration::from_millis(10))) as Box<dyn Transport>), |_| {});
+    connection
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::job::{job_lines, resume_job_lines, JobProgress, JobState};
+    use crate::api::sender::MachineConnection;
+    use crate::api::status::{parse_status, MachineState};
+    use crossbeam_channel::Receiver;
+
+    // a program with two cuts, the torch is on while cutting
+    const PROGRAM: &str = "G21\nG90\nG0 X0 Y0\nM3\nG4 P0.1\nG1 X10 Y0 F3000\nG1 X10 Y10\nM5\nG0 X20 Y0\nM3\nG4 P0.1\nG1 X30 Y0\nM5\n";
+
+    fn fast_simulator() -> Arc<Mutex<GrblSimulator>> {
+        Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig { time_scale: 20.0, ..Default::default() })))
+    }
+
+    fn output_lines(simulator: &mut GrblSimulator) -> Vec<String> {
+        String::from_utf8_lossy(&simulator.take_output())
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    fn status(simulator: &mut GrblSimulator) -> crate::api::status::MachineStatus {
+        simulator.receive(b"?");
+        let line = output_lines(simulator).into_iter().rfind(|line| line.starts_with('<')).unwrap();
+        parse_status(line).unwrap()
+    }
+
+    fn start_job(connection: &MachineConnection, lines: Vec<crate::api::job::JobLine>) -> Receiver<JobProgress> {
+        let (progress_tx, progress_rx) = crossbeam_channel::unbounded();
+        connection.start_job_with(lines, move |progress| {
+            let _ = progress_tx.send(progress);
+        });
+        progress_rx
+    }
+
+    fn wait_for_state(progress: &Receiver<JobProgress>, state: JobState) -> JobProgress {
+        loop {
+            let update = progress.recv_timeout(Duration::from_secs(10)).expect("job stalled");
+            if update.state == state {
+                return update;
+            }
+        }
+    }
+
+    #[test]
+    fn answers_lines_with_ok() {
+        let mut simulator = GrblSimulator::new(SimulatorConfig::default());
+        assert_eq!(output_lines(&mut simulator), vec![WELCOME_MESSAGE.to_string()]);
+
+        simulator.receive(b"G21\r\nG90 (absolute)\n\nG38.2 X1\n");
+        assert_eq!(output_lines(&mut simulator), vec!["ok", "ok", "ok", "error:20"]);
+    }
+
+    #[test]
+    fn reports_a_parseable_status() {
+        let mut simulator = GrblSimulator::new(SimulatorConfig { time_scale: 1000.0, ..Default::default() });
+        simulator.receive(b"G0 X12.5 Y-3\n");
+        std::thread::sleep(Duration::from_millis(20));
+
+        let status = status(&mut simulator);
+        assert!(matches!(status.machine_state, MachineState::Idle));
+        assert_eq!(status.position.x, 12.5);
+        assert_eq!(status.position.y, -3.0);
+        assert_eq!(status.buffer.unwrap().planner_blocks, 15);
+    }
+
+    #[test]
+    fn moves_take_as_long_as_their_feed_rate_says() {
+        let mut simulator = GrblSimulator::new(SimulatorConfig::default());
+        // 10mm at 600mm/min takes a second
+        simulator.receive(b"G1 X10 F600\n");
+        std::thread::sleep(Duration::from_millis(100));
+
+        let status = status(&mut simulator);
+        assert!(matches!(status.machine_state, MachineState::Run));
+        assert!(status.position.x > 0.0 && status.position.x < 5.0);
+        assert_eq!(status.feed_rate, Some(600.0));
+    }
+
+    #[test]
+    fn holds_back_the_ok_while_the_planner_is_full() {
+        let mut simulator = GrblSimulator::new(SimulatorConfig { planner_blocks: 2, ..Default::default() });
+        output_lines(&mut simulator);
+        simulator.receive(b"G1 X10 F60\nG1 X20\nG1 X30\n");
+        assert_eq!(output_lines(&mut simulator), vec!["ok", "ok"]);
+
+        simulator.receive(&[SOFT_RESET]);
+        assert!(simulator.in_alarm());
+    }
+
+    #[test]
+    fn alarms_lock_the_machine_until_unlocked() {
+        let mut simulator = GrblSimulator::new(SimulatorConfig::default());
+        output_lines(&mut simulator);
+        simulator.inject_alarm(1);
+        simulator.receive(b"G0 X1\n$X\nG0 X1\n");
+        assert_eq!(output_lines(&mut simulator), vec!["ALARM:1", "error:9", "[MSG:Caution: Unlocked]", "ok", "ok"]);
+    }
+
+    #[test]
+    fn runs_a_job_to_completion() {
+        let simulator = fast_simulator();
+        let mut connection = connect_to_simulator(simulator.clone());
+        let progress = start_job(&connection, job_lines(PROGRAM));
+
+        let done = wait_for_state(&progress, JobState::Completed);
+        assert_eq!(done.lines_acknowledged, done.total_lines);
+        assert_eq!(done.percent, 100.0);
+        connection.disconnect();
+
+        let simulator = simulator.lock().unwrap();
+        let received: Vec<&str> = simulator.received_lines().iter().map(|line| line.as_str()).filter(|line| *line != "M5").collect();
+        assert!(received.ends_with(&["G0X20Y0", "M3", "G4P0.1", "G1X30Y0"]));
+    }
+
+    #[test]
+    fn an_error_fails_the_job_with_its_line() {
+        let simulator = fast_simulator();
+        let mut connection = connect_to_simulator(simulator.clone());
+        simulator.lock().unwrap().inject_error(33);
+        let progress = start_job(&connection, job_lines(PROGRAM));
+
+        let failed = wait_for_state(&progress, JobState::Failed);
+        assert_eq!(failed.error.as_deref(), Some("Machine reported error:33 on line 1"));
+        connection.disconnect();
+    }
+
+    #[test]
+    fn aborting_stops_the_machine_with_the_torch_off() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+        // more moves than fit into the planner, slow enough to still be cutting when the abort comes in
+        let mut program = "M3\nG1 F600\n".to_string();
+        for i in 1..=20 {
+            program += &format!("G1 X{}\n", i * 10);
+        }
+        let progress = start_job(&connection, job_lines(&program));
+        wait_for_state(&progress, JobState::Running);
+        std::thread::sleep(Duration::from_millis(200));
+        assert!(simulator.lock().unwrap().torch_on());
+
+        connection.abort_job();
+        wait_for_state(&progress, JobState::Aborted);
+        assert!(!simulator.lock().unwrap().torch_on());
+        connection.disconnect();
+    }
+
+    #[test]
+    fn pauses_and_resumes_a_job() {
+        let simulator = fast_simulator();
+        let mut connection = connect_to_simulator(simulator.clone());
+        let progress = start_job(&connection, job_lines(PROGRAM));
+
+        connection.pause_job();
+        let paused = wait_for_state(&progress, JobState::Paused);
+        assert!(paused.lines_acknowledged < paused.total_lines);
+
+        connection.resume_job();
+        wait_for_state(&progress, JobState::Completed);
+        connection.disconnect();
+    }
+
+    #[test]
+    fn resumes_a_job_in_the_middle_of_a_cut() {
+        let simulator = fast_simulator();
+        let mut connection = connect_to_simulator(simulator.clone());
+        // start on the second move of the first cut
+        let progress = start_job(&connection, resume_job_lines(PROGRAM, 6));
+
+        wait_for_state(&progress, JobState::Completed);
+        connection.disconnect();
+
+        let simulator = simulator.lock().unwrap();
+        // the sender asks for the build info ($I) after connecting
+        let program: Vec<&String> = simulator.received_lines().iter().filter(|line| !line.starts_with('$')).collect();
+        assert!(program.starts_with(&["M5", "G21", "G90", "G0X10Y0", "G1F3000", "M3", "G4P0.1", "G1X10Y10"].map(String::from).each_ref()));
+        assert_eq!(simulator.position().x, 30.0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn runs_a_job_over_a_pseudo_terminal() {
+        let pty = PtySimulator::start(SimulatorConfig { time_scale: 20.0, ..Default::default() }).unwrap();
+
+        let mut connection = MachineConnection::new();
+        connection.set_serial_port(pty.path().to_string());
+        connection.connect(|_| {});
+        let progress = start_job(&connection, job_lines(PROGRAM));
+
+        wait_for_state(&progress, JobState::Completed);
+        connection.disconnect();
+        assert_eq!(pty.simulator().lock().unwrap().position().x, 30.0);
+    }
+}
@@ -1,4 +1,7 @@
+use std::fmt;
+
 use pest::Parser;
+use pest::iterators::Pair;
 use pest_derive::Parser;
 
 use crate::api::gcode::Pos2D;
@@ -19,20 +22,171 @@ pub enum MachineState {
     Sleep,
     Tool
 }
+
+// planner and serial buffer space reported with Bf:
+#[derive(Clone)]
+pub struct BufferState {
+    pub planner_blocks: u32,
+    pub rx_bytes: u32,
+}
+
+// feed, rapid and spindle overrides reported with Ov: (in percent)
+#[derive(Clone)]
+pub struct Overrides {
+    pub feed: u32,
+    pub rapid: u32,
+    pub spindle: u32,
+}
+
+// accessory states reported with A:
+#[derive(Clone, Default)]
+pub struct Accessories {
+    pub spindle_cw: bool,
+    pub spindle_ccw: bool,
+    pub flood_coolant: bool,
+    pub mist_coolant: bool,
+    pub tool_change_pending: bool,
+}
+
+#[derive(Clone)]
 pub struct MachineStatus {
+    // x and y of the machine position, falls back to the work position if only WPos was reported
     pub position: Pos2D,
-    pub machine_state: MachineState
+    pub machine_state: MachineState,
+    // e.g. the 0 in Hold:0
+    pub sub_state: Option<u32>,
+
+    // all reported axes, WPos is derived from MPos - WCO (and the other way around) when possible
+    pub machine_position: Option<Vec<f32>>,
+    pub work_position: Option<Vec<f32>>,
+    pub work_coordinate_offset: Option<Vec<f32>>,
+
+    pub buffer: Option<BufferState>,
+    pub line_number: Option<u32>,
+    pub feed_rate: Option<f32>,
+    pub spindle_speed: Option<f32>,
+    pub overrides: Option<Overrides>,
+    // the letters of the triggered input pins, e.g. "XYP"
+    pub pins: Option<String>,
+    pub accessories: Option<Accessories>,
 }
 impl MachineStatus {
     pub fn new() -> MachineStatus {
-        MachineStatus { position: Pos2D::new(0.0, 0.0), machine_state: MachineState::Idle }
+        MachineStatus {
+            position: Pos2D::new(0.0, 0.0),
+            machine_state: MachineState::Idle,
+            sub_state: None,
+            machine_position: None,
+            work_position: None,
+            work_coordinate_offset: None,
+            buffer: None,
+            line_number: None,
+            feed_rate: None,
+            spindle_speed: None,
+            overrides: None,
+            pins: None,
+            accessories: None,
+        }
+    }
+
+    // check if a pin (e.g. 'P' for the probe) was reported as triggered
+    pub fn pin_triggered(&self, pin: char) -> bool {
+        match &self.pins {
+            Some(pins) => pins.contains(pin),
+            None => false,
+        }
+    }
+
+    // derive whichever position is missing, grbl only sends WCO every 10-30 reports
+    // so the offset can also be one remembered from an earlier report
+    pub(crate) fn apply_work_coordinate_offset(&mut self, wco: Vec<f32>) {
+        if self.work_position.is_none() {
+            if let Some(mpos) = &self.machine_position {
+                self.work_position = Some(mpos.iter().zip(&wco).map(|(m, o)| m - o).collect());
+            }
+        } else if self.machine_position.is_none() {
+            if let Some(wpos) = &self.work_position {
+                self.machine_position = Some(wpos.iter().zip(&wco).map(|(w, o)| w + o).collect());
+            }
+        }
+        self.work_coordinate_offset = Some(wco);
+        self.update_position();
+    }
+
+    // keep the 2D position up to date for the gui
+    fn update_position(&mut self) {
+        let reported_position = self.machine_position.as_ref().or(self.work_position.as_ref());
+        if let Some(axes) = reported_position {
+            let pos_x = axes.first().copied().unwrap_or(0.0);
+            let pos_y = axes.get(1).copied().unwrap_or(0.0);
+            self.position = Pos2D::new(pos_x, pos_y);
+        }
+    }
+}
+
+impl Default for MachineStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// error returned when a line from the controller isn't a valid status report
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatusParseError {
+    // the line doesn't match the status report grammar at all
+    InvalidSyntax(String),
+    // the line matched, but one of the values couldn't be converted
+    InvalidValue(String),
+}
+
+impl fmt::Display for StatusParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusParseError::InvalidSyntax(msg) => write!(f, "invalid status report: {}", msg),
+            StatusParseError::InvalidValue(value) => write!(f, "invalid value in status report: {}", value),
+        }
+    }
+}
+
+impl std::error::Error for StatusParseError {}
+
+fn parse_number<T: std::str::FromStr>(text: &str) -> Result<T, StatusParseError> {
+    text.parse().map_err(|_| StatusParseError::InvalidValue(text.to_string()))
+}
+
+// collect all the numbers inside of a rule into a vector
+fn parse_numbers(pair: Pair<Rule>) -> Result<Vec<f32>, StatusParseError> {
+    pair.into_inner().map(|n| parse_number(n.as_str())).collect()
+}
+
+// get the first inner pair of a rule, the grammar guarantees it exists for the rules we use it on
+fn first_inner(pair: Pair<Rule>) -> Result<Pair<Rule>, StatusParseError> {
+    let text = pair.as_str().to_string();
+    pair.into_inner().next().ok_or(StatusParseError::InvalidValue(text))
+}
+
+fn parse_accessories(letters: &str) -> Accessories {
+    let mut accessories = Accessories::default();
+    for letter in letters.chars() {
+        match letter {
+            'S' => { accessories.spindle_cw = true },
+            'C' => { accessories.spindle_ccw = true },
+            'F' => { accessories.flood_coolant = true },
+            'M' => { accessories.mist_coolant = true },
+            'T' => { accessories.tool_change_pending = true },
+            _ => {},
+        }
     }
+    accessories
 }
 
-pub fn parse_status(status_string: String) -> MachineStatus {
-    let parsed_msg = StatusParser::parse(Rule::status, &status_string).expect("Failed to parse status").next().unwrap();
+pub fn parse_status(status_string: String) -> Result<MachineStatus, StatusParseError> {
+    let parsed_msg = StatusParser::parse(Rule::status, &status_string)
+        .map_err(|e| StatusParseError::InvalidSyntax(e.to_string()))?
+        .next()
+        .ok_or(StatusParseError::InvalidSyntax(status_string.clone()))?;
 
-    let mut machine_status = MachineStatus { position: Pos2D::new(0.0, 0.0), machine_state: MachineState::Idle };
+    let mut machine_status = MachineStatus::new();
 
     for part in parsed_msg.into_inner() {
         match part.as_rule() {
@@ -53,17 +207,143 @@ pub fn parse_status(status_string: String) -> MachineStatus {
                 }
             },
 
+            Rule::sub_state => {
+                let code = first_inner(part)?;
+                machine_status.sub_state = Some(parse_number(code.as_str())?);
+            },
+
             Rule::mpos => {
-                // get the x and y positions by getting the inner rules
-                let mut inner_rules = part.into_inner();
-                let pos_x: f32 = inner_rules.next().unwrap().as_str().parse().unwrap();
-                let pos_y: f32 = inner_rules.next().unwrap().as_str().parse().unwrap();
-                machine_status.position = Pos2D::new(pos_x, pos_y); 
+                machine_status.machine_position = Some(parse_numbers(first_inner(part)?)?);
+            },
+
+            Rule::wpos => {
+                machine_status.work_position = Some(parse_numbers(first_inner(part)?)?);
+            },
+
+            Rule::wco => {
+                machine_status.work_coordinate_offset = Some(parse_numbers(first_inner(part)?)?);
+            },
+
+            Rule::buffer => {
+                let numbers = parse_numbers(part)?;
+                machine_status.buffer = Some(BufferState { planner_blocks: numbers[0] as u32, rx_bytes: numbers[1] as u32 });
+            },
+
+            Rule::line_number => {
+                let numbers = parse_numbers(part)?;
+                machine_status.line_number = Some(numbers[0] as u32);
+            },
+
+            Rule::feed => {
+                let numbers = parse_numbers(part)?;
+                machine_status.feed_rate = Some(numbers[0]);
+            },
+
+            Rule::feed_speed => {
+                let numbers = parse_numbers(part)?;
+                machine_status.feed_rate = Some(numbers[0]);
+                machine_status.spindle_speed = Some(numbers[1]);
+            },
 
+            Rule::overrides => {
+                let numbers = parse_numbers(part)?;
+                machine_status.overrides = Some(Overrides { feed: numbers[0] as u32, rapid: numbers[1] as u32, spindle: numbers[2] as u32 });
+            },
+
+            Rule::pins => {
+                let letters = first_inner(part)?;
+                machine_status.pins = Some(letters.as_str().to_string());
+            },
+
+            Rule::accessories => {
+                let letters = first_inner(part)?;
+                machine_status.accessories = Some(parse_accessories(letters.as_str()));
             },
             _ => {}
         }
     }
-    
-    return machine_status;
-}
\ No newline at end of file
+
+    if let Some(wco) = machine_status.work_coordinate_offset.clone() {
+        machine_status.apply_work_coordinate_offset(wco);
+    } else {
+        machine_status.update_position();
+    }
+
+    Ok(machine_status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const FULL_REPORT: &str = "<Hold:0|MPos:10.000,20.000,-1.500,0.000|Bf:15,128|Ln:42|FS:1200,0|Ov:100,50,100|WCO:5.000,5.000,0.000,0.000|Pn:XP|A:SF|WCS:G54>";
+
+    #[test]
+    fn parses_full_report() {
+        let status = parse_status(FULL_REPORT.to_string()).unwrap();
+        assert!(matches!(status.machine_state, MachineState::Hold));
+        assert_eq!(status.sub_state, Some(0));
+        assert_eq!(status.machine_position, Some(vec![10.0, 20.0, -1.5, 0.0]));
+        assert_eq!(status.work_position, Some(vec![5.0, 15.0, -1.5, 0.0]));
+        assert_eq!(status.line_number, Some(42));
+        assert_eq!(status.feed_rate, Some(1200.0));
+        assert_eq!(status.overrides.as_ref().unwrap().rapid, 50);
+        assert!(status.pin_triggered('P'));
+        assert!(status.accessories.as_ref().unwrap().flood_coolant);
+        assert_eq!((status.position.x, status.position.y), (10.0, 20.0));
+    }
+
+    #[test]
+    fn derives_machine_position_from_work_position() {
+        let status = parse_status("<Idle|WPos:1.000,2.000,3.000|WCO:1.000,1.000,1.000>".to_string()).unwrap();
+        assert_eq!(status.machine_position, Some(vec![2.0, 3.0, 4.0]));
+        // the gui position follows the machine position
+        assert_eq!((status.position.x, status.position.y), (2.0, 3.0));
+    }
+
+    #[test]
+    fn parses_grblhal_reports() {
+        let status = parse_status("<Run|WPos:1.500,-2.000,0.000,90.000|FS:800,12000|Pn:XYZ>".to_string()).unwrap();
+        assert!(matches!(status.machine_state, MachineState::Run));
+        assert_eq!(status.work_position, Some(vec![1.5, -2.0, 0.0, 90.0]));
+        assert_eq!(status.machine_position, None);
+        assert_eq!((status.feed_rate, status.spindle_speed), (Some(800.0), Some(12000.0)));
+        assert_eq!(status.pins.as_deref(), Some("XYZ"));
+        assert!(!status.pin_triggered('P'));
+        assert_eq!((status.position.x, status.position.y), (1.5, -2.0));
+    }
+
+    #[test]
+    fn rejects_garbled_lines() {
+        // a report cut short (e.g. by noise on the line) would give a wrong position
+        for line in ["", "ok", "<", "<Idel|MPos:0,0,0>", "\u{fffd}<Idle|MPos:0,0,0>", "<Idle|MPos:10.000,2", "<Idle|MPos:0,0,0>ok"] {
+            assert!(matches!(parse_status(line.to_string()), Err(StatusParseError::InvalidSyntax(_))), "{:?}", line);
+        }
+        assert!(matches!(parse_status("<Door:1.5|MPos:0,0,0>".to_string()), Err(StatusParseError::InvalidValue(_))));
+    }
+
+    proptest! {
+        #[test]
+        fn random_input_never_panics(line in "\\PC*") {
+            let _ = parse_status(line);
+        }
+
+        #[test]
+        fn noisy_reports_never_panic(line in "<[A-Za-z]{0,6}(:[0-9.]{0,3})?(\\|[A-Za-z]{1,4}:[-0-9.,]{0,20}){0,6}>?") {
+            let _ = parse_status(line);
+        }
+
+        #[test]
+        fn truncated_reports_are_rejected(len in 0..FULL_REPORT.len()) {
+            prop_assert!(parse_status(FULL_REPORT[..len].to_string()).is_err());
+        }
+
+        #[test]
+        fn reported_position_round_trips(x in -10000.0f32..10000.0, y in -10000.0f32..10000.0) {
+            let status = parse_status(format!("<Run|MPos:{:.3},{:.3},0.000|FS:500,0>", x, y)).unwrap();
+            prop_assert!((status.position.x - x).abs() < 0.001);
+            prop_assert!((status.position.y - y).abs() < 0.001);
+        }
+    }
+}
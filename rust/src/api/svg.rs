@@ -0,0 +1,469 @@
+use std::fs;
+
+use crate::api::gcode::Pos2D;
+use crate::api::profile::{Profile, ProfileSegment};
+
+// svg pixels are 1/96 inch
+const MM_PER_PX: f32 = 25.4 / 96.0;
+
+// the value of an attribute in a tag like `<path d="..."`
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let mut rest = tag;
+    while let Some(index) = rest.find(name) {
+        let before = rest[..index].chars().last();
+        let after = rest[index + name.len()..].trim_start();
+        rest = &rest[index + name.len()..];
+        if !before.is_some_and(char::is_whitespace) {
+            continue;
+        }
+        let Some(after) = after.strip_prefix('=') else { continue };
+        let after = after.trim_start();
+        let quote = after.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let value = &after[1..];
+        return value.find(quote).map(|end| value[..end].to_string());
+    }
+    None
+}
+
+// every opening tag with the given name, e.g. all the `<path ...>` tags
+fn tags<'a>(contents: &'a str, name: &str) -> Vec<&'a str> {
+    let opening = format!("<{}", name);
+    let mut found = Vec::new();
+    let mut rest = contents;
+    while let Some(index) = rest.find(&opening) {
+        rest = &rest[index + opening.len()..];
+        if !rest.starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/') {
+            continue;
+        }
+        let end = rest.find('>').unwrap_or(rest.len());
+        found.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    found
+}
+
+// a length like "210mm" or "800" (pixels) in mm
+fn length_in_mm(length: &str) -> Result<f32, String> {
+    let length = length.trim();
+    let split = length.find(|c: char| c.is_ascii_alphabetic() || c == '%').unwrap_or(length.len());
+    let (number, unit) = length.split_at(split);
+    let value: f32 = number.trim().parse().map_err(|_| format!("Can't read the length {}", length))?;
+    let scale = match unit {
+        "" | "px" => MM_PER_PX,
+        "mm" => 1.0,
+        "cm" => 10.0,
+        "in" => 25.4,
+        "pt" => 25.4 / 72.0,
+        "pc" => 25.4 / 6.0,
+        _ => return Err(format!("Can't read the length {}, use mm, cm, in or px", length)),
+    };
+    Ok(value * scale)
+}
+
+// mm per user unit, from the document size and its viewBox
+fn document_scale(svg_tag: &str) -> Result<f32, String> {
+    let view_box: Option<Vec<f32>> = attribute(svg_tag, "viewBox").map(|view_box| {
+        view_box.split(|c: char| c.is_whitespace() || c == ',').filter(|part| !part.is_empty()).filter_map(|part| part.parse().ok()).collect()
+    });
+    // a percentage only says how big it's shown, the viewBox units are taken as pixels then
+    let width = attribute(svg_tag, "width").filter(|width| !width.trim().ends_with('%'));
+    match (width, view_box) {
+        (Some(width), Some(view_box)) if view_box.len() == 4 && view_box[2] > 0.0 => Ok(length_in_mm(&width)? / view_box[2]),
+        _ => Ok(MM_PER_PX),
+    }
+}
+
+// reads the numbers and flags of path data, svg allows things like "10-5.5.5" for 10, -5.5, 0.5
+struct PathData<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PathData<'a> {
+    fn skip_separators(&mut self) {
+        while self.position < self.data.len() && (self.data[self.position].is_ascii_whitespace() || self.data[self.position] == b',') {
+            self.position += 1;
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_separators();
+        self.position >= self.data.len()
+    }
+
+    fn command(&mut self) -> Option<u8> {
+        self.skip_separators();
+        let byte = *self.data.get(self.position)?;
+        if byte.is_ascii_alphabetic() && byte != b'e' && byte != b'E' {
+            self.position += 1;
+            Some(byte)
+        } else {
+            None
+        }
+    }
+
+    fn starts_number(&mut self) -> bool {
+        self.skip_separators();
+        self.data.get(self.position).is_some_and(|byte| byte.is_ascii_digit() || matches!(byte, b'-' | b'+' | b'.'))
+    }
+
+    fn number(&mut self) -> Result<f32, String> {
+        self.skip_separators();
+        let start = self.position;
+        let mut seen_dot = false;
+        let mut seen_exponent = false;
+        while let Some(&byte) = self.data.get(self.position) {
+            let sign_allowed = self.position == start || matches!(self.data[self.position - 1], b'e' | b'E');
+            match byte {
+                b'0'..=b'9' => {},
+                b'-' | b'+' if sign_allowed => {},
+                b'.' if !seen_dot && !seen_exponent => seen_dot = true,
+                b'e' | b'E' if !seen_exponent && self.position > start => seen_exponent = true,
+                _ => break,
+            }
+            self.position += 1;
+        }
+        let text = std::str::from_utf8(&self.data[start..self.position]).unwrap_or_default();
+        text.parse().map_err(|_| format!("Can't read the path data at \"{}\"", String::from_utf8_lossy(&self.data[start..]).chars().take(20).collect::<String>()))
+    }
+
+    // arc flags are a single 0 or 1 and needn't be separated, e.g. "a5 5 0 105 5"
+    fn flag(&mut self) -> Result<bool, String> {
+        self.skip_separators();
+        match self.data.get(self.position) {
+            Some(b'0') => {
+                self.position += 1;
+                Ok(false)
+            },
+            Some(b'1') => {
+                self.position += 1;
+                Ok(true)
+            },
+            _ => Err("An arc flag has to be 0 or 1".to_string()),
+        }
+    }
+
+    fn point(&mut self) -> Result<(f32, f32), String> {
+        Ok((self.number()?, self.number()?))
+    }
+}
+
+// turns path data into segments in mm, y up so the drawing doesn't come out mirrored
+struct PathBuilder {
+    // mm per user unit
+    scale: f32,
+    // in user units
+    tolerance: f32,
+    segments: Vec<ProfileSegment>,
+    current: (f32, f32),
+    subpath_start: (f32, f32),
+}
+
+impl PathBuilder {
+    fn to_mm(&self, (x, y): (f32, f32)) -> Pos2D {
+        Pos2D::new(x * self.scale, -y * self.scale)
+    }
+
+    fn line_to(&mut self, to: (f32, f32)) {
+        if to != self.current {
+            self.segments.push(ProfileSegment::Line { start: self.to_mm(self.current), end: self.to_mm(to) });
+        }
+        self.current = to;
+    }
+
+    // uniform steps, the second differences of the control points bound how far the chords stray
+    fn bezier_to(&mut self, controls: &[(f32, f32)], to: (f32, f32)) {
+        let mut points = vec![self.current];
+        points.extend_from_slice(controls);
+        points.push(to);
+        let degree = (points.len() - 1) as f32;
+        let second_difference = points
+            .windows(3)
+            .map(|p| ((p[0].0 - 2.0 * p[1].0 + p[2].0).powi(2) + (p[0].1 - 2.0 * p[1].1 + p[2].1).powi(2)).sqrt())
+            .fold(0.0, f32::max);
+        let steps = ((degree * (degree - 1.0) * second_difference / (8.0 * self.tolerance)).sqrt().ceil() as usize).max(1);
+        for step in 1..=steps {
+            let t = step as f32 / steps as f32;
+            // de casteljau
+            let mut level = points.clone();
+            while level.len() > 1 {
+                level = level.windows(2).map(|p| (p[0].0 + (p[1].0 - p[0].0) * t, p[0].1 + (p[1].1 - p[0].1) * t)).collect();
+            }
+            self.line_to(if step == steps { to } else { level[0] });
+        }
+    }
+
+    // endpoint to center parameterization from the svg spec, circles become arcs, ellipses chords
+    fn arc_to(&mut self, radii: (f32, f32), rotation: f32, large_arc: bool, sweep: bool, to: (f32, f32)) {
+        let from = self.current;
+        let (mut rx, mut ry) = (radii.0.abs(), radii.1.abs());
+        if from == to {
+            return;
+        }
+        if rx == 0.0 || ry == 0.0 {
+            self.line_to(to);
+            return;
+        }
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        let (dx, dy) = ((from.0 - to.0) / 2.0, (from.1 - to.1) / 2.0);
+        let (x1, y1) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+        // radii too small to reach are scaled up
+        let lambda = (x1 / rx).powi(2) + (y1 / ry).powi(2);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+        let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let sign = if large_arc != sweep { 1.0 } else { -1.0 };
+        let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+        let (cx1, cy1) = (coefficient * rx * y1 / ry, -coefficient * ry * x1 / rx);
+        let center = (cos * cx1 - sin * cy1 + (from.0 + to.0) / 2.0, sin * cx1 + cos * cy1 + (from.1 + to.1) / 2.0);
+
+        if (rx - ry).abs() <= rx * 1e-4 {
+            // sweep is clockwise on screen, and stays clockwise with y flipped up
+            self.segments.push(ProfileSegment::Arc { start: self.to_mm(from), end: self.to_mm(to), center: self.to_mm(center), clockwise: sweep });
+            self.current = to;
+            return;
+        }
+
+        let angle = |x: f32, y: f32| y.atan2(x);
+        let start_angle = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+        let mut sweep_angle = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry) - start_angle;
+        if sweep && sweep_angle < 0.0 {
+            sweep_angle += 2.0 * std::f32::consts::PI;
+        } else if !sweep && sweep_angle > 0.0 {
+            sweep_angle -= 2.0 * std::f32::consts::PI;
+        }
+        let step = 2.0 * (1.0 - (self.tolerance / rx.max(ry)).min(1.0)).acos();
+        let steps = ((sweep_angle.abs() / step).ceil() as usize).max(1);
+        for index in 1..=steps {
+            let theta = start_angle + sweep_angle * index as f32 / steps as f32;
+            let (x, y) = (rx * theta.cos(), ry * theta.sin());
+            let point = (center.0 + cos * x - sin * y, center.1 + sin * x + cos * y);
+            self.line_to(if index == steps { to } else { point });
+        }
+    }
+
+    fn close(&mut self) {
+        self.line_to(self.subpath_start);
+    }
+}
+
+// the segments of one path's `d` attribute, tolerance in mm
+fn path_segments(d: &str, scale: f32, tolerance: f32) -> Result<Vec<ProfileSegment>, String> {
+    let mut data = PathData { data: d.as_bytes(), position: 0 };
+    let mut path = PathBuilder { scale, tolerance: tolerance / scale, segments: Vec::new(), current: (0.0, 0.0), subpath_start: (0.0, 0.0) };
+    // the control point smooth curves reflect, and whether the last curve was cubic
+    let mut last_control: Option<((f32, f32), bool)> = None;
+    let mut command = None;
+
+    while !data.at_end() {
+        // a command letter, or more arguments for the last one
+        command = match data.command() {
+            Some(letter) => Some(letter),
+            None if data.starts_number() => match command {
+                Some(b'M') => Some(b'L'),
+                Some(b'm') => Some(b'l'),
+                Some(b'Z' | b'z') | None => return Err("The path data has numbers without a command".to_string()),
+                repeated => repeated,
+            },
+            None => return Err(format!("Unknown path command {}", data.data[data.position] as char)),
+        };
+        let letter = command.unwrap_or_default();
+        let relative = letter.is_ascii_lowercase();
+        let (ox, oy) = if relative { path.current } else { (0.0, 0.0) };
+        let absolute = |(x, y): (f32, f32)| (x + ox, y + oy);
+        let reflected = |cubic: bool| match last_control {
+            Some((control, was_cubic)) if was_cubic == cubic => (2.0 * path.current.0 - control.0, 2.0 * path.current.1 - control.1),
+            _ => path.current,
+        };
+
+        let mut control = None;
+        match letter.to_ascii_uppercase() {
+            b'M' => {
+                path.current = absolute(data.point()?);
+                path.subpath_start = path.current;
+            },
+            b'L' => path.line_to(absolute(data.point()?)),
+            b'H' => path.line_to((data.number()? + ox, path.current.1)),
+            b'V' => path.line_to((path.current.0, data.number()? + oy)),
+            b'C' => {
+                let (first, second, to) = (absolute(data.point()?), absolute(data.point()?), absolute(data.point()?));
+                path.bezier_to(&[first, second], to);
+                control = Some((second, true));
+            },
+            b'S' => {
+                let first = reflected(true);
+                let (second, to) = (absolute(data.point()?), absolute(data.point()?));
+                path.bezier_to(&[first, second], to);
+                control = Some((second, true));
+            },
+            b'Q' => {
+                let (first, to) = (absolute(data.point()?), absolute(data.point()?));
+                path.bezier_to(&[first], to);
+                control = Some((first, false));
+            },
+            b'T' => {
+                let first = reflected(false);
+                path.bezier_to(&[first], absolute(data.point()?));
+                control = Some((first, false));
+            },
+            b'A' => {
+                let radii = data.point()?;
+                let rotation = data.number()?;
+                let (large_arc, sweep) = (data.flag()?, data.flag()?);
+                path.arc_to(radii, rotation, large_arc, sweep, absolute(data.point()?));
+            },
+            b'Z' => path.close(),
+            _ => return Err(format!("Unknown path command {}", letter as char)),
+        }
+        last_control = control;
+    }
+    Ok(path.segments)
+}
+
+impl Profile {
+    // read the <path> elements of an svg drawing in mm, curves are followed within tolerance (mm)
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn from_svg(contents: String, tolerance: f32) -> Result<Profile, String> {
+        if tolerance <= 0.0 {
+            return Err("The tolerance has to be more than 0".to_string());
+        }
+        let svg_tag = tags(&contents, "svg").into_iter().next().ok_or("The file isn't an svg drawing".to_string())?;
+        let scale = document_scale(svg_tag)?;
+
+        // placing shapes with transforms would need them applied, better to refuse than cut the wrong shape
+        if ["g", "path"].iter().flat_map(|name| tags(&contents, name)).any(|tag| attribute(tag, "transform").is_some()) {
+            return Err("The drawing uses transforms, apply them to the paths before importing".to_string());
+        }
+
+        let mut segments = Vec::new();
+        for tag in tags(&contents, "path") {
+            if let Some(d) = attribute(tag, "d") {
+                segments.extend(path_segments(&d, scale, tolerance)?);
+            }
+        }
+        if segments.is_empty() {
+            return Err("The drawing has no paths".to_string());
+        }
+        Ok(Profile::from_segments(segments))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_svg(path: String, tolerance: f32) -> Result<Profile, String> {
+        let contents = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        Profile::from_svg(contents, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::gcode::Gcode;
+    use crate::api::profile::CutParameters;
+    use crate::api::toolpath::SegmentKind;
+
+    fn svg(paths: &[&str]) -> String {
+        let mut contents = "<?xml version=\"1.0\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100mm\" height=\"50mm\" viewBox=\"0 0 200 100\">\n".to_string();
+        for d in paths {
+            contents += &format!("  <path fill=\"none\" d=\"{}\"/>\n", d);
+        }
+        contents + "</svg>\n"
+    }
+
+    fn close(a: &Pos2D, b: &Pos2D) -> bool {
+        (a.x - b.x).abs() < 0.001 && (a.y - b.y).abs() < 0.001
+    }
+
+    #[test]
+    fn reads_lines_and_circular_arcs_in_mm() {
+        let contents = svg(&["M10,10 H110 V90 H10 Z", "M60 50 m-20 0 a20 20 0 1 0 40 0 a20 20 0 1 0 -40 0z"]);
+        let profile = Profile::from_svg(contents, 0.1).unwrap();
+        assert_eq!(profile.contours.len(), 2);
+        assert!(profile.contours.iter().all(|contour| contour.closed));
+
+        // the circle is inside the square so it's cut first, half a mm per user unit and y flipped up
+        let circle = &profile.contours[0];
+        assert_eq!(circle.segments.len(), 2);
+        match &circle.segments[0] {
+            ProfileSegment::Arc { start, center, .. } => {
+                assert!(close(start, &Pos2D::new(20.0, -25.0)));
+                assert!(close(center, &Pos2D::new(30.0, -25.0)));
+            },
+            segment => panic!("expected an arc, got {:?}", segment),
+        }
+        assert_eq!(profile.contours[1].segments.len(), 4);
+        let (min, max) = profile.bounds().unwrap();
+        assert!(close(&min, &Pos2D::new(5.0, -45.0)) && close(&max, &Pos2D::new(55.0, -5.0)));
+    }
+
+    #[test]
+    fn flattens_curves_within_tolerance() {
+        let d = "M 150 10 C 150 40 190 40 190 10";
+        let coarse = path_segments(d, 0.5, 0.5).unwrap();
+        let fine = path_segments(d, 0.5, 0.01).unwrap();
+        assert!(fine.len() > coarse.len());
+        assert!(close(fine[0].start(), &Pos2D::new(75.0, -5.0)));
+        assert!(close(fine[fine.len() - 1].end(), &Pos2D::new(95.0, -5.0)));
+        // the middle of the curve is 32.5 units down, 16.25mm
+        let lowest = fine.iter().map(|segment| segment.end().y).fold(f32::MAX, f32::min);
+        assert!((lowest + 16.25).abs() < 0.01);
+
+        // smooth and quadratic curves, an ellipse is flattened into lines
+        let segments = path_segments("M0 0 Q10 10 20 0 T40 0 S60 -10 80 0 A20 10 0 0 1 120 0", 1.0, 0.05).unwrap();
+        assert!(segments.iter().all(|segment| matches!(segment, ProfileSegment::Line { .. })));
+        assert!(close(segments[segments.len() - 1].end(), &Pos2D::new(120.0, 0.0)));
+        let highest = segments.iter().map(|segment| segment.end().y).fold(f32::MIN, f32::max);
+        assert!((highest - 10.0).abs() < 0.06);
+    }
+
+    #[test]
+    fn reads_compact_path_data() {
+        let segments = path_segments("M0,0L10-5.5.5.5zm20 0a5 5 0 105 5", 1.0, 0.1).unwrap();
+        assert_eq!(segments.len(), 4);
+        assert!(close(segments[0].end(), &Pos2D::new(10.0, 5.5)));
+        assert!(close(segments[1].end(), &Pos2D::new(0.5, -0.5)));
+        // a relative move after z starts from where the subpath started
+        assert!(close(segments[3].start(), &Pos2D::new(20.0, 0.0)));
+        assert!(matches!(segments[3], ProfileSegment::Arc { clockwise: false, .. }));
+
+        assert!(path_segments("M0 0 L10", 1.0, 0.1).is_err());
+        assert!(path_segments("10 10", 1.0, 0.1).is_err());
+    }
+
+    #[test]
+    fn places_on_flat_stock_and_cuts() {
+        let profile = Profile::from_svg(svg(&["M10,10 H110 V90 H10 Z"]), 0.1).unwrap();
+        let placed = profile.place_on_flat_stock(0.0, 0.0);
+        let (min, max) = placed.bounds().unwrap();
+        assert!(close(&min, &Pos2D::new(0.0, 0.0)) && close(&max, &Pos2D::new(50.0, 40.0)));
+
+        let mut gcode = Gcode::new();
+        gcode.add_profile(&placed, &CutParameters { feedrate: 1200.0, pierce_delay: 0.2 });
+        let toolpath = gcode.get_toolpath();
+        let cut: f32 = toolpath.iter().filter(|segment| segment.kind == SegmentKind::Cut && segment.torch_on).map(|segment| segment.length()).sum();
+        assert!((cut - 180.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn explains_what_it_cant_read() {
+        assert_eq!(Profile::from_svg("<html></html>".to_string(), 0.1), Err("The file isn't an svg drawing".to_string()));
+        assert_eq!(Profile::from_svg(svg(&[]), 0.1), Err("The drawing has no paths".to_string()));
+        let transformed = svg(&["M0 0 H10"]).replace("<path", "<path transform=\"scale(2)\"");
+        assert!(Profile::from_svg(transformed, 0.1).unwrap_err().contains("transforms"));
+        // pixels without a viewBox
+        let pixels = "<svg width=\"96\" height=\"96\"><path d=\"M0 0 H96\"/></svg>".to_string();
+        let (min, max) = Profile::from_svg(pixels, 0.1).unwrap().bounds().unwrap();
+        assert!((max.x - min.x - 25.4).abs() < 0.001);
+        // a relative width with a viewBox, or a viewBox alone, is in pixels too
+        for size in ["width=\"100%\" height=\"100%\"", ""] {
+            let relative = format!("<svg {} viewBox=\"0 0 192 96\"><path d=\"M0 0 H96\"/></svg>", size);
+            let (min, max) = Profile::from_svg(relative, 0.1).unwrap().bounds().unwrap();
+            assert!((max.x - min.x - 25.4).abs() < 0.001);
+        }
+    }
+}
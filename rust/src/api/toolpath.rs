@@ -0,0 +1,263 @@
+use std::f32::consts::PI;
+
+use super::gcode::{DistUnit, Gcode, Pos2D};
+use super::interpreter::{parse_words, value_to_mm, ModalState, MotionMode, Word};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SegmentKind {
+    // G0, the torch is normally off
+    Rapid,
+    // G1
+    Cut,
+    // G2/G3 around a center
+    Arc { center: Pos2D, clockwise: bool },
+    // G4, the machine stands still
+    Dwell { seconds: f32 },
+    TorchOn,
+    TorchOff,
+}
+
+// one step of the program, start and end are in mm
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToolpathSegment {
+    pub kind: SegmentKind,
+    pub start: Pos2D,
+    pub end: Pos2D,
+    // the line (0 based) of the program it came from
+    pub line: u32,
+    // mm/min
+    pub feedrate: Option<f32>,
+    pub torch_on: bool,
+}
+
+impl ToolpathSegment {
+    pub fn length(&self) -> f32 {
+        match &self.kind {
+            SegmentKind::Rapid | SegmentKind::Cut => distance(&self.start, &self.end),
+            SegmentKind::Arc { center, clockwise } => distance(&self.start, center) * arc_sweep(&self.start, &self.end, center, *clockwise).abs(),
+            _ => 0.0,
+        }
+    }
+
+    // the points along the segment, arcs are split into chords that stay within tolerance (mm) of the arc
+    pub fn flatten(&self, tolerance: f32) -> Vec<Pos2D> {
+        match &self.kind {
+            SegmentKind::Arc { center, clockwise } => flatten_arc(&self.start, &self.end, center, *clockwise, tolerance),
+            _ => vec![self.start.clone(), self.end.clone()],
+        }
+    }
+}
+
+pub(crate) fn distance(a: &Pos2D, b: &Pos2D) -> f32 {
+    ((b.x - a.x).powi(2) + (b.y - a.y).powi(2)).sqrt()
+}
+
+// the angle an arc turns through, negative for clockwise, a full turn if it ends where it starts
+fn arc_sweep(start: &Pos2D, end: &Pos2D, center: &Pos2D, clockwise: bool) -> f32 {
+    let start_angle = (start.y - center.y).atan2(start.x - center.x);
+    let end_angle = (end.y - center.y).atan2(end.x - center.x);
+    let mut sweep = end_angle - start_angle;
+    if clockwise {
+        if sweep >= 0.0 {
+            sweep -= 2.0 * PI;
+        }
+    } else if sweep <= 0.0 {
+        sweep += 2.0 * PI;
+    }
+    sweep
+}
+
+// points along an arc, joined by chords that stay within tolerance (mm) of it
+pub(crate) fn flatten_arc(start: &Pos2D, end: &Pos2D, center: &Pos2D, clockwise: bool, tolerance: f32) -> Vec<Pos2D> {
+    let radius = distance(start, center);
+    let sweep = arc_sweep(start, end, center, clockwise);
+    // the sagitta of a chord spanning angle a is r * (1 - cos(a / 2))
+    let max_step = if radius > tolerance { 2.0 * (1.0 - tolerance / radius).acos() } else { PI / 2.0 };
+    let steps = ((sweep.abs() / max_step.max(0.001)).ceil() as usize).max(1);
+    let start_angle = (start.y - center.y).atan2(start.x - center.x);
+
+    let mut points = vec![start.clone()];
+    for step in 1..steps {
+        let angle = start_angle + sweep * step as f32 / steps as f32;
+        points.push(Pos2D::new(center.x + radius * angle.cos(), center.y + radius * angle.sin()));
+    }
+    points.push(end.clone());
+    points
+}
+
+fn word_value(words: &[Word], letter: char) -> Option<f32> {
+    words.iter().find(|word| word.letter == letter).map(|word| word.value)
+}
+
+// the center of an arc from start to end (mm), from I/J or R in the program's units
+fn arc_center(start: &Pos2D, end: &Pos2D, words: &[Word], clockwise: bool, units: DistUnit) -> Option<Pos2D> {
+    let word_mm = |letter: char| word_value(words, letter).map(|value| value_to_mm(value, units));
+    if let Some(radius) = word_mm('R') {
+        // the same construction grbl uses, a negative radius picks the longer of the two arcs
+        let x = end.x - start.x;
+        let y = end.y - start.y;
+        let chord = (x * x + y * y).sqrt();
+        if chord == 0.0 {
+            return None;
+        }
+        let mut h = -(4.0 * radius * radius - x * x - y * y).max(0.0).sqrt() / chord;
+        if !clockwise {
+            h = -h;
+        }
+        if radius < 0.0 {
+            h = -h;
+        }
+        return Some(Pos2D::new(start.x + 0.5 * (x - y * h), start.y + 0.5 * (y + x * h)));
+    }
+    let i = word_mm('I');
+    let j = word_mm('J');
+    if i.is_none() && j.is_none() {
+        return None;
+    }
+    Some(Pos2D::new(start.x + i.unwrap_or(0.0), start.y + j.unwrap_or(0.0)))
+}
+
+// walk a program and turn it into segments, lines that don't do anything (or that we don't understand) are skipped
+pub fn simulate_toolpath(program: String) -> Vec<ToolpathSegment> {
+    simulate_toolpath_at(&program, &Pos2D::new(0.0, 0.0))
+}
+
+// like simulate_toolpath, with work zero at work_offset (machine coordinates) so G53 moves end up in the right place
+pub(crate) fn simulate_toolpath_at(program: &str, work_offset: &Pos2D) -> Vec<ToolpathSegment> {
+    let mut state = ModalState::new();
+    state.work_offset = work_offset.clone();
+    let mut segments = Vec::new();
+
+    for (line_index, line) in program.lines().enumerate() {
+        let words = parse_words(line);
+        if words.is_empty() {
+            continue;
+        }
+        let line = line_index as u32;
+        let start = state.position.clone();
+        let was_torch_on = state.torch_on;
+        state.apply_words(&words);
+
+        let segment = |kind: SegmentKind, end: Pos2D, state: &ModalState| ToolpathSegment {
+            kind,
+            start: start.clone(),
+            end,
+            line,
+            feedrate: state.feedrate.map(|feedrate| value_to_mm(feedrate, state.units)),
+            torch_on: state.torch_on,
+        };
+
+        // grbl switches the spindle and dwells before it moves
+        if state.torch_on != was_torch_on {
+            let kind = if state.torch_on { SegmentKind::TorchOn } else { SegmentKind::TorchOff };
+            segments.push(segment(kind, start.clone(), &state));
+        }
+        if words.iter().any(|word| word.letter == 'G' && word.value == 4.0) {
+            let seconds = word_value(&words, 'P').unwrap_or(0.0);
+            segments.push(segment(SegmentKind::Dwell { seconds }, start.clone(), &state));
+            continue;
+        }
+
+        let end = state.position.clone();
+        let moved = end.x != start.x || end.y != start.y;
+        match state.motion_mode {
+            MotionMode::Rapid if moved => segments.push(segment(SegmentKind::Rapid, end, &state)),
+            MotionMode::Linear if moved => segments.push(segment(SegmentKind::Cut, end, &state)),
+            MotionMode::ArcClockwise | MotionMode::ArcCounterClockwise => {
+                let clockwise = state.motion_mode == MotionMode::ArcClockwise;
+                // I/J without a move is a full circle
+                if let Some(center) = arc_center(&start, &end, &words, clockwise, state.units) {
+                    let kind = SegmentKind::Arc { center, clockwise };
+                    segments.push(segment(kind, end, &state));
+                }
+            },
+            _ => {}
+        }
+    }
+    segments
+}
+
+impl Gcode {
+    // the segments of the generated program, e.g. to draw a preview
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_toolpath(&self) -> Vec<ToolpathSegment> {
+        simulate_toolpath(self.get_gcode_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cut::Cut;
+    use crate::api::settings::CutterSettings;
+
+    fn kinds(segments: &[ToolpathSegment]) -> Vec<SegmentKind> {
+        segments.iter().map(|segment| segment.kind.clone()).collect()
+    }
+
+    #[test]
+    fn emits_typed_segments() {
+        let segments = simulate_toolpath("G21 G90\nG0 X10 Y0\nM3\nG4 P0.5\nG1 X20 F1000 (cut)\nM5\nG0 X0\n".to_string());
+        assert_eq!(
+            kinds(&segments),
+            vec![SegmentKind::Rapid, SegmentKind::TorchOn, SegmentKind::Dwell { seconds: 0.5 }, SegmentKind::Cut, SegmentKind::TorchOff, SegmentKind::Rapid]
+        );
+
+        let cut = &segments[3];
+        assert_eq!((cut.start.x, cut.end.x, cut.line), (10.0, 20.0, 4));
+        assert_eq!(cut.feedrate, Some(1000.0));
+        assert!(cut.torch_on);
+        assert!(!segments[5].torch_on);
+        assert_eq!(cut.length(), 10.0);
+    }
+
+    #[test]
+    fn follows_relative_moves_and_inches() {
+        let segments = simulate_toolpath("G20 G91\nG1 X1 Y1 F10\nG1 X1\n".to_string());
+        assert_eq!(segments.len(), 2);
+        assert_eq!((segments[1].start.x, segments[1].end.x, segments[1].end.y), (25.4, 50.8, 25.4));
+        assert_eq!(segments[1].feedrate, Some(254.0));
+    }
+
+    #[test]
+    fn switching_units_in_place_isnt_a_move() {
+        let segments = simulate_toolpath("G21 G0 X25.4\nG20 G0 X1\nG3 X0 Y1 I-0.5 J0.5 F10\n".to_string());
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].kind, SegmentKind::Arc { center: Pos2D::new(12.7, 12.7), clockwise: false });
+        assert_eq!((segments[1].end.x, segments[1].end.y), (0.0, 25.4));
+    }
+
+    #[test]
+    fn builds_arcs_from_offsets_and_radius() {
+        let segments = simulate_toolpath("G0 X10 Y0\nG3 X0 Y10 I-10 J0 F500\nG2 X10 Y0 R10\nG2 I-10 J0\n".to_string());
+        assert_eq!(segments.len(), 4);
+        assert_eq!(segments[1].kind, SegmentKind::Arc { center: Pos2D::new(0.0, 0.0), clockwise: false });
+        assert!((segments[1].length() - 5.0 * PI).abs() < 0.001);
+
+        let SegmentKind::Arc { center, clockwise } = &segments[2].kind else { panic!("not an arc") };
+        assert!(*clockwise);
+        assert!(center.x.abs() < 0.001 && center.y.abs() < 0.001);
+
+        // a full circle
+        assert!((segments[3].length() - 20.0 * PI).abs() < 0.01);
+        let points = segments[3].flatten(0.01);
+        assert!(points.len() > 20);
+        assert!(points.iter().all(|point| (distance(point, &Pos2D::new(0.0, 0.0)) - 10.0).abs() < 0.01));
+    }
+
+    #[test]
+    fn previews_a_generated_cut() {
+        let mut gcode = Gcode::new();
+        let mut cut = Cut::new();
+        cut.start_position = Pos2D::new(5.0, 0.0);
+        gcode.add_cut(cut, &CutterSettings { clear_existing_gcode: true, ..Default::default() });
+
+        let segments = gcode.get_toolpath();
+        let torch_ons = segments.iter().filter(|segment| segment.kind == SegmentKind::TorchOn).count();
+        assert_eq!(torch_ons, 2);
+        // everything cut with the torch on stays on the line across the tube
+        for segment in segments.iter().filter(|segment| segment.kind == SegmentKind::Cut && segment.torch_on) {
+            assert!(segment.start.y.abs() < 0.001 && segment.end.y.abs() < 0.001);
+        }
+    }
+}
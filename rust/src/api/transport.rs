@@ -0,0 +1,213 @@
+use std::collections::VecDeque;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serial2::SerialPort;
+use tungstenite::{Message, WebSocket};
+
+use crate::api::settings::{ConnectionSettings, ControlLineOnOpen, SerialFlowControl, TransportKind};
+use crate::api::simulator::{GrblSimulator, SimulatorConfig, SimulatorTransport};
+
+// how long to wait for a networked board to accept the connection
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+// a byte stream to the controller, the sender doesn't care if it's a serial port or a socket
+pub(crate) trait Transport: Send {
+    // read whatever arrived, returns an error of kind TimedOut if nothing came in within the read timeout
+    // and Ok(0) if the other side went away
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize>;
+
+    fn write_all(&mut self, data: &[u8]) -> std::io::Result<()>;
+}
+
+// opens the port for the connection thread, called again on every reconnect
+pub(crate) type OpenTransport = Box<dyn FnMut(&ConnectionSettings) -> std::io::Result<Box<dyn Transport>> + Send>;
+
+// open the transport selected in the connection settings
+pub(crate) fn open_transport(settings: &ConnectionSettings) -> std::io::Result<Box<dyn Transport>> {
+    match settings.transport {
+        TransportKind::Serial => Ok(Box::new(SerialTransport::open(settings)?)),
+        TransportKind::Tcp => Ok(Box::new(TcpTransport::open(settings)?)),
+        TransportKind::WebSocket => Ok(Box::new(WebSocketTransport::open(settings)?)),
+        // a fresh controller every time, like a board that was power cycled
+        TransportKind::Simulator => {
+            let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+            Ok(Box::new(SimulatorTransport::new(simulator, Duration::from_millis(settings.read_timeout_ms.max(1).into()))))
+        },
+    }
+}
+
+// a usb/serial connection to the controller
+pub(crate) struct SerialTransport {
+    port: SerialPort,
+}
+
+impl SerialTransport {
+    pub(crate) fn open(settings: &ConnectionSettings) -> std::io::Result<Self> {
+        let baudrate = settings.baudrate;
+        let flow_control = match settings.flow_control {
+            SerialFlowControl::None => serial2::FlowControl::None,
+            SerialFlowControl::Software => serial2::FlowControl::XonXoff,
+            SerialFlowControl::Hardware => serial2::FlowControl::RtsCts,
+        };
+
+        let mut port = SerialPort::open(&settings.serial_port, move |mut port_settings: serial2::Settings| {
+            port_settings.set_raw();
+            port_settings.set_baud_rate(baudrate)?;
+            port_settings.set_flow_control(flow_control);
+            Ok(port_settings)
+        })?;
+        port.set_read_timeout(Duration::from_millis(settings.read_timeout_ms.into()))?;
+
+        set_control_line(settings.dtr_on_open, |state| port.set_dtr(state))?;
+        set_control_line(settings.rts_on_open, |state| port.set_rts(state))?;
+
+        Ok(Self { port })
+    }
+}
+
+fn set_control_line(behavior: ControlLineOnOpen, set_line: impl Fn(bool) -> std::io::Result<()>) -> std::io::Result<()> {
+    match behavior {
+        ControlLineOnOpen::Unchanged => Ok(()),
+        ControlLineOnOpen::High => set_line(true),
+        ControlLineOnOpen::Low => set_line(false),
+        ControlLineOnOpen::Pulse => {
+            set_line(false)?;
+            std::thread::sleep(Duration::from_millis(100));
+            set_line(true)
+        }
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+impl Transport for SerialTransport {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.port.read(buf)
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> std::io::Result<()> {
+        self.port.write_all(data)
+    }
+}
+
+fn connect_tcp(settings: &ConnectionSettings) -> std::io::Result<TcpStream> {
+    let address = (settings.host.as_str(), settings.network_port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or(std::io::Error::new(ErrorKind::NotFound, format!("Could not resolve {}", settings.host)))?;
+    let stream = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT)?;
+    // the commands are tiny, send them right away
+    stream.set_nodelay(true)?;
+    Ok(stream)
+}
+
+fn read_timeout(settings: &ConnectionSettings) -> Option<Duration> {
+    // a zero timeout would make the socket block forever
+    Some(Duration::from_millis(settings.read_timeout_ms.max(1).into()))
+}
+
+// a raw tcp (telnet) connection, grblHAL listens on port 23 by default
+pub(crate) struct TcpTransport {
+    stream: TcpStream,
+}
+
+impl TcpTransport {
+    pub(crate) fn open(settings: &ConnectionSettings) -> std::io::Result<Self> {
+        let stream = connect_tcp(settings)?;
+        stream.set_read_timeout(read_timeout(settings))?;
+        Ok(Self { stream })
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+impl Transport for TcpTransport {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.stream.read(buf) {
+            // sockets report a timeout as WouldBlock on some platforms
+            Err(e) if e.kind() == ErrorKind::WouldBlock => Err(ErrorKind::TimedOut.into()),
+            result => result,
+        }
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> std::io::Result<()> {
+        self.stream.write_all(data)
+    }
+}
+
+// a websocket connection, grblHAL boards with the websocket daemon listen on port 81 by default
+pub(crate) struct WebSocketTransport {
+    socket: WebSocket<TcpStream>,
+    // bytes of a received message that didn't fit into the caller's buffer yet
+    pending: VecDeque<u8>,
+}
+
+impl WebSocketTransport {
+    pub(crate) fn open(settings: &ConnectionSettings) -> std::io::Result<Self> {
+        let stream = connect_tcp(settings)?;
+        stream.set_read_timeout(Some(CONNECT_TIMEOUT))?;
+
+        let url = format!("ws://{}:{}/", settings.host, settings.network_port);
+        let (socket, _) = tungstenite::client(url.as_str(), stream)
+            .map_err(|e| std::io::Error::new(ErrorKind::ConnectionRefused, e.to_string()))?;
+
+        socket.get_ref().set_read_timeout(read_timeout(settings))?;
+        Ok(Self { socket, pending: VecDeque::new() })
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+impl Transport for WebSocketTransport {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.pending.is_empty() {
+            match self.socket.read() {
+                Ok(Message::Text(text)) => self.pending.extend(text.as_bytes()),
+                Ok(Message::Binary(data)) => self.pending.extend(data.iter()),
+                Ok(Message::Close(_)) => return Ok(0),
+                // pings are answered by tungstenite itself
+                Ok(_) => {},
+                Err(tungstenite::Error::Io(e)) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => {
+                    return Err(ErrorKind::TimedOut.into());
+                },
+                Err(tungstenite::Error::ConnectionClosed) | Err(tungstenite::Error::AlreadyClosed) => return Ok(0),
+                Err(e) => return Err(std::io::Error::other(e.to_string())),
+            }
+        }
+
+        let n = buf.len().min(self.pending.len());
+        for (slot, byte) in buf.iter_mut().zip(self.pending.drain(..n)) {
+            *slot = byte;
+        }
+        Ok(n)
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> std::io::Result<()> {
+        // binary frames so realtime bytes that aren't valid utf-8 go through untouched
+        self.socket
+            .send(Message::binary(data.to_vec()))
+            .map_err(|e| std::io::Error::other(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn pulses_and_sets_the_control_lines() {
+        let changes = Mutex::new(Vec::new());
+        let record = |state| {
+            changes.lock().unwrap().push(state);
+            Ok(())
+        };
+        set_control_line(ControlLineOnOpen::Unchanged, record).unwrap();
+        set_control_line(ControlLineOnOpen::High, record).unwrap();
+        set_control_line(ControlLineOnOpen::Pulse, record).unwrap();
+        assert_eq!(*changes.lock().unwrap(), vec![true, false, true]);
+
+        let settings = ConnectionSettings { serial_port: "/nonexistent/ttyUSB9".to_string(), ..ConnectionSettings::default() };
+        assert!(open_transport(&settings).is_err());
+    }
+}
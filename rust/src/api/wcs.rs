@@ -0,0 +1,287 @@
+use serde::{Deserialize, Serialize};
+
+// one of the six work coordinate systems grbl keeps in its eeprom
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum WorkCoordinateSystem {
+    G54,
+    G55,
+    G56,
+    G57,
+    G58,
+    G59,
+}
+
+impl WorkCoordinateSystem {
+    pub const ALL: [WorkCoordinateSystem; 6] = [
+        WorkCoordinateSystem::G54,
+        WorkCoordinateSystem::G55,
+        WorkCoordinateSystem::G56,
+        WorkCoordinateSystem::G57,
+        WorkCoordinateSystem::G58,
+        WorkCoordinateSystem::G59,
+    ];
+
+    // the command that makes it the active one, e.g. G55
+    pub fn gcode(&self) -> &'static str {
+        match self {
+            WorkCoordinateSystem::G54 => "G54",
+            WorkCoordinateSystem::G55 => "G55",
+            WorkCoordinateSystem::G56 => "G56",
+            WorkCoordinateSystem::G57 => "G57",
+            WorkCoordinateSystem::G58 => "G58",
+            WorkCoordinateSystem::G59 => "G59",
+        }
+    }
+
+    // the P word G10 uses for it, 1 for G54 up to 6 for G59
+    pub fn index(&self) -> u32 {
+        Self::ALL.iter().position(|wcs| wcs == self).unwrap() as u32 + 1
+    }
+
+    pub fn from_gcode(gcode: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|wcs| wcs.gcode() == gcode)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    fn letter(&self) -> char {
+        match self {
+            Axis::X => 'X',
+            Axis::Y => 'Y',
+            Axis::Z => 'Z',
+        }
+    }
+}
+
+// the offset of a work coordinate system from machine zero
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkOffset {
+    pub wcs: WorkCoordinateSystem,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+// what $# and $G report
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CoordinateOffsets {
+    pub work_offsets: Vec<WorkOffset>,
+    // the temporary G92 offset on top of the work offset
+    pub g92: Option<Vec<f32>>,
+    pub active: Option<WorkCoordinateSystem>,
+}
+
+impl CoordinateOffsets {
+    pub fn offset(&self, wcs: WorkCoordinateSystem) -> Option<WorkOffset> {
+        self.work_offsets.iter().find(|offset| offset.wcs == wcs).cloned()
+    }
+
+    // where the zero of a system (the active one if None) is now, as a fixture to save
+    pub fn capture_fixture(&self, name: String, wcs: Option<WorkCoordinateSystem>) -> Result<Fixture, String> {
+        let wcs = wcs.or(self.active).ok_or("The active work coordinate system wasn't read yet".to_string())?;
+        let offset = self.offset(wcs).ok_or(format!("The {} offset wasn't read yet", wcs.gcode()))?;
+        Ok(Fixture { name, wcs, x: offset.x, y: offset.y })
+    }
+
+    // update with a line of the controller's reply, returns false if the line isn't about offsets
+    pub(crate) fn apply_report_line(&mut self, line: &str) -> bool {
+        let Some(report) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) else {
+            return false;
+        };
+        let Some((name, values)) = report.split_once(':') else {
+            return false;
+        };
+
+        // the parser state, e.g. [GC:G0 G54 G17 G21 G90 G94 M5 M9 T0 F0 S0]
+        if name == "GC" {
+            if let Some(active) = values.split_whitespace().find_map(WorkCoordinateSystem::from_gcode) {
+                self.active = Some(active);
+            }
+            return true;
+        }
+
+        let Some(values) = values.split(',').map(|value| value.parse::<f32>().ok()).collect::<Option<Vec<f32>>>() else {
+            return false;
+        };
+        if name == "G92" {
+            self.g92 = Some(values);
+            return true;
+        }
+        let Some(wcs) = WorkCoordinateSystem::from_gcode(name) else {
+            return false;
+        };
+        let offset = WorkOffset {
+            wcs,
+            x: values.first().copied().unwrap_or(0.0),
+            y: values.get(1).copied().unwrap_or(0.0),
+            z: values.get(2).copied().unwrap_or(0.0),
+        };
+        match self.work_offsets.iter_mut().find(|existing| existing.wcs == wcs) {
+            Some(existing) => *existing = offset,
+            None => self.work_offsets.push(offset),
+        }
+        true
+    }
+}
+
+fn wcs_p_word(wcs: Option<WorkCoordinateSystem>) -> u32 {
+    // P0 is whichever system is active
+    wcs.map(|wcs| wcs.index()).unwrap_or(0)
+}
+
+// make the current position the given value on each axis, in wcs or the active one if None
+pub(crate) fn set_position_command(wcs: Option<WorkCoordinateSystem>, axes: &[(Axis, f32)]) -> String {
+    let mut command = format!("G10 L20 P{}", wcs_p_word(wcs));
+    for (axis, value) in axes {
+        command += &format!(" {}{}", axis.letter(), value);
+    }
+    command
+}
+
+// set the offset from machine zero directly on each axis
+pub(crate) fn set_offset_command(wcs: WorkCoordinateSystem, axes: &[(Axis, f32)]) -> String {
+    let mut command = format!("G10 L2 P{}", wcs.index());
+    for (axis, value) in axes {
+        command += &format!(" {}{}", axis.letter(), value);
+    }
+    command
+}
+
+// a saved work zero, e.g. the left stop of a jig, to recall instead of zeroing by hand again
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Fixture {
+    pub name: String,
+    pub wcs: WorkCoordinateSystem,
+    // the offset from machine zero
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Fixture {
+    // the commands that load the fixture into its work coordinate system and make it active
+    pub(crate) fn recall_commands(&self) -> Vec<String> {
+        vec![set_offset_command(self.wcs, &[(Axis::X, self.x), (Axis::Y, self.y)]), self.wcs.gcode().to_string()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::job::job_lines;
+    use crate::api::sender::MachineConnection;
+    use crate::api::simulator::{connect_to_simulator, GrblSimulator, SimulatorConfig};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    fn wait_for_offsets(connection: &MachineConnection, matches: impl Fn(&CoordinateOffsets) -> bool) -> CoordinateOffsets {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let offsets = connection.get_coordinate_offsets();
+            if matches(&offsets) || Instant::now() > deadline {
+                return offsets;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn parses_the_offset_report() {
+        let mut offsets = CoordinateOffsets::default();
+        for line in ["[G54:10.000,-2.500,0.000]", "[G59:1.000,2.000,3.000]", "[G92:0.000,0.000,0.000]", "[GC:G0 G55 G17 G21 G90 G94 M5 M9 T0 F0 S0]"] {
+            assert!(offsets.apply_report_line(line));
+        }
+        assert!(!offsets.apply_report_line("[MSG:Caution: Unlocked]"));
+        assert!(!offsets.apply_report_line("ok"));
+
+        assert_eq!(offsets.offset(WorkCoordinateSystem::G54), Some(WorkOffset { wcs: WorkCoordinateSystem::G54, x: 10.0, y: -2.5, z: 0.0 }));
+        assert_eq!(offsets.offset(WorkCoordinateSystem::G59).unwrap().z, 3.0);
+        assert_eq!(offsets.g92, Some(vec![0.0, 0.0, 0.0]));
+        assert_eq!(offsets.active, Some(WorkCoordinateSystem::G55));
+
+        let fixture = offsets.capture_fixture("jig A".to_string(), Some(WorkCoordinateSystem::G59)).unwrap();
+        assert_eq!(fixture, Fixture { name: "jig A".to_string(), wcs: WorkCoordinateSystem::G59, x: 1.0, y: 2.0 });
+        // the active G55 wasn't in the report
+        assert_eq!(offsets.capture_fixture("jig B".to_string(), None), Err("The G55 offset wasn't read yet".to_string()));
+        assert!(CoordinateOffsets::default().capture_fixture("jig D".to_string(), None).is_err());
+    }
+
+    #[test]
+    fn builds_g10_commands() {
+        assert_eq!(set_position_command(None, &[(Axis::X, 0.0), (Axis::Y, 0.0), (Axis::Z, 0.0)]), "G10 L20 P0 X0 Y0 Z0");
+        assert_eq!(set_position_command(Some(WorkCoordinateSystem::G56), &[(Axis::Y, 5.5)]), "G10 L20 P3 Y5.5");
+        let fixture = Fixture { name: "left stop on jig A".to_string(), wcs: WorkCoordinateSystem::G55, x: 120.0, y: -4.0 };
+        assert_eq!(fixture.recall_commands(), vec!["G10 L2 P2 X120 Y-4", "G55"]);
+    }
+
+    #[test]
+    fn sets_and_reads_back_offsets() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig { time_scale: 100.0, ..Default::default() })));
+        simulator.lock().unwrap().receive(b"G0 X30 Y40\n");
+        let mut connection = connect_to_simulator(simulator.clone());
+
+        // zero x of G55 where the machine is, and set y directly
+        connection.zero_axis(Some(WorkCoordinateSystem::G55), Axis::X).unwrap();
+        connection.set_work_offset(WorkCoordinateSystem::G55, Axis::Y, 12.0).unwrap();
+        connection.select_work_coordinate_system(WorkCoordinateSystem::G55).unwrap();
+        connection.read_coordinate_offsets();
+
+        let offsets = wait_for_offsets(&connection, |offsets| offsets.active == Some(WorkCoordinateSystem::G55));
+        let g55 = offsets.offset(WorkCoordinateSystem::G55).unwrap();
+        assert_eq!((g55.x, g55.y), (30.0, 12.0));
+        assert_eq!(offsets.offset(WorkCoordinateSystem::G54).unwrap().x, 0.0);
+        let fixture = connection.capture_fixture("left stop".to_string(), None).unwrap();
+        assert_eq!((fixture.wcs, fixture.x, fixture.y), (WorkCoordinateSystem::G55, 30.0, 12.0));
+
+        // work zero of G55 is now at machine X30 Y12
+        connection.go_to_work_zero();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while simulator.lock().unwrap().position().y != 12.0 && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(simulator.lock().unwrap().position().x, 30.0);
+        assert_eq!(simulator.lock().unwrap().position().y, 12.0);
+        connection.disconnect();
+    }
+
+    #[test]
+    fn recalls_a_fixture() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+
+        connection.recall_fixture(&Fixture { name: "jig A".to_string(), wcs: WorkCoordinateSystem::G57, x: 7.0, y: 8.0 }).unwrap();
+        connection.read_coordinate_offsets();
+
+        let offsets = wait_for_offsets(&connection, |offsets| offsets.active == Some(WorkCoordinateSystem::G57));
+        assert_eq!(offsets.active, Some(WorkCoordinateSystem::G57));
+        let g57 = offsets.offset(WorkCoordinateSystem::G57).unwrap();
+        assert_eq!((g57.x, g57.y), (7.0, 8.0));
+        connection.disconnect();
+    }
+
+    #[test]
+    fn refuses_to_change_offsets_during_a_job() {
+        let simulator = Arc::new(Mutex::new(GrblSimulator::new(SimulatorConfig::default())));
+        let mut connection = connect_to_simulator(simulator.clone());
+        // more slow moves than fit in the planner, so the job still has lines to send
+        let program: String = (1..=40).map(|x| format!("G1 X{} F100\n", x)).collect();
+        connection.start_job_with(job_lines(&program), |_| {});
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !simulator.lock().unwrap().received_lines().iter().any(|line| line == "G1X1F100") && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert_eq!(connection.zero_axis(None, Axis::X), Err("Not possible while a job is running".to_string()));
+        assert!(connection.select_work_coordinate_system(WorkCoordinateSystem::G55).is_err());
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(!simulator.lock().unwrap().received_lines().iter().any(|line| line.starts_with("G10") || line == "G55"));
+        connection.abort_job();
+        connection.disconnect();
+    }
+}